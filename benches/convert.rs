@@ -3,6 +3,7 @@ use half::prelude::*;
 use std::{f32, f64, iter};
 
 const SIMD_LARGE_BENCH_SLICE_LEN: usize = 1024;
+const SIMD_HUGE_BENCH_SLICE_LEN: usize = 1 << 20;
 
 fn bench_f32_to_f16(c: &mut Criterion) {
     let mut group = c.benchmark_group("Convert f16 From f32");
@@ -88,12 +89,46 @@ fn bench_f16_to_f64(c: &mut Criterion) {
     }
 }
 
+// The `const` conversions always use the software fallback, so with the `lut` feature these
+// compare the lookup tables against it over inputs that take every branch of the fallback.
+fn bench_all_f16_values(c: &mut Criterion) {
+    let halves: Vec<f16> = (0..=u16::MAX).map(f16::from_bits).collect();
+    let floats: Vec<_> = halves
+        .iter()
+        .map(|h| h.to_f32() * (1. + f32::EPSILON))
+        .collect();
+    let mut group = c.benchmark_group("Convert all f16 values");
+    group.bench_function("f16::to_f32", |b| {
+        b.iter(|| halves.iter().map(|h| h.to_f32()).sum::<f32>())
+    });
+    group.bench_function("f16::to_f32_const", |b| {
+        b.iter(|| halves.iter().map(|h| h.to_f32_const()).sum::<f32>())
+    });
+    group.bench_function("f16::from_f32", |b| {
+        b.iter(|| {
+            floats
+                .iter()
+                .map(|f| f16::from_f32(*f).to_bits())
+                .fold(0, u16::wrapping_add)
+        })
+    });
+    group.bench_function("f16::from_f32_const", |b| {
+        b.iter(|| {
+            floats
+                .iter()
+                .map(|f| f16::from_f32_const(*f).to_bits())
+                .fold(0, u16::wrapping_add)
+        })
+    });
+}
+
 criterion_group!(
     f16_sisd,
     bench_f32_to_f16,
     bench_f64_to_f16,
     bench_f16_to_f32,
-    bench_f16_to_f64
+    bench_f16_to_f64,
+    bench_all_f16_values
 );
 
 fn bench_slice_f32_to_f16(c: &mut Criterion) {
@@ -126,6 +161,13 @@ fn bench_slice_f32_to_f16(c: &mut Criterion) {
         "HalfFloatSliceExt::convert_from_f32_slice/large",
         |b: &mut Bencher<'_>| b.iter(|| large_buffer.convert_from_f32_slice(&large)),
     );
+
+    let huge: Vec<_> = (0..SIMD_HUGE_BENCH_SLICE_LEN).map(|i| i as f32).collect();
+    let mut huge_buffer = vec![f16::ZERO; SIMD_HUGE_BENCH_SLICE_LEN];
+    c.bench_function(
+        "HalfFloatSliceExt::convert_from_f32_slice/huge",
+        |b: &mut Bencher<'_>| b.iter(|| huge_buffer.convert_from_f32_slice(&huge)),
+    );
 }
 
 fn bench_slice_f64_to_f16(c: &mut Criterion) {
@@ -180,7 +222,7 @@ fn bench_slice_f16_to_f32(c: &mut Criterion) {
         |b: &mut Bencher<'_>| b.iter(|| constants.convert_to_f32_slice(&mut constant_buffer)),
     );
 
-    let large: Vec<_> = iter::repeat(0)
+    let large: Vec<f16> = iter::repeat(0)
         .enumerate()
         .map(|(i, _)| f16::from_f32(i as f32))
         .take(SIMD_LARGE_BENCH_SLICE_LEN)
@@ -190,6 +232,15 @@ fn bench_slice_f16_to_f32(c: &mut Criterion) {
         "HalfFloatSliceExt::convert_to_f32_slice/large",
         |b: &mut Bencher<'_>| b.iter(|| large.convert_to_f32_slice(&mut large_buffer)),
     );
+
+    let huge: Vec<f16> = (0..SIMD_HUGE_BENCH_SLICE_LEN)
+        .map(|i| f16::from_bits(i as u16))
+        .collect();
+    let mut huge_buffer = vec![0f32; SIMD_HUGE_BENCH_SLICE_LEN];
+    c.bench_function(
+        "HalfFloatSliceExt::convert_to_f32_slice/huge",
+        |b: &mut Bencher<'_>| b.iter(|| huge.convert_to_f32_slice(&mut huge_buffer)),
+    );
 }
 
 fn bench_slice_f16_to_f64(c: &mut Criterion) {
@@ -212,7 +263,7 @@ fn bench_slice_f16_to_f64(c: &mut Criterion) {
         |b: &mut Bencher<'_>| b.iter(|| constants.convert_to_f64_slice(&mut constant_buffer)),
     );
 
-    let large: Vec<_> = iter::repeat(0)
+    let large: Vec<f16> = iter::repeat(0)
         .enumerate()
         .map(|(i, _)| f16::from_f64(i as f64))
         .take(SIMD_LARGE_BENCH_SLICE_LEN)
@@ -0,0 +1,131 @@
+use crate::{bf16, f16};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+macro_rules! impl_approx {
+    ($ty:ident) => {
+        impl AbsDiffEq for $ty {
+            type Epsilon = $ty;
+
+            #[inline]
+            fn default_epsilon() -> $ty {
+                $ty::EPSILON
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$ty, epsilon: $ty) -> bool {
+                let (a, b) = (self.to_f32(), other.to_f32());
+                (if a > b { a - b } else { b - a }) <= epsilon.to_f32()
+            }
+        }
+
+        impl RelativeEq for $ty {
+            #[inline]
+            fn default_max_relative() -> $ty {
+                $ty::EPSILON
+            }
+
+            fn relative_eq(&self, other: &$ty, epsilon: $ty, max_relative: $ty) -> bool {
+                // Handle same infinities
+                if self == other {
+                    return true;
+                }
+
+                // Handle remaining infinities
+                if self.is_infinite() || other.is_infinite() {
+                    return false;
+                }
+
+                let (a, b) = (self.to_f32(), other.to_f32());
+                let abs_diff = (a - b).abs();
+
+                // For when the numbers are really close together
+                if abs_diff <= epsilon.to_f32() {
+                    return true;
+                }
+
+                // Use a relative difference comparison
+                abs_diff <= a.abs().max(b.abs()) * max_relative.to_f32()
+            }
+        }
+
+        impl UlpsEq for $ty {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                4
+            }
+
+            fn ulps_eq(&self, other: &$ty, epsilon: $ty, max_ulps: u32) -> bool {
+                // For when the numbers are really close together
+                if self.abs_diff_eq(other, epsilon) {
+                    return true;
+                }
+
+                // Trivial negative sign check, which also rejects NaN
+                if self.signum() != other.signum() {
+                    return false;
+                }
+
+                // ULPS difference comparison on the 16-bit representation
+                let (bits, other_bits) = (self.to_bits(), other.to_bits());
+                u32::from(bits.max(other_bits) - bits.min(other_bits)) <= max_ulps
+            }
+        }
+    };
+}
+
+impl_approx!(f16);
+impl_approx!(bf16);
+
+#[cfg(test)]
+mod test {
+    use crate::{bf16, f16};
+    use approx::{
+        assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq, assert_ulps_ne,
+    };
+
+    #[test]
+    fn abs_diff_eq() {
+        let one = f16::ONE;
+        let next = f16::from_bits(one.to_bits() + 1);
+        assert_abs_diff_eq!(one, next, epsilon = f16::EPSILON);
+        assert_abs_diff_eq!(one, f16::from_f32(1.5), epsilon = f16::from_f32(0.5));
+        assert!(approx::abs_diff_ne!(
+            bf16::ONE,
+            bf16::from_f32(1.5),
+            epsilon = bf16::from_f32(0.25)
+        ));
+        assert!(approx::abs_diff_ne!(f16::NAN, f16::NAN));
+    }
+
+    #[test]
+    fn relative_eq() {
+        let x = f16::from_f32(1000.);
+        let next = f16::from_bits(x.to_bits() + 1);
+        assert_relative_eq!(x, next);
+        assert_relative_ne!(x, f16::from_bits(x.to_bits() + 2));
+        assert_relative_eq!(f16::INFINITY, f16::INFINITY);
+        assert_relative_ne!(f16::INFINITY, f16::MAX);
+        assert_relative_ne!(f16::NAN, f16::NAN);
+        assert_relative_eq!(
+            bf16::from_f32(100.),
+            bf16::from_f32(101.),
+            max_relative = bf16::from_f32(0.01)
+        );
+    }
+
+    #[test]
+    fn ulps_eq() {
+        let x = f16::from_f32(-3.);
+        assert_ulps_eq!(x, f16::from_bits(x.to_bits() + 4));
+        assert_ulps_ne!(x, f16::from_bits(x.to_bits() + 5));
+        assert_ulps_eq!(x, f16::from_bits(x.to_bits() + 5), max_ulps = 5);
+        assert_ulps_ne!(
+            f16::MIN_POSITIVE_SUBNORMAL,
+            -f16::MIN_POSITIVE_SUBNORMAL,
+            epsilon = f16::ZERO
+        );
+        assert_ulps_ne!(f16::NAN, f16::NAN);
+        let y = bf16::from_f32(1e30);
+        assert_ulps_eq!(y, bf16::from_bits(y.to_bits() - 3));
+    }
+}
@@ -0,0 +1,377 @@
+//! Contains atomic [`f16`] and [`bf16`] types, which can be safely shared between threads.
+//!
+//! [`AtomicF16`] and [`AtomicBf16`] store the bits of the value in an [`AtomicU16`], and have the
+//! same in-memory representation as [`f16`] and [`bf16`]. The arithmetic read-modify-write
+//! operations, such as [`AtomicF16::fetch_add`], are implemented with a compare-and-swap loop
+//! around the usual operators of the half precision types.
+//!
+//! Like the atomic types of the standard library, each operation takes an [`Ordering`] argument
+//! describing the memory ordering of the operation.
+//!
+//! This module is available with the `std` feature, which uses the native [`AtomicU16`] of the
+//! target. With the `portable-atomic` feature, the [`AtomicU16`] of the [`portable-atomic`] crate is
+//! used instead, which also supports targets without native 16-bit atomic operations and does not
+//! require `std`. Refer to its documentation for the configuration needed on those targets, for
+//! example on single-core `thumbv6m` microcontrollers.
+//!
+//! [`portable-atomic`]: https://crates.io/crates/portable-atomic
+
+use crate::{bf16, f16};
+#[cfg(not(feature = "portable-atomic"))]
+use core::sync::atomic::AtomicU16;
+use core::{
+    fmt::{Debug, Formatter},
+    sync::atomic::Ordering,
+};
+#[cfg(feature = "portable-atomic")]
+use portable_atomic::AtomicU16;
+
+/// Returns the strongest ordering allowed for the load of a failed compare-and-swap with `order`
+/// as the ordering of the whole read-modify-write operation.
+#[inline]
+fn failure_ordering(order: Ordering) -> Ordering {
+    match order {
+        Ordering::Release | Ordering::Relaxed => Ordering::Relaxed,
+        Ordering::AcqRel | Ordering::Acquire => Ordering::Acquire,
+        _ => Ordering::SeqCst,
+    }
+}
+
+macro_rules! impl_atomic {
+    ($name:ident, $ty:ident) => {
+        impl $name {
+            #[doc = concat!("Creates a new atomic [`", stringify!($ty), "`].")]
+            #[inline]
+            #[must_use]
+            pub const fn new(value: $ty) -> $name {
+                $name {
+                    bits: AtomicU16::new(value.to_bits()),
+                }
+            }
+
+            /// Returns a mutable reference to the underlying value.
+            ///
+            /// This is safe because the mutable reference guarantees that no other threads are
+            /// concurrently accessing the atomic data.
+            #[inline]
+            pub fn get_mut(&mut self) -> &mut $ty {
+                let pointer: *mut u16 = self.bits.get_mut();
+                // SAFETY: the type is `repr(transparent)` over `u16`, and the exclusive borrow of
+                // `self` is held by the returned reference.
+                unsafe { &mut *pointer.cast::<$ty>() }
+            }
+
+            /// Consumes the atomic and returns the contained value.
+            ///
+            /// This is safe because passing `self` by value guarantees that no other threads are
+            /// concurrently accessing the atomic data.
+            #[inline]
+            #[must_use]
+            pub fn into_inner(self) -> $ty {
+                $ty::from_bits(self.bits.into_inner())
+            }
+
+            /// Loads a value from the atomic.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `order` is [`Release`][Ordering::Release] or
+            /// [`AcqRel`][Ordering::AcqRel].
+            #[inline]
+            #[must_use]
+            pub fn load(&self, order: Ordering) -> $ty {
+                $ty::from_bits(self.bits.load(order))
+            }
+
+            /// Stores a value into the atomic.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `order` is [`Acquire`][Ordering::Acquire] or
+            /// [`AcqRel`][Ordering::AcqRel].
+            #[inline]
+            pub fn store(&self, value: $ty, order: Ordering) {
+                self.bits.store(value.to_bits(), order);
+            }
+
+            /// Stores a value into the atomic, returning the previous value.
+            #[inline]
+            pub fn swap(&self, value: $ty, order: Ordering) -> $ty {
+                $ty::from_bits(self.bits.swap(value.to_bits(), order))
+            }
+
+            /// Stores `new` into the atomic if the current value is the same as `current`.
+            ///
+            /// The values are compared by their bits rather than with [`PartialEq`], so a NaN
+            /// matches the same NaN and negative zero does not match positive zero. The return
+            /// value is a result indicating whether the new value was written and containing the
+            /// previous value, like [`AtomicU16::compare_exchange`].
+            ///
+            /// # Panics
+            ///
+            /// Panics if `failure` is [`Release`][Ordering::Release] or
+            /// [`AcqRel`][Ordering::AcqRel].
+            #[inline]
+            pub fn compare_exchange(
+                &self,
+                current: $ty,
+                new: $ty,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<$ty, $ty> {
+                self.bits
+                    .compare_exchange(current.to_bits(), new.to_bits(), success, failure)
+                    .map($ty::from_bits)
+                    .map_err($ty::from_bits)
+            }
+
+            /// Stores `new` into the atomic if the current value is the same as `current`.
+            ///
+            #[doc = concat!("Unlike [`compare_exchange`][", stringify!($name), "::compare_exchange], this function is allowed to spuriously fail even")]
+            /// when the comparison succeeds, which can result in more efficient code on some
+            /// platforms. The values are compared by their bits.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `failure` is [`Release`][Ordering::Release] or
+            /// [`AcqRel`][Ordering::AcqRel].
+            #[inline]
+            pub fn compare_exchange_weak(
+                &self,
+                current: $ty,
+                new: $ty,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<$ty, $ty> {
+                self.bits
+                    .compare_exchange_weak(current.to_bits(), new.to_bits(), success, failure)
+                    .map($ty::from_bits)
+                    .map_err($ty::from_bits)
+            }
+
+            /// Fetches the value, and applies a function to it that returns an optional new value.
+            ///
+            /// Returns `Ok(previous_value)` if the function returned `Some(_)`, else
+            /// `Err(previous_value)`. The function may be called multiple times if the value was
+            /// changed by other threads in the meantime, like [`AtomicU16::fetch_update`].
+            ///
+            /// # Panics
+            ///
+            /// Panics if `fetch_order` is [`Release`][Ordering::Release] or
+            /// [`AcqRel`][Ordering::AcqRel].
+            #[inline]
+            pub fn fetch_update<F>(
+                &self,
+                set_order: Ordering,
+                fetch_order: Ordering,
+                mut f: F,
+            ) -> Result<$ty, $ty>
+            where
+                F: FnMut($ty) -> Option<$ty>,
+            {
+                self.bits
+                    .fetch_update(set_order, fetch_order, |bits| {
+                        f($ty::from_bits(bits)).map($ty::to_bits)
+                    })
+                    .map($ty::from_bits)
+                    .map_err($ty::from_bits)
+            }
+
+            /// Adds to the current value, returning the previous value.
+            ///
+            /// The sum is rounded like the `+` operator.
+            #[inline]
+            pub fn fetch_add(&self, value: $ty, order: Ordering) -> $ty {
+                self.update(order, |x| x + value)
+            }
+
+            /// Subtracts from the current value, returning the previous value.
+            ///
+            /// The difference is rounded like the `-` operator.
+            #[inline]
+            pub fn fetch_sub(&self, value: $ty, order: Ordering) -> $ty {
+                self.update(order, |x| x - value)
+            }
+
+            #[doc = concat!("Stores the maximum of the current value and `value` like [`", stringify!($ty), "::max`], returning the previous value.")]
+            #[inline]
+            pub fn fetch_max(&self, value: $ty, order: Ordering) -> $ty {
+                self.update(order, |x| x.max(value))
+            }
+
+            #[doc = concat!("Stores the minimum of the current value and `value` like [`", stringify!($ty), "::min`], returning the previous value.")]
+            #[inline]
+            pub fn fetch_min(&self, value: $ty, order: Ordering) -> $ty {
+                self.update(order, |x| x.min(value))
+            }
+
+            #[inline]
+            fn update(&self, order: Ordering, mut f: impl FnMut($ty) -> $ty) -> $ty {
+                match self.fetch_update(order, failure_ordering(order), |x| Some(f(x))) {
+                    Ok(previous) | Err(previous) => previous,
+                }
+            }
+        }
+
+        impl Default for $name {
+            #[inline]
+            fn default() -> $name {
+                $name::new($ty::ZERO)
+            }
+        }
+
+        impl From<$ty> for $name {
+            #[inline]
+            fn from(value: $ty) -> $name {
+                $name::new(value)
+            }
+        }
+
+        impl Debug for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Debug::fmt(&self.load(Ordering::Relaxed), f)
+            }
+        }
+    };
+}
+
+/// A [`f16`] type which can be safely shared between threads.
+///
+/// This type has the same in-memory representation as [`f16`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::{atomic::AtomicF16, f16};
+/// use std::{sync::{atomic::Ordering, Arc}, thread};
+///
+/// let total = Arc::new(AtomicF16::new(f16::ZERO));
+/// let threads: Vec<_> = (0..4)
+///     .map(|_| {
+///         let total = Arc::clone(&total);
+///         thread::spawn(move || total.fetch_add(f16::from_f32(0.25), Ordering::Relaxed))
+///     })
+///     .collect();
+/// for thread in threads {
+///     thread.join().unwrap();
+/// }
+/// assert_eq!(total.load(Ordering::Relaxed), f16::ONE);
+/// ```
+#[repr(transparent)]
+pub struct AtomicF16 {
+    bits: AtomicU16,
+}
+
+/// A [`bf16`] type which can be safely shared between threads.
+///
+/// This type has the same in-memory representation as [`bf16`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::{atomic::AtomicBf16, bf16};
+/// use std::sync::atomic::Ordering;
+///
+/// let peak = AtomicBf16::new(bf16::NEG_INFINITY);
+/// for x in [1., 7., -3.] {
+///     peak.fetch_max(bf16::from_f32(x), Ordering::Relaxed);
+/// }
+/// assert_eq!(peak.into_inner(), bf16::from_f32(7.));
+/// ```
+#[repr(transparent)]
+pub struct AtomicBf16 {
+    bits: AtomicU16,
+}
+
+impl_atomic!(AtomicF16, f16);
+impl_atomic!(AtomicBf16, bf16);
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use super::{AtomicBf16, AtomicF16};
+    use crate::{bf16, f16};
+    use core::sync::atomic::Ordering;
+    use std::{format, sync::Arc, thread, vec::Vec};
+
+    #[test]
+    fn load_store_swap() {
+        let mut a = AtomicF16::default();
+        assert_eq!(a.load(Ordering::SeqCst), f16::ZERO);
+        a.store(f16::ONE, Ordering::Release);
+        assert_eq!(a.swap(f16::NAN, Ordering::AcqRel), f16::ONE);
+        assert!(a.load(Ordering::Acquire).is_nan());
+        *a.get_mut() = f16::E;
+        assert_eq!(a.into_inner(), f16::E);
+        assert_eq!(format!("{:?}", AtomicBf16::from(bf16::ONE)), "1.0");
+    }
+
+    #[test]
+    fn compare_exchange() {
+        let a = AtomicF16::new(f16::NAN);
+        assert_eq!(
+            a.compare_exchange(f16::NAN, f16::ONE, Ordering::SeqCst, Ordering::SeqCst)
+                .map(f16::to_bits),
+            Ok(f16::NAN.to_bits())
+        );
+        assert_eq!(
+            a.compare_exchange(f16::ZERO, f16::ONE, Ordering::SeqCst, Ordering::Relaxed),
+            Err(f16::ONE)
+        );
+
+        let b = AtomicBf16::new(bf16::ZERO);
+        assert_eq!(
+            b.compare_exchange(
+                bf16::NEG_ZERO,
+                bf16::ONE,
+                Ordering::SeqCst,
+                Ordering::SeqCst
+            ),
+            Err(bf16::ZERO)
+        );
+        while b
+            .compare_exchange_weak(bf16::ZERO, bf16::ONE, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {}
+        assert_eq!(b.load(Ordering::SeqCst), bf16::ONE);
+        assert_eq!(
+            b.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |_| None),
+            Err(bf16::ONE)
+        );
+    }
+
+    #[test]
+    fn fetch_ops() {
+        let a = AtomicF16::new(f16::ONE);
+        assert_eq!(a.fetch_add(f16::from_f32(2.), Ordering::Relaxed), f16::ONE);
+        assert_eq!(a.fetch_sub(f16::ONE, Ordering::Release), f16::from_f32(3.));
+        assert_eq!(a.fetch_max(f16::NAN, Ordering::AcqRel), f16::from_f32(2.));
+        assert_eq!(
+            a.fetch_min(f16::NEG_ONE, Ordering::SeqCst),
+            f16::from_f32(2.)
+        );
+        assert_eq!(
+            a.fetch_max(f16::from_f32(-5.), Ordering::Acquire),
+            f16::NEG_ONE
+        );
+        assert_eq!(a.load(Ordering::Relaxed), f16::NEG_ONE);
+    }
+
+    #[test]
+    fn concurrent_fetch_add() {
+        let total = Arc::new(AtomicBf16::new(bf16::ZERO));
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                let total = Arc::clone(&total);
+                thread::spawn(move || {
+                    for _ in 0..32 {
+                        total.fetch_add(bf16::ONE, Ordering::Relaxed);
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        assert_eq!(total.load(Ordering::SeqCst), bf16::from_f32(128.));
+    }
+}
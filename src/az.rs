@@ -0,0 +1,289 @@
+//! Casts of the [`az`] crate between [`f16`] or [`bf16`] and the primitive numbers.
+//!
+//! Casts from half precision values to integers go through [`f32`], which represents every value
+//! exactly, so they behave exactly like the casts of [`f32`]. Casts to half precision values round
+//! to nearest once. Like `as`, [`Cast`] rounds values that are too large to an infinity, while
+//! [`CheckedCast`] returns [`None`] and [`UnwrappedCast`] panics if a finite value would become
+//! infinite, and [`SaturatingCast`] returns [`f16::MAX`] or [`f16::MIN`] instead. NaNs and
+//! infinities are cast to themselves by all of them.
+//!
+//! [`az`]: https://crates.io/crates/az
+//! [`f16::MAX`]: crate::f16::MAX
+//! [`f16::MIN`]: crate::f16::MIN
+
+use crate::{bf16, f16};
+use az::{Cast, CheckedCast, OverflowingCast, SaturatingCast, UnwrappedCast, WrappingCast};
+
+macro_rules! impl_half_to_int {
+    ($half:ty; $($int:ty)*) => {$(
+        impl Cast<$int> for $half {
+            #[inline]
+            #[track_caller]
+            fn cast(self) -> $int {
+                self.to_f32().cast()
+            }
+        }
+
+        impl CheckedCast<$int> for $half {
+            #[inline]
+            fn checked_cast(self) -> Option<$int> {
+                self.to_f32().checked_cast()
+            }
+        }
+
+        impl SaturatingCast<$int> for $half {
+            #[inline]
+            #[track_caller]
+            fn saturating_cast(self) -> $int {
+                self.to_f32().saturating_cast()
+            }
+        }
+
+        impl WrappingCast<$int> for $half {
+            #[inline]
+            #[track_caller]
+            fn wrapping_cast(self) -> $int {
+                self.to_f32().wrapping_cast()
+            }
+        }
+
+        impl OverflowingCast<$int> for $half {
+            #[inline]
+            #[track_caller]
+            fn overflowing_cast(self) -> ($int, bool) {
+                self.to_f32().overflowing_cast()
+            }
+        }
+
+        impl UnwrappedCast<$int> for $half {
+            #[inline]
+            #[track_caller]
+            fn unwrapped_cast(self) -> $int {
+                self.to_f32().unwrapped_cast()
+            }
+        }
+    )*};
+}
+
+impl_half_to_int!(f16; i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+impl_half_to_int!(bf16; i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+// Casts to a half precision type, given the rounding conversion of each source type.
+macro_rules! impl_to_half {
+    ($half:ident; $($src:ty => |$x:ident| $convert:expr;)*) => {$(
+        impl Cast<$half> for $src {
+            #[inline]
+            fn cast(self) -> $half {
+                let $x = self;
+                $convert
+            }
+        }
+
+        impl CheckedCast<$half> for $src {
+            #[inline]
+            fn checked_cast(self) -> Option<$half> {
+                let $x = self;
+                let value: $half = $convert;
+                if value.is_infinite() && !is_infinite(self) {
+                    None
+                } else {
+                    Some(value)
+                }
+            }
+        }
+
+        impl SaturatingCast<$half> for $src {
+            #[inline]
+            fn saturating_cast(self) -> $half {
+                let $x = self;
+                let value: $half = $convert;
+                if value.is_infinite() && !is_infinite(self) {
+                    if value.is_sign_negative() {
+                        $half::MIN
+                    } else {
+                        $half::MAX
+                    }
+                } else {
+                    value
+                }
+            }
+        }
+
+        impl UnwrappedCast<$half> for $src {
+            #[inline]
+            #[track_caller]
+            fn unwrapped_cast(self) -> $half {
+                match self.checked_cast() {
+                    Some(value) => value,
+                    None => panic!("overflow"),
+                }
+            }
+        }
+    )*};
+}
+
+impl_to_half! {
+    f16;
+    i8 => |x| f16::from(x);
+    i16 => |x| f16::from_i16(x);
+    i32 => |x| f16::from_i32(x);
+    i64 => |x| f16::from_i64(x);
+    i128 => |x| f16::from_fixed(x, 0);
+    isize => |x| f16::from_i64(x as i64);
+    u8 => |x| f16::from(x);
+    u16 => |x| f16::from_u16(x);
+    u32 => |x| f16::from_u32(x);
+    u64 => |x| f16::from_u64(x);
+    u128 => |x| f16::from_fixed(x, 0);
+    usize => |x| f16::from_u64(x as u64);
+    f32 => |x| f16::from_f32(x);
+    f64 => |x| f16::from_f64(x);
+    bf16 => |x| f16::from_bf16(x);
+}
+
+impl_to_half! {
+    bf16;
+    i8 => |x| bf16::from(x);
+    i16 => |x| bf16::from_i16(x);
+    i32 => |x| bf16::from_i32(x);
+    i64 => |x| bf16::from_i64(x);
+    i128 => |x| bf16::from_fixed(x, 0);
+    isize => |x| bf16::from_i64(x as i64);
+    u8 => |x| bf16::from(x);
+    u16 => |x| bf16::from_u16(x);
+    u32 => |x| bf16::from_u32(x);
+    u64 => |x| bf16::from_u64(x);
+    u128 => |x| bf16::from_fixed(x, 0);
+    usize => |x| bf16::from_u64(x as u64);
+    f32 => |x| bf16::from_f32(x);
+    f64 => |x| bf16::from_f64(x);
+    f16 => |x| bf16::from_f16(x);
+}
+
+// Casts from a half precision type to a wider float type, which are always exact.
+macro_rules! impl_half_to_float {
+    ($($half:ty => $float:ty, $convert:ident;)*) => {$(
+        impl Cast<$float> for $half {
+            #[inline]
+            fn cast(self) -> $float {
+                self.$convert()
+            }
+        }
+
+        impl CheckedCast<$float> for $half {
+            #[inline]
+            fn checked_cast(self) -> Option<$float> {
+                Some(self.$convert())
+            }
+        }
+
+        impl UnwrappedCast<$float> for $half {
+            #[inline]
+            fn unwrapped_cast(self) -> $float {
+                self.$convert()
+            }
+        }
+    )*};
+}
+
+impl_half_to_float! {
+    f16 => f32, to_f32;
+    f16 => f64, to_f64;
+    bf16 => f32, to_f32;
+    bf16 => f64, to_f64;
+}
+
+/// Whether a source value of a cast is infinite, so that an infinite result is not an overflow.
+trait IsInfinite: Copy {
+    fn is_infinite(self) -> bool;
+}
+
+#[inline]
+fn is_infinite<T: IsInfinite>(value: T) -> bool {
+    value.is_infinite()
+}
+
+macro_rules! impl_is_infinite {
+    (finite: $($int:ty)*; float: $($float:ty)*) => {
+        $(
+            impl IsInfinite for $int {
+                #[inline]
+                fn is_infinite(self) -> bool {
+                    false
+                }
+            }
+        )*
+        $(
+            impl IsInfinite for $float {
+                #[inline]
+                fn is_infinite(self) -> bool {
+                    <$float>::is_infinite(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_is_infinite!(
+    finite: i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize;
+    float: f32 f64 f16 bf16
+);
+
+#[allow(clippy::float_cmp)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use az::{Az, CheckedAs, SaturatingAs};
+
+    #[test]
+    fn test_half_to_int() {
+        let x = f16::from_f32(300.7);
+        assert_eq!(x.az::<i32>(), 300);
+        assert_eq!(x.checked_as::<u8>(), None);
+        assert_eq!(x.checked_as::<u16>(), Some(300));
+        assert_eq!(x.saturating_as::<u8>(), 255);
+        assert_eq!((-x).saturating_as::<u8>(), 0);
+        assert_eq!(WrappingCast::<u8>::wrapping_cast(x), 44);
+        assert_eq!(OverflowingCast::<u8>::overflowing_cast(x), (44, true));
+        assert_eq!(f16::NAN.checked_as::<i32>(), None);
+        assert_eq!(bf16::INFINITY.checked_as::<i128>(), None);
+        assert_eq!(bf16::MAX.saturating_as::<u64>(), u64::MAX);
+        assert_eq!(
+            bf16::MIN.saturating_as::<i128>(),
+            bf16::MIN.to_f32() as i128
+        );
+    }
+
+    #[test]
+    fn test_to_half() {
+        assert_eq!(1000u32.az::<f16>(), f16::from_f32(1000.));
+        assert_eq!(70000i32.az::<f16>(), f16::INFINITY);
+        assert_eq!(70000i32.checked_as::<f16>(), None);
+        assert_eq!(70000i32.saturating_as::<f16>(), f16::MAX);
+        assert_eq!((-1e10f64).saturating_as::<f16>(), f16::MIN);
+        assert_eq!(i128::MIN.checked_as::<f16>(), None);
+        assert_eq!(u128::MAX.checked_as::<bf16>(), None);
+        assert_eq!(
+            (u128::MAX >> 1).checked_as::<bf16>().map(bf16::to_f32),
+            Some((1u128 << 127) as f32)
+        );
+        assert_eq!(0.1f32.checked_as::<f16>(), Some(f16::from_f32(0.1)));
+        assert_eq!(bf16::MAX.saturating_as::<f16>(), f16::MAX);
+        assert_eq!(f16::MAX.checked_as::<bf16>(), Some(bf16::from_f32(65536.)));
+
+        // Infinities and NaNs are not overflows
+        assert_eq!(f32::INFINITY.checked_as::<f16>(), Some(f16::INFINITY));
+        assert_eq!(
+            f64::NEG_INFINITY.saturating_as::<bf16>(),
+            bf16::NEG_INFINITY
+        );
+        assert!(f32::NAN.checked_as::<f16>().unwrap().is_nan());
+        assert_eq!(f16::ONE.checked_as::<f64>(), Some(1.));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn test_unwrapped_overflow() {
+        let _: f16 = UnwrappedCast::<f16>::unwrapped_cast(1e6f32);
+    }
+}
@@ -1,3 +1,18 @@
+#[cfg(any(feature = "std", feature = "math"))]
+use crate::math;
+use crate::{
+    exceptions, f16, flush, int,
+    rounding::{self, Rounding},
+    ExceptionFlags, FloatToInt, FlushMode, RoundingMode,
+};
+#[cfg(not(target_arch = "spirv"))]
+use crate::{
+    fmt,
+    iter::{AllFinite, AllValues},
+    parse, ParseError, TryFromFloatError,
+};
+#[cfg(all(feature = "alloc", not(target_arch = "spirv")))]
+use alloc::string::{String, ToString};
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
 use core::{
@@ -17,7 +32,7 @@ use core::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "zerocopy")]
-use zerocopy::{AsBytes, FromBytes};
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
 
 pub(crate) mod convert;
 
@@ -32,13 +47,25 @@ pub(crate) mod convert;
 /// compact storage rather than calculations. Operations should be performed with [`f32`] or
 /// higher-precision types and converted to/from [`bf16`] as necessary.
 ///
+/// [`bf16`] is `#[repr(transparent)]` around the [`u16`] returned by [`to_bits`][Self::to_bits], so
+/// it has the same size, alignment and function call ABI as [`u16`] and the C `uint16_t` type.
+///
 /// [`bfloat16`]: https://en.wikipedia.org/wiki/Bfloat16_floating-point_format
 #[allow(non_camel_case_types)]
 #[derive(Clone, Copy, Default)]
 #[repr(transparent)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 #[cfg_attr(feature = "bytemuck", derive(Zeroable, Pod))]
-#[cfg_attr(feature = "zerocopy", derive(AsBytes, FromBytes))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(FromBytes, Immutable, IntoBytes, KnownLayout)
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(archived = "ArchivedBf16", resolver = "Bf16Resolver"),
+    archive_attr(derive(Clone, Copy, Debug))
+)]
 pub struct bf16(u16);
 
 impl bf16 {
@@ -103,6 +130,417 @@ impl bf16 {
         bf16(convert::f64_to_bf16(value))
     }
 
+    /// Constructs a [`bf16`] value from a 32-bit floating point value, rounding in the given
+    /// direction.
+    ///
+    /// With [`RoundingMode::NearestEven`] this gives the same result as
+    /// [`from_f32`][Self::from_f32]. The other modes also decide whether values too large for
+    /// [`bf16`] become ±∞ or the largest finite value of the same sign. NaN values are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// use half::RoundingMode;
+    ///
+    /// let third = 1.0 / 3.0;
+    /// let down = bf16::from_f32_round(third, RoundingMode::TowardNegative);
+    /// let up = bf16::from_f32_round(third, RoundingMode::TowardPositive);
+    /// assert!(down.to_f32() < third && third < up.to_f32());
+    /// assert_eq!(up.to_bits() - down.to_bits(), 1);
+    ///
+    /// assert_eq!(bf16::from_f32_round(f32::MAX, RoundingMode::TowardZero), bf16::MAX);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_f32_round(value: f32, mode: RoundingMode) -> bf16 {
+        bf16(rounding::f32_to_ieee_round(
+            value,
+            Rounding::Mode(mode),
+            7,
+            8,
+        ))
+    }
+
+    /// Constructs a [`bf16`] value from a 64-bit floating point value, rounding in the given
+    /// direction.
+    ///
+    /// With [`RoundingMode::NearestEven`] this gives the same result as
+    /// [`from_f64`][Self::from_f64]. The other modes also decide whether values too large for
+    /// [`bf16`] become ±∞ or the largest finite value of the same sign. NaN values are preserved.
+    #[inline]
+    #[must_use]
+    pub const fn from_f64_round(value: f64, mode: RoundingMode) -> bf16 {
+        bf16(rounding::f64_to_ieee_round(
+            value,
+            Rounding::Mode(mode),
+            7,
+            8,
+        ))
+    }
+
+    /// Constructs a [`bf16`] value from a 32-bit floating point value by truncating the low 16 bits
+    /// of the [`f32`] representation.
+    ///
+    /// This is the conversion many machine learning frameworks and accelerators use: it is cheaper
+    /// than [`from_f32`][Self::from_f32], but always rounds toward zero, so the result may be one
+    /// unit in the last place further from `value` than the nearest [`bf16`]. Apart from NaN
+    /// payloads, the result is the same as [`from_f32_round`][Self::from_f32_round] with
+    /// [`RoundingMode::TowardZero`]. Unlike plain bit truncation, NaN values always stay NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// // Just below the next bf16 value after 1.0
+    /// let x = f32::from_bits(0x3F80_FFFF);
+    /// assert_eq!(bf16::from_f32(x).to_bits(), 0x3F81);
+    /// assert_eq!(bf16::from_f32_truncate(x).to_bits(), 0x3F80);
+    ///
+    /// assert_eq!(bf16::from_f32_truncate(f32::MAX), bf16::MAX);
+    /// assert!(bf16::from_f32_truncate(f32::from_bits(0x7F80_0001)).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_f32_truncate(value: f32) -> bf16 {
+        bf16(convert::f32_to_bf16_truncate(value))
+    }
+
+    /// Constructs a [`bf16`] value from a 32-bit floating point value using stochastic rounding.
+    ///
+    /// The value is rounded away from zero with a probability equal to the fraction of a unit in
+    /// the last place that would otherwise be truncated, and toward zero otherwise. `rng_bits`
+    /// must be uniformly random for each call; since [`bf16`] keeps the upper half of the [`f32`]
+    /// bits, this amounts to adding `rng_bits` to the lower half before truncating it.
+    ///
+    /// Values whose rounded magnitude would exceed [`bf16::MAX`] become ±∞, and NaN values are
+    /// preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// // A quarter of the way between 1.0 and the next bf16 value
+    /// let x = 1.0 + bf16::EPSILON.to_f32() / 4.0;
+    /// assert_eq!(bf16::from_f32_stochastic(x, 0xBFFF), bf16::ONE);
+    /// assert_eq!(bf16::from_f32_stochastic(x, 0xC000), bf16::ONE + bf16::EPSILON);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_f32_stochastic(value: f32, rng_bits: u16) -> bf16 {
+        bf16(rounding::f32_to_ieee_round(
+            value,
+            Rounding::Stochastic(rng_bits),
+            7,
+            8,
+        ))
+    }
+
+    /// Constructs a [`bf16`] value from a 32-bit floating point value, flushing subnormal inputs
+    /// or results to zero as selected by `mode`.
+    ///
+    /// With [`FlushMode::Preserve`] this is the same as [`from_f32`][Self::from_f32]. Flushed
+    /// values keep their sign.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// use half::FlushMode;
+    ///
+    /// // bf16 shares the exponent range of f32, so subnormal inputs can give subnormal results
+    /// let subnormal = f32::from_bits(0x0040_0000);
+    /// assert_eq!(bf16::from_f32_flush(subnormal, FlushMode::Preserve).to_bits(), 0x0040);
+    /// assert_eq!(bf16::from_f32_flush(subnormal, FlushMode::FlushToZero), bf16::ZERO);
+    ///
+    /// // Without DAZ the largest f32 subnormal rounds up to the smallest normal bf16
+    /// let largest = f32::from_bits(0x007F_FFFF);
+    /// assert_eq!(bf16::from_f32_flush(largest, FlushMode::FlushToZero), bf16::MIN_POSITIVE);
+    /// assert_eq!(bf16::from_f32_flush(largest, FlushMode::Both), bf16::ZERO);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_f32_flush(value: f32, mode: FlushMode) -> bf16 {
+        let value = if mode.flushes_inputs() {
+            flush::flush_f32(value)
+        } else {
+            value
+        };
+        let result = bf16::from_f32(value);
+        if mode.flushes_outputs() {
+            result.flush_subnormal_to_zero()
+        } else {
+            result
+        }
+    }
+
+    /// Constructs a [`bf16`] value from a 64-bit floating point value, flushing subnormal inputs
+    /// or results to zero as selected by `mode`.
+    ///
+    /// With [`FlushMode::Preserve`] this is the same as [`from_f64`][Self::from_f64].
+    #[inline]
+    #[must_use]
+    pub fn from_f64_flush(value: f64, mode: FlushMode) -> bf16 {
+        let value = if mode.flushes_inputs() {
+            flush::flush_f64(value)
+        } else {
+            value
+        };
+        let result = bf16::from_f64(value);
+        if mode.flushes_outputs() {
+            result.flush_subnormal_to_zero()
+        } else {
+            result
+        }
+    }
+
+    /// Constructs a [`bf16`] value from a 32-bit floating point value, also returning the
+    /// floating point exceptions raised by the conversion.
+    ///
+    /// The result is the same as [`from_f32`][Self::from_f32]. A signaling NaN raises
+    /// [`ExceptionFlags::INVALID`], and a rounded result raises [`ExceptionFlags::INEXACT`] along
+    /// with [`ExceptionFlags::OVERFLOW`] if it rounded to infinity, or
+    /// [`ExceptionFlags::UNDERFLOW`] if the value was below [`bf16::MIN_POSITIVE`] in magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// use half::ExceptionFlags;
+    ///
+    /// let (x, flags) = bf16::from_f32_with_flags(0.5);
+    /// assert_eq!(x, bf16::from_f32(0.5));
+    /// assert!(flags.is_empty());
+    ///
+    /// let (_, flags) = bf16::from_f32_with_flags(0.1);
+    /// assert_eq!(flags, ExceptionFlags::INEXACT);
+    ///
+    /// let (x, flags) = bf16::from_f32_with_flags(f32::MAX);
+    /// assert_eq!(x, bf16::INFINITY);
+    /// assert_eq!(flags, ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_f32_with_flags(value: f32) -> (bf16, ExceptionFlags) {
+        let result = bf16::from_f32(value);
+        let signaling = value.is_nan() && value.to_bits() & 0x0040_0000 == 0;
+        let flags = exceptions::conversion_flags(
+            f64::from(value),
+            signaling,
+            result.to_f64(),
+            bf16::MIN_POSITIVE.to_f64(),
+        );
+        (result, flags)
+    }
+
+    /// Constructs a [`bf16`] value from a 64-bit floating point value, also returning the
+    /// floating point exceptions raised by the conversion.
+    ///
+    /// The result is the same as [`from_f64`][Self::from_f64], and the exceptions are those of
+    /// [`from_f32_with_flags`][Self::from_f32_with_flags].
+    #[inline]
+    #[must_use]
+    pub fn from_f64_with_flags(value: f64) -> (bf16, ExceptionFlags) {
+        let result = bf16::from_f64(value);
+        let signaling = value.is_nan() && value.to_bits() & 0x0008_0000_0000_0000 == 0;
+        let flags = exceptions::conversion_flags(
+            value,
+            signaling,
+            result.to_f64(),
+            bf16::MIN_POSITIVE.to_f64(),
+        );
+        (result, flags)
+    }
+
+    /// Constructs a [`bf16`] value from a 32-bit floating point value, saturating finite values
+    /// that are too large to [`bf16::MAX`] or [`bf16::MIN`] instead of producing ±∞.
+    ///
+    /// Otherwise identical to [`from_f32`][Self::from_f32]: values are rounded to nearest, and
+    /// infinities and NaN values are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32_saturating(f32::MAX), bf16::MAX);
+    /// assert_eq!(bf16::from_f32_saturating(-f32::MAX), bf16::MIN);
+    /// assert_eq!(bf16::from_f32_saturating(f32::NEG_INFINITY), bf16::NEG_INFINITY);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_f32_saturating(value: f32) -> bf16 {
+        let result = bf16::from_f32(value);
+        if result.is_infinite() && value.is_finite() {
+            bf16::MAX.copysign(result)
+        } else {
+            result
+        }
+    }
+
+    /// Constructs a [`bf16`] value from a 64-bit floating point value, saturating finite values
+    /// that are too large to [`bf16::MAX`] or [`bf16::MIN`] instead of producing ±∞.
+    ///
+    /// Otherwise identical to [`from_f64`][Self::from_f64]: values are rounded to nearest, and
+    /// infinities and NaN values are preserved.
+    #[inline]
+    #[must_use]
+    pub fn from_f64_saturating(value: f64) -> bf16 {
+        let result = bf16::from_f64(value);
+        if result.is_infinite() && value.is_finite() {
+            bf16::MAX.copysign(result)
+        } else {
+            result
+        }
+    }
+
+    /// Constructs a [`bf16`] value from a 32-bit floating point value only if no rounding is
+    /// needed, returning [`None`] otherwise.
+    ///
+    /// Values that would overflow to ±∞ or underflow to zero are not exact either. NaN values are
+    /// always converted, keeping as much of the payload as fits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32_exact(0.375), Some(bf16::from_f32(0.375)));
+    /// assert_eq!(bf16::from_f32_exact(1.001), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_f32_exact(value: f32) -> Option<bf16> {
+        let result = bf16::from_f32(value);
+        if value.is_nan() || result.to_f32() == value {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Constructs a [`bf16`] value from a 64-bit floating point value only if no rounding is
+    /// needed, returning [`None`] otherwise.
+    ///
+    /// Values that would overflow to ±∞ or underflow to zero are not exact either. NaN values are
+    /// always converted, keeping as much of the payload as fits.
+    #[inline]
+    #[must_use]
+    pub fn from_f64_exact(value: f64) -> Option<bf16> {
+        let result = bf16::from_f64(value);
+        if value.is_nan() || result.to_f64() == value {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the 32-bit floating point value can be converted to [`bf16`] without
+    /// rounding.
+    ///
+    /// This is equivalent to `bf16::from_f32_exact(value).is_some()`, so NaN values are considered
+    /// representable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert!(bf16::is_exactly_representable(0.375));
+    /// assert!(!bf16::is_exactly_representable(1.001));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_exactly_representable(value: f32) -> bool {
+        bf16::from_f32_exact(value).is_some()
+    }
+
+    /// Constructs a [`bf16`] value from a [`f16`] value in a single rounding step.
+    ///
+    /// Every [`f16`] value, including subnormals, is within the normal range of [`bf16`], but
+    /// [`bf16`] has three fewer mantissa bits, so values are rounded to nearest, ties to even.
+    /// Infinities and NaN values are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f16(f16::from_f32(1.5)), bf16::from_f32(1.5));
+    /// assert_eq!(bf16::from_f16(f16::MAX), bf16::from_f32(65536.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_f16(value: f16) -> bf16 {
+        bf16(rounding::ieee16_to_ieee_round(
+            value.to_bits(),
+            10,
+            5,
+            Rounding::Mode(RoundingMode::NearestEven),
+            7,
+            8,
+        ))
+    }
+
+    /// Constructs a [`bf16`] from a 16-bit signed integer.
+    ///
+    /// Integers with a magnitude above 256 are rounded to the nearest representable value, with ties
+    /// rounding to even. Unlike converting through [`f32`] first, this never rounds twice.
+    #[inline]
+    #[must_use]
+    pub const fn from_i16(value: i16) -> bf16 {
+        let sign = if value < 0 { 0x8000 } else { 0 };
+        bf16(convert::u64_to_bf16(sign, value.unsigned_abs() as u64))
+    }
+
+    /// Constructs a [`bf16`] from a 16-bit unsigned integer.
+    ///
+    /// Integers with a magnitude above 256 are rounded to the nearest representable value, with ties
+    /// rounding to even. Unlike converting through [`f32`] first, this never rounds twice.
+    #[inline]
+    #[must_use]
+    pub const fn from_u16(value: u16) -> bf16 {
+        bf16(convert::u64_to_bf16(0, value as u64))
+    }
+
+    /// Constructs a [`bf16`] from a 32-bit signed integer.
+    ///
+    /// Integers with a magnitude above 256 are rounded to the nearest representable value, with ties
+    /// rounding to even. Unlike converting through [`f32`] first, this never rounds twice.
+    #[inline]
+    #[must_use]
+    pub const fn from_i32(value: i32) -> bf16 {
+        let sign = if value < 0 { 0x8000 } else { 0 };
+        bf16(convert::u64_to_bf16(sign, value.unsigned_abs() as u64))
+    }
+
+    /// Constructs a [`bf16`] from a 32-bit unsigned integer.
+    ///
+    /// Integers with a magnitude above 256 are rounded to the nearest representable value, with ties
+    /// rounding to even. Unlike converting through [`f32`] first, this never rounds twice.
+    #[inline]
+    #[must_use]
+    pub const fn from_u32(value: u32) -> bf16 {
+        bf16(convert::u64_to_bf16(0, value as u64))
+    }
+
+    /// Constructs a [`bf16`] from a 64-bit signed integer.
+    ///
+    /// Integers with a magnitude above 256 are rounded to the nearest representable value, with ties
+    /// rounding to even. Unlike converting through [`f32`] first, this never rounds twice.
+    #[inline]
+    #[must_use]
+    pub const fn from_i64(value: i64) -> bf16 {
+        let sign = if value < 0 { 0x8000 } else { 0 };
+        bf16(convert::u64_to_bf16(sign, value.unsigned_abs()))
+    }
+
+    /// Constructs a [`bf16`] from a 64-bit unsigned integer.
+    ///
+    /// Integers with a magnitude above 256 are rounded to the nearest representable value, with ties
+    /// rounding to even. Unlike converting through [`f32`] first, this never rounds twice.
+    #[inline]
+    #[must_use]
+    pub const fn from_u64(value: u64) -> bf16 {
+        bf16(convert::u64_to_bf16(0, value))
+    }
+
     /// Converts a [`bf16`] into the underlying bit representation.
     #[inline]
     #[must_use]
@@ -263,6 +701,210 @@ impl bf16 {
         convert::bf16_to_f64(self.0)
     }
 
+    /// Converts a [`bf16`] value into a `f32` value, flushing a subnormal `self` or result to
+    /// zero as selected by `mode`.
+    ///
+    /// With [`FlushMode::Preserve`] this is the same as [`to_f32`][Self::to_f32].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// use half::FlushMode;
+    ///
+    /// let subnormal = bf16::from_bits(0x0001);
+    /// assert_eq!(subnormal.to_f32_flush(FlushMode::Preserve), f32::from_bits(0x0001_0000));
+    /// assert_eq!(subnormal.to_f32_flush(FlushMode::DenormalsAreZero), 0.0);
+    /// assert_eq!(subnormal.to_f32_flush(FlushMode::FlushToZero), 0.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_f32_flush(self, mode: FlushMode) -> f32 {
+        let value = if mode.flushes_inputs() {
+            self.flush_subnormal_to_zero()
+        } else {
+            self
+        };
+        let result = value.to_f32();
+        if mode.flushes_outputs() {
+            flush::flush_f32(result)
+        } else {
+            result
+        }
+    }
+
+    /// Converts a [`bf16`] value into a `f64` value, flushing a subnormal `self` or result to
+    /// zero as selected by `mode`.
+    ///
+    /// With [`FlushMode::Preserve`] this is the same as [`to_f64`][Self::to_f64].
+    #[inline]
+    #[must_use]
+    pub fn to_f64_flush(self, mode: FlushMode) -> f64 {
+        let value = if mode.flushes_inputs() {
+            self.flush_subnormal_to_zero()
+        } else {
+            self
+        };
+        let result = value.to_f64();
+        if mode.flushes_outputs() {
+            flush::flush_f64(result)
+        } else {
+            result
+        }
+    }
+
+    /// Converts a [`bf16`] value into a primitive integer, with the same semantics as an `as`
+    /// cast of a primitive float.
+    ///
+    /// The value is rounded toward zero, values outside the range of the integer type saturate to
+    /// its minimum or maximum, and NaN converts to `0`. Every [`bf16`] value is exactly
+    /// representable in [`f32`], so this is always identical to `self.to_f32() as T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(-3.75).to_int::<i32>(), -3);
+    /// assert_eq!(bf16::from_f32(1e10).to_int::<u32>(), u32::MAX);
+    /// assert_eq!(bf16::NAN.to_int::<i64>(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_int<T: FloatToInt>(self) -> T {
+        T::saturating_from_f32(self.to_f32())
+    }
+
+    /// Converts a [`bf16`] value into a primitive integer, rounding toward zero and assuming that
+    /// the value is finite and fits in the integer type.
+    ///
+    /// This is the [`bf16`] equivalent of [`f32::to_int_unchecked`].
+    ///
+    /// # Safety
+    ///
+    /// The value must not be NaN or infinite, and after truncating off its fractional part it must
+    /// be representable in the return type `T`.
+    #[inline]
+    #[must_use]
+    pub unsafe fn to_int_unchecked<T: FloatToInt>(self) -> T {
+        T::unchecked_from_f32(self.to_f32())
+    }
+
+    /// Converts a [`bf16`] value into a signed or unsigned fixed-point number with `frac_bits`
+    /// fractional bits, such as Q15 in an [`i16`] or Q7 in an [`i8`].
+    ///
+    /// The value is multiplied by 2<sup>`frac_bits`</sup> and rounded to the nearest integer,
+    /// ties to even. Values outside the range of the integer type saturate to its minimum or
+    /// maximum, and NaN converts to `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// // Q15: 15 fractional bits in an i16, covering [-1, 1)
+    /// assert_eq!(bf16::from_f32(0.5).to_fixed::<i16>(15), 0x4000);
+    /// assert_eq!(bf16::from_f32(-1.0).to_fixed::<i16>(15), i16::MIN);
+    /// assert_eq!(bf16::ONE.to_fixed::<i16>(15), i16::MAX); // Saturated
+    ///
+    /// // Q7 in an i8
+    /// assert_eq!(bf16::from_f32(-0.75).to_fixed::<i8>(7), -96);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_fixed<T: FloatToInt>(self, frac_bits: u32) -> T {
+        int::to_fixed(self.to_f64(), frac_bits)
+    }
+
+    /// Converts a signed or unsigned fixed-point number with `frac_bits` fractional bits, such as
+    /// Q15 in an [`i16`] or Q7 in an [`i8`], into a [`bf16`] value.
+    ///
+    /// The value is divided by 2<sup>`frac_bits`</sup> and rounded to the nearest representable
+    /// value, ties to even. Values too large for [`bf16`] become ±∞.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_fixed(0x4000i16, 15), bf16::from_f32(0.5));
+    /// assert_eq!(bf16::from_fixed(i16::MIN, 15), bf16::from_f32(-1.0));
+    /// assert_eq!(bf16::from_fixed(-96i8, 7), bf16::from_f32(-0.75));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_fixed<T: FloatToInt>(value: T, frac_bits: u32) -> bf16 {
+        bf16(int::from_fixed(value, frac_bits, 7, 8))
+    }
+
+    /// Formats the exact value as a hexadecimal float in the style of C's `%a` format, such as
+    /// `0x1.8p-2`. Subnormal values keep a leading `0` digit, and infinities and NaN are
+    /// formatted like [`Display`] does. The output can be parsed back with [`FromStr`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(0.375).to_hex_string(), "0x1.8p-2");
+    /// assert_eq!(bf16::MAX.to_hex_string(), "0x1.fep+127");
+    /// assert_eq!("0x1.8p-2".parse::<bf16>().unwrap(), bf16::from_f32(0.375));
+    /// ```
+    #[cfg(all(feature = "alloc", not(target_arch = "spirv")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    pub fn to_hex_string(&self) -> String {
+        crate::fmt::HexFloat {
+            bits: self.0,
+            man_bits: 7,
+            exp_bits: 8,
+        }
+        .to_string()
+    }
+
+    /// Parses a float from a string in the given radix, correctly rounded to the nearest
+    /// [`bf16`].
+    ///
+    /// The string may have a leading `+` or `-` sign and a radix point, and may also be `inf`,
+    /// `infinity` or `nan` in any case. A radix of 10 accepts the same strings as [`FromStr`], and
+    /// a radix of 16 accepts an optional binary exponent like `1.8p-2`. Other radices do not
+    /// support exponents.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_str_radix("-101.01", 2), Ok(bf16::from_f32(-5.25)));
+    /// assert_eq!(bf16::from_str_radix("1p100", 16), Ok(bf16::from_f32(2f32.powi(100))));
+    /// assert!(bf16::from_str_radix("12", 2).is_err());
+    /// ```
+    #[cfg(not(target_arch = "spirv"))]
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<bf16, ParseFloatError> {
+        parse::parse_float(src, radix, 7, 8).map(bf16)
+    }
+
+    /// Parses a float from a string like [`FromStr`] does, but returns an error instead of
+    /// rounding a finite value to infinity, or a nonzero value to zero.
+    ///
+    /// Explicit infinities like `inf` are still accepted, as are values that round to a
+    /// subnormal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::parse_strict("0.5"), Ok(bf16::from_f32(0.5)));
+    /// assert!(bf16::parse_strict("1e39").unwrap_err().is_overflow());
+    /// assert!(bf16::parse_strict("-1e-46").unwrap_err().is_underflow());
+    /// assert_eq!("1e39".parse::<bf16>().unwrap(), bf16::INFINITY);
+    /// ```
+    #[cfg(not(target_arch = "spirv"))]
+    pub fn parse_strict(src: &str) -> Result<bf16, ParseError> {
+        let value = src.parse::<bf16>().map_err(|_| ParseError::syntax(src))?;
+        parse::check_range(src, value.is_infinite(), value.0 & 0x7FFF == 0)?;
+        Ok(value)
+    }
+
     /// Returns `true` if this value is NaN and `false` otherwise.
     ///
     /// # Examples
@@ -388,6 +1030,29 @@ impl bf16 {
         }
     }
 
+    /// Replaces a subnormal value with a zero of the same sign, leaving all other values
+    /// unchanged.
+    ///
+    /// This is what hardware running in flush-to-zero mode does to its inputs and results. See
+    /// [`FlushMode`] for conversions that flush, and
+    /// [`HalfFloatSliceExt::flush_subnormals_to_zero`][crate::slice::HalfFloatSliceExt::flush_subnormals_to_zero]
+    /// to flush a whole slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let subnormal = bf16::from_bits(0x0001);
+    /// assert_eq!(subnormal.flush_subnormal_to_zero(), bf16::ZERO);
+    /// assert_eq!(bf16::MIN_POSITIVE.flush_subnormal_to_zero(), bf16::MIN_POSITIVE);
+    /// assert_eq!(bf16::NEG_INFINITY.flush_subnormal_to_zero(), bf16::NEG_INFINITY);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn flush_subnormal_to_zero(self) -> bf16 {
+        bf16(flush::flush_bits(self.0, 0x7F80))
+    }
+
     /// Returns a number that represents the sign of `self`.
     ///
     /// * 1.0 if the number is positive, +0.0 or [`INFINITY`][bf16::INFINITY]
@@ -642,6 +1307,405 @@ impl bf16 {
         left.cmp(&right)
     }
 
+    /// Returns the number of representable values between `self` and `other`, or [`None`] if
+    /// either is NaN.
+    ///
+    /// This is the distance in units in the last place, counted on the bit representation, so
+    /// adjacent values are 1 apart even across a change of exponent or sign. Both zeros are the
+    /// same value with a distance of 0, and the infinities are 1 beyond [`MAX`][Self::MAX] and
+    /// [`MIN`][Self::MIN].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let one = bf16::ONE;
+    /// assert_eq!(one.ulp_distance(one), Some(0));
+    /// assert_eq!(one.ulp_distance(one + bf16::EPSILON), Some(1));
+    /// assert_eq!(bf16::NEG_ZERO.ulp_distance(bf16::ZERO), Some(0));
+    /// assert_eq!(bf16::from_bits(1).ulp_distance(-bf16::from_bits(1)), Some(2));
+    /// assert_eq!(bf16::NEG_INFINITY.ulp_distance(bf16::INFINITY), Some(0xFF00));
+    /// assert_eq!(one.ulp_distance(bf16::NAN), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn ulp_distance(self, other: bf16) -> Option<u16> {
+        if self.is_nan() || other.is_nan() {
+            None
+        } else {
+            let distance = order_key(self.0) as i32 - order_key(other.0) as i32;
+            Some(distance.unsigned_abs() as u16)
+        }
+    }
+
+    /// Returns the least value greater than `self`.
+    ///
+    /// Both zeros step to [`MIN_POSITIVE_SUBNORMAL`][Self::MIN_POSITIVE_SUBNORMAL] and
+    /// [`MAX`][Self::MAX] steps to infinity. NaN and positive infinity are returned unchanged, and
+    /// the negative value closest to zero steps to negative zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::ONE.next_up(), bf16::ONE + bf16::EPSILON);
+    /// assert_eq!(bf16::NEG_ZERO.next_up(), bf16::MIN_POSITIVE_SUBNORMAL);
+    /// assert_eq!(bf16::MAX.next_up(), bf16::INFINITY);
+    /// assert_eq!(bf16::NEG_INFINITY.next_up(), bf16::MIN);
+    /// assert!(bf16::NAN.next_up().is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn next_up(self) -> bf16 {
+        if self.is_nan() || self.0 == 0x7F80 {
+            self
+        } else if self.0 & 0x7FFF == 0 {
+            Self::MIN_POSITIVE_SUBNORMAL
+        } else if self.0 & 0x8000 == 0 {
+            bf16(self.0 + 1)
+        } else {
+            bf16(self.0 - 1)
+        }
+    }
+
+    /// Returns the greatest value less than `self`.
+    ///
+    /// This is the mirror image of [`next_up`][Self::next_up]: both zeros step to the negative
+    /// value closest to zero, [`MIN`][Self::MIN] steps to negative infinity, and NaN and negative
+    /// infinity are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::ONE.next_down().next_up(), bf16::ONE);
+    /// assert_eq!(bf16::ZERO.next_down(), -bf16::MIN_POSITIVE_SUBNORMAL);
+    /// assert_eq!(bf16::MIN.next_down(), bf16::NEG_INFINITY);
+    /// assert_eq!(bf16::INFINITY.next_down(), bf16::MAX);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn next_down(self) -> bf16 {
+        if self.is_nan() || self.0 == 0xFF80 {
+            self
+        } else if self.0 & 0x7FFF == 0 {
+            bf16(0x8001)
+        } else if self.0 & 0x8000 == 0 {
+            bf16(self.0 - 1)
+        } else {
+            bf16(self.0 + 1)
+        }
+    }
+
+    /// Returns the unit in the last place of `self`, the positive distance from `self` to the
+    /// next value of greater magnitude.
+    ///
+    /// The result only depends on the exponent of `self`, so it is the same for both signs and
+    /// for [`MAX`][Self::MAX], where the next value would be infinite. Zeros and subnormal values
+    /// have a unit of [`MIN_POSITIVE_SUBNORMAL`][Self::MIN_POSITIVE_SUBNORMAL]. The result is
+    /// positive infinity for the infinities and NaN for NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::ONE.ulp(), bf16::EPSILON);
+    /// assert_eq!(bf16::from_f32(-256.0).ulp(), bf16::from_f32(2.0));
+    /// assert_eq!(bf16::ZERO.ulp(), bf16::MIN_POSITIVE_SUBNORMAL);
+    /// assert_eq!(bf16::NEG_INFINITY.ulp(), bf16::INFINITY);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn ulp(self) -> bf16 {
+        let exponent = (self.0 >> 7) & 0xFF;
+        if exponent == 0xFF {
+            bf16(self.0 & 0x7FFF)
+        } else if exponent > 7 {
+            bf16((exponent - 7) << 7)
+        } else if exponent > 0 {
+            bf16(1 << (exponent - 1))
+        } else {
+            Self::MIN_POSITIVE_SUBNORMAL
+        }
+    }
+
+    /// Raises `self` to the power of `n`.
+    ///
+    /// The power is computed in [`f64`], where both operands are exact, and rounded once to the
+    /// nearest [`bf16`]. Special cases follow the C `pow` function, so for instance a negative
+    /// base with a non-integer exponent is NaN, and anything to the power of zero is one, even
+    /// NaN.
+    ///
+    /// This method requires the `std` or `math` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let x = bf16::from_f32(-2.0);
+    /// assert_eq!(x.powf(bf16::from_f32(3.0)), bf16::from_f32(-8.0));
+    /// assert_eq!(x.powf(bf16::from_f32(-1.0)), bf16::from_f32(-0.5));
+    /// assert!(x.powf(bf16::from_f32(0.5)).is_nan());
+    /// assert_eq!(bf16::NAN.powf(bf16::ZERO), bf16::ONE);
+    /// assert_eq!(bf16::from_f32(2.0).powf(bf16::from_f32(-133.0)), bf16::MIN_POSITIVE_SUBNORMAL);
+    /// ```
+    #[cfg(any(feature = "std", feature = "math"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "math"))))]
+    #[inline]
+    #[must_use]
+    pub fn powf(self, n: bf16) -> bf16 {
+        bf16::from_f64(math::pow(self.to_f64(), n.to_f64()))
+    }
+
+    /// Raises `self` to the power of an [`f32`] exponent `n`.
+    ///
+    /// This is the same as [`powf`][Self::powf], but the exponent keeps the precision of
+    /// [`f32`], which matters for exponents such as `1.0 / 3.0` that are not representable in
+    /// half precision, as in shaders that raise half precision colors to an [`f32`] gamma.
+    ///
+    /// This method requires the `std` or `math` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let x = bf16::from_f32(1000.0);
+    /// assert_eq!(x.powf_f32(1.0 / 3.0), bf16::from_f32(10.0));
+    /// assert_ne!(x.powf(bf16::from_f32(1.0 / 3.0)), bf16::from_f32(10.0));
+    /// ```
+    #[cfg(any(feature = "std", feature = "math"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "math"))))]
+    #[inline]
+    #[must_use]
+    pub fn powf_f32(self, n: f32) -> bf16 {
+        bf16::from_f64(math::pow(self.to_f64(), f64::from(n)))
+    }
+
+    /// Returns `ln(1 + self)`, the natural logarithm of one more than `self`.
+    ///
+    /// The result is computed in [`f64`] and rounded once to the nearest [`bf16`], so it is
+    /// accurate even when `self` is so close to zero that `1 + self` would round to one. Small and
+    /// subnormal values are returned unchanged, since the result rounds to `self`. The result is
+    /// negative infinity for `-1` and NaN below `-1`.
+    ///
+    /// This method requires the `std` or `math` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let tiny = bf16::from_f32(1e-30);
+    /// assert_eq!(tiny.ln_1p(), tiny);
+    /// assert_eq!(bf16::ONE.ln_1p(), bf16::from_f64(2f64.ln()));
+    /// assert_eq!(bf16::NEG_ONE.ln_1p(), bf16::NEG_INFINITY);
+    /// assert!(bf16::from_f32(-2.0).ln_1p().is_nan());
+    /// ```
+    #[cfg(any(feature = "std", feature = "math"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "math"))))]
+    #[inline]
+    #[must_use]
+    pub fn ln_1p(self) -> bf16 {
+        bf16::from_f64(math::ln_1p(self.to_f64()))
+    }
+
+    /// Returns `e^self - 1`.
+    ///
+    /// The result is computed in [`f64`] and rounded once to the nearest [`bf16`], so it keeps its
+    /// precision for values close to zero, where `e^self` would round to one. Small and subnormal
+    /// values are returned unchanged, and the result tends to `-1` for large negative values.
+    ///
+    /// This method requires the `std` or `math` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let tiny = bf16::from_f32(1e-30);
+    /// assert_eq!(tiny.exp_m1(), tiny);
+    /// assert_eq!(bf16::ONE.exp_m1(), bf16::from_f64(1f64.exp() - 1.0));
+    /// assert_eq!(bf16::NEG_INFINITY.exp_m1(), bf16::NEG_ONE);
+    /// assert_eq!(bf16::NEG_ZERO.exp_m1().to_bits(), bf16::NEG_ZERO.to_bits());
+    /// ```
+    #[cfg(any(feature = "std", feature = "math"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "math"))))]
+    #[inline]
+    #[must_use]
+    pub fn exp_m1(self) -> bf16 {
+        bf16::from_f64(math::exp_m1(self.to_f64()))
+    }
+
+    /// Returns `2^self`.
+    ///
+    /// The result is computed in [`f64`] and rounded once to the nearest [`bf16`], so integer
+    /// values give exact powers of two, including the subnormal ones, and values too large or too
+    /// small for the type give infinity or zero.
+    ///
+    /// This method requires the `std` or `math` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(-3.0).exp2(), bf16::from_f32(0.125));
+    /// assert_eq!(bf16::from_f32(0.5).exp2(), bf16::from_f64(2f64.sqrt()));
+    /// assert_eq!(bf16::MAX.exp2(), bf16::INFINITY);
+    /// assert_eq!(bf16::NEG_INFINITY.exp2(), bf16::ZERO);
+    /// ```
+    #[cfg(any(feature = "std", feature = "math"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "math"))))]
+    #[inline]
+    #[must_use]
+    pub fn exp2(self) -> bf16 {
+        bf16::from_f64(math::exp2(self.to_f64()))
+    }
+
+    /// Returns the error function of `self`.
+    ///
+    /// The result is computed in [`f64`] and rounded once to the nearest [`bf16`]. It lies between
+    /// `-1` and `1`, which are reached at the infinities and already by moderately large values,
+    /// since the function converges quickly.
+    ///
+    /// This method requires the `math` feature, even with `std`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::ZERO.erf(), bf16::ZERO);
+    /// assert_eq!(bf16::ONE.erf(), bf16::from_f32(0.8427008));
+    /// assert_eq!(bf16::from_f32(-5.0).erf(), bf16::NEG_ONE);
+    /// ```
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    #[inline]
+    #[must_use]
+    pub fn erf(self) -> bf16 {
+        bf16::from_f64(math::erf(self.to_f64()))
+    }
+
+    /// Returns the complementary error function of `self`, `1 - erf(self)`.
+    ///
+    /// The result is computed in [`f64`] and rounded once to the nearest [`bf16`], so unlike
+    /// subtracting [`erf`][Self::erf] from one, it stays accurate in the tail for large values.
+    ///
+    /// This method requires the `math` feature, even with `std`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let x = bf16::from_f32(3.0);
+    /// assert_eq!(x.erfc(), bf16::from_f64(2.209049699858544e-5));
+    /// assert_eq!(bf16::ONE - x.erf(), bf16::ZERO);
+    /// assert_eq!(bf16::NEG_INFINITY.erfc(), bf16::from_f32(2.0));
+    /// ```
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    #[inline]
+    #[must_use]
+    pub fn erfc(self) -> bf16 {
+        bf16::from_f64(math::erfc(self.to_f64()))
+    }
+
+    /// Returns the gamma function of `self`, known as `tgamma` in C.
+    ///
+    /// The result is computed in [`f64`] and rounded once to the nearest [`bf16`]. For positive
+    /// integers `n`, this is the factorial of `n - 1`. Zeros give an infinity with the same sign,
+    /// and negative integers and negative infinity give NaN.
+    ///
+    /// This method requires the `math` feature, even with `std`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::from_f32(5.0).gamma(), bf16::from_f32(24.0));
+    /// assert_eq!(bf16::from_f32(0.5).gamma(), bf16::from_f64(core::f64::consts::PI.sqrt()));
+    /// assert_eq!(bf16::NEG_ZERO.gamma(), bf16::NEG_INFINITY);
+    /// assert!(bf16::from_f32(-2.0).gamma().is_nan());
+    /// ```
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    #[inline]
+    #[must_use]
+    pub fn gamma(self) -> bf16 {
+        bf16::from_f64(math::tgamma(self.to_f64()))
+    }
+
+    /// Returns the natural logarithm of the absolute value of the gamma function of `self`, and
+    /// the sign of the gamma function, known as `lgamma` in C.
+    ///
+    /// The logarithm is computed in [`f64`] and rounded once to the nearest [`bf16`], so it stays
+    /// finite for values where [`gamma`][Self::gamma] overflows. The sign is `1` or `-1`.
+    ///
+    /// This method requires the `math` feature, even with `std`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let x = bf16::from_f32(100.0);
+    /// assert_eq!(x.ln_gamma(), (bf16::from_f32(359.13422), 1));
+    /// assert_eq!(bf16::from_f32(-0.5).ln_gamma(), (bf16::from_f32(1.2655121), -1));
+    /// assert_eq!(bf16::ONE.ln_gamma(), (bf16::ZERO, 1));
+    /// ```
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
+    #[inline]
+    #[must_use]
+    pub fn ln_gamma(self) -> (bf16, i32) {
+        let (value, sign) = math::lgamma_r(self.to_f64());
+        (bf16::from_f64(value), sign)
+    }
+
+    /// Returns an iterator over all 65536 bit patterns of [`bf16`], in increasing order of their
+    /// [`to_bits`][bf16::to_bits] representation.
+    ///
+    /// This includes both zeros, the infinities and every NaN payload. The order starts with the
+    /// positive values from zero up to infinity followed by the positive NaNs, then the same for
+    /// the negative values, so it is not sorted by value. Use this to test a function of [`bf16`]
+    /// exhaustively.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(bf16::all_values().len(), 65536);
+    /// assert_eq!(bf16::all_values().filter(|x| x.is_nan()).count(), 254);
+    ///
+    /// // Every value except the signaling NaNs survives a round trip through `f32`
+    /// let round_trip = |x: bf16| bf16::from_f32(x.to_f32()).to_bits() == x.to_bits();
+    /// assert!(bf16::all_values().filter(|x| !x.is_nan()).all(round_trip));
+    /// ```
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    pub fn all_values() -> AllValues<bf16> {
+        AllValues::new()
+    }
+
+    /// Returns an iterator over all finite values of [`bf16`], in increasing order of their
+    /// [`to_bits`][bf16::to_bits] representation.
+    ///
+    /// This is [`all_values`][bf16::all_values] without the infinities and NaNs: first the
+    /// positive values from `0.0` up to [`MAX`][bf16::MAX], then the negative values from `-0.0`
+    /// down to [`MIN`][bf16::MIN].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut values = bf16::all_finite();
+    /// assert_eq!(values.len(), 65280);
+    /// assert_eq!(values.next(), Some(bf16::ZERO));
+    /// assert_eq!(values.next_back(), Some(bf16::MIN));
+    /// assert_eq!(values.nth(0x7F80 - 1).map(bf16::to_bits), Some(bf16::NEG_ZERO.to_bits()));
+    /// ```
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    pub fn all_finite() -> AllFinite<bf16> {
+        AllFinite::new()
+    }
+
     /// Alternate serialize adapter for serializing as a float.
     ///
     /// By default, [`bf16`] serializes as a newtype of [`u16`]. This is an alternate serialize
@@ -694,7 +1758,7 @@ impl bf16 {
         &self,
         serializer: S,
     ) -> Result<S::Ok, S::Error> {
-        serializer.serialize_str(&self.to_string())
+        serializer.collect_str(self)
     }
 
     /// Approximate number of [`bf16`] significant digits in base 10
@@ -803,174 +1867,224 @@ impl From<i8> for bf16 {
     }
 }
 
-impl From<u8> for bf16 {
+impl From<u8> for bf16 {
+    #[inline]
+    fn from(x: u8) -> bf16 {
+        // Convert to f32, then to f16
+        bf16::from_f32(f32::from(x))
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl TryFrom<f32> for bf16 {
+    type Error = TryFromFloatError;
+
+    /// Converts a [`f32`] to [`bf16`] only if the value is exactly representable.
+    ///
+    /// NaN values always convert, keeping as much of the payload as fits. Any other value that
+    /// would be rounded, including finite values that would overflow to ±∞, is an error.
+    #[inline]
+    fn try_from(value: f32) -> Result<bf16, TryFromFloatError> {
+        if let Some(half) = bf16::from_f32_exact(value) {
+            Ok(half)
+        } else if value > bf16::MAX.to_f32() || value < bf16::MIN.to_f32() {
+            Err(TryFromFloatError::overflow())
+        } else {
+            Err(TryFromFloatError::inexact())
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl TryFrom<f64> for bf16 {
+    type Error = TryFromFloatError;
+
+    /// Converts a [`f64`] to [`bf16`] only if the value is exactly representable.
+    ///
+    /// NaN values always convert, keeping as much of the payload as fits. Any other value that
+    /// would be rounded, including finite values that would overflow to ±∞, is an error.
     #[inline]
-    fn from(x: u8) -> bf16 {
-        // Convert to f32, then to f16
-        bf16::from_f32(f32::from(x))
+    fn try_from(value: f64) -> Result<bf16, TryFromFloatError> {
+        if let Some(half) = bf16::from_f64_exact(value) {
+            Ok(half)
+        } else if value > bf16::MAX.to_f64() || value < bf16::MIN.to_f64() {
+            Err(TryFromFloatError::overflow())
+        } else {
+            Err(TryFromFloatError::inexact())
+        }
     }
 }
 
+// Maps the sign-magnitude bits to integers ordered like the values, with both zeros mapped to 0
+#[inline]
+const fn order_key(bits: u16) -> i16 {
+    let sign = (bits as i16) >> 15;
+    ((bits & 0x7FFF) as i16 ^ sign) - sign
+}
+
 impl PartialEq for bf16 {
+    #[inline]
     fn eq(&self, other: &bf16) -> bool {
-        if self.is_nan() || other.is_nan() {
-            false
-        } else {
-            (self.0 == other.0) || ((self.0 | other.0) & 0x7FFFu16 == 0)
-        }
+        // The non-short-circuiting operators keep the comparison branchless
+        !(self.is_nan() | other.is_nan()) & (order_key(self.0) == order_key(other.0))
     }
 }
 
 impl PartialOrd for bf16 {
+    #[inline]
     fn partial_cmp(&self, other: &bf16) -> Option<Ordering> {
-        if self.is_nan() || other.is_nan() {
+        if self.is_nan() | other.is_nan() {
             None
         } else {
-            let neg = self.0 & 0x8000u16 != 0;
-            let other_neg = other.0 & 0x8000u16 != 0;
-            match (neg, other_neg) {
-                (false, false) => Some(self.0.cmp(&other.0)),
-                (false, true) => {
-                    if (self.0 | other.0) & 0x7FFFu16 == 0 {
-                        Some(Ordering::Equal)
-                    } else {
-                        Some(Ordering::Greater)
-                    }
-                }
-                (true, false) => {
-                    if (self.0 | other.0) & 0x7FFFu16 == 0 {
-                        Some(Ordering::Equal)
-                    } else {
-                        Some(Ordering::Less)
-                    }
-                }
-                (true, true) => Some(other.0.cmp(&self.0)),
-            }
+            Some(order_key(self.0).cmp(&order_key(other.0)))
         }
     }
 
+    #[inline]
     fn lt(&self, other: &bf16) -> bool {
-        if self.is_nan() || other.is_nan() {
-            false
-        } else {
-            let neg = self.0 & 0x8000u16 != 0;
-            let other_neg = other.0 & 0x8000u16 != 0;
-            match (neg, other_neg) {
-                (false, false) => self.0 < other.0,
-                (false, true) => false,
-                (true, false) => (self.0 | other.0) & 0x7FFFu16 != 0,
-                (true, true) => self.0 > other.0,
-            }
-        }
+        !(self.is_nan() | other.is_nan()) & (order_key(self.0) < order_key(other.0))
     }
 
+    #[inline]
     fn le(&self, other: &bf16) -> bool {
-        if self.is_nan() || other.is_nan() {
-            false
-        } else {
-            let neg = self.0 & 0x8000u16 != 0;
-            let other_neg = other.0 & 0x8000u16 != 0;
-            match (neg, other_neg) {
-                (false, false) => self.0 <= other.0,
-                (false, true) => (self.0 | other.0) & 0x7FFFu16 == 0,
-                (true, false) => true,
-                (true, true) => self.0 >= other.0,
-            }
-        }
+        !(self.is_nan() | other.is_nan()) & (order_key(self.0) <= order_key(other.0))
     }
 
+    #[inline]
     fn gt(&self, other: &bf16) -> bool {
-        if self.is_nan() || other.is_nan() {
-            false
-        } else {
-            let neg = self.0 & 0x8000u16 != 0;
-            let other_neg = other.0 & 0x8000u16 != 0;
-            match (neg, other_neg) {
-                (false, false) => self.0 > other.0,
-                (false, true) => (self.0 | other.0) & 0x7FFFu16 != 0,
-                (true, false) => false,
-                (true, true) => self.0 < other.0,
-            }
-        }
+        !(self.is_nan() | other.is_nan()) & (order_key(self.0) > order_key(other.0))
     }
 
+    #[inline]
     fn ge(&self, other: &bf16) -> bool {
-        if self.is_nan() || other.is_nan() {
-            false
-        } else {
-            let neg = self.0 & 0x8000u16 != 0;
-            let other_neg = other.0 & 0x8000u16 != 0;
-            match (neg, other_neg) {
-                (false, false) => self.0 >= other.0,
-                (false, true) => true,
-                (true, false) => (self.0 | other.0) & 0x7FFFu16 == 0,
-                (true, true) => self.0 <= other.0,
-            }
-        }
+        !(self.is_nan() | other.is_nan()) & (order_key(self.0) >= order_key(other.0))
     }
 }
 
 #[cfg(not(target_arch = "spirv"))]
 impl FromStr for bf16 {
     type Err = ParseFloatError;
+
+    /// Parses a decimal float in any format accepted by [`f32`], or a hexadecimal float like
+    /// `0x1.8p-2` as printed by [`to_hex_string`][bf16::to_hex_string]. Both are rounded to the
+    /// nearest [`bf16`] value directly, so the result is correctly rounded for any number of
+    /// digits.
     fn from_str(src: &str) -> Result<bf16, ParseFloatError> {
-        f32::from_str(src).map(bf16::from_f32)
+        bf16::from_str_radix(src, 10)
     }
 }
 
 #[cfg(not(target_arch = "spirv"))]
 impl Debug for bf16 {
+    /// Formats the numeric value like [`Display`], with the fewest digits that convert back to
+    /// the same [`bf16`], but always includes a fractional part or exponent like [`f32`] does.
+    ///
+    /// Use [`LowerHex`] or [`UpperHex`] (e.g. `{:x}`) or [`to_bits`][bf16::to_bits] to see the
+    /// raw bits instead.
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:?}", self.to_f32())
+        match self.shortest_decimal() {
+            Some(decimal) if f.precision().is_none() => {
+                fmt::write_debug(f, self.is_sign_negative(), decimal)
+            }
+            _ => Debug::fmt(&self.to_f32(), f),
+        }
     }
 }
 
 #[cfg(not(target_arch = "spirv"))]
 impl Display for bf16 {
+    /// Formats the value with the fewest decimal digits that convert back to the same [`bf16`].
+    ///
+    /// When a precision is given, the exact value is rounded to that many fractional digits
+    /// instead. Width, fill, alignment, sign and zero padding flags work the same as for [`f32`].
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{}", self.to_f32())
+        match self.shortest_decimal() {
+            Some(decimal) if f.precision().is_none() => {
+                fmt::write_decimal(f, self.is_sign_negative(), decimal)
+            }
+            _ => Display::fmt(&self.to_f32(), f),
+        }
     }
 }
 
 #[cfg(not(target_arch = "spirv"))]
 impl LowerExp for bf16 {
+    /// Formats the value in scientific notation with the fewest digits that convert back to the
+    /// same [`bf16`].
+    ///
+    /// Precision and the other formatting flags work the same as for [`f32`].
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:e}", self.to_f32())
+        match self.shortest_decimal() {
+            Some(decimal) if f.precision().is_none() => {
+                fmt::write_exp(f, self.is_sign_negative(), decimal, false)
+            }
+            _ => LowerExp::fmt(&self.to_f32(), f),
+        }
     }
 }
 
 #[cfg(not(target_arch = "spirv"))]
 impl UpperExp for bf16 {
+    /// Formats the value in scientific notation with the fewest digits that convert back to the
+    /// same [`bf16`].
+    ///
+    /// Precision and the other formatting flags work the same as for [`f32`].
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:E}", self.to_f32())
+        match self.shortest_decimal() {
+            Some(decimal) if f.precision().is_none() => {
+                fmt::write_exp(f, self.is_sign_negative(), decimal, true)
+            }
+            _ => UpperExp::fmt(&self.to_f32(), f),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl bf16 {
+    // The shortest decimal digits that round-trip, or `None` for infinities and NaN
+    fn shortest_decimal(self) -> Option<fmt::Decimal> {
+        let exp = (self.0 >> 7) & 0xFF;
+        let man = (self.0 & 0x007F) as u64;
+        match exp {
+            0xFF => None,
+            0 if man == 0 => Some(fmt::Decimal { digits: 0, exp: 0 }),
+            0 => Some(fmt::shortest_decimal(man, -133, false)),
+            _ => Some(fmt::shortest_decimal(
+                man | 0x0080,
+                exp as i32 - 134,
+                man == 0 && exp > 1,
+            )),
+        }
     }
 }
 
 #[cfg(not(target_arch = "spirv"))]
 impl Binary for bf16 {
+    /// Formats the raw bits of the value like the same formatting of [`u16`] would, including
+    /// width, fill and `#` flags. [`Octal`], [`LowerHex`] and [`UpperHex`] do the same.
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:b}", self.0)
+        Binary::fmt(&self.0, f)
     }
 }
 
 #[cfg(not(target_arch = "spirv"))]
 impl Octal for bf16 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:o}", self.0)
+        Octal::fmt(&self.0, f)
     }
 }
 
 #[cfg(not(target_arch = "spirv"))]
 impl LowerHex for bf16 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:x}", self.0)
+        LowerHex::fmt(&self.0, f)
     }
 }
 
 #[cfg(not(target_arch = "spirv"))]
 impl UpperHex for bf16 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:X}", self.0)
+        UpperHex::fmt(&self.0, f)
     }
 }
 
@@ -1260,7 +2374,7 @@ impl Sum for bf16 {
 impl<'a> Sum<&'a bf16> for bf16 {
     #[inline]
     fn sum<I: Iterator<Item = &'a bf16>>(iter: I) -> Self {
-        bf16::from_f32(iter.map(|f| f.to_f32()).product())
+        bf16::from_f32(iter.map(|f| f.to_f32()).sum())
     }
 }
 
@@ -1281,7 +2395,7 @@ impl<'de> Deserialize<'de> for bf16 {
 impl<'de> serde::de::Visitor<'de> for Visitor {
     type Value = bf16;
 
-    fn expecting(&self, formatter: &mut alloc::fmt::Formatter) -> alloc::fmt::Result {
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(formatter, "tuple struct bf16")
     }
 
@@ -1316,6 +2430,60 @@ impl<'de> serde::de::Visitor<'de> for Visitor {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for bf16 {
+    /// Generates any bit pattern, including NaNs, infinities and subnormals.
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        <u16 as arbitrary::Arbitrary>::arbitrary(u).map(Self)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <u16 as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+#[cfg(any(feature = "quickcheck", test))]
+impl quickcheck::Arbitrary for bf16 {
+    /// Generates any bit pattern, including NaNs, infinities and subnormals.
+    #[inline]
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        bf16(u16::arbitrary(g))
+    }
+
+    /// Shrinks the bits toward zero, which ends at positive zero.
+    fn shrink(&self) -> alloc::boxed::Box<dyn Iterator<Item = Self>> {
+        alloc::boxed::Box::new(self.0.shrink().map(bf16))
+    }
+}
+
+#[cfg(feature = "speedy")]
+impl<'a, C: speedy::Context> speedy::Readable<'a, C> for bf16 {
+    #[inline]
+    fn read_from<R: speedy::Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        reader.read_u16().map(bf16)
+    }
+
+    #[inline]
+    fn minimum_bytes_needed() -> usize {
+        2
+    }
+}
+
+#[cfg(feature = "speedy")]
+impl<C: speedy::Context> speedy::Writable<C> for bf16 {
+    #[inline]
+    fn write_to<W: ?Sized + speedy::Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+        writer.write_u16(self.0)
+    }
+
+    #[inline]
+    fn bytes_needed(&self) -> Result<usize, C::Error> {
+        Ok(2)
+    }
+}
+
 #[allow(
     clippy::cognitive_complexity,
     clippy::float_cmp,
@@ -1329,6 +2497,44 @@ mod test {
     use num_traits::{AsPrimitive, FromPrimitive, ToPrimitive};
     use quickcheck_macros::quickcheck;
 
+    #[cfg(all(feature = "speedy", feature = "std"))]
+    #[test]
+    fn speedy() {
+        use speedy::{Endianness, Readable, Writable};
+
+        let values = std::vec![bf16::ONE, bf16::NEG_INFINITY, bf16::from_bits(0x1234)];
+        let bits = values
+            .iter()
+            .map(|x| x.to_bits())
+            .collect::<std::vec::Vec<_>>();
+        for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+            let bytes = values.write_to_vec_with_ctx(endianness).unwrap();
+            assert_eq!(bytes, bits.write_to_vec_with_ctx(endianness).unwrap());
+            let read =
+                std::vec::Vec::<bf16>::read_from_buffer_with_ctx(endianness, &bytes).unwrap();
+            assert_eq!(read, values);
+        }
+        assert_eq!(bf16::ONE.write_to_vec().unwrap(), bf16::ONE.to_le_bytes());
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut bytes = [0u8; 64];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = (i * 37) as u8;
+        }
+        let mut u = Unstructured::new(&bytes);
+        let mut bits = Unstructured::new(&bytes);
+        while !u.is_empty() {
+            let x = bf16::arbitrary(&mut u).unwrap();
+            assert_eq!(x.to_bits(), u16::arbitrary(&mut bits).unwrap());
+        }
+        assert_eq!(bf16::size_hint(0), u16::size_hint(0));
+    }
+
     #[cfg(feature = "num-traits")]
     #[test]
     fn as_primitive() {
@@ -1361,15 +2567,87 @@ mod test {
         assert_eq!(<bf16 as FromPrimitive>::from_f64(2.0).unwrap(), two);
     }
 
+    #[cfg(all(feature = "rkyv", feature = "std"))]
+    #[test]
+    fn rkyv() {
+        use rkyv::Deserialize;
+
+        let values = std::vec![bf16::ONE, bf16::NEG_INFINITY, bf16::NAN];
+        let bytes = rkyv::to_bytes::<_, 256>(&values).unwrap();
+        let archived = unsafe { rkyv::archived_root::<std::vec::Vec<bf16>>(&bytes) };
+        let first: &crate::ArchivedBf16 = &archived[0];
+        assert_eq!(first.deserialize(&mut rkyv::Infallible), Ok(bf16::ONE));
+
+        let deserialized: std::vec::Vec<bf16> =
+            archived.deserialize(&mut rkyv::Infallible).unwrap();
+        let bits = |values: &[bf16]| {
+            values
+                .iter()
+                .map(|x| x.to_bits())
+                .collect::<std::vec::Vec<_>>()
+        };
+        assert_eq!(bits(&deserialized), bits(&values));
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn float_traits() {
+        use num_traits::float::{Float, FloatCore};
+
+        fn hypot<F: Float>(x: F, y: F) -> F {
+            (x * x + y * y).sqrt()
+        }
+        fn clamp_abs<F: FloatCore>(x: F, limit: F) -> F {
+            let y = x.abs().min(limit);
+            if x.is_sign_negative() {
+                -y
+            } else {
+                y
+            }
+        }
+
+        let (three, four) = (bf16::from_f32(3.), bf16::from_f32(4.));
+        assert_eq!(hypot(three, four), bf16::from_f32(5.));
+        assert_eq!(clamp_abs(-four, three), -three);
+
+        let subnormal = bf16::from_bits(0x0001);
+        assert!(Float::is_subnormal(subnormal));
+        assert!(FloatCore::is_subnormal(-subnormal));
+        assert!(!Float::is_subnormal(bf16::MIN_POSITIVE));
+        assert!(!FloatCore::is_subnormal(bf16::ZERO));
+        assert!(!Float::is_subnormal(bf16::NAN));
+
+        assert_eq!(Float::copysign(three, bf16::NEG_ZERO), -three);
+        assert_eq!(Float::abs(bf16::NEG_INFINITY), bf16::INFINITY);
+        assert!(Float::abs(-bf16::NAN).is_sign_positive());
+        assert_eq!(Float::signum(-four), bf16::NEG_ONE);
+        assert!(Float::signum(bf16::NAN).is_nan());
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn signed() {
+        use num_traits::Signed;
+
+        let (one, two) = (bf16::ONE, bf16::from_f32(2.));
+        assert_eq!(Signed::abs(&-two), two);
+        assert_eq!(Signed::abs_sub(&two, &one), one);
+        assert_eq!(Signed::abs_sub(&one, &two), bf16::ZERO);
+        assert_eq!(Signed::signum(&bf16::NEG_ZERO), bf16::NEG_ONE);
+        assert!(Signed::is_positive(&bf16::ZERO));
+        assert!(Signed::is_negative(&bf16::NEG_INFINITY));
+        assert!(!Signed::is_negative(&bf16::ONE));
+    }
+
     #[test]
     fn test_bf16_consts_from_f32() {
         let one = bf16::from_f32(1.0);
         let zero = bf16::from_f32(0.0);
         let neg_zero = bf16::from_f32(-0.0);
         let neg_one = bf16::from_f32(-1.0);
-        let inf = bf16::from_f32(core::f32::INFINITY);
-        let neg_inf = bf16::from_f32(core::f32::NEG_INFINITY);
-        let nan = bf16::from_f32(core::f32::NAN);
+        let inf = bf16::from_f32(f32::INFINITY);
+        let neg_inf = bf16::from_f32(f32::NEG_INFINITY);
+        let nan = bf16::from_f32(f32::NAN);
 
         assert_eq!(bf16::ONE, one);
         assert_eq!(bf16::ZERO, zero);
@@ -1429,9 +2707,9 @@ mod test {
         let one = bf16::from_f64(1.0);
         let zero = bf16::from_f64(0.0);
         let neg_zero = bf16::from_f64(-0.0);
-        let inf = bf16::from_f64(core::f64::INFINITY);
-        let neg_inf = bf16::from_f64(core::f64::NEG_INFINITY);
-        let nan = bf16::from_f64(core::f64::NAN);
+        let inf = bf16::from_f64(f64::INFINITY);
+        let neg_inf = bf16::from_f64(f64::NEG_INFINITY);
+        let nan = bf16::from_f64(f64::NAN);
 
         assert_eq!(bf16::ONE, one);
         assert_eq!(bf16::ZERO, zero);
@@ -1623,6 +2901,232 @@ mod test {
         assert!(!(neg_one >= one));
     }
 
+    #[test]
+    fn test_ulp_distance() {
+        // Consecutive values are one apart across every exponent and the sign change
+        let mut prev = bf16::NEG_INFINITY;
+        let finite = bf16::all_finite()
+            .rev()
+            .take_while(|x| x.is_sign_negative());
+        for x in finite
+            .chain(
+                bf16::all_finite()
+                    .skip(1)
+                    .take_while(|x| x.is_sign_positive()),
+            )
+            .chain([bf16::INFINITY])
+        {
+            assert_eq!(prev.ulp_distance(x), Some(1));
+            assert_eq!(x.ulp_distance(prev), Some(1));
+            prev = x;
+        }
+        assert_eq!(bf16::MIN.ulp_distance(bf16::MAX), Some(0xFF00 - 2));
+        assert_eq!(bf16::NAN.ulp_distance(bf16::NAN), None);
+        assert_eq!(bf16::INFINITY.ulp_distance(-bf16::NAN), None);
+    }
+
+    #[test]
+    fn test_next_up_down() {
+        // Stepping through every non-NaN value in order matches `ulp_distance`
+        let mut x = bf16::NEG_INFINITY;
+        let mut steps = 0u32;
+        while x != bf16::INFINITY {
+            let next = x.next_up();
+            assert!(next > x);
+            assert_eq!(next.next_down().to_bits() & 0x7FFF, x.to_bits() & 0x7FFF);
+            x = next;
+            steps += 1;
+        }
+        assert_eq!(
+            steps,
+            bf16::NEG_INFINITY.ulp_distance(bf16::INFINITY).unwrap() as u32
+        );
+        assert_eq!(bf16::INFINITY.next_up(), bf16::INFINITY);
+        assert_eq!(bf16::NEG_INFINITY.next_down(), bf16::NEG_INFINITY);
+        assert_eq!((-bf16::MIN_POSITIVE_SUBNORMAL).next_up().to_bits(), 0x8000);
+        assert!(bf16::NAN.next_down().is_nan());
+    }
+
+    #[test]
+    fn test_ulp() {
+        for x in bf16::all_finite() {
+            let ulp = x.ulp();
+            let abs = x.copysign(bf16::ONE);
+            if abs == bf16::MAX {
+                assert_eq!(abs - abs.next_down(), ulp);
+            } else {
+                assert_eq!(abs.next_up() - abs, ulp);
+            }
+            assert_eq!(ulp, (-x).ulp());
+        }
+        assert_eq!(bf16::INFINITY.ulp(), bf16::INFINITY);
+        assert!(bf16::NAN.ulp().is_nan());
+    }
+
+    #[cfg(any(feature = "std", feature = "math"))]
+    #[test]
+    fn test_powf() {
+        let two = bf16::from_f32(2.0);
+        for i in -30..30 {
+            let expected = bf16::from_f64(f64::from_bits(((1023 + i) as u64) << 52));
+            assert_eq!(two.powf(bf16::from_f32(i as f32)), expected);
+            assert_eq!(two.powf_f32(i as f32), expected);
+        }
+        assert_eq!(two.powf(bf16::from_f32(128.0)), bf16::INFINITY);
+        assert_eq!(bf16::ZERO.powf(bf16::from_f32(-1.0)), bf16::INFINITY);
+        assert_eq!(
+            bf16::NEG_ZERO.powf(bf16::from_f32(-3.0)),
+            bf16::NEG_INFINITY
+        );
+        assert_eq!(bf16::ONE.powf(bf16::NAN), bf16::ONE);
+        assert_eq!(bf16::from_f32(0.5).powf(bf16::INFINITY), bf16::ZERO);
+        assert!(bf16::NAN.powf(bf16::ONE).is_nan());
+    }
+
+    #[cfg(any(feature = "std", feature = "math"))]
+    #[test]
+    fn test_ln_1p_exp_m1_exp2() {
+        for bits in [0x0001, 0x0003, 0x8001] {
+            let x = bf16::from_bits(bits);
+            assert_eq!(x.ln_1p().to_bits(), bits);
+            assert_eq!(x.exp_m1().to_bits(), bits);
+            assert_eq!(x.exp2(), bf16::ONE);
+        }
+        for i in -133..16 {
+            let x = bf16::from_f32(i as f32);
+            let expected = bf16::from_f64(f64::from_bits(((1023 + i) as u64) << 52));
+            assert_eq!(x.exp2(), expected);
+        }
+        assert_eq!(bf16::from_f32(-133.0 - 1.0).exp2(), bf16::ZERO);
+        assert_eq!(bf16::INFINITY.ln_1p(), bf16::INFINITY);
+        assert_eq!(bf16::INFINITY.exp_m1(), bf16::INFINITY);
+        assert_eq!(bf16::NEG_ZERO.ln_1p().to_bits(), 0x8000);
+        assert!(bf16::NAN.ln_1p().is_nan());
+        assert!(bf16::NAN.exp_m1().is_nan());
+        assert!(bf16::NAN.exp2().is_nan());
+    }
+
+    #[cfg(feature = "math")]
+    #[test]
+    fn test_special_functions() {
+        let mut factorial = 1.0;
+        for n in 1..9 {
+            let x = bf16::from_f32(n as f32);
+            assert_eq!(x.gamma(), bf16::from_f32(factorial));
+            factorial *= n as f32;
+        }
+        for bits in [0x0001, 0x8001] {
+            let x = bf16::from_bits(bits);
+            assert_eq!(
+                x.erf().to_bits(),
+                bf16::from_f64(x.to_f64() * core::f64::consts::FRAC_2_SQRT_PI).to_bits()
+            );
+            assert_eq!(x.erfc(), bf16::ONE);
+            assert!(x.gamma().is_infinite());
+            assert_eq!(x.gamma().is_sign_negative(), bits == 0x8001);
+        }
+        assert_eq!(bf16::INFINITY.erf(), bf16::ONE);
+        assert_eq!(bf16::INFINITY.erfc(), bf16::ZERO);
+        assert_eq!(bf16::INFINITY.gamma(), bf16::INFINITY);
+        assert_eq!(bf16::from_f32(2.0).ln_gamma(), (bf16::ZERO, 1));
+        assert_eq!(bf16::from_f32(-3.0).ln_gamma().0, bf16::INFINITY);
+        assert_eq!(bf16::from_f32(-2.5).ln_gamma().1, -1);
+        assert_eq!(bf16::from_f32(-1.5).ln_gamma().1, 1);
+        assert!(bf16::NAN.erf().is_nan());
+        assert!(bf16::NAN.gamma().is_nan());
+    }
+
+    #[test]
+    fn test_from_f32_with_flags() {
+        assert_eq!(
+            bf16::from_f32_with_flags(1.5),
+            (bf16::from_f32(1.5), ExceptionFlags::empty())
+        );
+        assert!(bf16::from_f32_with_flags(f32::INFINITY).1.is_empty());
+        assert!(bf16::from_f64_with_flags(f64::NAN).1.is_empty());
+        assert_eq!(
+            bf16::from_f32_with_flags(f32::from_bits(0x7F80_0001)).1,
+            ExceptionFlags::INVALID
+        );
+        assert_eq!(
+            bf16::from_f64_with_flags(f64::from_bits(0x7FF0_0000_0000_0001)).1,
+            ExceptionFlags::INVALID
+        );
+        assert_eq!(bf16::from_f64_with_flags(0.1).1, ExceptionFlags::INEXACT);
+        assert_eq!(
+            bf16::from_f32_with_flags(f32::from_bits(0x0001_0001)).1,
+            ExceptionFlags::UNDERFLOW | ExceptionFlags::INEXACT
+        );
+        assert!(bf16::from_f32_with_flags(f32::from_bits(0x0001_0000))
+            .1
+            .is_empty());
+        assert_eq!(
+            bf16::from_f64_with_flags(1e300).1,
+            ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT
+        );
+    }
+
+    #[test]
+    fn test_flush_subnormal_to_zero() {
+        for bits in 0..=u16::MAX {
+            let x = bf16::from_bits(bits);
+            let flushed = x.flush_subnormal_to_zero();
+            if bits & 0x7F80 == 0 {
+                assert_eq!(flushed.to_bits(), bits & 0x8000);
+            } else {
+                assert_eq!(flushed.to_bits(), bits);
+            }
+
+            // Flushing the input is the same as converting zero, and nothing is flushed otherwise
+            let f = x.to_f32();
+            assert_eq!(
+                x.to_f32_flush(FlushMode::Both).to_bits(),
+                flushed.to_f32().to_bits()
+            );
+            assert_eq!(x.to_f32_flush(FlushMode::Preserve).to_bits(), f.to_bits());
+            assert_eq!(
+                x.to_f64_flush(FlushMode::DenormalsAreZero).to_bits(),
+                flushed.to_f64().to_bits()
+            );
+            if !x.is_nan() {
+                assert_eq!(
+                    bf16::from_f32_flush(f, FlushMode::FlushToZero).to_bits(),
+                    flushed.to_bits()
+                );
+                assert_eq!(
+                    bf16::from_f64_flush(x.to_f64(), FlushMode::Both).to_bits(),
+                    flushed.to_bits()
+                );
+            }
+        }
+        assert_eq!(
+            bf16::from_bits(0x007F).flush_subnormal_to_zero(),
+            bf16::ZERO
+        );
+    }
+
+    #[test]
+    fn test_comparisons_match_f32() {
+        // Every bit pattern against a spread of others, including zeros, infinities and NaNs
+        let others = (0..=u16::MAX).step_by(1021).map(bf16::from_bits).chain([
+            bf16::NEG_ZERO,
+            bf16::INFINITY,
+            bf16::NEG_INFINITY,
+        ]);
+        for bits in 0..=u16::MAX {
+            let x = bf16::from_bits(bits);
+            for y in others.clone() {
+                let (fx, fy) = (x.to_f32(), y.to_f32());
+                assert_eq!(x.partial_cmp(&y), fx.partial_cmp(&fy));
+                assert_eq!(x == y, fx == fy);
+                assert_eq!(x < y, fx < fy);
+                assert_eq!(x <= y, fx <= fy);
+                assert_eq!(x > y, fx > fy);
+                assert_eq!(x >= y, fx >= fy);
+            }
+        }
+    }
+
     #[test]
     #[allow(clippy::erasing_op, clippy::identity_op)]
     fn round_to_even_f32() {
@@ -1813,12 +3317,320 @@ mod test {
         );
     }
 
-    impl quickcheck::Arbitrary for bf16 {
-        fn arbitrary(g: &mut quickcheck::Gen) -> Self {
-            bf16(u16::arbitrary(g))
+    #[test]
+    fn test_assign_ops() {
+        let mut x = bf16::from_f32(3.0);
+        x += bf16::from_f32(1.0);
+        assert_eq!(x, bf16::from_f32(4.0));
+        x -= &bf16::from_f32(2.0);
+        assert_eq!(x, bf16::from_f32(2.0));
+        x *= bf16::from_f32(4.0);
+        assert_eq!(x, bf16::from_f32(8.0));
+        x /= &bf16::from_f32(2.0);
+        assert_eq!(x, bf16::from_f32(4.0));
+        x %= bf16::from_f32(3.0);
+        assert_eq!(x, bf16::from_f32(1.0));
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let values = [
+            bf16::from_f32(1.0),
+            bf16::from_f32(2.0),
+            bf16::from_f32(3.0),
+            bf16::from_f32(4.0),
+        ];
+
+        assert_eq!(values.iter().copied().sum::<bf16>(), bf16::from_f32(10.0));
+        assert_eq!(values.iter().sum::<bf16>(), bf16::from_f32(10.0));
+        assert_eq!(
+            values.iter().copied().product::<bf16>(),
+            bf16::from_f32(24.0)
+        );
+        assert_eq!(values.iter().product::<bf16>(), bf16::from_f32(24.0));
+
+        let empty: [bf16; 0] = [];
+        assert_eq!(empty.iter().sum::<bf16>(), bf16::ZERO);
+        assert_eq!(empty.iter().product::<bf16>(), bf16::ONE);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_format_bits() {
+        let one = bf16::ONE;
+        assert_eq!(format!("{:x}", one), "3f80");
+        assert_eq!(format!("{:#06X}", one), "0x3F80");
+        assert_eq!(format!("{:016b}", one), "0011111110000000");
+        assert_eq!(format!("{:#b}", -one), "0b1011111110000000");
+        assert_eq!(format!("{:>8o}", one), "   37600");
+        assert_eq!(format!("{:_<6x}", one), "3f80__");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_display_shortest() {
+        assert_eq!(bf16::from_f32(0.1).to_string(), "0.1");
+        assert_eq!(bf16::from_f32(-1234.0).to_string(), "-1230");
+        assert_eq!(bf16::NEG_ZERO.to_string(), "-0");
+        assert_eq!(bf16::EPSILON.to_string(), "0.0078");
+        assert_eq!(bf16::from_f32(1e10).to_string(), "10000000000");
+        assert_eq!(bf16::INFINITY.to_string(), "inf");
+        assert_eq!(bf16::NAN.to_string(), "NaN");
+        assert_eq!(format!("{:.3}", bf16::from_f32(0.3)), "0.301");
+
+        assert_eq!(format!("{:e}", bf16::from_f32(0.3)), "3e-1");
+        assert_eq!(format!("{:E}", bf16::from_f32(-1234.0)), "-1.23E3");
+        assert_eq!(format!("{:e}", bf16::MAX), "3.39e38");
+        assert_eq!(format!("{:e}", bf16::MIN_POSITIVE), "1.18e-38");
+        assert_eq!(format!("{:e}", bf16::MIN_POSITIVE_SUBNORMAL), "9e-41");
+        assert_eq!(format!("{:+>8e}", bf16::from_f32(1.5)), "+++1.5e0");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_debug() {
+        assert_eq!(format!("{:?}", bf16::ONE), "1.0");
+        assert_eq!(format!("{:?}", bf16::from_f32(-0.1)), "-0.1");
+        assert_eq!(format!("{:?}", bf16::NEG_ZERO), "-0.0");
+        assert_eq!(format!("{:?}", bf16::MAX), "3.39e38");
+        assert_eq!(format!("{:?}", [bf16::ONE, bf16::INFINITY]), "[1.0, inf]");
+        assert_eq!(format!("{:.2?}", bf16::from_f32(0.3)), "0.30");
+        assert_eq!(format!("{:>6?}", bf16::ONE), "   1.0");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_display_roundtrip_exhaustive() {
+        for bits in 0..=u16::MAX {
+            let x = bf16::from_bits(bits);
+            if !x.is_finite() {
+                continue;
+            }
+            let display = x.to_string();
+            let exp = format!("{:e}", x);
+            let shown = display.parse::<f64>().unwrap();
+            assert_eq!(bf16::from_f64(shown).to_bits(), bits, "{}", display);
+            assert_eq!(exp.parse::<f64>().unwrap(), shown, "{}", exp);
+            // Dropping the last digit must not round trip, or the output is not the shortest
+            let (mantissa, exponent) = exp.split_once('e').unwrap();
+            let digits = mantissa.replace(['-', '.'], "");
+            if digits.len() > 1 {
+                let decimal = exponent.parse::<i32>().unwrap() - digits.len() as i32 + 2;
+                let shorter: u64 = digits[..digits.len() - 1].parse().unwrap();
+                for candidate in [shorter, shorter + 1] {
+                    let value: f64 = format!("{}e{}", candidate, decimal).parse().unwrap();
+                    let value = if x.is_sign_negative() { -value } else { value };
+                    assert_ne!(bf16::from_f64(value).to_bits(), bits, "{}", display);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_try_from_float() {
+        use core::convert::TryFrom;
+        assert_eq!(bf16::try_from(0.5f32), Ok(bf16::from_f32(0.5)));
+        assert_eq!(bf16::try_from(-256.0f64), Ok(bf16::from_f32(-256.0)));
+        assert!(bf16::try_from(f32::NAN).unwrap().is_nan());
+        assert_eq!(bf16::try_from(f32::INFINITY), Ok(bf16::INFINITY));
+        assert!(!bf16::try_from(0.1f32).unwrap_err().is_overflow());
+        assert!(!bf16::try_from(257.0f64).unwrap_err().is_overflow());
+        assert!(bf16::try_from(f32::MAX).unwrap_err().is_overflow());
+        assert!(bf16::try_from(-1e300f64).unwrap_err().is_overflow());
+    }
+
+    #[quickcheck]
+    fn qc_to_int_matches_as(x: bf16) -> bool {
+        x.to_int::<i8>() == x.to_f32() as i8
+            && x.to_int::<u32>() == x.to_f32() as u32
+            && x.to_int::<i128>() == x.to_f32() as i128
+    }
+
+    #[quickcheck]
+    fn qc_from_f32_round(x: f32) -> bool {
+        use crate::RoundingMode::*;
+        let nearest = bf16::from_f32_round(x, NearestEven);
+        let down = bf16::from_f32_round(x, TowardNegative);
+        let up = bf16::from_f32_round(x, TowardPositive);
+        let zero = bf16::from_f32_round(x, TowardZero);
+        if x.is_nan() {
+            return nearest.is_nan() && down.is_nan() && up.is_nan() && zero.is_nan();
+        }
+        let exact = bf16::from_f32_exact(x).is_some();
+        nearest.to_bits() == bf16::from_f32(x).to_bits()
+            && down.to_f32() <= x
+            && x <= up.to_f32()
+            && (exact || down.to_bits() != up.to_bits())
+            && zero.to_f32().abs() <= x.abs()
+            && (zero == down || zero == up)
+    }
+
+    #[quickcheck]
+    fn qc_from_f32_truncate(x: f32) -> bool {
+        let truncated = bf16::from_f32_truncate(x);
+        if x.is_nan() {
+            return truncated.is_nan() && truncated.is_sign_negative() == x.is_sign_negative();
+        }
+        truncated.to_bits() == bf16::from_f32_round(x, crate::RoundingMode::TowardZero).to_bits()
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_hex_float() {
+        assert_eq!(bf16::ONE.to_hex_string(), "0x1p+0");
+        assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.to_hex_string(), "0x0.02p-126");
+        for bits in 0..=u16::MAX {
+            let x = bf16::from_bits(bits);
+            if !x.is_nan() {
+                assert_eq!(x.to_hex_string().parse::<bf16>().unwrap().to_bits(), bits);
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parse_correctly_rounded() {
+        let parse = |s: &str| s.parse::<bf16>().map(bf16::to_bits).ok();
+        assert_eq!(parse("-1.5e-3"), Some(bf16::from_f32(-1.5e-3).to_bits()));
+        assert_eq!(parse("3.4e38"), Some(0x7F80));
+        assert_eq!(parse("-INF"), Some(0xFF80));
+        assert_eq!(parse("1e-46"), Some(0));
+        assert_eq!(parse("1."), Some(0x3F80));
+        assert_eq!(bf16::from_str_radix("z", 36), Ok(bf16::from_f32(35.0)));
+        for invalid in ["", "-", "1e", "0x", "1.2.3"] {
+            assert_eq!(parse(invalid), None, "{}", invalid);
+        }
+
+        // The halfway point between 1.0 and the next value is exactly representable in `f32`,
+        // where parsing through `f32` would round ties to even
+        assert_eq!(parse("1.00390625"), Some(0x3F80));
+        assert_eq!(parse("1.00390625000000000000000001"), Some(0x3F81));
+
+        for bits in 0..0x7F80u16 {
+            let x = bf16::from_bits(bits);
+            assert_eq!(parse(&format!("{:e}", x.to_f64())), Some(bits));
+
+            let next = if x == bf16::MAX {
+                2f64.powi(128)
+            } else {
+                bf16::from_bits(bits + 1).to_f64()
+            };
+            let mid = (x.to_f64() + next) / 2.0;
+            let exact = format!("{:.160e}", mid);
+            let (digits, exp) = exact.split_once('e').unwrap();
+            let digits = digits.trim_end_matches('0').trim_end_matches('.');
+            let even = if bits & 1 == 0 { bits } else { bits + 1 };
+            assert_eq!(parse(&format!("{}e{}", digits, exp)), Some(even));
+            let point = if digits.contains('.') { "" } else { "." };
+            let above = format!("{}{}0001e{}", digits, point, exp);
+            assert_eq!(parse(&above), Some(bits + 1), "{}", above);
+        }
+    }
+
+    #[test]
+    fn test_parse_strict() {
+        let err = |s: &str| bf16::parse_strict(s).unwrap_err();
+        assert_eq!(bf16::parse_strict("1e38"), Ok(bf16::from_f32(1e38)));
+        assert_eq!(bf16::parse_strict("-inf"), Ok(bf16::NEG_INFINITY));
+        assert_eq!(bf16::parse_strict("0"), Ok(bf16::ZERO));
+        assert!(err("3.4e38").is_overflow());
+        assert!(err("-1e-45").is_underflow());
+        assert!(!err("1.5.").is_overflow());
+    }
+
+    #[test]
+    fn test_from_int() {
+        assert_eq!(
+            bf16::from_i16(-256).to_bits(),
+            bf16::from_f32(-256.0).to_bits()
+        );
+        assert_eq!(bf16::from_u16(0).to_bits(), bf16::ZERO.to_bits());
+        // Ties round to even
+        assert_eq!(bf16::from_u16(257), bf16::from_f32(256.0));
+        assert_eq!(bf16::from_i32(-259), bf16::from_f32(-260.0));
+        // Rounding through f32 first would tie to even twice and give 2^24
+        assert_eq!(bf16::from_u32(0x0101_0001), bf16::from_f32(16_908_288.0));
+        assert_eq!(bf16::from_i64(i64::MIN), bf16::from_f32(-9.223_372e18));
+        assert_eq!(bf16::from_u64(u64::MAX), bf16::from_f32(1.844_674_4e19));
+    }
+
+    #[quickcheck]
+    fn qc_from_i16_matches_f32(x: i16) -> bool {
+        // Every i16 is exact in f32, so converting from there rounds only once
+        bf16::from_i16(x).to_bits() == bf16::from_f32(x as f32).to_bits()
+    }
+
+    #[test]
+    fn test_from_f64_sticky_rounding() {
+        // Just above halfway, with the deciding bit outside the high 32 bits of the f64
+        let x = 1.0 + 2f64.powi(-8) + 2f64.powi(-40);
+        assert_eq!(bf16::from_f64(x).to_bits(), 0x3F81);
+        assert_eq!(bf16::from_f64_const(x).to_bits(), 0x3F81);
+    }
+
+    #[quickcheck]
+    fn qc_from_f32_stochastic_adds_low_bits(f: f32, rng: u16) -> bool {
+        // For finite results, stochastic rounding is adding to the discarded half and truncating
+        let bits = f.to_bits();
+        let sum = (bits & 0x7FFF_FFFF) + rng as u32;
+        let expected = (bits & 0x8000_0000 | sum) >> 16;
+        let rounded = bf16::from_f32_stochastic(f, rng);
+        f.is_nan() || sum >= 0x7F80_0000 || rounded.to_bits() as u32 == expected
+    }
+
+    #[test]
+    fn test_from_float_saturating() {
+        assert_eq!(bf16::from_f32_saturating(f32::MAX), bf16::MAX);
+        assert_eq!(bf16::from_f64_saturating(-1e300), bf16::MIN);
+        assert_eq!(bf16::from_f32_saturating(1.5), bf16::from_f32(1.5));
+        assert_eq!(
+            bf16::from_f64_saturating(f64::NEG_INFINITY),
+            bf16::NEG_INFINITY
+        );
+        assert!(bf16::from_f32_saturating(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_from_float_exact() {
+        assert_eq!(bf16::from_f32_exact(-256.0), Some(bf16::from_f32(-256.0)));
+        assert_eq!(bf16::from_f32_exact(257.0), None);
+        assert_eq!(bf16::from_f64_exact(1e300), None);
+        assert_eq!(
+            bf16::from_f64_exact(f64::NEG_INFINITY),
+            Some(bf16::NEG_INFINITY)
+        );
+        assert!(bf16::is_exactly_representable(f32::from_bits(0x3F81_0000)));
+        assert!(!bf16::is_exactly_representable(f32::from_bits(0x3F81_0001)));
+    }
+
+    #[test]
+    fn test_from_f16() {
+        // Going through f32 is exact for f16, so it only rounds once as well
+        for bits in 0..=u16::MAX {
+            let x = f16::from_bits(bits);
+            assert_eq!(
+                bf16::from_f16(x).to_bits(),
+                bf16::from_f32(x.to_f32()).to_bits(),
+                "{:#06x}",
+                bits
+            );
         }
     }
 
+    #[test]
+    fn test_fixed_point() {
+        assert_eq!(bf16::from_f32(0.25).to_fixed::<i16>(15), 0x2000);
+        assert_eq!(bf16::MAX.to_fixed::<i128>(0), i128::MAX);
+        assert_eq!(bf16::from_f32(-1e-10).to_fixed::<i32>(31), 0);
+        assert_eq!(bf16::from_fixed(i16::MIN, 15), -bf16::ONE);
+        assert_eq!(bf16::from_fixed(257u16, 0), bf16::from_f32(256.0));
+        assert_eq!(bf16::from_fixed(u128::MAX, 0), bf16::INFINITY);
+        assert_eq!(
+            bf16::from_fixed(u128::MAX, 1),
+            bf16::from_f32(2f32.powi(127))
+        );
+    }
+
     #[quickcheck]
     fn qc_roundtrip_bf16_f32_is_identity(f: bf16) -> bool {
         let roundtrip = bf16::from_f32(f.to_f32());
@@ -1,3 +1,9 @@
+// `f32::from_bits`/`to_bits` are not `const` on our MSRV, see the TODOs below
+#![allow(
+    unknown_lints,
+    unnecessary_transmutes,
+    clippy::missing_transmute_annotations
+)]
 use crate::leading_zeros::leading_zeros_u16;
 use core::mem;
 
@@ -21,12 +27,25 @@ pub(crate) const fn f32_to_bf16(value: f32) -> u16 {
     }
 }
 
+// Drops the low 16 bits, which rounds toward zero, except that NaN values stay NaN
+pub(crate) const fn f32_to_bf16_truncate(value: f32) -> u16 {
+    // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
+    let x: u32 = unsafe { mem::transmute(value) };
+
+    // A NaN with only low payload bits set would otherwise become infinity
+    if x & 0x7FFF_FFFFu32 > 0x7F80_0000u32 {
+        return ((x >> 16) | 0x0040u32) as u16;
+    }
+    (x >> 16) as u16
+}
+
 pub(crate) const fn f64_to_bf16(value: f64) -> u16 {
     // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
     // Convert to raw bytes, truncating the last 32-bits of mantissa; that precision will always
-    // be lost on half-precision.
+    // be lost on half-precision, but any set bits there are folded into the lowest remaining bit
+    // so they still count towards rounding.
     let val: u64 = unsafe { mem::transmute(value) };
-    let x = (val >> 32) as u32;
+    let x = (val >> 32) as u32 | (val as u32 != 0) as u32;
 
     // Extract IEEE754 components
     let sign = x & 0x8000_0000u32;
@@ -88,6 +107,33 @@ pub(crate) const fn f64_to_bf16(value: f64) -> u16 {
     }
 }
 
+// Converts an integer magnitude to bfloat16 with the given sign bit, rounding to nearest even.
+// The exponent range of bfloat16 covers any `u64`, so this never overflows.
+pub(crate) const fn u64_to_bf16(sign: u16, value: u64) -> u16 {
+    if value == 0 {
+        return sign;
+    }
+
+    // The most significant set bit becomes the hidden mantissa bit
+    let msb = 63 - value.leading_zeros();
+    let man = if msb <= 7 {
+        value << (7 - msb)
+    } else {
+        let shift = msb - 7;
+        let man = value >> shift;
+        // Check for rounding
+        let round_bit = 1u64 << (shift - 1);
+        if (value & round_bit) != 0 && (value & (3 * round_bit - 1)) != 0 {
+            man + 1
+        } else {
+            man
+        }
+    };
+
+    // Adding the hidden bit into the exponent field also carries a rounding overflow into it
+    sign | ((((msb + 126) as u64) << 7) + man) as u16
+}
+
 pub(crate) const fn bf16_to_f32(i: u16) -> f32 {
     // TODO: Replace mem::transmute with from_bits() once from_bits is const-stabilized
     // If NaN, keep current mantissa but also set most significiant mantissa bit
@@ -142,3 +188,133 @@ pub(crate) const fn bf16_to_f64(i: u16) -> f64 {
     let man = (half_man & 0x007Fu64) << 45;
     unsafe { mem::transmute(sign | exp | man) }
 }
+
+// Converts a slice with the AVX-512 BF16 or AVX-NE-CONVERT instructions when the `use-intrinsics`
+// feature is enabled and the CPU supports them.
+#[cfg(all(
+    feature = "use-intrinsics",
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+pub(crate) use x86::f32_to_bf16_slice;
+
+#[cfg(not(all(
+    feature = "use-intrinsics",
+    any(target_arch = "x86", target_arch = "x86_64")
+)))]
+pub(crate) use self::f32_to_bf16_slice_fallback as f32_to_bf16_slice;
+
+#[inline]
+pub(crate) fn f32_to_bf16_slice_fallback(src: &[f32], dst: &mut [u16]) {
+    for (dst, f) in dst.iter_mut().zip(src) {
+        *dst = f32_to_bf16(*f);
+    }
+}
+
+// Truncating eight values at once is simple enough for the compiler to vectorize on its own
+#[inline]
+pub(crate) fn f32x8_to_bf16x8_truncate(v: &[f32]) -> [u16; 8] {
+    debug_assert!(v.len() >= 8);
+
+    let mut result = [0; 8];
+    for (dst, f) in result.iter_mut().zip(v) {
+        *dst = f32_to_bf16_truncate(*f);
+    }
+    result
+}
+
+/////////////// x86/x86_64 AVX-512 BF16 and AVX-NE-CONVERT ////////////////
+#[cfg(all(
+    feature = "use-intrinsics",
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+// The AVX-512 BF16 and AVX-NE-CONVERT intrinsics need Rust 1.89, newer than the crate MSRV
+#[allow(clippy::incompatible_msrv)]
+mod x86 {
+    use super::{f32_to_bf16, f32_to_bf16_slice_fallback};
+    use crate::dispatch::{convert_chunks, multiversion};
+
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::{__m128bh, _mm256_cvtneps_avx_pbh, _mm256_cvtneps_pbh, _mm256_loadu_ps};
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::{
+        __m128bh, _mm256_cvtneps_avx_pbh, _mm256_cvtneps_pbh, _mm256_loadu_ps,
+    };
+
+    // Without `std` there is no runtime detection, so only use the instructions if the compile
+    // target has them.
+    #[inline]
+    pub(super) fn has_avx512bf16() -> bool {
+        #[cfg(feature = "std")]
+        {
+            std::is_x86_feature_detected!("avx512bf16") && std::is_x86_feature_detected!("avx512vl")
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            cfg!(all(
+                target_feature = "avx512bf16",
+                target_feature = "avx512vl"
+            ))
+        }
+    }
+
+    #[inline]
+    pub(super) fn has_avxneconvert() -> bool {
+        #[cfg(feature = "std")]
+        {
+            std::is_x86_feature_detected!("avxneconvert")
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            cfg!(target_feature = "avxneconvert")
+        }
+    }
+
+    multiversion! {
+        #[inline]
+        pub(crate) fn f32_to_bf16_slice(src: &[f32], dst: &mut [u16]) {
+            has_avx512bf16() => f32_to_bf16_slice_avx512bf16,
+            has_avxneconvert() => f32_to_bf16_slice_avxneconvert,
+            _ => f32_to_bf16_slice_fallback,
+        }
+    }
+
+    #[target_feature(enable = "avx512bf16,avx512vl")]
+    unsafe fn f32_to_bf16_slice_avx512bf16(src: &[f32], dst: &mut [u16]) {
+        convert_chunks!(src, dst, 8, f32x8_to_bf16x8_avx512bf16)
+    }
+
+    #[target_feature(enable = "avxneconvert")]
+    unsafe fn f32_to_bf16_slice_avxneconvert(src: &[f32], dst: &mut [u16]) {
+        convert_chunks!(src, dst, 8, f32x8_to_bf16x8_avxneconvert)
+    }
+
+    #[target_feature(enable = "avx512bf16,avx512vl")]
+    #[inline]
+    unsafe fn f32x8_to_bf16x8_avx512bf16(v: &[f32], dst: &mut [u16]) {
+        debug_assert!(v.len() >= 8 && dst.len() >= 8);
+
+        let retval = _mm256_cvtneps_pbh(_mm256_loadu_ps(v.as_ptr()));
+        dst[..8].copy_from_slice(&fix_subnormals(v, *(&retval as *const __m128bh).cast()));
+    }
+
+    #[target_feature(enable = "avxneconvert")]
+    #[inline]
+    unsafe fn f32x8_to_bf16x8_avxneconvert(v: &[f32], dst: &mut [u16]) {
+        debug_assert!(v.len() >= 8 && dst.len() >= 8);
+
+        let retval = _mm256_cvtneps_avx_pbh(_mm256_loadu_ps(v.as_ptr()));
+        dst[..8].copy_from_slice(&fix_subnormals(v, *(&retval as *const __m128bh).cast()));
+    }
+
+    // Both instructions treat subnormal inputs as zero, so redo those in software to get the
+    // same results as the scalar conversion.
+    #[inline]
+    fn fix_subnormals(v: &[f32], mut result: [u16; 8]) -> [u16; 8] {
+        for (dst, f) in result.iter_mut().zip(v) {
+            if f.is_subnormal() {
+                *dst = f32_to_bf16(*f);
+            }
+        }
+        result
+    }
+}
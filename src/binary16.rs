@@ -1,3 +1,18 @@
+#[cfg(any(feature = "std", feature = "math"))]
+use crate::math;
+use crate::{
+    bf16, exceptions, flush, int,
+    rounding::{self, Rounding},
+    ExceptionFlags, FloatToInt, FlushMode, RoundingMode,
+};
+#[cfg(not(target_arch = "spirv"))]
+use crate::{
+    fmt,
+    iter::{AllFinite, AllValues},
+    parse, ParseError, TryFromFloatError,
+};
+#[cfg(all(feature = "alloc", not(target_arch = "spirv")))]
+use alloc::string::{String, ToString};
 #[cfg(feature = "bytemuck")]
 use bytemuck::{Pod, Zeroable};
 use core::{
@@ -17,9 +32,12 @@ use core::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "zerocopy")]
-use zerocopy::{AsBytes, FromBytes};
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
 
+pub(crate) mod arith;
 pub(crate) mod convert;
+#[cfg(feature = "nightly-f16")]
+mod nightly;
 
 /// A 16-bit floating point type implementing the IEEE 754-2008 standard [`binary16`] a.k.a `half`
 /// format.
@@ -30,13 +48,25 @@ pub(crate) mod convert;
 /// implemented. Operations should be performed with [`f32`] or higher-precision types and converted
 /// to/from [`f16`] as necessary.
 ///
+/// [`f16`] is `#[repr(transparent)]` around the [`u16`] returned by [`to_bits`][Self::to_bits], so
+/// it has the same size, alignment and function call ABI as [`u16`] and the C `uint16_t` type.
+///
 /// [`binary16`]: https://en.wikipedia.org/wiki/Half-precision_floating-point_format
 #[allow(non_camel_case_types)]
 #[derive(Clone, Copy, Default)]
 #[repr(transparent)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 #[cfg_attr(feature = "bytemuck", derive(Zeroable, Pod))]
-#[cfg_attr(feature = "zerocopy", derive(AsBytes, FromBytes))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(FromBytes, Immutable, IntoBytes, KnownLayout)
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(archived = "ArchivedF16", resolver = "F16Resolver"),
+    archive_attr(derive(Clone, Copy, Debug))
+)]
 pub struct f16(u16);
 
 impl f16 {
@@ -107,6 +137,403 @@ impl f16 {
         f16(convert::f64_to_f16_fallback(value))
     }
 
+    /// Constructs a 16-bit floating point value from a 32-bit floating point value, rounding in
+    /// the given direction.
+    ///
+    /// With [`RoundingMode::NearestEven`] this gives the same result as
+    /// [`from_f32`][Self::from_f32]. The other modes also decide whether values too large for
+    /// 16-bits become ±∞ or the largest finite value of the same sign. NaN values are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// use half::RoundingMode;
+    ///
+    /// let third = 1.0 / 3.0;
+    /// let down = f16::from_f32_round(third, RoundingMode::TowardNegative);
+    /// let up = f16::from_f32_round(third, RoundingMode::TowardPositive);
+    /// assert!(down.to_f32() < third && third < up.to_f32());
+    /// assert_eq!(up.to_bits() - down.to_bits(), 1);
+    ///
+    /// assert_eq!(f16::from_f32_round(1e6, RoundingMode::TowardZero), f16::MAX);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_f32_round(value: f32, mode: RoundingMode) -> f16 {
+        f16(rounding::f32_to_ieee_round(
+            value,
+            Rounding::Mode(mode),
+            10,
+            5,
+        ))
+    }
+
+    /// Constructs a 16-bit floating point value from a 64-bit floating point value, rounding in
+    /// the given direction.
+    ///
+    /// With [`RoundingMode::NearestEven`] this gives the same result as
+    /// [`from_f64`][Self::from_f64]. The other modes also decide whether values too large for
+    /// 16-bits become ±∞ or the largest finite value of the same sign. NaN values are preserved.
+    #[inline]
+    #[must_use]
+    pub const fn from_f64_round(value: f64, mode: RoundingMode) -> f16 {
+        f16(rounding::f64_to_ieee_round(
+            value,
+            Rounding::Mode(mode),
+            10,
+            5,
+        ))
+    }
+
+    /// Constructs a 16-bit floating point value from a 32-bit floating point value using stochastic
+    /// rounding.
+    ///
+    /// The value is rounded away from zero with a probability equal to the fraction of a unit in
+    /// the last place that would otherwise be truncated, and toward zero otherwise. `rng_bits`
+    /// must be uniformly random for each call; it is added just below the last retained bit, so
+    /// the probability is quantized to 16 bits. This makes rounding errors unbiased on average,
+    /// which keeps the small updates of low-precision training from being lost.
+    ///
+    /// Subnormal results are rounded the same way. Values whose rounded magnitude would exceed
+    /// [`f16::MAX`] become ±∞, and NaN values are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// // A quarter of the way between 1.0 and the next f16 value
+    /// let x = 1.0 + f16::EPSILON.to_f32() / 4.0;
+    /// assert_eq!(f16::from_f32_stochastic(x, 0), f16::ONE);
+    /// assert_eq!(f16::from_f32_stochastic(x, 0xBFFF), f16::ONE);
+    /// assert_eq!(f16::from_f32_stochastic(x, 0xC000), f16::ONE + f16::EPSILON);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_f32_stochastic(value: f32, rng_bits: u16) -> f16 {
+        f16(rounding::f32_to_ieee_round(
+            value,
+            Rounding::Stochastic(rng_bits),
+            10,
+            5,
+        ))
+    }
+
+    /// Constructs a [`f16`] value from a 32-bit floating point value, flushing subnormal inputs
+    /// or results to zero as selected by `mode`.
+    ///
+    /// With [`FlushMode::Preserve`] this is the same as [`from_f32`][Self::from_f32]. Flushed
+    /// values keep their sign.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// use half::FlushMode;
+    ///
+    /// // f16 has subnormals down to 2^-24, but flushing hardware rounds them away
+    /// let tiny = 1e-6;
+    /// assert_eq!(f16::from_f32_flush(tiny, FlushMode::Preserve).to_bits(), 0x0011);
+    /// assert_eq!(f16::from_f32_flush(tiny, FlushMode::FlushToZero), f16::ZERO);
+    /// assert_eq!(f16::from_f32_flush(-tiny, FlushMode::Both).to_bits(), 0x8000);
+    ///
+    /// // Only the result is checked, so values rounding up to the smallest normal are kept
+    /// let almost_min = f16::MIN_POSITIVE.to_f32() * 0.9999;
+    /// assert_eq!(f16::from_f32_flush(almost_min, FlushMode::Both), f16::MIN_POSITIVE);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_f32_flush(value: f32, mode: FlushMode) -> f16 {
+        let value = if mode.flushes_inputs() {
+            flush::flush_f32(value)
+        } else {
+            value
+        };
+        let result = f16::from_f32(value);
+        if mode.flushes_outputs() {
+            result.flush_subnormal_to_zero()
+        } else {
+            result
+        }
+    }
+
+    /// Constructs a [`f16`] value from a 64-bit floating point value, flushing subnormal inputs
+    /// or results to zero as selected by `mode`.
+    ///
+    /// With [`FlushMode::Preserve`] this is the same as [`from_f64`][Self::from_f64].
+    #[inline]
+    #[must_use]
+    pub fn from_f64_flush(value: f64, mode: FlushMode) -> f16 {
+        let value = if mode.flushes_inputs() {
+            flush::flush_f64(value)
+        } else {
+            value
+        };
+        let result = f16::from_f64(value);
+        if mode.flushes_outputs() {
+            result.flush_subnormal_to_zero()
+        } else {
+            result
+        }
+    }
+
+    /// Constructs a [`f16`] value from a 32-bit floating point value, also returning the
+    /// floating point exceptions raised by the conversion.
+    ///
+    /// The result is the same as [`from_f32`][Self::from_f32]. A signaling NaN raises
+    /// [`ExceptionFlags::INVALID`], and a rounded result raises [`ExceptionFlags::INEXACT`] along
+    /// with [`ExceptionFlags::OVERFLOW`] if it rounded to infinity, or
+    /// [`ExceptionFlags::UNDERFLOW`] if the value was below [`f16::MIN_POSITIVE`] in magnitude.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// use half::ExceptionFlags;
+    ///
+    /// let (x, flags) = f16::from_f32_with_flags(0.5);
+    /// assert_eq!(x, f16::from_f32(0.5));
+    /// assert!(flags.is_empty());
+    ///
+    /// let (_, flags) = f16::from_f32_with_flags(0.1);
+    /// assert_eq!(flags, ExceptionFlags::INEXACT);
+    ///
+    /// let (x, flags) = f16::from_f32_with_flags(1e5);
+    /// assert_eq!(x, f16::INFINITY);
+    /// assert_eq!(flags, ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT);
+    ///
+    /// let (_, flags) = f16::from_f32_with_flags(1e-7);
+    /// assert_eq!(flags, ExceptionFlags::UNDERFLOW | ExceptionFlags::INEXACT);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_f32_with_flags(value: f32) -> (f16, ExceptionFlags) {
+        let result = f16::from_f32(value);
+        let signaling = value.is_nan() && value.to_bits() & 0x0040_0000 == 0;
+        let flags = exceptions::conversion_flags(
+            f64::from(value),
+            signaling,
+            result.to_f64(),
+            f16::MIN_POSITIVE.to_f64(),
+        );
+        (result, flags)
+    }
+
+    /// Constructs a [`f16`] value from a 64-bit floating point value, also returning the
+    /// floating point exceptions raised by the conversion.
+    ///
+    /// The result is the same as [`from_f64`][Self::from_f64], and the exceptions are those of
+    /// [`from_f32_with_flags`][Self::from_f32_with_flags].
+    #[inline]
+    #[must_use]
+    pub fn from_f64_with_flags(value: f64) -> (f16, ExceptionFlags) {
+        let result = f16::from_f64(value);
+        let signaling = value.is_nan() && value.to_bits() & 0x0008_0000_0000_0000 == 0;
+        let flags = exceptions::conversion_flags(
+            value,
+            signaling,
+            result.to_f64(),
+            f16::MIN_POSITIVE.to_f64(),
+        );
+        (result, flags)
+    }
+
+    /// Constructs a [`f16`] value from a 32-bit floating point value, saturating finite values
+    /// that are too large to [`f16::MAX`] or [`f16::MIN`] instead of producing ±∞.
+    ///
+    /// Otherwise identical to [`from_f32`][Self::from_f32]: values are rounded to nearest, and
+    /// infinities and NaN values are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(f16::from_f32_saturating(1e6), f16::MAX);
+    /// assert_eq!(f16::from_f32_saturating(-1e6), f16::MIN);
+    /// assert_eq!(f16::from_f32_saturating(f32::NEG_INFINITY), f16::NEG_INFINITY);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_f32_saturating(value: f32) -> f16 {
+        let result = f16::from_f32(value);
+        if result.is_infinite() && value.is_finite() {
+            f16::MAX.copysign(result)
+        } else {
+            result
+        }
+    }
+
+    /// Constructs a [`f16`] value from a 64-bit floating point value, saturating finite values
+    /// that are too large to [`f16::MAX`] or [`f16::MIN`] instead of producing ±∞.
+    ///
+    /// Otherwise identical to [`from_f64`][Self::from_f64]: values are rounded to nearest, and
+    /// infinities and NaN values are preserved.
+    #[inline]
+    #[must_use]
+    pub fn from_f64_saturating(value: f64) -> f16 {
+        let result = f16::from_f64(value);
+        if result.is_infinite() && value.is_finite() {
+            f16::MAX.copysign(result)
+        } else {
+            result
+        }
+    }
+
+    /// Constructs a [`f16`] value from a 32-bit floating point value only if no rounding is
+    /// needed, returning [`None`] otherwise.
+    ///
+    /// Values that would overflow to ±∞ or underflow to zero are not exact either. NaN values are
+    /// always converted, keeping as much of the payload as fits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(f16::from_f32_exact(0.375), Some(f16::from_f32(0.375)));
+    /// assert_eq!(f16::from_f32_exact(0.1), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_f32_exact(value: f32) -> Option<f16> {
+        let result = f16::from_f32(value);
+        if value.is_nan() || result.to_f32() == value {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Constructs a [`f16`] value from a 64-bit floating point value only if no rounding is
+    /// needed, returning [`None`] otherwise.
+    ///
+    /// Values that would overflow to ±∞ or underflow to zero are not exact either. NaN values are
+    /// always converted, keeping as much of the payload as fits.
+    #[inline]
+    #[must_use]
+    pub fn from_f64_exact(value: f64) -> Option<f16> {
+        let result = f16::from_f64(value);
+        if value.is_nan() || result.to_f64() == value {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the 32-bit floating point value can be converted to [`f16`] without
+    /// rounding.
+    ///
+    /// This is equivalent to `f16::from_f32_exact(value).is_some()`, so NaN values are considered
+    /// representable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert!(f16::is_exactly_representable(0.375));
+    /// assert!(!f16::is_exactly_representable(0.1));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_exactly_representable(value: f32) -> bool {
+        f16::from_f32_exact(value).is_some()
+    }
+
+    /// Constructs a [`f16`] value from a [`bf16`] value in a single rounding step.
+    ///
+    /// [`bf16`] has fewer mantissa bits but a much wider exponent range, so the conversion is
+    /// exact for [`bf16`] values within the normal range of [`f16`]. Smaller values are rounded to
+    /// nearest, ties to even, into the [`f16`] subnormals or ±0, and values too large for
+    /// [`f16`] become ±∞. NaN values are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(f16::from_bf16(bf16::from_f32(1.5)), f16::from_f32(1.5));
+    /// assert_eq!(f16::from_bf16(bf16::MAX), f16::INFINITY);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_bf16(value: bf16) -> f16 {
+        f16(rounding::ieee16_to_ieee_round(
+            value.to_bits(),
+            7,
+            8,
+            Rounding::Mode(RoundingMode::NearestEven),
+            10,
+            5,
+        ))
+    }
+
+    /// Constructs a [`f16`] from a 16-bit signed integer.
+    ///
+    /// Integers with a magnitude above 2048 are rounded to the nearest representable value, with
+    /// ties rounding to even, and values too large for 16 bits result in ±∞. Unlike converting
+    /// through a wider float first, this never rounds twice.
+    #[inline]
+    #[must_use]
+    pub const fn from_i16(value: i16) -> f16 {
+        let sign = if value < 0 { 0x8000 } else { 0 };
+        f16(convert::u64_to_f16(sign, value.unsigned_abs() as u64))
+    }
+
+    /// Constructs a [`f16`] from a 16-bit unsigned integer.
+    ///
+    /// Integers with a magnitude above 2048 are rounded to the nearest representable value, with
+    /// ties rounding to even, and values too large for 16 bits result in ±∞. Unlike converting
+    /// through a wider float first, this never rounds twice.
+    #[inline]
+    #[must_use]
+    pub const fn from_u16(value: u16) -> f16 {
+        f16(convert::u64_to_f16(0, value as u64))
+    }
+
+    /// Constructs a [`f16`] from a 32-bit signed integer.
+    ///
+    /// Integers with a magnitude above 2048 are rounded to the nearest representable value, with
+    /// ties rounding to even, and values too large for 16 bits result in ±∞. Unlike converting
+    /// through a wider float first, this never rounds twice.
+    #[inline]
+    #[must_use]
+    pub const fn from_i32(value: i32) -> f16 {
+        let sign = if value < 0 { 0x8000 } else { 0 };
+        f16(convert::u64_to_f16(sign, value.unsigned_abs() as u64))
+    }
+
+    /// Constructs a [`f16`] from a 32-bit unsigned integer.
+    ///
+    /// Integers with a magnitude above 2048 are rounded to the nearest representable value, with
+    /// ties rounding to even, and values too large for 16 bits result in ±∞. Unlike converting
+    /// through a wider float first, this never rounds twice.
+    #[inline]
+    #[must_use]
+    pub const fn from_u32(value: u32) -> f16 {
+        f16(convert::u64_to_f16(0, value as u64))
+    }
+
+    /// Constructs a [`f16`] from a 64-bit signed integer.
+    ///
+    /// Integers with a magnitude above 2048 are rounded to the nearest representable value, with
+    /// ties rounding to even, and values too large for 16 bits result in ±∞. Unlike converting
+    /// through a wider float first, this never rounds twice.
+    #[inline]
+    #[must_use]
+    pub const fn from_i64(value: i64) -> f16 {
+        let sign = if value < 0 { 0x8000 } else { 0 };
+        f16(convert::u64_to_f16(sign, value.unsigned_abs()))
+    }
+
+    /// Constructs a [`f16`] from a 64-bit unsigned integer.
+    ///
+    /// Integers with a magnitude above 2048 are rounded to the nearest representable value, with
+    /// ties rounding to even, and values too large for 16 bits result in ±∞. Unlike converting
+    /// through a wider float first, this never rounds twice.
+    #[inline]
+    #[must_use]
+    pub const fn from_u64(value: u64) -> f16 {
+        f16(convert::u64_to_f16(0, value))
+    }
+
     /// Converts a [`f16`] into the underlying bit representation.
     #[inline]
     #[must_use]
@@ -271,6 +698,224 @@ impl f16 {
         convert::f16_to_f64_fallback(self.0)
     }
 
+    /// Converts a [`f16`] value into a `f32` value, flushing a subnormal `self` or result to
+    /// zero as selected by `mode`.
+    ///
+    /// With [`FlushMode::Preserve`] this is the same as [`to_f32`][Self::to_f32].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// use half::FlushMode;
+    ///
+    /// let subnormal = f16::from_bits(0x0001);
+    /// assert_eq!(subnormal.to_f32_flush(FlushMode::Preserve), 2f32.powi(-24));
+    /// assert_eq!(subnormal.to_f32_flush(FlushMode::DenormalsAreZero), 0.0);
+    /// assert_eq!(f16::ONE.to_f32_flush(FlushMode::Both), 1.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_f32_flush(self, mode: FlushMode) -> f32 {
+        let value = if mode.flushes_inputs() {
+            self.flush_subnormal_to_zero()
+        } else {
+            self
+        };
+        let result = value.to_f32();
+        if mode.flushes_outputs() {
+            flush::flush_f32(result)
+        } else {
+            result
+        }
+    }
+
+    /// Converts a [`f16`] value into a `f64` value, flushing a subnormal `self` or result to
+    /// zero as selected by `mode`.
+    ///
+    /// With [`FlushMode::Preserve`] this is the same as [`to_f64`][Self::to_f64].
+    #[inline]
+    #[must_use]
+    pub fn to_f64_flush(self, mode: FlushMode) -> f64 {
+        let value = if mode.flushes_inputs() {
+            self.flush_subnormal_to_zero()
+        } else {
+            self
+        };
+        let result = value.to_f64();
+        if mode.flushes_outputs() {
+            flush::flush_f64(result)
+        } else {
+            result
+        }
+    }
+
+    /// Converts a [`f16`] value into a primitive integer, with the same semantics as an `as` cast
+    /// of a primitive float.
+    ///
+    /// The value is rounded toward zero, values outside the range of the integer type saturate to
+    /// its minimum or maximum, and NaN converts to `0`. Since every [`f16`] value is exactly
+    /// representable in [`f32`], this is always identical to `self.to_f32() as T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(f16::from_f32(-3.75).to_int::<i32>(), -3);
+    /// assert_eq!(f16::from_f32(300.0).to_int::<u8>(), 255);
+    /// assert_eq!(f16::from_f32(-1.0).to_int::<u16>(), 0);
+    /// assert_eq!(f16::NAN.to_int::<i64>(), 0);
+    /// assert_eq!(f16::INFINITY.to_int::<i8>(), i8::MAX);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_int<T: FloatToInt>(self) -> T {
+        T::saturating_from_f32(self.to_f32())
+    }
+
+    /// Converts a [`f16`] value into a primitive integer, rounding toward zero and assuming that
+    /// the value is finite and fits in the integer type.
+    ///
+    /// This is the [`f16`] equivalent of [`f32::to_int_unchecked`].
+    ///
+    /// # Safety
+    ///
+    /// The value must not be NaN or infinite, and after truncating off its fractional part it must
+    /// be representable in the return type `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let value = f16::from_f32(-12.5);
+    /// assert_eq!(unsafe { value.to_int_unchecked::<i16>() }, -12);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub unsafe fn to_int_unchecked<T: FloatToInt>(self) -> T {
+        T::unchecked_from_f32(self.to_f32())
+    }
+
+    /// Converts a [`f16`] value into a signed or unsigned fixed-point number with `frac_bits`
+    /// fractional bits, such as Q15 in an [`i16`] or Q7 in an [`i8`].
+    ///
+    /// The value is multiplied by 2<sup>`frac_bits`</sup> and rounded to the nearest integer,
+    /// ties to even. Values outside the range of the integer type saturate to its minimum or
+    /// maximum, and NaN converts to `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// // Q15: 15 fractional bits in an i16, covering [-1, 1)
+    /// assert_eq!(f16::from_f32(0.5).to_fixed::<i16>(15), 0x4000);
+    /// assert_eq!(f16::from_f32(-1.0).to_fixed::<i16>(15), i16::MIN);
+    /// assert_eq!(f16::ONE.to_fixed::<i16>(15), i16::MAX); // Saturated
+    ///
+    /// // Q7 in an i8
+    /// assert_eq!(f16::from_f32(-0.75).to_fixed::<i8>(7), -96);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_fixed<T: FloatToInt>(self, frac_bits: u32) -> T {
+        int::to_fixed(self.to_f64(), frac_bits)
+    }
+
+    /// Converts a signed or unsigned fixed-point number with `frac_bits` fractional bits, such as
+    /// Q15 in an [`i16`] or Q7 in an [`i8`], into a [`f16`] value.
+    ///
+    /// The value is divided by 2<sup>`frac_bits`</sup> and rounded to the nearest representable
+    /// value, ties to even. Values too large for [`f16`] become ±∞.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(f16::from_fixed(0x4000i16, 15), f16::from_f32(0.5));
+    /// assert_eq!(f16::from_fixed(i16::MIN, 15), f16::from_f32(-1.0));
+    /// assert_eq!(f16::from_fixed(-96i8, 7), f16::from_f32(-0.75));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_fixed<T: FloatToInt>(value: T, frac_bits: u32) -> f16 {
+        f16(int::from_fixed(value, frac_bits, 10, 5))
+    }
+
+    /// Formats the exact value as a hexadecimal float in the style of C's `%a` format, such as
+    /// `0x1.8p-2` for 0.375.
+    ///
+    /// Subnormal values keep a leading zero, e.g. `0x0.004p-14`, and infinities and NaN are
+    /// formatted like [`Display`] does. The output can be parsed back with [`FromStr`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(f16::from_f32(0.375).to_hex_string(), "0x1.8p-2");
+    /// assert_eq!(f16::MAX.to_hex_string(), "0x1.ffcp+15");
+    /// assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.to_hex_string(), "0x0.004p-14");
+    /// assert_eq!("0x1.8p-2".parse::<f16>().unwrap(), f16::from_f32(0.375));
+    /// ```
+    #[cfg(all(feature = "alloc", not(target_arch = "spirv")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    pub fn to_hex_string(&self) -> String {
+        fmt::HexFloat {
+            bits: self.0,
+            man_bits: 10,
+            exp_bits: 5,
+        }
+        .to_string()
+    }
+
+    /// Parses a float from a string in the given radix, correctly rounded to the nearest [`f16`].
+    ///
+    /// The string may have a leading `+` or `-` sign and a radix point, and may also be `inf`,
+    /// `infinity` or `nan` in any case. A radix of 10 accepts the same strings as [`FromStr`], and
+    /// a radix of 16 accepts an optional binary exponent like `1.8p-2`. Other radices do not
+    /// support exponents.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(f16::from_str_radix("-101.01", 2), Ok(f16::from_f32(-5.25)));
+    /// assert_eq!(f16::from_str_radix("17.4", 8), Ok(f16::from_f32(15.5)));
+    /// assert_eq!(f16::from_str_radix("ff.8p4", 16), Ok(f16::from_f32(4088.0)));
+    /// assert_eq!(f16::from_str_radix("0.1", 3).unwrap(), f16::from_f32(1.0 / 3.0));
+    /// assert!(f16::from_str_radix("12", 2).is_err());
+    /// ```
+    #[cfg(not(target_arch = "spirv"))]
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<f16, ParseFloatError> {
+        parse::parse_float(src, radix, 10, 5).map(f16)
+    }
+
+    /// Parses a float from a string like [`FromStr`] does, but returns an error instead of
+    /// rounding a finite value to infinity, or a nonzero value to zero.
+    ///
+    /// Explicit infinities like `inf` are still accepted, as are values that round to a
+    /// subnormal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(f16::parse_strict("0.5"), Ok(f16::from_f32(0.5)));
+    /// assert!(f16::parse_strict("1e5").unwrap_err().is_overflow());
+    /// assert!(f16::parse_strict("-1e-8").unwrap_err().is_underflow());
+    /// assert_eq!("1e5".parse::<f16>().unwrap(), f16::INFINITY);
+    /// ```
+    #[cfg(not(target_arch = "spirv"))]
+    pub fn parse_strict(src: &str) -> Result<f16, ParseError> {
+        let value = src.parse::<f16>().map_err(|_| ParseError::syntax(src))?;
+        parse::check_range(src, value.is_infinite(), value.0 & 0x7FFF == 0)?;
+        Ok(value)
+    }
+
     /// Returns `true` if this value is `NaN` and `false` otherwise.
     ///
     /// # Examples
@@ -397,6 +1042,29 @@ impl f16 {
         }
     }
 
+    /// Replaces a subnormal value with a zero of the same sign, leaving all other values
+    /// unchanged.
+    ///
+    /// This is what hardware running in flush-to-zero mode does to its inputs and results. See
+    /// [`FlushMode`] for conversions that flush, and
+    /// [`HalfFloatSliceExt::flush_subnormals_to_zero`][crate::slice::HalfFloatSliceExt::flush_subnormals_to_zero]
+    /// to flush a whole slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let subnormal = f16::from_bits(0x8001);
+    /// assert_eq!(subnormal.flush_subnormal_to_zero(), f16::NEG_ZERO);
+    /// assert_eq!(f16::MIN_POSITIVE.flush_subnormal_to_zero(), f16::MIN_POSITIVE);
+    /// assert!(f16::NAN.flush_subnormal_to_zero().is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn flush_subnormal_to_zero(self) -> f16 {
+        f16(flush::flush_bits(self.0, 0x7C00))
+    }
+
     /// Returns a number that represents the sign of `self`.
     ///
     /// * `1.0` if the number is positive, `+0.0` or [`INFINITY`][f16::INFINITY]
@@ -468,111 +1136,405 @@ impl f16 {
     /// ```
     #[inline]
     #[must_use]
-    pub const fn is_sign_negative(self) -> bool {
-        self.0 & 0x8000u16 != 0
+    pub const fn is_sign_negative(self) -> bool {
+        self.0 & 0x8000u16 != 0
+    }
+
+    /// Returns a number composed of the magnitude of `self` and the sign of `sign`.
+    ///
+    /// Equal to `self` if the sign of `self` and `sign` are the same, otherwise equal to `-self`.
+    /// If `self` is NaN, then NaN with the sign of `sign` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use half::prelude::*;
+    /// let f = f16::from_f32(3.5);
+    ///
+    /// assert_eq!(f.copysign(f16::from_f32(0.42)), f16::from_f32(3.5));
+    /// assert_eq!(f.copysign(f16::from_f32(-0.42)), f16::from_f32(-3.5));
+    /// assert_eq!((-f).copysign(f16::from_f32(0.42)), f16::from_f32(3.5));
+    /// assert_eq!((-f).copysign(f16::from_f32(-0.42)), f16::from_f32(-3.5));
+    ///
+    /// assert!(f16::NAN.copysign(f16::from_f32(1.0)).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn copysign(self, sign: f16) -> f16 {
+        f16((sign.0 & 0x8000u16) | (self.0 & 0x7FFFu16))
+    }
+
+    /// Returns the maximum of the two numbers.
+    ///
+    /// If one of the arguments is NaN, then the other argument is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use half::prelude::*;
+    /// let x = f16::from_f32(1.0);
+    /// let y = f16::from_f32(2.0);
+    ///
+    /// assert_eq!(x.max(y), y);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn max(self, other: f16) -> f16 {
+        if other > self && !other.is_nan() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the minimum of the two numbers.
+    ///
+    /// If one of the arguments is NaN, then the other argument is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use half::prelude::*;
+    /// let x = f16::from_f32(1.0);
+    /// let y = f16::from_f32(2.0);
+    ///
+    /// assert_eq!(x.min(y), x);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn min(self, other: f16) -> f16 {
+        if other < self && !other.is_nan() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Restrict a value to a certain interval unless it is NaN.
+    ///
+    /// Returns `max` if `self` is greater than `max`, and `min` if `self` is less than `min`.
+    /// Otherwise this returns `self`.
+    ///
+    /// Note that this function returns NaN if the initial value was NaN as well.
+    ///
+    /// # Panics
+    /// Panics if `min > max`, `min` is NaN, or `max` is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use half::prelude::*;
+    /// assert!(f16::from_f32(-3.0).clamp(f16::from_f32(-2.0), f16::from_f32(1.0)) == f16::from_f32(-2.0));
+    /// assert!(f16::from_f32(0.0).clamp(f16::from_f32(-2.0), f16::from_f32(1.0)) == f16::from_f32(0.0));
+    /// assert!(f16::from_f32(2.0).clamp(f16::from_f32(-2.0), f16::from_f32(1.0)) == f16::from_f32(1.0));
+    /// assert!(f16::NAN.clamp(f16::from_f32(-2.0), f16::from_f32(1.0)).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn clamp(self, min: f16, max: f16) -> f16 {
+        assert!(min <= max);
+        let mut x = self;
+        if x < min {
+            x = min;
+        }
+        if x > max {
+            x = max;
+        }
+        x
+    }
+
+    /// Computes the remainder of `self` divided by `other` with the sign of `self`, like the C
+    /// `fmod` function and the `%` operator.
+    ///
+    /// The result is `self - n * other`, where `n` is the quotient truncated toward zero. It is
+    /// always exactly representable, so no rounding occurs. The result is NaN if `other` is zero,
+    /// `self` is infinite or either value is NaN, and `self` if `other` is infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use half::prelude::*;
+    /// let x = f16::from_f32(7.5);
+    /// assert_eq!(x.fmod(f16::from_f32(2.0)), f16::from_f32(1.5));
+    /// assert_eq!((-x).fmod(f16::from_f32(2.0)), f16::from_f32(-1.5));
+    /// assert_eq!(x.fmod(f16::from_f32(2.0)), x % f16::from_f32(2.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn fmod(self, other: f16) -> f16 {
+        // The remainder of the exact `f32` values is exact, and fits in `f16`
+        f16::from_f32(self.to_f32() % other.to_f32())
+    }
+
+    /// Computes the IEEE 754 remainder of `self` divided by `other`.
+    ///
+    /// The result is `self - n * other`, where `n` is the quotient rounded to the nearest integer,
+    /// with ties to even. Unlike [`fmod`][Self::fmod], the result is in the range
+    /// `[-other/2, other/2]` and may have the opposite sign of `self`, which suits wrapping values
+    /// of a periodic domain such as angles around zero. Like [`fmod`][Self::fmod], the result is
+    /// exact. A zero result has the sign of `self`. The result is NaN if `other` is zero, `self`
+    /// is infinite or either value is NaN, and `self` if `other` is infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use half::prelude::*;
+    /// let x = f16::from_f32(7.5);
+    /// assert_eq!(x.rem_ieee(f16::from_f32(2.0)), f16::from_f32(-0.5));
+    /// assert_eq!(x.fmod(f16::from_f32(2.0)), f16::from_f32(1.5));
+    ///
+    /// // Ties round the quotient to even
+    /// assert_eq!(f16::from_f32(5.0).rem_ieee(f16::from_f32(2.0)), f16::from_f32(1.0));
+    /// assert_eq!(f16::from_f32(7.0).rem_ieee(f16::from_f32(2.0)), f16::from_f32(-1.0));
+    /// ```
+    #[must_use]
+    pub fn rem_ieee(self, other: f16) -> f16 {
+        let y = f16(other.0 & 0x7FFF).to_f32();
+        // The remainder of a division by `2 * y` gives the parity of the quotient, and like the
+        // adjustments below it is exact in `f32`
+        let mut r = f16(self.0 & 0x7FFF).to_f32() % (2.0 * y);
+        let half = 0.5 * y;
+        if r > half {
+            r -= y;
+            if r >= half {
+                r -= y;
+            }
+        }
+        let r = f16::from_f32(r);
+        if self.is_sign_negative() {
+            -r
+        } else {
+            r
+        }
+    }
+
+    /// Raises `self` to the power of `n`.
+    ///
+    /// The power is computed in [`f64`], where both operands are exact, and rounded once to the
+    /// nearest [`f16`]. Special cases follow the C `pow` function, so for instance a negative
+    /// base with a non-integer exponent is NaN, and anything to the power of zero is one, even
+    /// NaN.
+    ///
+    /// This method requires the `std` or `math` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let x = f16::from_f32(-2.0);
+    /// assert_eq!(x.powf(f16::from_f32(3.0)), f16::from_f32(-8.0));
+    /// assert_eq!(x.powf(f16::from_f32(-1.0)), f16::from_f32(-0.5));
+    /// assert!(x.powf(f16::from_f32(0.5)).is_nan());
+    /// assert_eq!(f16::NAN.powf(f16::ZERO), f16::ONE);
+    /// assert_eq!(f16::from_f32(2.0).powf(f16::from_f32(-24.0)), f16::MIN_POSITIVE_SUBNORMAL);
+    /// ```
+    #[cfg(any(feature = "std", feature = "math"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "math"))))]
+    #[inline]
+    #[must_use]
+    pub fn powf(self, n: f16) -> f16 {
+        f16::from_f64(math::pow(self.to_f64(), n.to_f64()))
+    }
+
+    /// Raises `self` to the power of an [`f32`] exponent `n`.
+    ///
+    /// This is the same as [`powf`][Self::powf], but the exponent keeps the precision of
+    /// [`f32`], which matters for exponents such as `1.0 / 3.0` that are not representable in
+    /// half precision, as in shaders that raise half precision colors to an [`f32`] gamma.
+    ///
+    /// This method requires the `std` or `math` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let x = f16::from_f32(1000.0);
+    /// assert_eq!(x.powf_f32(1.0 / 3.0), f16::from_f32(10.0));
+    /// assert_ne!(x.powf(f16::from_f32(1.0 / 3.0)), f16::from_f32(10.0));
+    /// ```
+    #[cfg(any(feature = "std", feature = "math"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "math"))))]
+    #[inline]
+    #[must_use]
+    pub fn powf_f32(self, n: f32) -> f16 {
+        f16::from_f64(math::pow(self.to_f64(), f64::from(n)))
+    }
+
+    /// Returns `ln(1 + self)`, the natural logarithm of one more than `self`.
+    ///
+    /// The result is computed in [`f64`] and rounded once to the nearest [`f16`], so it is
+    /// accurate even when `self` is so close to zero that `1 + self` would round to one. Small and
+    /// subnormal values are returned unchanged, since the result rounds to `self`. The result is
+    /// negative infinity for `-1` and NaN below `-1`.
+    ///
+    /// This method requires the `std` or `math` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let tiny = f16::from_f32(1e-6);
+    /// assert_eq!(tiny.ln_1p(), tiny);
+    /// assert_eq!(f16::ONE.ln_1p(), f16::from_f64(2f64.ln()));
+    /// assert_eq!(f16::NEG_ONE.ln_1p(), f16::NEG_INFINITY);
+    /// assert!(f16::from_f32(-2.0).ln_1p().is_nan());
+    /// ```
+    #[cfg(any(feature = "std", feature = "math"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "math"))))]
+    #[inline]
+    #[must_use]
+    pub fn ln_1p(self) -> f16 {
+        f16::from_f64(math::ln_1p(self.to_f64()))
+    }
+
+    /// Returns `e^self - 1`.
+    ///
+    /// The result is computed in [`f64`] and rounded once to the nearest [`f16`], so it keeps its
+    /// precision for values close to zero, where `e^self` would round to one. Small and subnormal
+    /// values are returned unchanged, and the result tends to `-1` for large negative values.
+    ///
+    /// This method requires the `std` or `math` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let tiny = f16::from_f32(1e-6);
+    /// assert_eq!(tiny.exp_m1(), tiny);
+    /// assert_eq!(f16::ONE.exp_m1(), f16::from_f64(1f64.exp() - 1.0));
+    /// assert_eq!(f16::NEG_INFINITY.exp_m1(), f16::NEG_ONE);
+    /// assert_eq!(f16::NEG_ZERO.exp_m1().to_bits(), f16::NEG_ZERO.to_bits());
+    /// ```
+    #[cfg(any(feature = "std", feature = "math"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "math"))))]
+    #[inline]
+    #[must_use]
+    pub fn exp_m1(self) -> f16 {
+        f16::from_f64(math::exp_m1(self.to_f64()))
+    }
+
+    /// Returns `2^self`.
+    ///
+    /// The result is computed in [`f64`] and rounded once to the nearest [`f16`], so integer
+    /// values give exact powers of two, including the subnormal ones, and values too large or too
+    /// small for the type give infinity or zero.
+    ///
+    /// This method requires the `std` or `math` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(f16::from_f32(-3.0).exp2(), f16::from_f32(0.125));
+    /// assert_eq!(f16::from_f32(0.5).exp2(), f16::from_f64(2f64.sqrt()));
+    /// assert_eq!(f16::MAX.exp2(), f16::INFINITY);
+    /// assert_eq!(f16::NEG_INFINITY.exp2(), f16::ZERO);
+    /// ```
+    #[cfg(any(feature = "std", feature = "math"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "math"))))]
+    #[inline]
+    #[must_use]
+    pub fn exp2(self) -> f16 {
+        f16::from_f64(math::exp2(self.to_f64()))
     }
 
-    /// Returns a number composed of the magnitude of `self` and the sign of `sign`.
+    /// Returns the error function of `self`.
     ///
-    /// Equal to `self` if the sign of `self` and `sign` are the same, otherwise equal to `-self`.
-    /// If `self` is NaN, then NaN with the sign of `sign` is returned.
+    /// The result is computed in [`f64`] and rounded once to the nearest [`f16`]. It lies between
+    /// `-1` and `1`, which are reached at the infinities and already by moderately large values,
+    /// since the function converges quickly.
+    ///
+    /// This method requires the `math` feature, even with `std`.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```rust
     /// # use half::prelude::*;
-    /// let f = f16::from_f32(3.5);
-    ///
-    /// assert_eq!(f.copysign(f16::from_f32(0.42)), f16::from_f32(3.5));
-    /// assert_eq!(f.copysign(f16::from_f32(-0.42)), f16::from_f32(-3.5));
-    /// assert_eq!((-f).copysign(f16::from_f32(0.42)), f16::from_f32(3.5));
-    /// assert_eq!((-f).copysign(f16::from_f32(-0.42)), f16::from_f32(-3.5));
-    ///
-    /// assert!(f16::NAN.copysign(f16::from_f32(1.0)).is_nan());
+    /// assert_eq!(f16::ZERO.erf(), f16::ZERO);
+    /// assert_eq!(f16::ONE.erf(), f16::from_f32(0.8427008));
+    /// assert_eq!(f16::from_f32(-5.0).erf(), f16::NEG_ONE);
     /// ```
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
     #[inline]
     #[must_use]
-    pub const fn copysign(self, sign: f16) -> f16 {
-        f16((sign.0 & 0x8000u16) | (self.0 & 0x7FFFu16))
+    pub fn erf(self) -> f16 {
+        f16::from_f64(math::erf(self.to_f64()))
     }
 
-    /// Returns the maximum of the two numbers.
+    /// Returns the complementary error function of `self`, `1 - erf(self)`.
     ///
-    /// If one of the arguments is NaN, then the other argument is returned.
+    /// The result is computed in [`f64`] and rounded once to the nearest [`f16`], so unlike
+    /// subtracting [`erf`][Self::erf] from one, it stays accurate in the tail for large values.
+    ///
+    /// This method requires the `math` feature, even with `std`.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```rust
     /// # use half::prelude::*;
-    /// let x = f16::from_f32(1.0);
-    /// let y = f16::from_f32(2.0);
-    ///
-    /// assert_eq!(x.max(y), y);
+    /// let x = f16::from_f32(3.0);
+    /// assert_eq!(x.erfc(), f16::from_f64(2.209049699858544e-5));
+    /// assert_eq!(f16::ONE - x.erf(), f16::ZERO);
+    /// assert_eq!(f16::NEG_INFINITY.erfc(), f16::from_f32(2.0));
     /// ```
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
     #[inline]
     #[must_use]
-    pub fn max(self, other: f16) -> f16 {
-        if other > self && !other.is_nan() {
-            other
-        } else {
-            self
-        }
+    pub fn erfc(self) -> f16 {
+        f16::from_f64(math::erfc(self.to_f64()))
     }
 
-    /// Returns the minimum of the two numbers.
+    /// Returns the gamma function of `self`, known as `tgamma` in C.
     ///
-    /// If one of the arguments is NaN, then the other argument is returned.
+    /// The result is computed in [`f64`] and rounded once to the nearest [`f16`]. For positive
+    /// integers `n`, this is the factorial of `n - 1`. Zeros give an infinity with the same sign,
+    /// and negative integers and negative infinity give NaN.
+    ///
+    /// This method requires the `math` feature, even with `std`.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```rust
     /// # use half::prelude::*;
-    /// let x = f16::from_f32(1.0);
-    /// let y = f16::from_f32(2.0);
-    ///
-    /// assert_eq!(x.min(y), x);
+    /// assert_eq!(f16::from_f32(5.0).gamma(), f16::from_f32(24.0));
+    /// assert_eq!(f16::from_f32(0.5).gamma(), f16::from_f64(core::f64::consts::PI.sqrt()));
+    /// assert_eq!(f16::NEG_ZERO.gamma(), f16::NEG_INFINITY);
+    /// assert!(f16::from_f32(-2.0).gamma().is_nan());
     /// ```
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
     #[inline]
     #[must_use]
-    pub fn min(self, other: f16) -> f16 {
-        if other < self && !other.is_nan() {
-            other
-        } else {
-            self
-        }
+    pub fn gamma(self) -> f16 {
+        f16::from_f64(math::tgamma(self.to_f64()))
     }
 
-    /// Restrict a value to a certain interval unless it is NaN.
-    ///
-    /// Returns `max` if `self` is greater than `max`, and `min` if `self` is less than `min`.
-    /// Otherwise this returns `self`.
+    /// Returns the natural logarithm of the absolute value of the gamma function of `self`, and
+    /// the sign of the gamma function, known as `lgamma` in C.
     ///
-    /// Note that this function returns NaN if the initial value was NaN as well.
+    /// The logarithm is computed in [`f64`] and rounded once to the nearest [`f16`], so it stays
+    /// finite for values where [`gamma`][Self::gamma] overflows. The sign is `1` or `-1`.
     ///
-    /// # Panics
-    /// Panics if `min > max`, `min` is NaN, or `max` is NaN.
+    /// This method requires the `math` feature, even with `std`.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```rust
     /// # use half::prelude::*;
-    /// assert!(f16::from_f32(-3.0).clamp(f16::from_f32(-2.0), f16::from_f32(1.0)) == f16::from_f32(-2.0));
-    /// assert!(f16::from_f32(0.0).clamp(f16::from_f32(-2.0), f16::from_f32(1.0)) == f16::from_f32(0.0));
-    /// assert!(f16::from_f32(2.0).clamp(f16::from_f32(-2.0), f16::from_f32(1.0)) == f16::from_f32(1.0));
-    /// assert!(f16::NAN.clamp(f16::from_f32(-2.0), f16::from_f32(1.0)).is_nan());
+    /// let x = f16::from_f32(100.0);
+    /// assert_eq!(x.ln_gamma(), (f16::from_f32(359.13422), 1));
+    /// assert_eq!(f16::from_f32(-0.5).ln_gamma(), (f16::from_f32(1.2655121), -1));
+    /// assert_eq!(f16::ONE.ln_gamma(), (f16::ZERO, 1));
     /// ```
+    #[cfg(feature = "math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "math")))]
     #[inline]
     #[must_use]
-    pub fn clamp(self, min: f16, max: f16) -> f16 {
-        assert!(min <= max);
-        let mut x = self;
-        if x < min {
-            x = min;
-        }
-        if x > max {
-            x = max;
-        }
-        x
+    pub fn ln_gamma(self) -> (f16, i32) {
+        let (value, sign) = math::lgamma_r(self.to_f64());
+        (f16::from_f64(value), sign)
     }
 
     /// Returns the ordering between `self` and `other`.
@@ -651,6 +1613,176 @@ impl f16 {
         left.cmp(&right)
     }
 
+    /// Returns the number of representable values between `self` and `other`, or [`None`] if
+    /// either is NaN.
+    ///
+    /// This is the distance in units in the last place, counted on the bit representation, so
+    /// adjacent values are 1 apart even across a change of exponent or sign. Both zeros are the
+    /// same value with a distance of 0, and the infinities are 1 beyond [`MAX`][Self::MAX] and
+    /// [`MIN`][Self::MIN].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let one = f16::ONE;
+    /// assert_eq!(one.ulp_distance(one), Some(0));
+    /// assert_eq!(one.ulp_distance(one + f16::EPSILON), Some(1));
+    /// assert_eq!(f16::NEG_ZERO.ulp_distance(f16::ZERO), Some(0));
+    /// assert_eq!(f16::from_bits(1).ulp_distance(-f16::from_bits(1)), Some(2));
+    /// assert_eq!(f16::NEG_INFINITY.ulp_distance(f16::INFINITY), Some(0xF800));
+    /// assert_eq!(one.ulp_distance(f16::NAN), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn ulp_distance(self, other: f16) -> Option<u16> {
+        if self.is_nan() || other.is_nan() {
+            None
+        } else {
+            let distance = order_key(self.0) as i32 - order_key(other.0) as i32;
+            Some(distance.unsigned_abs() as u16)
+        }
+    }
+
+    /// Returns the least value greater than `self`.
+    ///
+    /// Both zeros step to [`MIN_POSITIVE_SUBNORMAL`][Self::MIN_POSITIVE_SUBNORMAL] and
+    /// [`MAX`][Self::MAX] steps to infinity. NaN and positive infinity are returned unchanged, and
+    /// the negative value closest to zero steps to negative zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(f16::ONE.next_up(), f16::ONE + f16::EPSILON);
+    /// assert_eq!(f16::NEG_ZERO.next_up(), f16::MIN_POSITIVE_SUBNORMAL);
+    /// assert_eq!(f16::MAX.next_up(), f16::INFINITY);
+    /// assert_eq!(f16::NEG_INFINITY.next_up(), f16::MIN);
+    /// assert!(f16::NAN.next_up().is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn next_up(self) -> f16 {
+        if self.is_nan() || self.0 == 0x7C00 {
+            self
+        } else if self.0 & 0x7FFF == 0 {
+            Self::MIN_POSITIVE_SUBNORMAL
+        } else if self.0 & 0x8000 == 0 {
+            f16(self.0 + 1)
+        } else {
+            f16(self.0 - 1)
+        }
+    }
+
+    /// Returns the greatest value less than `self`.
+    ///
+    /// This is the mirror image of [`next_up`][Self::next_up]: both zeros step to the negative
+    /// value closest to zero, [`MIN`][Self::MIN] steps to negative infinity, and NaN and negative
+    /// infinity are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(f16::ONE.next_down().next_up(), f16::ONE);
+    /// assert_eq!(f16::ZERO.next_down(), -f16::MIN_POSITIVE_SUBNORMAL);
+    /// assert_eq!(f16::MIN.next_down(), f16::NEG_INFINITY);
+    /// assert_eq!(f16::INFINITY.next_down(), f16::MAX);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn next_down(self) -> f16 {
+        if self.is_nan() || self.0 == 0xFC00 {
+            self
+        } else if self.0 & 0x7FFF == 0 {
+            f16(0x8001)
+        } else if self.0 & 0x8000 == 0 {
+            f16(self.0 - 1)
+        } else {
+            f16(self.0 + 1)
+        }
+    }
+
+    /// Returns the unit in the last place of `self`, the positive distance from `self` to the
+    /// next value of greater magnitude.
+    ///
+    /// The result only depends on the exponent of `self`, so it is the same for both signs and
+    /// for [`MAX`][Self::MAX], where the next value would be infinite. Zeros and subnormal values
+    /// have a unit of [`MIN_POSITIVE_SUBNORMAL`][Self::MIN_POSITIVE_SUBNORMAL]. The result is
+    /// positive infinity for the infinities and NaN for NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(f16::ONE.ulp(), f16::EPSILON);
+    /// assert_eq!(f16::from_f32(-2048.0).ulp(), f16::from_f32(2.0));
+    /// assert_eq!(f16::ZERO.ulp(), f16::MIN_POSITIVE_SUBNORMAL);
+    /// assert_eq!(f16::NEG_INFINITY.ulp(), f16::INFINITY);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn ulp(self) -> f16 {
+        let exponent = (self.0 >> 10) & 0x1F;
+        if exponent == 0x1F {
+            f16(self.0 & 0x7FFF)
+        } else if exponent > 10 {
+            f16((exponent - 10) << 10)
+        } else if exponent > 0 {
+            f16(1 << (exponent - 1))
+        } else {
+            Self::MIN_POSITIVE_SUBNORMAL
+        }
+    }
+
+    /// Returns an iterator over all 65536 bit patterns of [`f16`], in increasing order of their
+    /// [`to_bits`][f16::to_bits] representation.
+    ///
+    /// This includes both zeros, the infinities and every NaN payload. The order starts with the
+    /// positive values from zero up to infinity followed by the positive NaNs, then the same for
+    /// the negative values, so it is not sorted by value. Use this to test a function of [`f16`]
+    /// exhaustively.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// assert_eq!(f16::all_values().len(), 65536);
+    /// assert_eq!(f16::all_values().filter(|x| x.is_nan()).count(), 2046);
+    ///
+    /// // Every value except the signaling NaNs survives a round trip through `f32`
+    /// let round_trip = |x: f16| f16::from_f32(x.to_f32()).to_bits() == x.to_bits();
+    /// assert!(f16::all_values().filter(|x| !x.is_nan()).all(round_trip));
+    /// ```
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    pub fn all_values() -> AllValues<f16> {
+        AllValues::new()
+    }
+
+    /// Returns an iterator over all finite values of [`f16`], in increasing order of their
+    /// [`to_bits`][f16::to_bits] representation.
+    ///
+    /// This is [`all_values`][Self::all_values] without the infinities and NaNs: first the
+    /// positive values from `0.0` up to [`MAX`][f16::MAX], then the negative values from `-0.0`
+    /// down to [`MIN`][f16::MIN].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut values = f16::all_finite();
+    /// assert_eq!(values.len(), 63488);
+    /// assert_eq!(values.next(), Some(f16::ZERO));
+    /// assert_eq!(values.next_back(), Some(f16::MIN));
+    /// assert_eq!(values.nth(0x7C00 - 1).map(f16::to_bits), Some(f16::NEG_ZERO.to_bits()));
+    /// ```
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    pub fn all_finite() -> AllFinite<f16> {
+        AllFinite::new()
+    }
+
     /// Alternate serialize adapter for serializing as a float.
     ///
     /// By default, [`f16`] serializes as a newtype of [`u16`]. This is an alternate serialize
@@ -703,7 +1835,7 @@ impl f16 {
         &self,
         serializer: S,
     ) -> Result<S::Ok, S::Error> {
-        serializer.serialize_str(&self.to_string())
+        serializer.collect_str(self)
     }
 
     /// Approximate number of [`f16`] significant digits in base 10
@@ -820,166 +1952,218 @@ impl From<u8> for f16 {
     }
 }
 
-impl PartialEq for f16 {
-    fn eq(&self, other: &f16) -> bool {
-        if self.is_nan() || other.is_nan() {
-            false
+#[cfg(not(target_arch = "spirv"))]
+impl TryFrom<f32> for f16 {
+    type Error = TryFromFloatError;
+
+    /// Converts a [`f32`] to [`f16`] only if the value is exactly representable.
+    ///
+    /// NaN values always convert, keeping as much of the payload as fits. Any other value that
+    /// would be rounded, including finite values that would overflow to ±∞, is an error.
+    #[inline]
+    fn try_from(value: f32) -> Result<f16, TryFromFloatError> {
+        if let Some(half) = f16::from_f32_exact(value) {
+            Ok(half)
+        } else if value > f16::MAX.to_f32() || value < f16::MIN.to_f32() {
+            Err(TryFromFloatError::overflow())
+        } else {
+            Err(TryFromFloatError::inexact())
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl TryFrom<f64> for f16 {
+    type Error = TryFromFloatError;
+
+    /// Converts a [`f64`] to [`f16`] only if the value is exactly representable.
+    ///
+    /// NaN values always convert, keeping as much of the payload as fits. Any other value that
+    /// would be rounded, including finite values that would overflow to ±∞, is an error.
+    #[inline]
+    fn try_from(value: f64) -> Result<f16, TryFromFloatError> {
+        if let Some(half) = f16::from_f64_exact(value) {
+            Ok(half)
+        } else if value > f16::MAX.to_f64() || value < f16::MIN.to_f64() {
+            Err(TryFromFloatError::overflow())
         } else {
-            (self.0 == other.0) || ((self.0 | other.0) & 0x7FFFu16 == 0)
+            Err(TryFromFloatError::inexact())
         }
     }
 }
 
+// Maps the sign-magnitude bits to integers in the same order as the values, with both zeros mapped
+// to 0, so that comparisons are a single integer comparison plus a NaN check instead of a branch
+// on each sign.
+#[inline]
+const fn order_key(bits: u16) -> i16 {
+    let sign = (bits as i16) >> 15;
+    ((bits & 0x7FFF) as i16 ^ sign) - sign
+}
+
+impl PartialEq for f16 {
+    #[inline]
+    fn eq(&self, other: &f16) -> bool {
+        // The non-short-circuiting operators keep the comparison branchless
+        !(self.is_nan() | other.is_nan()) & (order_key(self.0) == order_key(other.0))
+    }
+}
+
 impl PartialOrd for f16 {
+    #[inline]
     fn partial_cmp(&self, other: &f16) -> Option<Ordering> {
-        if self.is_nan() || other.is_nan() {
+        if self.is_nan() | other.is_nan() {
             None
         } else {
-            let neg = self.0 & 0x8000u16 != 0;
-            let other_neg = other.0 & 0x8000u16 != 0;
-            match (neg, other_neg) {
-                (false, false) => Some(self.0.cmp(&other.0)),
-                (false, true) => {
-                    if (self.0 | other.0) & 0x7FFFu16 == 0 {
-                        Some(Ordering::Equal)
-                    } else {
-                        Some(Ordering::Greater)
-                    }
-                }
-                (true, false) => {
-                    if (self.0 | other.0) & 0x7FFFu16 == 0 {
-                        Some(Ordering::Equal)
-                    } else {
-                        Some(Ordering::Less)
-                    }
-                }
-                (true, true) => Some(other.0.cmp(&self.0)),
-            }
+            Some(order_key(self.0).cmp(&order_key(other.0)))
         }
     }
 
+    #[inline]
     fn lt(&self, other: &f16) -> bool {
-        if self.is_nan() || other.is_nan() {
-            false
-        } else {
-            let neg = self.0 & 0x8000u16 != 0;
-            let other_neg = other.0 & 0x8000u16 != 0;
-            match (neg, other_neg) {
-                (false, false) => self.0 < other.0,
-                (false, true) => false,
-                (true, false) => (self.0 | other.0) & 0x7FFFu16 != 0,
-                (true, true) => self.0 > other.0,
-            }
-        }
+        !(self.is_nan() | other.is_nan()) & (order_key(self.0) < order_key(other.0))
     }
 
+    #[inline]
     fn le(&self, other: &f16) -> bool {
-        if self.is_nan() || other.is_nan() {
-            false
-        } else {
-            let neg = self.0 & 0x8000u16 != 0;
-            let other_neg = other.0 & 0x8000u16 != 0;
-            match (neg, other_neg) {
-                (false, false) => self.0 <= other.0,
-                (false, true) => (self.0 | other.0) & 0x7FFFu16 == 0,
-                (true, false) => true,
-                (true, true) => self.0 >= other.0,
-            }
-        }
+        !(self.is_nan() | other.is_nan()) & (order_key(self.0) <= order_key(other.0))
     }
 
+    #[inline]
     fn gt(&self, other: &f16) -> bool {
-        if self.is_nan() || other.is_nan() {
-            false
-        } else {
-            let neg = self.0 & 0x8000u16 != 0;
-            let other_neg = other.0 & 0x8000u16 != 0;
-            match (neg, other_neg) {
-                (false, false) => self.0 > other.0,
-                (false, true) => (self.0 | other.0) & 0x7FFFu16 != 0,
-                (true, false) => false,
-                (true, true) => self.0 < other.0,
-            }
-        }
+        !(self.is_nan() | other.is_nan()) & (order_key(self.0) > order_key(other.0))
     }
 
+    #[inline]
     fn ge(&self, other: &f16) -> bool {
-        if self.is_nan() || other.is_nan() {
-            false
-        } else {
-            let neg = self.0 & 0x8000u16 != 0;
-            let other_neg = other.0 & 0x8000u16 != 0;
-            match (neg, other_neg) {
-                (false, false) => self.0 >= other.0,
-                (false, true) => true,
-                (true, false) => (self.0 | other.0) & 0x7FFFu16 == 0,
-                (true, true) => self.0 <= other.0,
-            }
-        }
+        !(self.is_nan() | other.is_nan()) & (order_key(self.0) >= order_key(other.0))
     }
 }
 
 #[cfg(not(target_arch = "spirv"))]
 impl FromStr for f16 {
     type Err = ParseFloatError;
+
+    /// Parses a decimal float in any format accepted by [`f32`], or a hexadecimal float like
+    /// `0x1.8p-2` as printed by [`to_hex_string`][crate::f16::to_hex_string]. Both are rounded to
+    /// the nearest [`f16`] value directly, so the result is correctly rounded for any number of
+    /// digits.
     fn from_str(src: &str) -> Result<f16, ParseFloatError> {
-        f32::from_str(src).map(f16::from_f32)
+        f16::from_str_radix(src, 10)
     }
 }
 
 #[cfg(not(target_arch = "spirv"))]
 impl Debug for f16 {
+    /// Formats the numeric value like [`Display`], with the fewest digits that convert back to
+    /// the same [`f16`], but always includes a fractional part or exponent like [`f32`] does.
+    ///
+    /// Use [`LowerHex`] or [`UpperHex`] (e.g. `{:x}`) or [`to_bits`][f16::to_bits] to see the
+    /// raw bits instead.
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:?}", self.to_f32())
+        match self.shortest_decimal() {
+            Some(decimal) if f.precision().is_none() => {
+                fmt::write_debug(f, self.is_sign_negative(), decimal)
+            }
+            _ => Debug::fmt(&self.to_f32(), f),
+        }
     }
 }
 
 #[cfg(not(target_arch = "spirv"))]
 impl Display for f16 {
+    /// Formats the value with the fewest decimal digits that convert back to the same [`f16`].
+    ///
+    /// When a precision is given, the exact value is rounded to that many fractional digits
+    /// instead. Width, fill, alignment, sign and zero padding flags work the same as for [`f32`].
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{}", self.to_f32())
+        match self.shortest_decimal() {
+            Some(decimal) if f.precision().is_none() => {
+                fmt::write_decimal(f, self.is_sign_negative(), decimal)
+            }
+            _ => Display::fmt(&self.to_f32(), f),
+        }
     }
 }
 
 #[cfg(not(target_arch = "spirv"))]
 impl LowerExp for f16 {
+    /// Formats the value in scientific notation with the fewest digits that convert back to the
+    /// same [`f16`].
+    ///
+    /// Precision and the other formatting flags work the same as for [`f32`].
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:e}", self.to_f32())
+        match self.shortest_decimal() {
+            Some(decimal) if f.precision().is_none() => {
+                fmt::write_exp(f, self.is_sign_negative(), decimal, false)
+            }
+            _ => LowerExp::fmt(&self.to_f32(), f),
+        }
     }
 }
 
 #[cfg(not(target_arch = "spirv"))]
 impl UpperExp for f16 {
+    /// Formats the value in scientific notation with the fewest digits that convert back to the
+    /// same [`f16`].
+    ///
+    /// Precision and the other formatting flags work the same as for [`f32`].
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:E}", self.to_f32())
+        match self.shortest_decimal() {
+            Some(decimal) if f.precision().is_none() => {
+                fmt::write_exp(f, self.is_sign_negative(), decimal, true)
+            }
+            _ => UpperExp::fmt(&self.to_f32(), f),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl f16 {
+    // The shortest decimal digits that round-trip, or `None` for infinities and NaN
+    fn shortest_decimal(self) -> Option<fmt::Decimal> {
+        let exp = (self.0 >> 10) & 0x1F;
+        let man = (self.0 & 0x03FF) as u64;
+        match exp {
+            0x1F => None,
+            0 if man == 0 => Some(fmt::Decimal { digits: 0, exp: 0 }),
+            0 => Some(fmt::shortest_decimal(man, -24, false)),
+            _ => Some(fmt::shortest_decimal(
+                man | 0x0400,
+                exp as i32 - 25,
+                man == 0 && exp > 1,
+            )),
+        }
     }
 }
 
 #[cfg(not(target_arch = "spirv"))]
 impl Binary for f16 {
+    /// Formats the raw bits of the value like the same formatting of [`u16`] would, including
+    /// width, fill and `#` flags. [`Octal`], [`LowerHex`] and [`UpperHex`] do the same.
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:b}", self.0)
+        Binary::fmt(&self.0, f)
     }
 }
 
 #[cfg(not(target_arch = "spirv"))]
 impl Octal for f16 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:o}", self.0)
+        Octal::fmt(&self.0, f)
     }
 }
 
 #[cfg(not(target_arch = "spirv"))]
 impl LowerHex for f16 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:x}", self.0)
+        LowerHex::fmt(&self.0, f)
     }
 }
 
 #[cfg(not(target_arch = "spirv"))]
 impl UpperHex for f16 {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{:X}", self.0)
+        UpperHex::fmt(&self.0, f)
     }
 }
 
@@ -1006,7 +2190,7 @@ impl Add for f16 {
 
     #[inline]
     fn add(self, rhs: Self) -> Self::Output {
-        Self::from_f32(Self::to_f32(self) + Self::to_f32(rhs))
+        arith::add(self, rhs)
     }
 }
 
@@ -1056,7 +2240,7 @@ impl Sub for f16 {
 
     #[inline]
     fn sub(self, rhs: Self) -> Self::Output {
-        Self::from_f32(Self::to_f32(self) - Self::to_f32(rhs))
+        arith::sub(self, rhs)
     }
 }
 
@@ -1106,7 +2290,7 @@ impl Mul for f16 {
 
     #[inline]
     fn mul(self, rhs: Self) -> Self::Output {
-        Self::from_f32(Self::to_f32(self) * Self::to_f32(rhs))
+        arith::mul(self, rhs)
     }
 }
 
@@ -1156,7 +2340,7 @@ impl Div for f16 {
 
     #[inline]
     fn div(self, rhs: Self) -> Self::Output {
-        Self::from_f32(Self::to_f32(self) / Self::to_f32(rhs))
+        arith::div(self, rhs)
     }
 }
 
@@ -1275,7 +2459,7 @@ impl Sum for f16 {
 impl<'a> Sum<&'a f16> for f16 {
     #[inline]
     fn sum<I: Iterator<Item = &'a f16>>(iter: I) -> Self {
-        f16::from_f32(iter.map(|f| f.to_f32()).product())
+        f16::from_f32(iter.map(|f| f.to_f32()).sum())
     }
 }
 
@@ -1296,7 +2480,7 @@ impl<'de> Deserialize<'de> for f16 {
 impl<'de> serde::de::Visitor<'de> for Visitor {
     type Value = f16;
 
-    fn expecting(&self, formatter: &mut alloc::fmt::Formatter) -> alloc::fmt::Result {
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(formatter, "tuple struct f16")
     }
 
@@ -1316,18 +2500,72 @@ impl<'de> serde::de::Visitor<'de> for Visitor {
         })
     }
 
-    fn visit_f32<E>(self, v: f32) -> Result<Self::Value, E>
-    where
-        E: serde::de::Error,
-    {
-        Ok(f16::from_f32(v))
+    fn visit_f32<E>(self, v: f32) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(f16::from_f32(v))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(f16::from_f64(v))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for f16 {
+    /// Generates any bit pattern, including NaNs, infinities and subnormals.
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        <u16 as arbitrary::Arbitrary>::arbitrary(u).map(Self)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <u16 as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+#[cfg(any(feature = "quickcheck", test))]
+impl quickcheck::Arbitrary for f16 {
+    /// Generates any bit pattern, including NaNs, infinities and subnormals.
+    #[inline]
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        f16(u16::arbitrary(g))
+    }
+
+    /// Shrinks the bits toward zero, which ends at positive zero.
+    fn shrink(&self) -> alloc::boxed::Box<dyn Iterator<Item = Self>> {
+        alloc::boxed::Box::new(self.0.shrink().map(f16))
+    }
+}
+
+#[cfg(feature = "speedy")]
+impl<'a, C: speedy::Context> speedy::Readable<'a, C> for f16 {
+    #[inline]
+    fn read_from<R: speedy::Reader<'a, C>>(reader: &mut R) -> Result<Self, C::Error> {
+        reader.read_u16().map(f16)
+    }
+
+    #[inline]
+    fn minimum_bytes_needed() -> usize {
+        2
+    }
+}
+
+#[cfg(feature = "speedy")]
+impl<C: speedy::Context> speedy::Writable<C> for f16 {
+    #[inline]
+    fn write_to<W: ?Sized + speedy::Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+        writer.write_u16(self.0)
     }
 
-    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
-    where
-        E: serde::de::Error,
-    {
-        Ok(f16::from_f64(v))
+    #[inline]
+    fn bytes_needed(&self) -> Result<usize, C::Error> {
+        Ok(2)
     }
 }
 
@@ -1344,6 +2582,43 @@ mod test {
     use num_traits::{AsPrimitive, FromPrimitive, ToPrimitive};
     use quickcheck_macros::quickcheck;
 
+    #[cfg(all(feature = "speedy", feature = "std"))]
+    #[test]
+    fn speedy() {
+        use speedy::{Endianness, Readable, Writable};
+
+        let values = std::vec![f16::ONE, f16::NEG_INFINITY, f16::from_bits(0x1234)];
+        let bits = values
+            .iter()
+            .map(|x| x.to_bits())
+            .collect::<std::vec::Vec<_>>();
+        for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+            let bytes = values.write_to_vec_with_ctx(endianness).unwrap();
+            assert_eq!(bytes, bits.write_to_vec_with_ctx(endianness).unwrap());
+            let read = std::vec::Vec::<f16>::read_from_buffer_with_ctx(endianness, &bytes).unwrap();
+            assert_eq!(read, values);
+        }
+        assert_eq!(f16::ONE.write_to_vec().unwrap(), f16::ONE.to_le_bytes());
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut bytes = [0u8; 64];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = (i * 37) as u8;
+        }
+        let mut u = Unstructured::new(&bytes);
+        let mut bits = Unstructured::new(&bytes);
+        while !u.is_empty() {
+            let x = f16::arbitrary(&mut u).unwrap();
+            assert_eq!(x.to_bits(), u16::arbitrary(&mut bits).unwrap());
+        }
+        assert_eq!(f16::size_hint(0), u16::size_hint(0));
+    }
+
     #[cfg(feature = "num-traits")]
     #[test]
     fn as_primitive() {
@@ -1376,14 +2651,99 @@ mod test {
         assert_eq!(<f16 as FromPrimitive>::from_f64(2.0).unwrap(), two);
     }
 
+    #[cfg(all(feature = "rkyv", feature = "std"))]
+    #[test]
+    fn rkyv() {
+        use rkyv::Deserialize;
+
+        let values = std::vec![f16::ONE, f16::NEG_INFINITY, f16::NAN];
+        let bytes = rkyv::to_bytes::<_, 256>(&values).unwrap();
+        let archived = unsafe { rkyv::archived_root::<std::vec::Vec<f16>>(&bytes) };
+        let first: &crate::ArchivedF16 = &archived[0];
+        assert_eq!(first.deserialize(&mut rkyv::Infallible), Ok(f16::ONE));
+
+        let deserialized: std::vec::Vec<f16> = archived.deserialize(&mut rkyv::Infallible).unwrap();
+        let bits = |values: &[f16]| {
+            values
+                .iter()
+                .map(|x| x.to_bits())
+                .collect::<std::vec::Vec<_>>()
+        };
+        assert_eq!(bits(&deserialized), bits(&values));
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn float_traits() {
+        use num_traits::float::{Float, FloatCore};
+
+        fn hypot<F: Float>(x: F, y: F) -> F {
+            (x * x + y * y).sqrt()
+        }
+        fn clamp_abs<F: FloatCore>(x: F, limit: F) -> F {
+            let y = x.abs().min(limit);
+            if x.is_sign_negative() {
+                -y
+            } else {
+                y
+            }
+        }
+
+        let (three, four) = (f16::from_f32(3.), f16::from_f32(4.));
+        assert_eq!(hypot(three, four), f16::from_f32(5.));
+        assert_eq!(clamp_abs(-four, three), -three);
+
+        let subnormal = f16::from_bits(0x0001);
+        assert!(Float::is_subnormal(subnormal));
+        assert!(FloatCore::is_subnormal(-subnormal));
+        assert!(!Float::is_subnormal(f16::MIN_POSITIVE));
+        assert!(!FloatCore::is_subnormal(f16::ZERO));
+        assert!(!Float::is_subnormal(f16::NAN));
+
+        assert_eq!(Float::copysign(three, f16::NEG_ZERO), -three);
+        assert_eq!(Float::abs(f16::NEG_INFINITY), f16::INFINITY);
+        assert!(Float::abs(-f16::NAN).is_sign_positive());
+        assert_eq!(Float::signum(-four), f16::NEG_ONE);
+        assert!(Float::signum(f16::NAN).is_nan());
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn signed() {
+        use num_traits::Signed;
+
+        let (one, two) = (f16::ONE, f16::from_f32(2.));
+        assert_eq!(Signed::abs(&-two), two);
+        assert_eq!(Signed::abs_sub(&two, &one), one);
+        assert_eq!(Signed::abs_sub(&one, &two), f16::ZERO);
+        assert_eq!(Signed::signum(&f16::NEG_ZERO), f16::NEG_ONE);
+        assert!(Signed::is_positive(&f16::ZERO));
+        assert!(Signed::is_negative(&f16::NEG_INFINITY));
+        assert!(!Signed::is_negative(&f16::ONE));
+    }
+
+    #[cfg(feature = "zerocopy")]
+    #[test]
+    fn zerocopy() {
+        use zerocopy::{FromBytes, IntoBytes};
+
+        let bytes = 0x3C00u16.to_ne_bytes();
+        assert_eq!(f16::read_from_bytes(&bytes), Ok(f16::ONE));
+        assert_eq!(f16::ONE.as_bytes(), bytes);
+
+        let halves = [f16::ONE, f16::NEG_INFINITY];
+        let bytes = halves.as_bytes();
+        assert_eq!(<[f16]>::ref_from_bytes(bytes).unwrap(), halves);
+    }
+
     #[test]
     fn test_f16_consts() {
         // DIGITS
         let digits = ((f16::MANTISSA_DIGITS as f32 - 1.0) * 2f32.log10()).floor() as u32;
         assert_eq!(f16::DIGITS, digits);
         // sanity check to show test is good
-        let digits32 = ((core::f32::MANTISSA_DIGITS as f32 - 1.0) * 2f32.log10()).floor() as u32;
-        assert_eq!(core::f32::DIGITS, digits32);
+        let digits32 = ((f32::MANTISSA_DIGITS as f32 - 1.0) * 2f32.log10()).floor() as u32;
+        assert_eq!(f32::DIGITS, digits32);
 
         // EPSILON
         let one = f16::from_f32(1.0);
@@ -1393,7 +2753,7 @@ mod test {
         // sanity check to show test is good
         let one_plus_epsilon32 = f32::from_bits(1.0f32.to_bits() + 1);
         let epsilon32 = one_plus_epsilon32 - 1f32;
-        assert_eq!(core::f32::EPSILON, epsilon32);
+        assert_eq!(f32::EPSILON, epsilon32);
 
         // MAX, MIN and MIN_POSITIVE
         let max = f16::from_bits(f16::INFINITY.to_bits() - 1);
@@ -1403,12 +2763,12 @@ mod test {
         assert_eq!(f16::MIN, min);
         assert_eq!(f16::MIN_POSITIVE, min_pos);
         // sanity check to show test is good
-        let max32 = f32::from_bits(core::f32::INFINITY.to_bits() - 1);
-        let min32 = f32::from_bits(core::f32::NEG_INFINITY.to_bits() - 1);
-        let min_pos32 = 2f32.powi(core::f32::MIN_EXP - 1);
-        assert_eq!(core::f32::MAX, max32);
-        assert_eq!(core::f32::MIN, min32);
-        assert_eq!(core::f32::MIN_POSITIVE, min_pos32);
+        let max32 = f32::from_bits(f32::INFINITY.to_bits() - 1);
+        let min32 = f32::from_bits(f32::NEG_INFINITY.to_bits() - 1);
+        let min_pos32 = 2f32.powi(f32::MIN_EXP - 1);
+        assert_eq!(f32::MAX, max32);
+        assert_eq!(f32::MIN, min32);
+        assert_eq!(f32::MIN_POSITIVE, min_pos32);
 
         // MIN_10_EXP and MAX_10_EXP
         let ten_to_min = 10f32.powi(f16::MIN_10_EXP);
@@ -1418,12 +2778,12 @@ mod test {
         assert!(ten_to_max < f16::MAX.to_f32());
         assert!(ten_to_max * 10.0 > f16::MAX.to_f32());
         // sanity check to show test is good
-        let ten_to_min32 = 10f64.powi(core::f32::MIN_10_EXP);
-        assert!(ten_to_min32 / 10.0 < f64::from(core::f32::MIN_POSITIVE));
-        assert!(ten_to_min32 > f64::from(core::f32::MIN_POSITIVE));
-        let ten_to_max32 = 10f64.powi(core::f32::MAX_10_EXP);
-        assert!(ten_to_max32 < f64::from(core::f32::MAX));
-        assert!(ten_to_max32 * 10.0 > f64::from(core::f32::MAX));
+        let ten_to_min32 = 10f64.powi(f32::MIN_10_EXP);
+        assert!(ten_to_min32 / 10.0 < f64::from(f32::MIN_POSITIVE));
+        assert!(ten_to_min32 > f64::from(f32::MIN_POSITIVE));
+        let ten_to_max32 = 10f64.powi(f32::MAX_10_EXP);
+        assert!(ten_to_max32 < f64::from(f32::MAX));
+        assert!(ten_to_max32 * 10.0 > f64::from(f32::MAX));
     }
 
     #[test]
@@ -1432,9 +2792,9 @@ mod test {
         let zero = f16::from_f32(0.0);
         let neg_zero = f16::from_f32(-0.0);
         let neg_one = f16::from_f32(-1.0);
-        let inf = f16::from_f32(core::f32::INFINITY);
-        let neg_inf = f16::from_f32(core::f32::NEG_INFINITY);
-        let nan = f16::from_f32(core::f32::NAN);
+        let inf = f16::from_f32(f32::INFINITY);
+        let neg_inf = f16::from_f32(f32::NEG_INFINITY);
+        let nan = f16::from_f32(f32::NAN);
 
         assert_eq!(f16::ONE, one);
         assert_eq!(f16::ZERO, zero);
@@ -1494,9 +2854,9 @@ mod test {
         let one = f16::from_f64(1.0);
         let zero = f16::from_f64(0.0);
         let neg_zero = f16::from_f64(-0.0);
-        let inf = f16::from_f64(core::f64::INFINITY);
-        let neg_inf = f16::from_f64(core::f64::NEG_INFINITY);
-        let nan = f16::from_f64(core::f64::NAN);
+        let inf = f16::from_f64(f64::INFINITY);
+        let neg_inf = f16::from_f64(f64::NEG_INFINITY);
+        let nan = f16::from_f64(f64::NAN);
 
         assert_eq!(f16::ONE, one);
         assert_eq!(f16::ZERO, zero);
@@ -1694,6 +3054,224 @@ mod test {
         assert!(!(neg_one >= one));
     }
 
+    #[test]
+    fn test_ulp_distance() {
+        // Consecutive values are one apart across every exponent and the sign change
+        let mut prev = f16::NEG_INFINITY;
+        let finite = f16::all_finite().rev().take_while(|x| x.is_sign_negative());
+        for x in finite
+            .chain(
+                f16::all_finite()
+                    .skip(1)
+                    .take_while(|x| x.is_sign_positive()),
+            )
+            .chain([f16::INFINITY])
+        {
+            assert_eq!(prev.ulp_distance(x), Some(1));
+            assert_eq!(x.ulp_distance(prev), Some(1));
+            prev = x;
+        }
+        assert_eq!(f16::MIN.ulp_distance(f16::MAX), Some(0xF800 - 2));
+        assert_eq!(f16::NAN.ulp_distance(f16::NAN), None);
+        assert_eq!(f16::INFINITY.ulp_distance(-f16::NAN), None);
+    }
+
+    #[test]
+    fn test_next_up_down() {
+        // Stepping through every non-NaN value in order matches `ulp_distance`
+        let mut x = f16::NEG_INFINITY;
+        let mut steps = 0u32;
+        while x != f16::INFINITY {
+            let next = x.next_up();
+            assert!(next > x);
+            assert_eq!(next.next_down().to_bits() & 0x7FFF, x.to_bits() & 0x7FFF);
+            x = next;
+            steps += 1;
+        }
+        assert_eq!(
+            steps,
+            f16::NEG_INFINITY.ulp_distance(f16::INFINITY).unwrap() as u32
+        );
+        assert_eq!(f16::INFINITY.next_up(), f16::INFINITY);
+        assert_eq!(f16::NEG_INFINITY.next_down(), f16::NEG_INFINITY);
+        assert_eq!((-f16::MIN_POSITIVE_SUBNORMAL).next_up().to_bits(), 0x8000);
+        assert!(f16::NAN.next_down().is_nan());
+    }
+
+    #[test]
+    fn test_ulp() {
+        for x in f16::all_finite() {
+            let ulp = x.ulp();
+            let abs = x.copysign(f16::ONE);
+            if abs == f16::MAX {
+                assert_eq!(abs - abs.next_down(), ulp);
+            } else {
+                assert_eq!(abs.next_up() - abs, ulp);
+            }
+            assert_eq!(ulp, (-x).ulp());
+        }
+        assert_eq!(f16::INFINITY.ulp(), f16::INFINITY);
+        assert!(f16::NAN.ulp().is_nan());
+    }
+
+    #[cfg(any(feature = "std", feature = "math"))]
+    #[test]
+    fn test_powf() {
+        let two = f16::from_f32(2.0);
+        for i in -30..30 {
+            let expected = f16::from_f64(f64::from_bits(((1023 + i) as u64) << 52));
+            assert_eq!(two.powf(f16::from_f32(i as f32)), expected);
+            assert_eq!(two.powf_f32(i as f32), expected);
+        }
+        assert_eq!(two.powf(f16::from_f32(16.0)), f16::INFINITY);
+        assert_eq!(f16::ZERO.powf(f16::from_f32(-1.0)), f16::INFINITY);
+        assert_eq!(f16::NEG_ZERO.powf(f16::from_f32(-3.0)), f16::NEG_INFINITY);
+        assert_eq!(f16::ONE.powf(f16::NAN), f16::ONE);
+        assert_eq!(f16::from_f32(0.5).powf(f16::INFINITY), f16::ZERO);
+        assert!(f16::NAN.powf(f16::ONE).is_nan());
+    }
+
+    #[cfg(any(feature = "std", feature = "math"))]
+    #[test]
+    fn test_ln_1p_exp_m1_exp2() {
+        for bits in [0x0001, 0x0003, 0x8001] {
+            let x = f16::from_bits(bits);
+            assert_eq!(x.ln_1p().to_bits(), bits);
+            assert_eq!(x.exp_m1().to_bits(), bits);
+            assert_eq!(x.exp2(), f16::ONE);
+        }
+        for i in -24..16 {
+            let x = f16::from_f32(i as f32);
+            let expected = f16::from_f64(f64::from_bits(((1023 + i) as u64) << 52));
+            assert_eq!(x.exp2(), expected);
+        }
+        assert_eq!(f16::from_f32(-24.0 - 1.0).exp2(), f16::ZERO);
+        assert_eq!(f16::INFINITY.ln_1p(), f16::INFINITY);
+        assert_eq!(f16::INFINITY.exp_m1(), f16::INFINITY);
+        assert_eq!(f16::NEG_ZERO.ln_1p().to_bits(), 0x8000);
+        assert!(f16::NAN.ln_1p().is_nan());
+        assert!(f16::NAN.exp_m1().is_nan());
+        assert!(f16::NAN.exp2().is_nan());
+    }
+
+    #[cfg(feature = "math")]
+    #[test]
+    fn test_special_functions() {
+        let mut factorial = 1.0;
+        for n in 1..9 {
+            let x = f16::from_f32(n as f32);
+            assert_eq!(x.gamma(), f16::from_f32(factorial));
+            factorial *= n as f32;
+        }
+        for bits in [0x0001, 0x8001] {
+            let x = f16::from_bits(bits);
+            assert_eq!(
+                x.erf().to_bits(),
+                f16::from_f64(x.to_f64() * core::f64::consts::FRAC_2_SQRT_PI).to_bits()
+            );
+            assert_eq!(x.erfc(), f16::ONE);
+            assert!(x.gamma().is_infinite());
+            assert_eq!(x.gamma().is_sign_negative(), bits == 0x8001);
+        }
+        assert_eq!(f16::INFINITY.erf(), f16::ONE);
+        assert_eq!(f16::INFINITY.erfc(), f16::ZERO);
+        assert_eq!(f16::INFINITY.gamma(), f16::INFINITY);
+        assert_eq!(f16::from_f32(2.0).ln_gamma(), (f16::ZERO, 1));
+        assert_eq!(f16::from_f32(-3.0).ln_gamma().0, f16::INFINITY);
+        assert_eq!(f16::from_f32(-2.5).ln_gamma().1, -1);
+        assert_eq!(f16::from_f32(-1.5).ln_gamma().1, 1);
+        assert!(f16::NAN.erf().is_nan());
+        assert!(f16::NAN.gamma().is_nan());
+    }
+
+    #[test]
+    fn test_from_f32_with_flags() {
+        assert_eq!(
+            f16::from_f32_with_flags(1.5),
+            (f16::from_f32(1.5), ExceptionFlags::empty())
+        );
+        assert!(f16::from_f32_with_flags(f32::INFINITY).1.is_empty());
+        assert!(f16::from_f64_with_flags(f64::NAN).1.is_empty());
+        assert_eq!(
+            f16::from_f32_with_flags(f32::from_bits(0x7F80_0001)).1,
+            ExceptionFlags::INVALID
+        );
+        assert_eq!(
+            f16::from_f64_with_flags(f64::from_bits(0x7FF0_0000_0000_0001)).1,
+            ExceptionFlags::INVALID
+        );
+        assert_eq!(f16::from_f64_with_flags(0.1).1, ExceptionFlags::INEXACT);
+        assert_eq!(
+            f16::from_f32_with_flags(1e-7).1,
+            ExceptionFlags::UNDERFLOW | ExceptionFlags::INEXACT
+        );
+        // Exact subnormal results do not underflow
+        assert!(f16::from_f32_with_flags(2f32.powi(-24)).1.is_empty());
+        assert_eq!(
+            f16::from_f64_with_flags(65520.0).1,
+            ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT
+        );
+        assert_eq!(f16::from_f64_with_flags(65519.0).1, ExceptionFlags::INEXACT);
+    }
+
+    #[test]
+    fn test_flush_subnormal_to_zero() {
+        for bits in 0..=u16::MAX {
+            let x = f16::from_bits(bits);
+            let flushed = x.flush_subnormal_to_zero();
+            if bits & 0x7C00 == 0 {
+                assert_eq!(flushed.to_bits(), bits & 0x8000);
+            } else {
+                assert_eq!(flushed.to_bits(), bits);
+            }
+
+            // Flushing the input is the same as converting zero, and nothing is flushed otherwise
+            let f = x.to_f32();
+            assert_eq!(
+                x.to_f32_flush(FlushMode::Both).to_bits(),
+                flushed.to_f32().to_bits()
+            );
+            assert_eq!(x.to_f32_flush(FlushMode::Preserve).to_bits(), f.to_bits());
+            assert_eq!(
+                x.to_f64_flush(FlushMode::DenormalsAreZero).to_bits(),
+                flushed.to_f64().to_bits()
+            );
+            if !x.is_nan() {
+                assert_eq!(
+                    f16::from_f32_flush(f, FlushMode::FlushToZero).to_bits(),
+                    flushed.to_bits()
+                );
+                assert_eq!(
+                    f16::from_f64_flush(x.to_f64(), FlushMode::Both).to_bits(),
+                    flushed.to_bits()
+                );
+            }
+        }
+        assert_eq!(f16::from_bits(0x03FF).flush_subnormal_to_zero(), f16::ZERO);
+    }
+
+    #[test]
+    fn test_comparisons_match_f32() {
+        // Every bit pattern against a spread of others, including zeros, infinities and NaNs
+        let others = (0..=u16::MAX).step_by(1021).map(f16::from_bits).chain([
+            f16::NEG_ZERO,
+            f16::INFINITY,
+            f16::NEG_INFINITY,
+        ]);
+        for bits in 0..=u16::MAX {
+            let x = f16::from_bits(bits);
+            for y in others.clone() {
+                let (fx, fy) = (x.to_f32(), y.to_f32());
+                assert_eq!(x.partial_cmp(&y), fx.partial_cmp(&fy));
+                assert_eq!(x == y, fx == fy);
+                assert_eq!(x < y, fx < fy);
+                assert_eq!(x <= y, fx <= fy);
+                assert_eq!(x > y, fx > fy);
+                assert_eq!(x >= y, fx >= fy);
+            }
+        }
+    }
+
     #[test]
     #[allow(clippy::erasing_op, clippy::identity_op)]
     fn round_to_even_f32() {
@@ -1884,10 +3462,679 @@ mod test {
         );
     }
 
-    impl quickcheck::Arbitrary for f16 {
-        fn arbitrary(g: &mut quickcheck::Gen) -> Self {
-            f16(u16::arbitrary(g))
+    #[test]
+    fn test_assign_ops() {
+        let mut x = f16::from_f32(3.0);
+        x += f16::from_f32(1.0);
+        assert_eq!(x, f16::from_f32(4.0));
+        x -= &f16::from_f32(2.0);
+        assert_eq!(x, f16::from_f32(2.0));
+        x *= f16::from_f32(4.0);
+        assert_eq!(x, f16::from_f32(8.0));
+        x /= &f16::from_f32(2.0);
+        assert_eq!(x, f16::from_f32(4.0));
+        x %= f16::from_f32(3.0);
+        assert_eq!(x, f16::from_f32(1.0));
+    }
+
+    #[test]
+    fn test_rem_ieee_fmod() {
+        let divisors = [1.0, -0.1, 3.0, 2.5, 6.0e-8, 65504.0, 0.5, -7.0];
+        for bits in 0..=u16::MAX {
+            let x = f16::from_bits(bits);
+            for &y in &divisors {
+                let y = f16::from_f32(y);
+                let r = x.rem_ieee(y);
+                let m = x.fmod(y);
+                assert_eq!(m.to_bits(), (x % y).to_bits());
+                if !x.is_finite() {
+                    assert!(r.is_nan() && m.is_nan());
+                    continue;
+                }
+                let (xf, yf, rf) = (x.to_f64(), y.to_f64().abs(), r.to_f64());
+                assert!(rf.abs() <= yf / 2.0, "{:?} {:?}", x, y);
+                assert!(m.to_f64().abs() < yf && m.is_sign_negative() == x.is_sign_negative());
+
+                // The quotient is an integer, and even on ties
+                let q = (xf - rf) / yf;
+                assert_eq!(q, (q as i64) as f64, "{:?} {:?}", x, y);
+                if rf.abs() == yf / 2.0 {
+                    assert_eq!(q as i64 % 2, 0);
+                }
+                if rf == 0.0 {
+                    assert_eq!(r.is_sign_negative(), x.is_sign_negative());
+                }
+            }
+        }
+
+        assert!(f16::ONE.rem_ieee(f16::ZERO).is_nan());
+        assert!(f16::NAN.rem_ieee(f16::ONE).is_nan());
+        assert_eq!(f16::ONE.rem_ieee(f16::INFINITY), f16::ONE);
+        assert_eq!(f16::NEG_ONE.fmod(f16::NEG_INFINITY), f16::NEG_ONE);
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let values = [
+            f16::from_f32(1.0),
+            f16::from_f32(2.0),
+            f16::from_f32(3.0),
+            f16::from_f32(4.0),
+        ];
+
+        assert_eq!(values.iter().copied().sum::<f16>(), f16::from_f32(10.0));
+        assert_eq!(values.iter().sum::<f16>(), f16::from_f32(10.0));
+        assert_eq!(values.iter().copied().product::<f16>(), f16::from_f32(24.0));
+        assert_eq!(values.iter().product::<f16>(), f16::from_f32(24.0));
+
+        let empty: [f16; 0] = [];
+        assert_eq!(empty.iter().sum::<f16>(), f16::ZERO);
+        assert_eq!(empty.iter().product::<f16>(), f16::ONE);
+    }
+
+    #[test]
+    fn test_from_int() {
+        assert_eq!(
+            f16::from_i16(-2048).to_bits(),
+            f16::from_f32(-2048.0).to_bits()
+        );
+        assert_eq!(f16::from_u16(0).to_bits(), f16::ZERO.to_bits());
+        // Ties round to even
+        assert_eq!(f16::from_u16(2049), f16::from_f32(2048.0));
+        assert_eq!(f16::from_i32(-2051), f16::from_f32(-2052.0));
+        assert_eq!(f16::from_u32(65519), f16::MAX);
+        assert_eq!(f16::from_u32(65520), f16::INFINITY);
+        assert_eq!(f16::from_i64(i64::MIN), f16::NEG_INFINITY);
+        assert_eq!(f16::from_u64(u64::MAX), f16::INFINITY);
+    }
+
+    #[quickcheck]
+    fn qc_from_i32_matches_f64(x: i32) -> bool {
+        // Every i32 is exact in f64, so converting from there rounds only once
+        f16::from_i32(x).to_bits() == f16::from_f64(x as f64).to_bits()
+    }
+
+    #[test]
+    fn test_try_from_float() {
+        assert_eq!(f16::try_from(1.5f32).unwrap(), f16::from_f32(1.5));
+        assert_eq!(f16::try_from(-65504.0f64).unwrap(), f16::MIN);
+        assert_eq!(
+            f16::try_from(-0.0f32).unwrap().to_bits(),
+            f16::NEG_ZERO.to_bits()
+        );
+        assert_eq!(
+            f16::try_from(2f32.powi(-24)).unwrap(),
+            f16::MIN_POSITIVE_SUBNORMAL
+        );
+        assert_eq!(f16::try_from(f64::INFINITY).unwrap(), f16::INFINITY);
+        assert!(f16::try_from(f32::NAN).unwrap().is_nan());
+
+        let err = f16::try_from(0.1f32).unwrap_err();
+        assert!(!err.is_overflow());
+        assert!(!f16::try_from(2f64.powi(-25)).unwrap_err().is_overflow());
+        assert!(!f16::try_from(2049.0f32).unwrap_err().is_overflow());
+        assert!(f16::try_from(65505.0f32).unwrap_err().is_overflow());
+        assert!(f16::try_from(f64::MIN).unwrap_err().is_overflow());
+    }
+
+    #[test]
+    fn test_to_int() {
+        assert_eq!(f16::from_f32(2.75).to_int::<u8>(), 2);
+        assert_eq!(f16::from_f32(-2.75).to_int::<i8>(), -2);
+        assert_eq!(f16::from_f32(-0.5).to_int::<u32>(), 0);
+        assert_eq!(f16::MAX.to_int::<i16>(), i16::MAX);
+        assert_eq!(f16::MIN.to_int::<i16>(), i16::MIN);
+        assert_eq!(f16::MAX.to_int::<u128>(), 65504);
+        assert_eq!(f16::NEG_INFINITY.to_int::<i64>(), i64::MIN);
+        assert_eq!(f16::NAN.to_int::<usize>(), 0);
+        assert_eq!(unsafe { f16::MIN.to_int_unchecked::<i32>() }, -65504);
+    }
+
+    #[quickcheck]
+    fn qc_to_int_matches_as(x: f16) -> bool {
+        let f = x.to_f32();
+        x.to_int::<i8>() == f as i8
+            && x.to_int::<u16>() == f as u16
+            && x.to_int::<i32>() == f as i32
+            && x.to_int::<u64>() == f as u64
+    }
+
+    #[test]
+    fn test_from_f32_round() {
+        use RoundingMode::*;
+        let x = 1.0 + 2f32.powi(-11); // Halfway between two f16 values
+        assert_eq!(f16::from_f32_round(x, NearestEven), f16::ONE);
+        assert_eq!(f16::from_f32_round(x, NearestAway).to_bits(), 0x3C01);
+        assert_eq!(f16::from_f32_round(x, TowardZero), f16::ONE);
+        assert_eq!(f16::from_f32_round(x, TowardPositive).to_bits(), 0x3C01);
+        assert_eq!(f16::from_f32_round(x, TowardNegative), f16::ONE);
+        assert_eq!(f16::from_f32_round(-x, TowardNegative).to_bits(), 0xBC01);
+        assert_eq!(f16::from_f32_round(-x, TowardPositive), -f16::ONE);
+
+        // Overflow
+        assert_eq!(f16::from_f32_round(65520.0, NearestEven), f16::INFINITY);
+        assert_eq!(f16::from_f32_round(65520.0, TowardZero), f16::MAX);
+        assert_eq!(f16::from_f32_round(1e10, TowardNegative), f16::MAX);
+        assert_eq!(f16::from_f32_round(-1e10, TowardPositive), f16::MIN);
+        assert_eq!(
+            f16::from_f64_round(-1e300, TowardNegative),
+            f16::NEG_INFINITY
+        );
+        assert_eq!(
+            f16::from_f64_round(f64::INFINITY, TowardZero),
+            f16::INFINITY
+        );
+
+        // Underflow and subnormals
+        let tiny = f32::from_bits(1);
+        assert_eq!(f16::from_f32_round(tiny, NearestEven).to_bits(), 0);
+        assert_eq!(
+            f16::from_f32_round(tiny, TowardPositive),
+            f16::MIN_POSITIVE_SUBNORMAL
+        );
+        assert_eq!(f16::from_f32_round(-tiny, TowardPositive).to_bits(), 0x8000);
+        assert_eq!(
+            f16::from_f64_round(-1e-300, TowardNegative).to_bits(),
+            0x8001
+        );
+        let below_min_normal = f16::MIN_POSITIVE.to_f32() - f32::EPSILON * 2f32.powi(-14);
+        assert_eq!(
+            f16::from_f32_round(below_min_normal, NearestAway),
+            f16::MIN_POSITIVE
+        );
+        assert!(f16::from_f32_round(f32::NAN, TowardZero).is_nan());
+    }
+
+    #[test]
+    fn test_from_f64_sticky_rounding() {
+        // Just above halfway, with the deciding bit outside the high 32 bits of the f64
+        let x = 1.0 + 2f64.powi(-11) + 2f64.powi(-40);
+        assert_eq!(f16::from_f64(x).to_bits(), 0x3C01);
+        assert_eq!(f16::from_f64_const(x).to_bits(), 0x3C01);
+        let x = 2f64.powi(-25) + 2f64.powi(-60);
+        assert_eq!(f16::from_f64(x), f16::MIN_POSITIVE_SUBNORMAL);
+        assert_eq!(f16::from_f64_const(x), f16::MIN_POSITIVE_SUBNORMAL);
+    }
+
+    #[quickcheck]
+    fn qc_from_float_round_nearest(f: f32, g: f64) -> bool {
+        let same = |a: f16, b: f16| a.to_bits() == b.to_bits();
+        same(
+            f16::from_f32_round(f, RoundingMode::NearestEven),
+            f16::from_f32(f),
+        ) && same(
+            f16::from_f64_round(g, RoundingMode::NearestEven),
+            f16::from_f64(g),
+        )
+    }
+
+    #[quickcheck]
+    fn qc_from_f32_round_directed(f: f32) -> bool {
+        if f.is_nan() {
+            return true;
+        }
+        let down = f16::from_f32_round(f, RoundingMode::TowardNegative).to_f32();
+        let up = f16::from_f32_round(f, RoundingMode::TowardPositive).to_f32();
+        let zero = f16::from_f32_round(f, RoundingMode::TowardZero).to_f32();
+        let exact = down == up;
+        down <= f
+            && f <= up
+            && (exact
+                || f16::from_f32(down).to_bits().wrapping_add(1) == f16::from_f32(up).to_bits()
+                || f16::from_f32(down).to_bits().wrapping_sub(1) == f16::from_f32(up).to_bits())
+            && zero == if f < 0.0 { up } else { down }
+    }
+
+    #[test]
+    fn test_from_f32_stochastic() {
+        // Exactly representable values never change
+        for rng in [0, 1, 0x8000, u16::MAX] {
+            assert_eq!(f16::from_f32_stochastic(1.5, rng), f16::from_f32(1.5));
+            assert_eq!(f16::from_f32_stochastic(-0.0, rng).to_bits(), 0x8000);
+            assert_eq!(f16::from_f32_stochastic(f32::INFINITY, rng), f16::INFINITY);
+        }
+        assert!(f16::from_f32_stochastic(f32::NAN, 0).is_nan());
+
+        // Halfway rounds up for exactly half of the random values
+        let x = -(1.0 + 2f32.powi(-11));
+        let ups = (0..=u16::MAX)
+            .filter(|&r| f16::from_f32_stochastic(x, r) != -f16::ONE)
+            .count();
+        assert_eq!(ups, 0x8000);
+
+        // Subnormals and overflow
+        let sub = 2f32.powi(-26); // A quarter of the smallest subnormal
+        assert_eq!(f16::from_f32_stochastic(sub, 0xBFFF).to_bits(), 0);
+        assert_eq!(
+            f16::from_f32_stochastic(sub, 0xC000),
+            f16::MIN_POSITIVE_SUBNORMAL
+        );
+        assert_eq!(f16::from_f32_stochastic(65504.0 + 16.0, 0x7FFF), f16::MAX);
+        assert_eq!(
+            f16::from_f32_stochastic(65504.0 + 16.0, 0x8000),
+            f16::INFINITY
+        );
+        assert_eq!(f16::from_f32_stochastic(1e9, 0), f16::INFINITY);
+        assert_eq!(
+            f16::from_f32_stochastic(f32::from_bits(1), u16::MAX).to_bits(),
+            0
+        );
+    }
+
+    #[quickcheck]
+    fn qc_from_f32_stochastic_brackets(f: f32, rng: u16) -> bool {
+        if f.is_nan() {
+            return true;
+        }
+        let rounded = f16::from_f32_stochastic(f, rng).to_bits();
+        rounded == f16::from_f32_round(f, RoundingMode::TowardZero).to_bits()
+            || rounded == f16::from_f32_round(f, RoundingMode::TowardPositive).to_bits()
+            || rounded == f16::from_f32_round(f, RoundingMode::TowardNegative).to_bits()
+            || (rounded & 0x7FFF == 0x7C00)
+    }
+
+    #[test]
+    fn test_from_float_saturating() {
+        assert_eq!(f16::from_f32_saturating(65519.0), f16::MAX);
+        assert_eq!(f16::from_f32_saturating(65520.0), f16::MAX);
+        assert_eq!(f16::from_f32_saturating(f32::MIN), f16::MIN);
+        assert_eq!(f16::from_f64_saturating(-1e300), f16::MIN);
+        assert_eq!(f16::from_f64_saturating(1e300), f16::MAX);
+        assert_eq!(f16::from_f32_saturating(1.5), f16::from_f32(1.5));
+        assert_eq!(f16::from_f64_saturating(f64::INFINITY), f16::INFINITY);
+        assert!(f16::from_f32_saturating(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_from_float_exact() {
+        assert_eq!(f16::from_f32_exact(-2048.0), Some(f16::from_f32(-2048.0)));
+        assert_eq!(f16::from_f32_exact(2049.0), None);
+        assert_eq!(f16::from_f32_exact(65536.0), None);
+        assert_eq!(f16::from_f32_exact(f32::INFINITY), Some(f16::INFINITY));
+        assert_eq!(
+            f16::from_f64_exact(2f64.powi(-24)),
+            Some(f16::MIN_POSITIVE_SUBNORMAL)
+        );
+        assert_eq!(f16::from_f64_exact(2f64.powi(-25)), None);
+        assert_eq!(f16::from_f64_exact(1.0 + 2f64.powi(-40)), None);
+        assert!(f16::is_exactly_representable(f32::NAN));
+        assert!(f16::is_exactly_representable(-0.0));
+        assert!(!f16::is_exactly_representable(f32::MAX));
+    }
+
+    #[quickcheck]
+    fn qc_from_f32_exact_matches_rounding(f: f32) -> bool {
+        let down = f16::from_f32_round(f, RoundingMode::TowardNegative);
+        let up = f16::from_f32_round(f, RoundingMode::TowardPositive);
+        f16::is_exactly_representable(f) == (f.is_nan() || down.to_bits() == up.to_bits())
+    }
+
+    #[test]
+    fn test_from_bf16() {
+        // Going through f32 is exact for bf16, so it only rounds once as well
+        for bits in 0..=u16::MAX {
+            let x = bf16::from_bits(bits);
+            assert_eq!(
+                f16::from_bf16(x).to_bits(),
+                f16::from_f32(x.to_f32()).to_bits(),
+                "{:#06x}",
+                bits
+            );
+        }
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        assert_eq!(f16::from_f32(0.25).to_fixed::<i16>(15), 0x2000);
+        assert_eq!(f16::from_f32(-0.999).to_fixed::<i16>(15), -32736);
+        assert_eq!(f16::from_f32(3.0).to_fixed::<i8>(7), i8::MAX);
+        assert_eq!(f16::NEG_INFINITY.to_fixed::<i32>(31), i32::MIN);
+        assert_eq!(f16::NAN.to_fixed::<i16>(15), 0);
+        assert_eq!(f16::from_f32(-2.5).to_fixed::<u8>(0), 0);
+        // Ties to even
+        assert_eq!(f16::from_f32(2.5).to_fixed::<i32>(0), 2);
+        assert_eq!(f16::from_f32(-3.5).to_fixed::<i32>(0), -4);
+        assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.to_fixed::<u64>(24), 1);
+        assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.to_fixed::<u64>(22), 0);
+        assert_eq!(f16::MAX.to_fixed::<u64>(5000), u64::MAX);
+
+        assert_eq!(f16::from_fixed(1i32, 24), f16::MIN_POSITIVE_SUBNORMAL);
+        assert_eq!(f16::from_fixed(1i32, 26).to_bits(), 0);
+        assert_eq!(f16::from_fixed(3i32, 26), f16::MIN_POSITIVE_SUBNORMAL);
+        assert_eq!(f16::from_fixed(-2049i32, 0), f16::from_f32(-2048.0));
+        assert_eq!(f16::from_fixed(u128::MAX, 0), f16::INFINITY);
+        assert_eq!(f16::from_fixed(u128::MAX, 113), f16::from_f32(32768.0));
+        assert_eq!(f16::from_fixed(i128::MIN, 112), f16::from_f32(-32768.0));
+        assert_eq!(f16::from_fixed(5u8, 400).to_bits(), 0);
+    }
+
+    #[quickcheck]
+    fn qc_fixed_point_q15_roundtrip(x: i16) -> bool {
+        // Q15 values need at most 15 significant bits, so rounding can only lose the low bits
+        let f = f16::from_fixed(x, 15);
+        let back = f.to_fixed::<i16>(15);
+        f == f16::from_f64(x as f64 / 32768.0) && (back as i32 - x as i32).abs() <= 8
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_display_shortest() {
+        assert_eq!(f16::from_f32(0.3).to_string(), "0.3");
+        assert_eq!(f16::from_f32(-1.0).to_string(), "-1");
+        assert_eq!(f16::NEG_ZERO.to_string(), "-0");
+        assert_eq!(f16::MAX.to_string(), "65500");
+        assert_eq!(f16::MIN_POSITIVE.to_string(), "0.00006104");
+        assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.to_string(), "0.00000006");
+        assert_eq!(f16::EPSILON.to_string(), "0.000977");
+        assert_eq!(f16::from_f32(1.0 + 2f32.powi(-10)).to_string(), "1.001");
+        assert_eq!(f16::INFINITY.to_string(), "inf");
+        assert_eq!(f16::NAN.to_string(), "NaN");
+        assert_eq!(format!("{:.3}", f16::from_f32(0.3)), "0.300");
+
+        assert_eq!(format!("{:e}", f16::from_f32(0.3)), "3e-1");
+        assert_eq!(format!("{:E}", f16::from_f32(-1234.0)), "-1.234E3");
+        assert_eq!(format!("{:e}", f16::MAX), "6.55e4");
+        assert_eq!(format!("{:e}", f16::ZERO), "0e0");
+        assert_eq!(format!("{:e}", f16::NEG_INFINITY), "-inf");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_format_bits() {
+        let one = f16::ONE;
+        assert_eq!(format!("{:x}", one), "3c00");
+        assert_eq!(format!("{:#06X}", one), "0x3C00");
+        assert_eq!(format!("{:016b}", one), "0011110000000000");
+        assert_eq!(format!("{:#b}", -one), "0b1011110000000000");
+        assert_eq!(format!("{:>8o}", one), "   36000");
+        assert_eq!(format!("{:_<6x}", one), "3c00__");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_format_flags() {
+        let x = f16::from_f32(1.5);
+        assert_eq!(format!("{:>6}", x), "   1.5");
+        assert_eq!(format!("{:*^7}", x), "**1.5**");
+        assert_eq!(format!("{:+}", x), "+1.5");
+        assert_eq!(format!("{:06}", -x), "-001.5");
+        assert_eq!(format!("{:+.2}", x), "+1.50");
+        assert_eq!(format!("{:>10.3}", f16::from_f32(0.3)), "     0.300");
+        assert_eq!(format!("{:<8e}|", x), "1.5e0   |");
+        assert_eq!(format!("{:+E}", f16::MAX), "+6.55E4");
+        assert_eq!(format!("{:+08.1e}", x), "+001.5e0");
+        assert_eq!(format!("{:>6?}", f16::ONE), "   1.0");
+        assert_eq!(format!("{:+}", f16::NEG_ZERO), "-0");
+        assert_eq!(format!("{:>5}", f16::INFINITY), "  inf");
+
+        // Flags are applied the same as for `f32` given the same digits
+        for value in [0.5f32, -2.0, 1024.0, 0.125] {
+            let x = f16::from_f32(value);
+            for (half, single) in [
+                (format!("{:+09}", x), format!("{:+09}", value)),
+                (format!("{:<9?}", x), format!("{:<9?}", value)),
+                (format!("{:>+12e}", x), format!("{:>+12e}", value)),
+            ] {
+                assert_eq!(half, single);
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_debug() {
+        assert_eq!(format!("{:?}", f16::ONE), "1.0");
+        assert_eq!(format!("{:?}", f16::from_f32(-0.3)), "-0.3");
+        assert_eq!(format!("{:?}", f16::NEG_ZERO), "-0.0");
+        assert_eq!(format!("{:?}", f16::MAX), "65500.0");
+        assert_eq!(format!("{:?}", f16::from_f32(0.0001)), "0.0001");
+        assert_eq!(format!("{:?}", f16::MIN_POSITIVE), "6.104e-5");
+        assert_eq!(format!("{:?}", f16::NAN), "NaN");
+        assert_eq!(format!("{:?}", [f16::ONE, f16::INFINITY]), "[1.0, inf]");
+        assert_eq!(format!("{:.2?}", f16::from_f32(0.3)), "0.30");
+        assert_eq!(format!("{:x}", f16::ONE), "3c00");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_hex_float() {
+        assert_eq!(f16::ONE.to_hex_string(), "0x1p+0");
+        assert_eq!(f16::from_f32(-0.375).to_hex_string(), "-0x1.8p-2");
+        assert_eq!(f16::NEG_ZERO.to_hex_string(), "-0x0p+0");
+        assert_eq!(f16::MAX_SUBNORMAL.to_hex_string(), "0x0.ffcp-14");
+        assert_eq!(f16::NEG_INFINITY.to_hex_string(), "-inf");
+        assert_eq!(f16::NAN.to_hex_string(), "NaN");
+
+        let parse = |s: &str| s.parse::<f16>().map(f16::to_bits).ok();
+        assert_eq!(parse("0x1.8p-2"), Some(0x3600));
+        assert_eq!(parse("-0X1.8P-2"), Some(0xB600));
+        assert_eq!(parse("+0x.8p1"), Some(0x3C00));
+        assert_eq!(parse("0x10"), Some(0x4C00));
+        assert_eq!(parse("0x1.ffep15"), Some(0x7C00));
+        assert_eq!(parse("0x1.ffdfffffffffffffffffp15"), Some(0x7BFF));
+        // Ties round to even, with digits past the significand still counted
+        assert_eq!(parse("0x1.002p0"), Some(0x3C00));
+        assert_eq!(parse("0x1.0020000000000000000001p0"), Some(0x3C01));
+        assert_eq!(parse("0x1p-25"), Some(0));
+        assert_eq!(parse("0x1.0001p-25"), Some(1));
+        assert_eq!(parse("0x1p-100000000"), Some(0));
+        assert_eq!(parse("0x0.0000000000000000000000001p+100"), Some(0x3C00));
+        for invalid in [
+            "0x", "0x.", "0x1p", "0x1p+", "0x1.2.3", "0xg", "0x1p1.5", "-0x-1",
+        ] {
+            assert_eq!(parse(invalid), None, "{}", invalid);
+        }
+
+        for bits in 0..=u16::MAX {
+            let x = f16::from_bits(bits);
+            if !x.is_nan() {
+                assert_eq!(parse(&x.to_hex_string()), Some(bits));
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_display_roundtrip_exhaustive() {
+        for bits in 0..=u16::MAX {
+            let x = f16::from_bits(bits);
+            if !x.is_finite() {
+                continue;
+            }
+            let display = x.to_string();
+            let exp = format!("{:e}", x);
+            let shown = display.parse::<f64>().unwrap();
+            assert_eq!(f16::from_f64(shown).to_bits(), bits, "{}", display);
+            assert_eq!(exp.parse::<f64>().unwrap(), shown, "{}", exp);
+            // Dropping the last digit must not round trip, or the output is not the shortest
+            let digits = exp.split('e').next().unwrap().replace(['-', '.'], "");
+            if digits.len() > 1 {
+                let decimal = decimal_exponent(&exp) - digits.len() as i32 + 2;
+                let shorter: u64 = digits[..digits.len() - 1].parse().unwrap();
+                for candidate in [shorter, shorter + 1] {
+                    let value = candidate as f64 * 10f64.powi(decimal);
+                    let value = if x.is_sign_negative() { -value } else { value };
+                    assert_ne!(f16::from_f64(value).to_bits(), bits, "{}", display);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parse_decimal() {
+        let parse = |s: &str| s.parse::<f16>().map(f16::to_bits).ok();
+        assert_eq!(parse("1"), Some(0x3C00));
+        assert_eq!(parse("-0"), Some(0x8000));
+        assert_eq!(parse("+.5"), Some(0x3800));
+        assert_eq!(parse("2."), Some(0x4000));
+        assert_eq!(parse("0001.5E+0"), Some(0x3E00));
+        assert_eq!(parse("65519.99"), Some(0x7BFF));
+        assert_eq!(parse("65520"), Some(0x7C00));
+        assert_eq!(parse("-1e100000000000"), Some(0xFC00));
+        assert_eq!(parse("1e-100000000000"), Some(0));
+        assert_eq!(
+            parse("0.0000000000000000000000000000000000000000001e43"),
+            Some(0x3C00)
+        );
+        assert_eq!(
+            parse("100000000000000000000000000000000000000000e-41"),
+            Some(0x3C00)
+        );
+        assert_eq!(parse("infinity"), Some(0x7C00));
+        assert_eq!(parse("-INF"), Some(0xFC00));
+        assert!(f16::from_str("NaN").unwrap().is_nan());
+        for invalid in [
+            "", "+", ".", "e1", "1e", "1e+", "1.2.3", "--1", "1x", "in", "nan1",
+        ] {
+            assert_eq!(parse(invalid), None, "{}", invalid);
+        }
+        assert_eq!(
+            f16::from_str("").unwrap_err(),
+            f32::from_str("").unwrap_err()
+        );
+
+        // Parsing through `f32` or `f64` rounds these to the halfway point first, then to even
+        for (src, bits) in [
+            ("2.98023223876953125e-8", 0x0000),
+            ("2.980232238769531250000000000000001e-8", 0x0001),
+            ("1.00048828125", 0x3C00),
+            (
+                "1.000488281250000000000000000000000000000000000000001",
+                0x3C01,
+            ),
+            (
+                "1.000488281249999999999999999999999999999999999999999",
+                0x3C00,
+            ),
+        ] {
+            assert_eq!(parse(src), Some(bits), "{}", src);
+        }
+
+        for bits in 0..0x7C00u16 {
+            let x = f16::from_bits(bits);
+            assert_eq!(parse(&x.to_string()), Some(bits));
+            assert_eq!(parse(&format!("{:e}", -x)), Some(bits | 0x8000));
+            assert_eq!(parse(&format!("{:?}", x)), Some(bits));
+
+            // The exact digits of the halfway point to the next value, and just either side of it
+            let next = if x == f16::MAX {
+                65536.0
+            } else {
+                f16::from_bits(bits + 1).to_f64()
+            };
+            let mid = (x.to_f64() + next) / 2.0;
+            let exact = format!("{:.60e}", mid);
+            let (digits, exp) = exact.split_once('e').unwrap();
+            let digits = digits.trim_end_matches('0').trim_end_matches('.');
+            let even = if bits & 1 == 0 { bits } else { bits + 1 };
+            assert_eq!(parse(&format!("{}e{}", digits, exp)), Some(even));
+            let point = if digits.contains('.') { "" } else { "." };
+            let above = format!("{}{}00000000001e{}", digits, point, exp);
+            assert_eq!(parse(&above), Some(bits + 1), "{}", above);
+            let last = digits.as_bytes()[digits.len() - 1] - 1;
+            let below = format!(
+                "{}{}{}99999999999e{}",
+                &digits[..digits.len() - 1],
+                last as char,
+                point,
+                exp
+            );
+            assert_eq!(parse(&below), Some(bits), "{}", below);
+        }
+    }
+
+    #[test]
+    fn test_parse_strict() {
+        let err = |s: &str| f16::parse_strict(s).unwrap_err();
+        assert_eq!(f16::parse_strict("65504"), Ok(f16::MAX));
+        assert_eq!(f16::parse_strict("-0.0e5"), Ok(f16::NEG_ZERO));
+        assert_eq!(f16::parse_strict("6e-8"), Ok(f16::MIN_POSITIVE_SUBNORMAL));
+        assert_eq!(f16::parse_strict("-INF"), Ok(f16::NEG_INFINITY));
+        assert!(f16::parse_strict("NaN").unwrap().is_nan());
+        assert!(err("65520").is_overflow());
+        assert!(err("-1e100").is_overflow());
+        assert!(err("0x1p16").is_overflow());
+        assert!(err("2.9e-8").is_underflow());
+        assert!(err("-0x0.0001p-20").is_underflow());
+        assert!(err("0.00000000000000000001e5").is_underflow());
+        for invalid in ["", "-", "1e", "0x", "infinit"] {
+            let err = err(invalid);
+            assert!(!err.is_overflow() && !err.is_underflow(), "{}", invalid);
+        }
+        assert_ne!(err(""), err("x"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_str_radix() {
+        let parse = |s: &str, radix| f16::from_str_radix(s, radix).map(f16::to_bits).ok();
+        assert_eq!(parse("+1.1", 2), Some(0x3E00));
+        assert_eq!(parse("-.4", 8), Some(0xB800));
+        assert_eq!(parse("1.8p-2", 16), Some(0x3600));
+        assert_eq!(parse("z.i", 36), Some(0x5070));
+        assert_eq!(parse("1e3", 10), Some(0x63D0));
+        assert_eq!(parse("0x1p1", 10), Some(0x4000));
+        assert_eq!(parse("1111111111110000", 2), Some(0x7C00));
+        assert_eq!(parse("1111111111101111.1", 2), Some(0x7BFF));
+        assert_eq!(parse("-Infinity", 5), Some(0xFC00));
+        for (invalid, radix) in [
+            ("", 2),
+            ("-", 2),
+            (".", 7),
+            ("2", 2),
+            ("1e3", 8),
+            ("1.2.3", 4),
+        ] {
+            assert_eq!(parse(invalid, radix), None, "{}", invalid);
+        }
+
+        // Every value and every halfway point between two values in binary
+        let binary = |units: u64, frac_bits: usize| {
+            let digits = format!("{:0>1$b}", units, frac_bits + 1);
+            let point = digits.len() - frac_bits;
+            format!("{}.{}", &digits[..point], &digits[point..])
+        };
+        let units = |bits: u16| (f16::from_bits(bits).to_f64() * 16777216.0) as u64;
+        for bits in 0..0x7BFFu16 {
+            assert_eq!(parse(&binary(units(bits), 24), 2), Some(bits));
+            let halfway = binary(units(bits) + units(bits + 1), 25);
+            let even = if bits & 1 == 0 { bits } else { bits + 1 };
+            assert_eq!(parse(&halfway, 2), Some(even), "{}", halfway);
+            assert_eq!(parse(&format!("{}0001", halfway), 2), Some(bits + 1));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[quickcheck]
+    fn qc_from_str_radix_integer(value: u64, radix: u8) -> bool {
+        let radix = radix as u32 % 35 + 2;
+        let mut digits = Vec::new();
+        let mut rest = value;
+        loop {
+            digits.push(core::char::from_digit((rest % radix as u64) as u32, radix).unwrap());
+            rest /= radix as u64;
+            if rest == 0 {
+                break;
+            }
         }
+        let src: String = digits.iter().rev().collect();
+        f16::from_str_radix(&src, radix).unwrap().to_bits() == f16::from_u64(value).to_bits()
+    }
+
+    #[cfg(feature = "std")]
+    fn decimal_exponent(exp: &str) -> i32 {
+        exp.split('e').nth(1).unwrap().parse().unwrap()
+    }
+
+    #[test]
+    fn test_quickcheck_shrink() {
+        use quickcheck::Arbitrary;
+
+        let x = f16::from_f32(-3.5);
+        assert!(x.shrink().all(|y| y.to_bits() < x.to_bits()));
+        assert!(x.shrink().any(|y| y == f16::ZERO));
+        assert_eq!(f16::ZERO.shrink().count(), 0);
     }
 
     #[quickcheck]
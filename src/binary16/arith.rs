@@ -0,0 +1,263 @@
+//! Backends for the arithmetic operator implementations of [`f16`].
+//!
+//! By default, operations are performed by promoting to [`f32`] and rounding the result back. On
+//! AArch64 CPUs with the half-precision arithmetic extension (`FEAT_FP16`), native instructions are
+//! used instead when the `use-intrinsics` feature is enabled, which requires Rust 1.59 or newer for
+//! the `asm!` macro. With the `nightly-f16` feature, operations go through the primitive `f16` type
+//! and are left to the compiler instead.
+
+#![allow(dead_code, unused_imports)]
+use crate::f16;
+
+macro_rules! arith_fn {
+    (fn $name:ident($($var:ident),+) {
+            if feature("fp16") { $fp16:expr }
+            else { $fallback:expr }}) => {
+        #[inline]
+        pub(crate) fn $name($($var: f16),+) -> f16 {
+            // Use CPU feature detection if using std
+            #[cfg(all(
+                feature = "use-intrinsics",
+                feature = "std",
+                not(feature = "softfloat"),
+                not(feature = "nightly-f16"),
+                target_arch = "aarch64",
+                not(target_feature = "fp16")
+            ))]
+            {
+                if std::arch::is_aarch64_feature_detected!("fp16") {
+                    $fp16
+                } else {
+                    $fallback
+                }
+            }
+            // Use intrinsics directly when a compile target or using no_std
+            #[cfg(all(
+                feature = "use-intrinsics",
+                not(feature = "softfloat"),
+                not(feature = "nightly-f16"),
+                target_arch = "aarch64",
+                target_feature = "fp16"
+            ))]
+            {
+                $fp16
+            }
+            // Fallback to software
+            #[cfg(any(
+                feature = "softfloat",
+                feature = "nightly-f16",
+                not(feature = "use-intrinsics"),
+                not(target_arch = "aarch64"),
+                all(not(feature = "std"), not(target_feature = "fp16"))
+            ))]
+            {
+                $fallback
+            }
+        }
+    };
+}
+
+arith_fn! {
+    fn add(a, b) {
+        if feature("fp16") {
+            unsafe { aarch64::add_f16_fp16(a, b) }
+        } else {
+            fallback::add(a, b)
+        }
+    }
+}
+
+arith_fn! {
+    fn sub(a, b) {
+        if feature("fp16") {
+            unsafe { aarch64::sub_f16_fp16(a, b) }
+        } else {
+            fallback::sub(a, b)
+        }
+    }
+}
+
+arith_fn! {
+    fn mul(a, b) {
+        if feature("fp16") {
+            unsafe { aarch64::mul_f16_fp16(a, b) }
+        } else {
+            fallback::mul(a, b)
+        }
+    }
+}
+
+arith_fn! {
+    fn div(a, b) {
+        if feature("fp16") {
+            unsafe { aarch64::div_f16_fp16(a, b) }
+        } else {
+            fallback::div(a, b)
+        }
+    }
+}
+
+arith_fn! {
+    fn mul_add(a, b, c) {
+        if feature("fp16") {
+            unsafe { aarch64::mul_add_f16_fp16(a, b, c) }
+        } else {
+            fallback::mul_add(a, b, c)
+        }
+    }
+}
+
+/////////////// Fallbacks ////////////////
+
+mod fallback {
+    use crate::f16;
+
+    #[inline]
+    pub(super) fn add(a: f16, b: f16) -> f16 {
+        #[cfg(feature = "softfloat")]
+        {
+            crate::softfloat::add(a, b)
+        }
+        #[cfg(all(feature = "nightly-f16", not(feature = "softfloat")))]
+        {
+            crate::binary16::nightly::add(a, b)
+        }
+        #[cfg(not(any(feature = "softfloat", feature = "nightly-f16")))]
+        {
+            f16::from_f32(a.to_f32() + b.to_f32())
+        }
+    }
+
+    #[inline]
+    pub(super) fn sub(a: f16, b: f16) -> f16 {
+        #[cfg(feature = "softfloat")]
+        {
+            crate::softfloat::sub(a, b)
+        }
+        #[cfg(all(feature = "nightly-f16", not(feature = "softfloat")))]
+        {
+            crate::binary16::nightly::sub(a, b)
+        }
+        #[cfg(not(any(feature = "softfloat", feature = "nightly-f16")))]
+        {
+            f16::from_f32(a.to_f32() - b.to_f32())
+        }
+    }
+
+    #[inline]
+    pub(super) fn mul(a: f16, b: f16) -> f16 {
+        #[cfg(feature = "softfloat")]
+        {
+            crate::softfloat::mul(a, b)
+        }
+        #[cfg(all(feature = "nightly-f16", not(feature = "softfloat")))]
+        {
+            crate::binary16::nightly::mul(a, b)
+        }
+        #[cfg(not(any(feature = "softfloat", feature = "nightly-f16")))]
+        {
+            f16::from_f32(a.to_f32() * b.to_f32())
+        }
+    }
+
+    #[inline]
+    pub(super) fn div(a: f16, b: f16) -> f16 {
+        #[cfg(feature = "softfloat")]
+        {
+            crate::softfloat::div(a, b)
+        }
+        #[cfg(all(feature = "nightly-f16", not(feature = "softfloat")))]
+        {
+            crate::binary16::nightly::div(a, b)
+        }
+        #[cfg(not(any(feature = "softfloat", feature = "nightly-f16")))]
+        {
+            f16::from_f32(a.to_f32() / b.to_f32())
+        }
+    }
+
+    #[inline]
+    pub(super) fn mul_add(a: f16, b: f16, c: f16) -> f16 {
+        #[cfg(all(feature = "nightly-f16", not(feature = "softfloat")))]
+        {
+            crate::binary16::nightly::mul_add(a, b, c)
+        }
+        // Fusing in f32 could round twice
+        #[cfg(not(all(feature = "nightly-f16", not(feature = "softfloat"))))]
+        {
+            crate::softfloat::mul_add(a, b, c)
+        }
+    }
+}
+
+/////////////// aarch64 fp16 ////////////////
+
+#[cfg(all(feature = "use-intrinsics", target_arch = "aarch64"))]
+// The `asm!` macro needs Rust 1.59, newer than the crate MSRV
+#[allow(clippy::incompatible_msrv)]
+mod aarch64 {
+    use crate::f16;
+    use core::arch::asm;
+
+    // The half-precision NEON intrinsics require the unstable `f16` primitive, so use the
+    // instructions directly on the `h` view of the vector registers.
+    macro_rules! binary_op {
+        ($name:ident, $insn:literal) => {
+            #[target_feature(enable = "fp16")]
+            #[inline]
+            pub(super) unsafe fn $name(a: f16, b: f16) -> f16 {
+                let result: u16;
+                asm!(
+                    concat!($insn, " {0:h}, {1:h}, {2:h}"),
+                    lateout(vreg) result,
+                    in(vreg) a.to_bits(),
+                    in(vreg) b.to_bits(),
+                    options(pure, nomem, nostack, preserves_flags)
+                );
+                f16::from_bits(result)
+            }
+        };
+    }
+
+    binary_op!(add_f16_fp16, "fadd");
+    binary_op!(sub_f16_fp16, "fsub");
+    binary_op!(mul_f16_fp16, "fmul");
+    binary_op!(div_f16_fp16, "fdiv");
+
+    #[target_feature(enable = "fp16")]
+    #[inline]
+    pub(super) unsafe fn mul_add_f16_fp16(a: f16, b: f16, c: f16) -> f16 {
+        let result: u16;
+        asm!(
+            "fmadd {0:h}, {1:h}, {2:h}, {3:h}",
+            lateout(vreg) result,
+            in(vreg) a.to_bits(),
+            in(vreg) b.to_bits(),
+            in(vreg) c.to_bits(),
+            options(pure, nomem, nostack, preserves_flags)
+        );
+        f16::from_bits(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use quickcheck_macros::quickcheck;
+
+    fn same(a: f16, b: f16) -> bool {
+        (a.is_nan() && b.is_nan()) || a.to_bits() == b.to_bits()
+    }
+
+    // Whichever backend is selected, results must match the correctly rounded software results.
+    #[quickcheck]
+    fn qc_backend_matches_softfloat(a: u16, b: u16, c: u16) -> bool {
+        use crate::softfloat;
+        let (a, b, c) = (f16::from_bits(a), f16::from_bits(b), f16::from_bits(c));
+        same(add(a, b), softfloat::add(a, b))
+            && same(sub(a, b), softfloat::sub(a, b))
+            && same(mul(a, b), softfloat::mul(a, b))
+            && same(div(a, b), softfloat::div(a, b))
+            && same(mul_add(a, b, c), softfloat::mul_add(a, b, c))
+    }
+}
@@ -1,4 +1,10 @@
 #![allow(dead_code, unused_imports)]
+// `f32::from_bits`/`to_bits` are not `const` on our MSRV, see the TODOs below
+#![allow(
+    unknown_lints,
+    unnecessary_transmutes,
+    clippy::missing_transmute_annotations
+)]
 use crate::leading_zeros::leading_zeros_u16;
 use core::mem;
 
@@ -13,7 +19,8 @@ macro_rules! convert_fn {
                 feature = "use-intrinsics",
                 feature = "std",
                 any(target_arch = "x86", target_arch = "x86_64"),
-                not(target_feature = "f16c")
+                not(target_feature = "f16c"),
+                not(half_force_f16c)
             ))]
             {
                 if is_x86_feature_detected!("f16c") {
@@ -22,11 +29,11 @@ macro_rules! convert_fn {
                     $fallback
                 }
             }
-            // Use intrinsics directly when a compile target or using no_std
+            // Use intrinsics directly when a compile target or forced with `--cfg half_force_f16c`
             #[cfg(all(
                 feature = "use-intrinsics",
                 any(target_arch = "x86", target_arch = "x86_64"),
-                target_feature = "f16c"
+                any(target_feature = "f16c", half_force_f16c)
             ))]
             {
                 $f16c
@@ -35,7 +42,11 @@ macro_rules! convert_fn {
             #[cfg(any(
                 not(feature = "use-intrinsics"),
                 not(any(target_arch = "x86", target_arch = "x86_64")),
-                all(not(feature = "std"), not(target_feature = "f16c"))
+                all(
+                    not(feature = "std"),
+                    not(target_feature = "f16c"),
+                    not(half_force_f16c)
+                )
             ))]
             {
                 $fallback
@@ -49,7 +60,7 @@ convert_fn! {
         if feature("f16c") {
             unsafe { x86::f32_to_f16_x86_f16c(f) }
         } else {
-            f32_to_f16_fallback(f)
+            f32_to_f16_software(f)
         }
     }
 }
@@ -57,9 +68,9 @@ convert_fn! {
 convert_fn! {
     fn f64_to_f16(f: f64) -> u16 {
         if feature("f16c") {
-            unsafe { x86::f32_to_f16_x86_f16c(f as f32) }
+            unsafe { x86::f32_to_f16_x86_f16c(f64_to_f32_round_odd(f)) }
         } else {
-            f64_to_f16_fallback(f)
+            f64_to_f16_software(f)
         }
     }
 }
@@ -69,7 +80,7 @@ convert_fn! {
         if feature("f16c") {
             unsafe { x86::f16_to_f32_x86_f16c(i) }
         } else {
-            f16_to_f32_fallback(i)
+            f16_to_f32_software(i)
         }
     }
 }
@@ -79,53 +90,56 @@ convert_fn! {
         if feature("f16c") {
             unsafe { x86::f16_to_f32_x86_f16c(i) as f64 }
         } else {
-            f16_to_f64_fallback(i)
+            f16_to_f64_software(i)
         }
     }
 }
 
-// TODO: While SIMD versions are faster, further improvements can be made by doing runtime feature
-// detection once at beginning of convert slice method, rather than per chunk
+// The slice conversions are defined by each architecture module, as versions of the kernel for
+// different CPU features that are selected once with `multiversion!`, or only by the compile
+// target features where there is no runtime detection. Each kernel runs its whole loop with the
+// target features enabled, so the SIMD conversions are inlined into it.
+#[cfg(all(
+    feature = "use-intrinsics",
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+use x86 as simd;
 
-convert_fn! {
-    fn f32x4_to_f16x4(f: &[f32]) -> [u16; 4] {
-        if feature("f16c") {
-            unsafe { x86::f32x4_to_f16x4_x86_f16c(f) }
-        } else {
-            f32x4_to_f16x4_fallback(f)
-        }
-    }
-}
+#[cfg(all(feature = "use-intrinsics", target_arch = "aarch64"))]
+use aarch64 as simd;
 
-convert_fn! {
-    fn f16x4_to_f32x4(i: &[u16]) -> [f32; 4] {
-        if feature("f16c") {
-            unsafe { x86::f16x4_to_f32x4_x86_f16c(i) }
-        } else {
-            f16x4_to_f32x4_fallback(i)
-        }
-    }
-}
+#[cfg(all(
+    feature = "use-intrinsics",
+    any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")
+))]
+pub(crate) use simd::{f16_to_f32_slice, f16_to_f64_slice, f32_to_f16_slice, f64_to_f16_slice};
 
-convert_fn! {
-    fn f64x4_to_f16x4(f: &[f64]) -> [u16; 4] {
-        if feature("f16c") {
-            unsafe { x86::f64x4_to_f16x4_x86_f16c(f) }
-        } else {
-            f64x4_to_f16x4_fallback(f)
-        }
-    }
-}
+#[cfg(not(all(
+    feature = "use-intrinsics",
+    any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")
+)))]
+pub(crate) use self::{
+    f16_to_f32_slice_fallback as f16_to_f32_slice, f16_to_f64_slice_fallback as f16_to_f64_slice,
+    f32_to_f16_slice_fallback as f32_to_f16_slice, f64_to_f16_slice_fallback as f64_to_f16_slice,
+};
 
-convert_fn! {
-    fn f16x4_to_f64x4(i: &[u16]) -> [f64; 4] {
-        if feature("f16c") {
-            unsafe { x86::f16x4_to_f64x4_x86_f16c(i) }
-        } else {
-            f16x4_to_f64x4_fallback(i)
-        }
-    }
-}
+// Arrays of four or eight values fit a single vector register, so F16C and NEON convert them in one
+// instruction. Their CPU features are checked on each call, which is cheaper than the slice
+// dispatch for such short conversions.
+#[cfg(all(
+    feature = "use-intrinsics",
+    any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")
+))]
+pub(crate) use simd::{f16x4_to_f32x4, f16x8_to_f32x8, f32x4_to_f16x4, f32x8_to_f16x8};
+
+#[cfg(not(all(
+    feature = "use-intrinsics",
+    any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")
+)))]
+pub(crate) use self::{
+    f16_to_f32_array_fallback as f16x4_to_f32x4, f16_to_f32_array_fallback as f16x8_to_f32x8,
+    f32_to_f16_array_fallback as f32x4_to_f16x4, f32_to_f16_array_fallback as f32x8_to_f16x8,
+};
 
 /////////////// Fallbacks ////////////////
 
@@ -205,10 +219,11 @@ pub(crate) const fn f32_to_f16_fallback(value: f32) -> u16 {
 
 pub(crate) const fn f64_to_f16_fallback(value: f64) -> u16 {
     // Convert to raw bytes, truncating the last 32-bits of mantissa; that precision will always
-    // be lost on half-precision.
+    // be lost on half-precision, but any set bits there are folded into the lowest remaining bit
+    // so they still count towards rounding.
     // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
     let val: u64 = unsafe { mem::transmute(value) };
-    let x = (val >> 32) as u32;
+    let x = (val >> 32) as u32 | (val as u32 != 0) as u32;
 
     // Extract IEEE754 components
     let sign = x & 0x8000_0000u32;
@@ -270,6 +285,57 @@ pub(crate) const fn f64_to_f16_fallback(value: f64) -> u16 {
     }
 }
 
+// Converts an integer magnitude to half precision with the given sign bit, rounding to nearest
+// even in the same way as the float conversions above.
+pub(crate) const fn u64_to_f16(sign: u16, value: u64) -> u16 {
+    if value == 0 {
+        return sign;
+    }
+
+    // The most significant set bit becomes the hidden mantissa bit
+    let msb = 63 - value.leading_zeros();
+    let man = if msb <= 10 {
+        value << (10 - msb)
+    } else {
+        let shift = msb - 10;
+        let man = value >> shift;
+        // Check for rounding (see comment above functions)
+        let round_bit = 1u64 << (shift - 1);
+        if (value & round_bit) != 0 && (value & (3 * round_bit - 1)) != 0 {
+            man + 1
+        } else {
+            man
+        }
+    };
+
+    // Adding the hidden bit into the exponent field also carries a rounding overflow into it
+    let bits = (((msb + 14) as u64) << 10) + man;
+    if bits >= 0x7C00 {
+        sign | 0x7C00u16
+    } else {
+        sign | bits as u16
+    }
+}
+
+// Narrows to f32 with round-to-odd, so that rounding the result again to half precision gives the
+// same result as rounding the f64 directly. A plain `as` cast could land exactly on a half
+// precision tie and round twice.
+#[inline]
+fn f64_to_f32_round_odd(value: f64) -> f32 {
+    let f = value as f32;
+    if f as f64 == value || value.is_nan() {
+        return f;
+    }
+    // Step back toward zero if the cast rounded away from zero, then mark the result inexact
+    let rounded_away = (f as f64 > value) == (value > 0.0);
+    let bits = if rounded_away {
+        f.to_bits() - 1
+    } else {
+        f.to_bits()
+    };
+    f32::from_bits(bits | 1)
+}
+
 pub(crate) const fn f16_to_f32_fallback(i: u16) -> f32 {
     // Check for signed zero
     // TODO: Replace mem::transmute with from_bits() once from_bits is const-stabilized
@@ -362,69 +428,411 @@ pub(crate) const fn f16_to_f64_fallback(i: u16) -> f64 {
     unsafe { mem::transmute(sign | exp | man) }
 }
 
+// The software conversions used when there are no intrinsics, which are table lookups with the
+// `lut` feature.
+macro_rules! software_fn {
+    ($name:ident($var:ident: $vartype:ty) -> $restype:ty = $lut:path, $fallback:path) => {
+        #[inline]
+        fn $name($var: $vartype) -> $restype {
+            #[cfg(feature = "lut")]
+            {
+                $lut($var)
+            }
+            #[cfg(not(feature = "lut"))]
+            {
+                $fallback($var)
+            }
+        }
+    };
+}
+
+software_fn!(f32_to_f16_software(f: f32) -> u16 = lut::f32_to_f16, f32_to_f16_fallback);
+software_fn!(f64_to_f16_software(f: f64) -> u16 = lut::f64_to_f16, f64_to_f16_fallback);
+software_fn!(f16_to_f32_software(i: u16) -> f32 = lut::f16_to_f32, f16_to_f32_fallback);
+software_fn!(f16_to_f64_software(i: u16) -> f64 = lut::f16_to_f64, f16_to_f64_fallback);
+
 #[inline]
-fn f16x4_to_f32x4_fallback(v: &[u16]) -> [f32; 4] {
-    debug_assert!(v.len() >= 4);
-
-    [
-        f16_to_f32_fallback(v[0]),
-        f16_to_f32_fallback(v[1]),
-        f16_to_f32_fallback(v[2]),
-        f16_to_f32_fallback(v[3]),
-    ]
+pub(crate) fn f32_to_f16_slice_fallback(src: &[f32], dst: &mut [u16]) {
+    for (dst, value) in dst.iter_mut().zip(src) {
+        *dst = f32_to_f16_software(*value);
+    }
 }
 
 #[inline]
-fn f32x4_to_f16x4_fallback(v: &[f32]) -> [u16; 4] {
-    debug_assert!(v.len() >= 4);
-
-    [
-        f32_to_f16_fallback(v[0]),
-        f32_to_f16_fallback(v[1]),
-        f32_to_f16_fallback(v[2]),
-        f32_to_f16_fallback(v[3]),
-    ]
+pub(crate) fn f16_to_f32_slice_fallback(src: &[u16], dst: &mut [f32]) {
+    for (dst, value) in dst.iter_mut().zip(src) {
+        *dst = f16_to_f32_software(*value);
+    }
 }
 
 #[inline]
-fn f16x4_to_f64x4_fallback(v: &[u16]) -> [f64; 4] {
-    debug_assert!(v.len() >= 4);
-
-    [
-        f16_to_f64_fallback(v[0]),
-        f16_to_f64_fallback(v[1]),
-        f16_to_f64_fallback(v[2]),
-        f16_to_f64_fallback(v[3]),
-    ]
+pub(crate) fn f64_to_f16_slice_fallback(src: &[f64], dst: &mut [u16]) {
+    for (dst, value) in dst.iter_mut().zip(src) {
+        *dst = f64_to_f16_software(*value);
+    }
 }
 
 #[inline]
-fn f64x4_to_f16x4_fallback(v: &[f64]) -> [u16; 4] {
-    debug_assert!(v.len() >= 4);
-
-    [
-        f64_to_f16_fallback(v[0]),
-        f64_to_f16_fallback(v[1]),
-        f64_to_f16_fallback(v[2]),
-        f64_to_f16_fallback(v[3]),
-    ]
+pub(crate) fn f16_to_f64_slice_fallback(src: &[u16], dst: &mut [f64]) {
+    for (dst, value) in dst.iter_mut().zip(src) {
+        *dst = f16_to_f64_software(*value);
+    }
+}
+
+#[inline]
+pub(crate) fn f32_to_f16_array_fallback<const N: usize>(src: [f32; N]) -> [u16; N] {
+    src.map(f32_to_f16_software)
+}
+
+#[inline]
+pub(crate) fn f16_to_f32_array_fallback<const N: usize>(src: [u16; N]) -> [f32; N] {
+    src.map(f16_to_f32_software)
 }
 
-/////////////// x86/x86_64 f16c ////////////////
+/////////////// Lookup tables ////////////////
+#[cfg(feature = "lut")]
+mod lut {
+    use super::{f32_to_f16_fallback, f64_to_f32_round_odd};
+
+    // Every half precision value widened to `f32` bits, 256 KiB in total
+    static F16_TO_F32: [u32; 0x1_0000] = f16_to_f32_table();
+
+    // The narrowing tables are indexed by the sign and exponent bits of the `f32`. The mantissa,
+    // including its hidden bit, is shifted right by `SHIFT` and added to `BASE`, which holds the
+    // half precision sign and exponent. Carries from rounding the mantissa spill over into the
+    // exponent, which also covers rounding up to the next binade or to infinity.
+    static F32_TO_F16_BASE: [u16; 0x200] = f32_to_f16_base_table();
+    static F32_TO_F16_SHIFT: [u8; 0x200] = f32_to_f16_shift_table();
+
+    // Filled one exponent at a time rather than through the fallback, since older compilers
+    // limit the number of steps in constant evaluation.
+    const fn f16_to_f32_table() -> [u32; 0x1_0000] {
+        let mut table = [0; 0x1_0000];
+        let mut block = 0u32;
+        while block < 0x40 {
+            let sign = (block & 0x20) << 26;
+            let exp = block & 0x1F;
+            let base = if exp == 0x1F {
+                sign | 0x7F80_0000
+            } else if exp == 0 {
+                sign
+            } else {
+                sign | ((exp + 112) << 23)
+            };
+            let mut man = 0;
+            while man < 0x400 {
+                table[((block << 10) | man) as usize] = base | (man << 13);
+                man += 1;
+            }
+            block += 1;
+        }
+
+        // Normalize the subnormals and set the most significant mantissa bit of NaNs
+        let mut man = 1u32;
+        while man < 0x400 {
+            let msb = 31 - man.leading_zeros();
+            let bits = ((msb + 103) << 23) | ((man << (23 - msb)) & 0x007F_FFFF);
+            table[man as usize] = bits;
+            table[(man | 0x8000) as usize] = bits | 0x8000_0000;
+            table[(man | 0x7C00) as usize] |= 0x0040_0000;
+            table[(man | 0xFC00) as usize] |= 0x0040_0000;
+            man += 1;
+        }
+        table
+    }
+
+    const fn f32_to_f16_base_table() -> [u16; 0x200] {
+        let mut table = [0; 0x200];
+        let mut i = 0;
+        while i < 0x100 {
+            let exp = i as i32 - 127;
+            let base = if exp > 15 {
+                // Overflows to infinity, the mantissa is shifted out entirely
+                0x7C00
+            } else if exp >= -14 {
+                // Normal, one less than the exponent to make room for the hidden bit
+                ((exp + 14) as u16) << 10
+            } else {
+                // Subnormal or zero, the hidden bit is part of the shifted mantissa
+                0
+            };
+            table[i] = base;
+            table[i | 0x100] = base | 0x8000;
+            i += 1;
+        }
+        table
+    }
+
+    const fn f32_to_f16_shift_table() -> [u8; 0x200] {
+        let mut table = [0; 0x200];
+        let mut i = 0;
+        while i < 0x100 {
+            let exp = i as i32 - 127;
+            let shift = if exp > 15 || exp < -25 {
+                25
+            } else if exp >= -14 {
+                13
+            } else {
+                (-exp - 1) as u8
+            };
+            table[i] = shift;
+            table[i | 0x100] = shift;
+            i += 1;
+        }
+        table
+    }
+
+    #[inline]
+    pub(super) fn f32_to_f16(value: f32) -> u16 {
+        let x = value.to_bits();
+        // Infinity and NaN are left to the fallback so NaN payloads are kept the same way
+        if x & 0x7F80_0000 == 0x7F80_0000 {
+            return f32_to_f16_fallback(value);
+        }
+
+        let index = (x >> 23) as usize;
+        let shift = u32::from(F32_TO_F16_SHIFT[index]);
+        let man = (x & 0x007F_FFFF) | 0x0080_0000;
+        let half = u32::from(F32_TO_F16_BASE[index]) + (man >> shift);
+        // Check for rounding (see comment above the fallback functions)
+        let round_bit = 1 << (shift - 1);
+        let round_up = (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0;
+        (half + u32::from(round_up)) as u16
+    }
+
+    #[inline]
+    pub(super) fn f64_to_f16(value: f64) -> u16 {
+        f32_to_f16(f64_to_f32_round_odd(value))
+    }
+
+    #[inline]
+    pub(super) fn f16_to_f32(i: u16) -> f32 {
+        f32::from_bits(F16_TO_F32[usize::from(i)])
+    }
+
+    #[inline]
+    pub(super) fn f16_to_f64(i: u16) -> f64 {
+        f64::from(f16_to_f32(i))
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::super::{
+            f16_to_f32_fallback, f16_to_f64_fallback, f32_to_f16_fallback, f64_to_f16_fallback,
+        };
+
+        #[test]
+        fn test_f16_to_float_matches_fallback() {
+            for i in 0..=u16::MAX {
+                assert_eq!(
+                    super::f16_to_f32(i).to_bits(),
+                    f16_to_f32_fallback(i).to_bits()
+                );
+                assert_eq!(
+                    super::f16_to_f64(i).to_bits(),
+                    f16_to_f64_fallback(i).to_bits()
+                );
+            }
+        }
+
+        #[test]
+        fn test_float_to_f16_matches_fallback() {
+            // Every exponent with mantissas around the rounding boundaries of each shift, plus
+            // a spread of other bit patterns
+            for exp in 0..0x200u32 {
+                for man in (0..0x80_0000u32)
+                    .step_by(0x1_0001)
+                    .chain([0x1, 0x7F_FFFF, 0x40_0000, 0x40_0001, 0x3F_FFFF])
+                {
+                    let f = f32::from_bits((exp << 23) | man);
+                    if f.is_nan() {
+                        assert!(crate::f16::from_bits(super::f32_to_f16(f)).is_nan());
+                        continue;
+                    }
+                    assert_eq!(super::f32_to_f16(f), f32_to_f16_fallback(f), "{:?}", f);
+                }
+            }
+            for shift in 13..25 {
+                for exp in 0..0x100u32 {
+                    let round_bit = 1u32 << (shift - 1);
+                    for man in [round_bit - 1, round_bit, round_bit + 1, 3 * round_bit] {
+                        let f = f32::from_bits((exp << 23) | (man & 0x7F_FFFF));
+                        if !f.is_nan() {
+                            assert_eq!(super::f32_to_f16(f), f32_to_f16_fallback(f), "{:?}", f);
+                        }
+                    }
+                }
+            }
+            for &f in &[
+                0.1f64,
+                -1e-8,
+                65520.,
+                65519.99,
+                5.960464477539063e-8,
+                1e300,
+                -0.,
+            ] {
+                assert_eq!(super::f64_to_f16(f), f64_to_f16_fallback(f), "{:?}", f);
+            }
+        }
+    }
+}
+
+/////////////// x86/x86_64 f16c and AVX-512F ////////////////
 #[cfg(all(
     feature = "use-intrinsics",
     any(target_arch = "x86", target_arch = "x86_64")
 ))]
+// The F16C intrinsics need Rust 1.68 and the AVX-512F ones Rust 1.89, newer than the crate MSRV,
+// so `use-intrinsics` is documented to require Rust 1.89 on x86
+#[allow(clippy::incompatible_msrv)]
 mod x86 {
-    use core::{mem::MaybeUninit, ptr};
+    use super::{
+        f16_to_f32_array_fallback, f16_to_f32_slice_fallback, f16_to_f64_slice_fallback,
+        f32_to_f16_array_fallback, f32_to_f16_slice_fallback, f64_to_f16_slice_fallback,
+    };
+    use crate::dispatch::{convert_chunks, multiversion};
+    use core::mem::{self, MaybeUninit};
 
     #[cfg(target_arch = "x86")]
-    use core::arch::x86::{__m128, __m128i, _mm_cvtph_ps, _mm_cvtps_ph, _MM_FROUND_TO_NEAREST_INT};
+    use core::arch::x86::{
+        __m128, __m128i, __m256, __m256d, __m512d, _mm256_andnot_pd, _mm256_castpd_ps,
+        _mm256_castps128_ps256, _mm256_castps256_ps128, _mm256_castps_pd, _mm256_castps_si256,
+        _mm256_castsi256_ps, _mm256_cmp_pd, _mm256_cvtpd_ps, _mm256_cvtph_ps, _mm256_cvtps_pd,
+        _mm256_cvtps_ph, _mm256_extractf128_ps, _mm256_insertf128_ps, _mm256_loadu_pd,
+        _mm256_loadu_ps, _mm256_loadu_si256, _mm256_set1_pd, _mm256_storeu_pd, _mm256_storeu_ps,
+        _mm256_storeu_si256, _mm512_castpd256_pd512, _mm512_castpd_ps, _mm512_castps512_ps256,
+        _mm512_castps_pd, _mm512_castsi256_si512, _mm512_castsi512_si256, _mm512_cmp_pd_mask,
+        _mm512_cvt_roundpd_ps, _mm512_cvtph_ps, _mm512_cvtps_pd, _mm512_cvtps_ph,
+        _mm512_extractf64x4_pd, _mm512_insertf64x4, _mm512_loadu_pd, _mm512_loadu_ps,
+        _mm512_mask_or_epi32, _mm512_set1_epi32, _mm512_storeu_pd, _mm512_storeu_ps, _mm_add_epi32,
+        _mm_and_si128, _mm_castps_si128, _mm_castsi128_ps, _mm_cvtph_ps, _mm_cvtps_ph,
+        _mm_loadu_si128, _mm_or_si128, _mm_set1_epi32, _mm_shuffle_ps, _mm_storeu_si128,
+        _CMP_GT_OQ, _CMP_NEQ_OQ, _MM_FROUND_NO_EXC, _MM_FROUND_TO_NEAREST_INT, _MM_FROUND_TO_ZERO,
+    };
     #[cfg(target_arch = "x86_64")]
     use core::arch::x86_64::{
-        __m128, __m128i, _mm_cvtph_ps, _mm_cvtps_ph, _MM_FROUND_TO_NEAREST_INT,
+        __m128, __m128i, __m256, __m256d, __m512d, _mm256_andnot_pd, _mm256_castpd_ps,
+        _mm256_castps128_ps256, _mm256_castps256_ps128, _mm256_castps_pd, _mm256_castps_si256,
+        _mm256_castsi256_ps, _mm256_cmp_pd, _mm256_cvtpd_ps, _mm256_cvtph_ps, _mm256_cvtps_pd,
+        _mm256_cvtps_ph, _mm256_extractf128_ps, _mm256_insertf128_ps, _mm256_loadu_pd,
+        _mm256_loadu_ps, _mm256_loadu_si256, _mm256_set1_pd, _mm256_storeu_pd, _mm256_storeu_ps,
+        _mm256_storeu_si256, _mm512_castpd256_pd512, _mm512_castpd_ps, _mm512_castps512_ps256,
+        _mm512_castps_pd, _mm512_castsi256_si512, _mm512_castsi512_si256, _mm512_cmp_pd_mask,
+        _mm512_cvt_roundpd_ps, _mm512_cvtph_ps, _mm512_cvtps_pd, _mm512_cvtps_ph,
+        _mm512_extractf64x4_pd, _mm512_insertf64x4, _mm512_loadu_pd, _mm512_loadu_ps,
+        _mm512_mask_or_epi32, _mm512_set1_epi32, _mm512_storeu_pd, _mm512_storeu_ps, _mm_add_epi32,
+        _mm_and_si128, _mm_castps_si128, _mm_castsi128_ps, _mm_cvtph_ps, _mm_cvtps_ph,
+        _mm_loadu_si128, _mm_or_si128, _mm_set1_epi32, _mm_shuffle_ps, _mm_storeu_si128,
+        _CMP_GT_OQ, _CMP_NEQ_OQ, _MM_FROUND_NO_EXC, _MM_FROUND_TO_NEAREST_INT, _MM_FROUND_TO_ZERO,
     };
 
+    // Without `std` there is no runtime detection, so only use the instructions if the compile
+    // target has them. `--cfg half_force_f16c` skips the detection and assumes F16C and AVX.
+    #[inline]
+    fn has_f16c() -> bool {
+        #[cfg(all(feature = "std", not(half_force_f16c)))]
+        {
+            std::is_x86_feature_detected!("f16c")
+        }
+        #[cfg(any(not(feature = "std"), half_force_f16c))]
+        {
+            cfg!(any(target_feature = "f16c", half_force_f16c))
+        }
+    }
+
+    #[inline]
+    fn has_avx512f() -> bool {
+        #[cfg(feature = "std")]
+        {
+            std::is_x86_feature_detected!("avx512f")
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            cfg!(target_feature = "avx512f")
+        }
+    }
+
+    // The `f32` conversions use 32-wide kernels with AVX-512F, and 16-wide kernels with F16C on
+    // AVX registers, each converting two vectors at a time.
+    multiversion! {
+        #[inline]
+        pub(crate) fn f32_to_f16_slice(src: &[f32], dst: &mut [u16]) {
+            has_avx512f() => f32_to_f16_slice_x86_avx512f,
+            has_f16c() => f32_to_f16_slice_x86_f16c,
+            _ => f32_to_f16_slice_fallback,
+        }
+    }
+
+    multiversion! {
+        #[inline]
+        pub(crate) fn f16_to_f32_slice(src: &[u16], dst: &mut [f32]) {
+            has_avx512f() => f16_to_f32_slice_x86_avx512f,
+            has_f16c() => f16_to_f32_slice_x86_f16c,
+            _ => f16_to_f32_slice_fallback,
+        }
+    }
+
+    // The `f64` conversions go through `f32` in registers, 16 values at a time with AVX-512F and
+    // 8 with F16C.
+    multiversion! {
+        #[inline]
+        pub(crate) fn f64_to_f16_slice(src: &[f64], dst: &mut [u16]) {
+            has_avx512f() => f64_to_f16_slice_x86_avx512f,
+            has_f16c() => f64_to_f16_slice_x86_f16c,
+            _ => f64_to_f16_slice_fallback,
+        }
+    }
+
+    multiversion! {
+        #[inline]
+        pub(crate) fn f16_to_f64_slice(src: &[u16], dst: &mut [f64]) {
+            has_avx512f() => f16_to_f64_slice_x86_avx512f,
+            has_f16c() => f16_to_f64_slice_x86_f16c,
+            _ => f16_to_f64_slice_fallback,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn f32x4_to_f16x4(src: [f32; 4]) -> [u16; 4] {
+        if has_f16c() {
+            // SAFETY: The CPU supports F16C
+            unsafe { f32x4_to_f16x4_x86_f16c(src) }
+        } else {
+            f32_to_f16_array_fallback(src)
+        }
+    }
+
+    #[inline]
+    pub(crate) fn f16x4_to_f32x4(src: [u16; 4]) -> [f32; 4] {
+        if has_f16c() {
+            // SAFETY: The CPU supports F16C
+            unsafe { f16x4_to_f32x4_x86_f16c(src) }
+        } else {
+            f16_to_f32_array_fallback(src)
+        }
+    }
+
+    #[inline]
+    pub(crate) fn f32x8_to_f16x8(src: [f32; 8]) -> [u16; 8] {
+        if has_f16c() {
+            // SAFETY: The CPU supports F16C and AVX
+            unsafe { f32x8_to_f16x8_x86_f16c(src) }
+        } else {
+            f32_to_f16_array_fallback(src)
+        }
+    }
+
+    #[inline]
+    pub(crate) fn f16x8_to_f32x8(src: [u16; 8]) -> [f32; 8] {
+        if has_f16c() {
+            // SAFETY: The CPU supports F16C and AVX
+            unsafe { f16x8_to_f32x8_x86_f16c(src) }
+        } else {
+            f16_to_f32_array_fallback(src)
+        }
+    }
+
     #[target_feature(enable = "f16c")]
     #[inline]
     pub(super) unsafe fn f16_to_f32_x86_f16c(i: u16) -> f32 {
@@ -445,57 +853,871 @@ mod x86 {
 
     #[target_feature(enable = "f16c")]
     #[inline]
-    pub(super) unsafe fn f16x4_to_f32x4_x86_f16c(v: &[u16]) -> [f32; 4] {
-        debug_assert!(v.len() >= 4);
+    unsafe fn f32x4_to_f16x4_x86_f16c(src: [f32; 4]) -> [u16; 4] {
+        let retval = _mm_cvtps_ph(mem::transmute(src), _MM_FROUND_TO_NEAREST_INT);
+        *(&retval as *const __m128i).cast()
+    }
 
+    #[target_feature(enable = "f16c")]
+    #[inline]
+    unsafe fn f16x4_to_f32x4_x86_f16c(src: [u16; 4]) -> [f32; 4] {
         let mut vec = MaybeUninit::<__m128i>::zeroed();
-        ptr::copy_nonoverlapping(v.as_ptr(), vec.as_mut_ptr().cast(), 4);
-        let retval = _mm_cvtph_ps(vec.assume_init());
-        *(&retval as *const __m128).cast()
+        vec.as_mut_ptr().cast::<[u16; 4]>().write(src);
+        mem::transmute(_mm_cvtph_ps(vec.assume_init()))
     }
 
-    #[target_feature(enable = "f16c")]
+    #[target_feature(enable = "avx,f16c")]
     #[inline]
-    pub(super) unsafe fn f32x4_to_f16x4_x86_f16c(v: &[f32]) -> [u16; 4] {
-        debug_assert!(v.len() >= 4);
+    unsafe fn f32x8_to_f16x8_x86_f16c(src: [f32; 8]) -> [u16; 8] {
+        mem::transmute(_mm256_cvtps_ph(
+            mem::transmute(src),
+            _MM_FROUND_TO_NEAREST_INT,
+        ))
+    }
 
-        let mut vec = MaybeUninit::<__m128>::uninit();
-        ptr::copy_nonoverlapping(v.as_ptr(), vec.as_mut_ptr().cast(), 4);
-        let retval = _mm_cvtps_ph(vec.assume_init(), _MM_FROUND_TO_NEAREST_INT);
-        *(&retval as *const __m128i).cast()
+    #[target_feature(enable = "avx,f16c")]
+    #[inline]
+    unsafe fn f16x8_to_f32x8_x86_f16c(src: [u16; 8]) -> [f32; 8] {
+        mem::transmute(_mm256_cvtph_ps(mem::transmute(src)))
     }
 
-    #[target_feature(enable = "f16c")]
+    #[target_feature(enable = "avx,f16c")]
     #[inline]
-    pub(super) unsafe fn f16x4_to_f64x4_x86_f16c(v: &[u16]) -> [f64; 4] {
-        debug_assert!(v.len() >= 4);
+    unsafe fn f16x16_to_f32x16_x86_f16c(v: &[u16], dst: &mut [f32]) {
+        debug_assert!(v.len() >= 16 && dst.len() >= 16);
 
-        let mut vec = MaybeUninit::<__m128i>::zeroed();
-        ptr::copy_nonoverlapping(v.as_ptr(), vec.as_mut_ptr().cast(), 4);
-        let retval = _mm_cvtph_ps(vec.assume_init());
-        let array = *(&retval as *const __m128).cast::<[f32; 4]>();
-        // Let compiler vectorize this regular cast for now.
-        // TODO: investigate auto-detecting sse2/avx convert features
-        [
-            array[0] as f64,
-            array[1] as f64,
-            array[2] as f64,
-            array[3] as f64,
-        ]
+        for i in [0, 8] {
+            let vec = _mm_loadu_si128(v.as_ptr().add(i).cast());
+            _mm256_storeu_ps(dst.as_mut_ptr().add(i), _mm256_cvtph_ps(vec));
+        }
     }
 
-    #[target_feature(enable = "f16c")]
+    #[target_feature(enable = "avx,f16c")]
     #[inline]
-    pub(super) unsafe fn f64x4_to_f16x4_x86_f16c(v: &[f64]) -> [u16; 4] {
-        debug_assert!(v.len() >= 4);
+    unsafe fn f32x16_to_f16x16_x86_f16c(v: &[f32], dst: &mut [u16]) {
+        debug_assert!(v.len() >= 16 && dst.len() >= 16);
 
-        // Let compiler vectorize this regular cast for now.
-        // TODO: investigate auto-detecting sse2/avx convert features
-        let v = [v[0] as f32, v[1] as f32, v[2] as f32, v[3] as f32];
+        for i in [0, 8] {
+            let vec = _mm256_loadu_ps(v.as_ptr().add(i));
+            let retval = _mm256_cvtps_ph(vec, _MM_FROUND_TO_NEAREST_INT);
+            _mm_storeu_si128(dst.as_mut_ptr().add(i).cast(), retval);
+        }
+    }
 
-        let mut vec = MaybeUninit::<__m128>::uninit();
-        ptr::copy_nonoverlapping(v.as_ptr(), vec.as_mut_ptr().cast(), 4);
-        let retval = _mm_cvtps_ph(vec.assume_init(), _MM_FROUND_TO_NEAREST_INT);
-        *(&retval as *const __m128i).cast()
+    #[target_feature(enable = "avx512f")]
+    #[inline]
+    unsafe fn f16x32_to_f32x32_x86_avx512f(v: &[u16], dst: &mut [f32]) {
+        debug_assert!(v.len() >= 32 && dst.len() >= 32);
+
+        for i in [0, 16] {
+            let vec = _mm256_loadu_si256(v.as_ptr().add(i).cast());
+            _mm512_storeu_ps(dst.as_mut_ptr().add(i), _mm512_cvtph_ps(vec));
+        }
+    }
+
+    #[target_feature(enable = "avx512f")]
+    #[inline]
+    unsafe fn f32x32_to_f16x32_x86_avx512f(v: &[f32], dst: &mut [u16]) {
+        debug_assert!(v.len() >= 32 && dst.len() >= 32);
+
+        for i in [0, 16] {
+            let vec = _mm512_loadu_ps(v.as_ptr().add(i));
+            let retval = _mm512_cvtps_ph(vec, _MM_FROUND_TO_NEAREST_INT);
+            _mm256_storeu_si256(dst.as_mut_ptr().add(i).cast(), retval);
+        }
+    }
+
+    #[target_feature(enable = "avx,f16c")]
+    #[inline]
+    unsafe fn f16x8_to_f64x8_x86_f16c(v: &[u16], dst: &mut [f64]) {
+        debug_assert!(v.len() >= 8 && dst.len() >= 8);
+
+        let floats = _mm256_cvtph_ps(_mm_loadu_si128(v.as_ptr().cast()));
+        let low = _mm256_castps256_ps128(floats);
+        let high = _mm256_extractf128_ps(floats, 1);
+        _mm256_storeu_pd(dst.as_mut_ptr(), _mm256_cvtps_pd(low));
+        _mm256_storeu_pd(dst.as_mut_ptr().add(4), _mm256_cvtps_pd(high));
+    }
+
+    #[target_feature(enable = "avx,f16c")]
+    #[inline]
+    unsafe fn f64x8_to_f16x8_x86_f16c(v: &[f64], dst: &mut [u16]) {
+        debug_assert!(v.len() >= 8 && dst.len() >= 8);
+
+        let low = f64x4_to_f32x4_round_odd_x86_avx(_mm256_loadu_pd(v.as_ptr()));
+        let high = f64x4_to_f32x4_round_odd_x86_avx(_mm256_loadu_pd(v.as_ptr().add(4)));
+        let floats = _mm256_insertf128_ps(_mm256_castps128_ps256(low), high, 1);
+        let retval = _mm256_cvtps_ph(floats, _MM_FROUND_TO_NEAREST_INT);
+        _mm_storeu_si128(dst.as_mut_ptr().cast(), retval);
+    }
+
+    // Vectorized `f64_to_f32_round_odd`: AVX can only narrow with the current rounding mode, so
+    // compare the widened result with the input to step back toward zero and set the inexact bit.
+    #[target_feature(enable = "avx")]
+    #[inline]
+    unsafe fn f64x4_to_f32x4_round_odd_x86_avx(v: __m256d) -> __m128 {
+        let rounded = _mm256_cvtpd_ps(v);
+        let widened = _mm256_cvtps_pd(rounded);
+
+        // Both comparisons are false for NaNs, which are kept as rounded
+        let sign = _mm256_set1_pd(-0.0);
+        let abs_widened = _mm256_andnot_pd(sign, widened);
+        let abs_v = _mm256_andnot_pd(sign, v);
+        let rounded_away = narrow_mask_x86_avx(_mm256_cmp_pd(abs_widened, abs_v, _CMP_GT_OQ));
+        let inexact = narrow_mask_x86_avx(_mm256_cmp_pd(widened, v, _CMP_NEQ_OQ));
+
+        // Subtracting one from the bits steps back toward zero for either sign
+        let bits = _mm_add_epi32(_mm_castps_si128(rounded), rounded_away);
+        let bits = _mm_or_si128(bits, _mm_and_si128(inexact, _mm_set1_epi32(1)));
+        _mm_castsi128_ps(bits)
+    }
+
+    // Narrows four 64-bit comparison masks to 32-bit masks of -1 or 0
+    #[target_feature(enable = "avx")]
+    #[inline]
+    unsafe fn narrow_mask_x86_avx(mask: __m256d) -> __m128i {
+        let mask = _mm256_castpd_ps(mask);
+        let low = _mm256_castps256_ps128(mask);
+        let high = _mm256_extractf128_ps(mask, 1);
+        _mm_castps_si128(_mm_shuffle_ps(low, high, 0b10_00_10_00))
+    }
+
+    #[target_feature(enable = "avx512f")]
+    #[inline]
+    unsafe fn f16x16_to_f64x16_x86_avx512f(v: &[u16], dst: &mut [f64]) {
+        debug_assert!(v.len() >= 16 && dst.len() >= 16);
+
+        let floats = _mm512_cvtph_ps(_mm256_loadu_si256(v.as_ptr().cast()));
+        let low = _mm512_castps512_ps256(floats);
+        let high = _mm256_castpd_ps(_mm512_extractf64x4_pd(_mm512_castps_pd(floats), 1));
+        _mm512_storeu_pd(dst.as_mut_ptr(), _mm512_cvtps_pd(low));
+        _mm512_storeu_pd(dst.as_mut_ptr().add(8), _mm512_cvtps_pd(high));
+    }
+
+    #[target_feature(enable = "avx512f")]
+    #[inline]
+    unsafe fn f64x16_to_f16x16_x86_avx512f(v: &[f64], dst: &mut [u16]) {
+        debug_assert!(v.len() >= 16 && dst.len() >= 16);
+
+        let low = f64x8_to_f32x8_round_odd_x86_avx512f(_mm512_loadu_pd(v.as_ptr()));
+        let high = f64x8_to_f32x8_round_odd_x86_avx512f(_mm512_loadu_pd(v.as_ptr().add(8)));
+        let floats = _mm512_insertf64x4(
+            _mm512_castpd256_pd512(_mm256_castps_pd(low)),
+            _mm256_castps_pd(high),
+            1,
+        );
+        let retval = _mm512_cvtps_ph(_mm512_castpd_ps(floats), _MM_FROUND_TO_NEAREST_INT);
+        _mm256_storeu_si256(dst.as_mut_ptr().cast(), retval);
+    }
+
+    // Vectorized `f64_to_f32_round_odd`: AVX-512F can narrow toward zero directly, so only the
+    // inexact bit needs to be set.
+    #[target_feature(enable = "avx512f")]
+    #[inline]
+    unsafe fn f64x8_to_f32x8_round_odd_x86_avx512f(v: __m512d) -> __m256 {
+        let truncated = _mm512_cvt_roundpd_ps(v, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC);
+        // False for NaNs, which are kept as truncated
+        let inexact = _mm512_cmp_pd_mask(_mm512_cvtps_pd(truncated), v, _CMP_NEQ_OQ);
+
+        // Only the low eight lanes of the 512-bit vector hold results
+        let bits = _mm512_castsi256_si512(_mm256_castps_si256(truncated));
+        let bits = _mm512_mask_or_epi32(bits, inexact.into(), bits, _mm512_set1_epi32(1));
+        _mm256_castsi256_ps(_mm512_castsi512_si256(bits))
+    }
+
+    #[target_feature(enable = "avx512f")]
+    unsafe fn f32_to_f16_slice_x86_avx512f(src: &[f32], dst: &mut [u16]) {
+        convert_chunks!(src, dst, 32, f32x32_to_f16x32_x86_avx512f)
+    }
+
+    #[target_feature(enable = "avx512f")]
+    unsafe fn f16_to_f32_slice_x86_avx512f(src: &[u16], dst: &mut [f32]) {
+        convert_chunks!(src, dst, 32, f16x32_to_f32x32_x86_avx512f)
+    }
+
+    #[target_feature(enable = "avx,f16c")]
+    unsafe fn f32_to_f16_slice_x86_f16c(src: &[f32], dst: &mut [u16]) {
+        convert_chunks!(src, dst, 16, f32x16_to_f16x16_x86_f16c)
+    }
+
+    #[target_feature(enable = "avx,f16c")]
+    unsafe fn f16_to_f32_slice_x86_f16c(src: &[u16], dst: &mut [f32]) {
+        convert_chunks!(src, dst, 16, f16x16_to_f32x16_x86_f16c)
+    }
+
+    #[target_feature(enable = "avx512f")]
+    unsafe fn f64_to_f16_slice_x86_avx512f(src: &[f64], dst: &mut [u16]) {
+        convert_chunks!(src, dst, 16, f64x16_to_f16x16_x86_avx512f)
+    }
+
+    #[target_feature(enable = "avx512f")]
+    unsafe fn f16_to_f64_slice_x86_avx512f(src: &[u16], dst: &mut [f64]) {
+        convert_chunks!(src, dst, 16, f16x16_to_f64x16_x86_avx512f)
+    }
+
+    #[target_feature(enable = "avx,f16c")]
+    unsafe fn f64_to_f16_slice_x86_f16c(src: &[f64], dst: &mut [u16]) {
+        convert_chunks!(src, dst, 8, f64x8_to_f16x8_x86_f16c)
+    }
+
+    #[target_feature(enable = "avx,f16c")]
+    unsafe fn f16_to_f64_slice_x86_f16c(src: &[u16], dst: &mut [f64]) {
+        convert_chunks!(src, dst, 8, f16x8_to_f64x8_x86_f16c)
+    }
+
+    #[cfg(all(test, feature = "std"))]
+    mod test {
+        use super::*;
+        use std::vec::Vec;
+
+        type ToF32 = fn(&[u16], &mut [f32]);
+        type ToF16 = fn(&[f32], &mut [u16]);
+
+        // Checks every kernel the CPU supports against the software conversion, including the
+        // padded remainder
+        #[test]
+        fn test_kernels_match_fallback() {
+            let halves: Vec<u16> = (0..=u16::MAX).chain(0..37).collect();
+            let floats: Vec<f32> = (0..halves.len() as u32)
+                .map(|i| f32::from_bits(i.wrapping_mul(0x9E37_79B9)))
+                .chain([0.5, 65520., 65519.99, f32::from_bits(0x3300_0001), f32::NAN])
+                .collect();
+
+            let mut expected_f32 = std::vec![0f32; halves.len()];
+            let mut expected_f16 = std::vec![0u16; floats.len()];
+            f16_to_f32_slice_fallback(&halves, &mut expected_f32);
+            f32_to_f16_slice_fallback(&floats, &mut expected_f16);
+
+            let mut kernels: Vec<(ToF32, ToF16)> = Vec::new();
+            if has_f16c() {
+                kernels.push((
+                    |src, dst| unsafe { f16_to_f32_slice_x86_f16c(src, dst) },
+                    |src, dst| unsafe { f32_to_f16_slice_x86_f16c(src, dst) },
+                ));
+            }
+            if has_avx512f() {
+                kernels.push((
+                    |src, dst| unsafe { f16_to_f32_slice_x86_avx512f(src, dst) },
+                    |src, dst| unsafe { f32_to_f16_slice_x86_avx512f(src, dst) },
+                ));
+            }
+
+            for (to_f32, to_f16) in kernels {
+                let mut actual = std::vec![0f32; halves.len()];
+                to_f32(&halves, &mut actual);
+                for (a, e) in actual.iter().zip(&expected_f32) {
+                    assert_eq!(a.to_bits(), e.to_bits());
+                }
+
+                let mut actual = std::vec![0u16; floats.len()];
+                to_f16(&floats, &mut actual);
+                assert_eq!(actual, expected_f16);
+            }
+        }
+
+        #[test]
+        fn test_array_kernels_match_fallback() {
+            if !has_f16c() {
+                return;
+            }
+
+            for i in (0..=u16::MAX).step_by(8) {
+                let mut halves = [0u16; 8];
+                for (j, half) in halves.iter_mut().enumerate() {
+                    *half = i + j as u16;
+                }
+                let low = [halves[0], halves[1], halves[2], halves[3]];
+                let floats = unsafe { f16x8_to_f32x8_x86_f16c(halves) };
+                let expected = f16_to_f32_array_fallback(halves);
+                assert_eq!(floats.map(f32::to_bits), expected.map(f32::to_bits));
+                let floats4 = unsafe { f16x4_to_f32x4_x86_f16c(low) };
+                assert_eq!(
+                    floats4.map(f32::to_bits)[..],
+                    expected.map(f32::to_bits)[..4]
+                );
+
+                let floats = floats.map(|f| f * 1.0009765);
+                let low = [floats[0], floats[1], floats[2], floats[3]];
+                let expected = f32_to_f16_array_fallback(floats);
+                assert_eq!(unsafe { f32x8_to_f16x8_x86_f16c(floats) }, expected);
+                assert_eq!(unsafe { f32x4_to_f16x4_x86_f16c(low) }[..], expected[..4]);
+            }
+        }
+
+        type ToF64 = fn(&[u16], &mut [f64]);
+        type FromF64 = fn(&[f64], &mut [u16]);
+
+        #[test]
+        fn test_f64_kernels_match_fallback() {
+            let halves: Vec<u16> = (0..=u16::MAX).chain(0..37).collect();
+            // Random bit patterns, plus values just off half precision ties and past the `f32`
+            // range, where narrowing to `f32` first must not round twice
+            let doubles: Vec<f64> = (0..halves.len() as u64)
+                .map(|i| f64::from_bits(i.wrapping_mul(0x9E37_79B9_7F4A_7C15)))
+                .chain((0..2048u64).map(|i| {
+                    let tie = f64::from_bits(0x3FF0_0200_0000_0000 | (i << 42));
+                    f64::from_bits(tie.to_bits() + (i % 3) - 1)
+                }))
+                .chain([1e300, -1e300, 1e-300, -1e-300, 65520., f64::NAN, -f64::NAN])
+                .chain([f64::from_bits(0x7FF0_0000_0000_0001)])
+                .collect();
+
+            let mut expected_f64 = std::vec![0f64; halves.len()];
+            let mut expected_f16 = std::vec![0u16; doubles.len()];
+            f16_to_f64_slice_fallback(&halves, &mut expected_f64);
+            f64_to_f16_slice_fallback(&doubles, &mut expected_f16);
+
+            let mut kernels: Vec<(ToF64, FromF64)> = Vec::new();
+            if has_f16c() {
+                kernels.push((
+                    |src, dst| unsafe { f16_to_f64_slice_x86_f16c(src, dst) },
+                    |src, dst| unsafe { f64_to_f16_slice_x86_f16c(src, dst) },
+                ));
+            }
+            if has_avx512f() {
+                kernels.push((
+                    |src, dst| unsafe { f16_to_f64_slice_x86_avx512f(src, dst) },
+                    |src, dst| unsafe { f64_to_f16_slice_x86_avx512f(src, dst) },
+                ));
+            }
+
+            for (to_f64, from_f64) in kernels {
+                let mut actual = std::vec![0f64; halves.len()];
+                to_f64(&halves, &mut actual);
+                for (a, e) in actual.iter().zip(&expected_f64) {
+                    assert_eq!(a.to_bits(), e.to_bits());
+                }
+
+                let mut actual = std::vec![0u16; doubles.len()];
+                from_f64(&doubles, &mut actual);
+                for ((a, e), d) in actual.iter().zip(&expected_f16).zip(&doubles) {
+                    assert_eq!(a, e, "{:?}", d);
+                }
+            }
+        }
+    }
+}
+
+/////////////// aarch64 NEON ////////////////
+#[cfg(all(feature = "use-intrinsics", target_arch = "aarch64"))]
+// The NEON load and store intrinsics need Rust 1.59, newer than the crate MSRV
+#[allow(clippy::incompatible_msrv)]
+mod aarch64 {
+    use super::{
+        f16_to_f32_array_fallback, f16_to_f32_slice_fallback, f16_to_f64_slice_fallback,
+        f32_to_f16_array_fallback, f32_to_f16_slice_fallback, f64_to_f16_slice_fallback,
+    };
+    use crate::dispatch::{convert_chunks, multiversion};
+    use core::arch::{
+        aarch64::{
+            float32x4_t, uint16x4_t, uint16x8_t, vcvt_f64_f32, vcvt_high_f64_f32, vcvtx_f32_f64,
+            vcvtx_high_f32_f64, vget_low_f32, vld1q_f32, vld1q_f64, vld1q_u16, vst1q_f32,
+            vst1q_f64, vst1q_u16,
+        },
+        asm,
+    };
+    use core::mem;
+
+    // NEON is part of the baseline of most AArch64 targets, so it is rarely detected at runtime.
+    // `--cfg half_force_neon` assumes it even when the compile target does not enable it.
+    #[inline]
+    fn has_neon() -> bool {
+        #[cfg(all(feature = "std", not(target_feature = "neon"), not(half_force_neon)))]
+        {
+            std::arch::is_aarch64_feature_detected!("neon")
+        }
+        #[cfg(any(not(feature = "std"), target_feature = "neon", half_force_neon))]
+        {
+            cfg!(any(target_feature = "neon", half_force_neon))
+        }
+    }
+
+    multiversion! {
+        #[inline]
+        pub(crate) fn f32_to_f16_slice(src: &[f32], dst: &mut [u16]) {
+            has_neon() => f32_to_f16_slice_neon,
+            _ => f32_to_f16_slice_fallback,
+        }
+    }
+
+    multiversion! {
+        #[inline]
+        pub(crate) fn f16_to_f32_slice(src: &[u16], dst: &mut [f32]) {
+            has_neon() => f16_to_f32_slice_neon,
+            _ => f16_to_f32_slice_fallback,
+        }
+    }
+
+    multiversion! {
+        #[inline]
+        pub(crate) fn f64_to_f16_slice(src: &[f64], dst: &mut [u16]) {
+            has_neon() => f64_to_f16_slice_neon,
+            _ => f64_to_f16_slice_fallback,
+        }
+    }
+
+    multiversion! {
+        #[inline]
+        pub(crate) fn f16_to_f64_slice(src: &[u16], dst: &mut [f64]) {
+            has_neon() => f16_to_f64_slice_neon,
+            _ => f16_to_f64_slice_fallback,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn f32x4_to_f16x4(src: [f32; 4]) -> [u16; 4] {
+        if has_neon() {
+            // SAFETY: The CPU supports NEON
+            unsafe { f32x4_to_f16x4_neon(src) }
+        } else {
+            f32_to_f16_array_fallback(src)
+        }
+    }
+
+    #[inline]
+    pub(crate) fn f16x4_to_f32x4(src: [u16; 4]) -> [f32; 4] {
+        if has_neon() {
+            // SAFETY: The CPU supports NEON
+            unsafe { f16x4_to_f32x4_neon(src) }
+        } else {
+            f16_to_f32_array_fallback(src)
+        }
+    }
+
+    #[inline]
+    pub(crate) fn f32x8_to_f16x8(src: [f32; 8]) -> [u16; 8] {
+        if has_neon() {
+            let mut dst = [0u16; 8];
+            // SAFETY: The CPU supports NEON
+            unsafe { f32x8_to_f16x8_neon(&src, &mut dst) };
+            dst
+        } else {
+            f32_to_f16_array_fallback(src)
+        }
+    }
+
+    #[inline]
+    pub(crate) fn f16x8_to_f32x8(src: [u16; 8]) -> [f32; 8] {
+        if has_neon() {
+            let mut dst = [0f32; 8];
+            // SAFETY: The CPU supports NEON
+            unsafe { f16x8_to_f32x8_neon(&src, &mut dst) };
+            dst
+        } else {
+            f16_to_f32_array_fallback(src)
+        }
+    }
+
+    #[target_feature(enable = "neon")]
+    #[inline]
+    unsafe fn f32x4_to_f16x4_neon(src: [f32; 4]) -> [u16; 4] {
+        let vec: uint16x4_t;
+        asm!(
+            "fcvtn {0:v}.4h, {1:v}.4s",
+            out(vreg) vec,
+            in(vreg) mem::transmute::<_, float32x4_t>(src),
+            options(pure, nomem, nostack, preserves_flags)
+        );
+        mem::transmute(vec)
+    }
+
+    #[target_feature(enable = "neon")]
+    #[inline]
+    unsafe fn f16x4_to_f32x4_neon(src: [u16; 4]) -> [f32; 4] {
+        let vec: float32x4_t;
+        asm!(
+            "fcvtl {0:v}.4s, {1:v}.4h",
+            out(vreg) vec,
+            in(vreg) mem::transmute::<_, uint16x4_t>(src),
+            options(pure, nomem, nostack, preserves_flags)
+        );
+        mem::transmute(vec)
+    }
+
+    #[target_feature(enable = "neon")]
+    #[inline]
+    unsafe fn widen_f16x8_neon(vec: uint16x8_t) -> (float32x4_t, float32x4_t) {
+        let low: float32x4_t;
+        let high: float32x4_t;
+        asm!(
+            "fcvtl {0:v}.4s, {2:v}.4h",
+            "fcvtl2 {1:v}.4s, {2:v}.8h",
+            out(vreg) low,
+            lateout(vreg) high,
+            in(vreg) vec,
+            options(pure, nomem, nostack, preserves_flags)
+        );
+        (low, high)
+    }
+
+    #[target_feature(enable = "neon")]
+    #[inline]
+    unsafe fn narrow_f32x4x2_neon(low: float32x4_t, high: float32x4_t) -> uint16x8_t {
+        let vec: uint16x8_t;
+        asm!(
+            "fcvtn {0:v}.4h, {1:v}.4s",
+            "fcvtn2 {0:v}.8h, {2:v}.4s",
+            out(vreg) vec,
+            in(vreg) low,
+            in(vreg) high,
+            options(pure, nomem, nostack, preserves_flags)
+        );
+        vec
+    }
+
+    #[target_feature(enable = "neon")]
+    #[inline]
+    unsafe fn f16x8_to_f32x8_neon(v: &[u16], dst: &mut [f32]) {
+        debug_assert!(v.len() >= 8 && dst.len() >= 8);
+
+        let (low, high) = widen_f16x8_neon(vld1q_u16(v.as_ptr()));
+        vst1q_f32(dst.as_mut_ptr(), low);
+        vst1q_f32(dst.as_mut_ptr().add(4), high);
+    }
+
+    #[target_feature(enable = "neon")]
+    #[inline]
+    unsafe fn f32x8_to_f16x8_neon(v: &[f32], dst: &mut [u16]) {
+        debug_assert!(v.len() >= 8 && dst.len() >= 8);
+
+        let low = vld1q_f32(v.as_ptr());
+        let high = vld1q_f32(v.as_ptr().add(4));
+        vst1q_u16(dst.as_mut_ptr(), narrow_f32x4x2_neon(low, high));
+    }
+
+    #[target_feature(enable = "neon")]
+    #[inline]
+    unsafe fn f16x8_to_f64x8_neon(v: &[u16], dst: &mut [f64]) {
+        debug_assert!(v.len() >= 8 && dst.len() >= 8);
+
+        let (low, high) = widen_f16x8_neon(vld1q_u16(v.as_ptr()));
+        let dst = dst.as_mut_ptr();
+        vst1q_f64(dst, vcvt_f64_f32(vget_low_f32(low)));
+        vst1q_f64(dst.add(2), vcvt_high_f64_f32(low));
+        vst1q_f64(dst.add(4), vcvt_f64_f32(vget_low_f32(high)));
+        vst1q_f64(dst.add(6), vcvt_high_f64_f32(high));
+    }
+
+    // `fcvtxn` narrows to `f32` with round-to-odd, so the second rounding to half precision is
+    // the same as rounding the `f64` directly.
+    #[target_feature(enable = "neon")]
+    #[inline]
+    unsafe fn f64x8_to_f16x8_neon(v: &[f64], dst: &mut [u16]) {
+        debug_assert!(v.len() >= 8 && dst.len() >= 8);
+
+        let v = v.as_ptr();
+        let low = vcvtx_high_f32_f64(vcvtx_f32_f64(vld1q_f64(v)), vld1q_f64(v.add(2)));
+        let high = vcvtx_high_f32_f64(vcvtx_f32_f64(vld1q_f64(v.add(4))), vld1q_f64(v.add(6)));
+        vst1q_u16(dst.as_mut_ptr(), narrow_f32x4x2_neon(low, high));
+    }
+
+    #[target_feature(enable = "neon")]
+    unsafe fn f32_to_f16_slice_neon(src: &[f32], dst: &mut [u16]) {
+        convert_chunks!(src, dst, 8, f32x8_to_f16x8_neon)
+    }
+
+    #[target_feature(enable = "neon")]
+    unsafe fn f16_to_f32_slice_neon(src: &[u16], dst: &mut [f32]) {
+        convert_chunks!(src, dst, 8, f16x8_to_f32x8_neon)
+    }
+
+    #[target_feature(enable = "neon")]
+    unsafe fn f64_to_f16_slice_neon(src: &[f64], dst: &mut [u16]) {
+        convert_chunks!(src, dst, 8, f64x8_to_f16x8_neon)
+    }
+
+    #[target_feature(enable = "neon")]
+    unsafe fn f16_to_f64_slice_neon(src: &[u16], dst: &mut [f64]) {
+        convert_chunks!(src, dst, 8, f16x8_to_f64x8_neon)
+    }
+
+    #[cfg(all(test, feature = "std"))]
+    mod test {
+        use super::*;
+        use std::vec::Vec;
+
+        #[test]
+        fn test_kernels_match_fallback() {
+            if !has_neon() {
+                return;
+            }
+
+            let halves: Vec<u16> = (0..=u16::MAX).chain(0..5).collect();
+            let floats: Vec<f32> = (0..halves.len() as u32)
+                .map(|i| f32::from_bits(i.wrapping_mul(0x9E37_79B9)))
+                .chain([0.5, 65520., f32::from_bits(0x3300_0001), f32::NAN])
+                .collect();
+
+            let mut expected = std::vec![0f32; halves.len()];
+            let mut actual = std::vec![0f32; halves.len()];
+            f16_to_f32_slice_fallback(&halves, &mut expected);
+            unsafe { f16_to_f32_slice_neon(&halves, &mut actual) };
+            for (a, e) in actual.iter().zip(&expected) {
+                assert_eq!(a.to_bits(), e.to_bits());
+            }
+
+            let mut expected = std::vec![0u16; floats.len()];
+            let mut actual = std::vec![0u16; floats.len()];
+            f32_to_f16_slice_fallback(&floats, &mut expected);
+            unsafe { f32_to_f16_slice_neon(&floats, &mut actual) };
+            assert_eq!(actual, expected);
+
+            let mut expected = std::vec![0f64; halves.len()];
+            let mut actual = std::vec![0f64; halves.len()];
+            f16_to_f64_slice_fallback(&halves, &mut expected);
+            unsafe { f16_to_f64_slice_neon(&halves, &mut actual) };
+            for (a, e) in actual.iter().zip(&expected) {
+                assert_eq!(a.to_bits(), e.to_bits());
+            }
+
+            let doubles: Vec<f64> = (0..halves.len() as u64)
+                .map(|i| f64::from_bits(i.wrapping_mul(0x9E37_79B9_7F4A_7C15)))
+                .chain([1e300, f64::from_bits(0x3FF0_0200_0000_0001), f64::NAN])
+                .collect();
+            let mut expected = std::vec![0u16; doubles.len()];
+            let mut actual = std::vec![0u16; doubles.len()];
+            f64_to_f16_slice_fallback(&doubles, &mut expected);
+            unsafe { f64_to_f16_slice_neon(&doubles, &mut actual) };
+            assert_eq!(actual, expected);
+        }
+    }
+}
+
+/////////////// riscv64 RVV and Zfhmin ////////////////
+
+// These kernels have not been run on RISC-V hardware or an emulator yet, so the slice conversions
+// do not dispatch to them until their test has passed with the `v,zvfhmin` and `zfhmin` features.
+#[cfg(all(feature = "use-intrinsics", target_arch = "riscv64"))]
+#[allow(dead_code)]
+mod riscv64 {
+    use super::{
+        f16_to_f32_fallback, f16_to_f32_slice_fallback, f32_to_f16_fallback,
+        f32_to_f16_slice_fallback,
+    };
+    use core::arch::asm;
+
+    // There is no stable runtime detection of RISC-V extensions yet, so the kernels are selected
+    // only by the compile target features. Note that the `v`, `zvfhmin` and `zfhmin` features are
+    // not yet visible to `cfg` on stable toolchains.
+
+    /// Number of elements converted at a time before fixing up NaNs, small enough to stay in cache.
+    const BLOCK_LEN: usize = 1024;
+
+    #[inline]
+    pub(crate) fn f32_to_f16_slice(src: &[f32], dst: &mut [u16]) {
+        #[cfg(target_feature = "zvfhmin")]
+        {
+            f32_to_f16_slice_rvv(src, dst)
+        }
+        #[cfg(all(not(target_feature = "zvfhmin"), target_feature = "zfhmin"))]
+        {
+            f32_to_f16_slice_zfhmin(src, dst)
+        }
+        #[cfg(not(any(target_feature = "zvfhmin", target_feature = "zfhmin")))]
+        {
+            f32_to_f16_slice_fallback(src, dst)
+        }
+    }
+
+    #[inline]
+    pub(crate) fn f16_to_f32_slice(src: &[u16], dst: &mut [f32]) {
+        #[cfg(target_feature = "zvfhmin")]
+        {
+            f16_to_f32_slice_rvv(src, dst)
+        }
+        #[cfg(all(not(target_feature = "zvfhmin"), target_feature = "zfhmin"))]
+        {
+            f16_to_f32_slice_zfhmin(src, dst)
+        }
+        #[cfg(not(any(target_feature = "zvfhmin", target_feature = "zfhmin")))]
+        {
+            f16_to_f32_slice_fallback(src, dst)
+        }
+    }
+
+    // Unlike the software conversion, the RISC-V conversion instructions return the canonical NaN
+    // for any NaN input, dropping its sign and payload. NaNs are rare, so the kernels convert
+    // everything in hardware and then redo the NaNs in software.
+
+    #[inline]
+    fn fix_f32_to_f16_nans(src: &[f32], dst: &mut [u16]) {
+        for (dst, value) in dst.iter_mut().zip(src) {
+            if value.is_nan() {
+                *dst = f32_to_f16_fallback(*value);
+            }
+        }
+    }
+
+    #[inline]
+    fn fix_f16_to_f32_nans(src: &[u16], dst: &mut [f32]) {
+        for (dst, &value) in dst.iter_mut().zip(src) {
+            if value & 0x7FFF > 0x7C00 {
+                *dst = f16_to_f32_fallback(value);
+            }
+        }
+    }
+
+    // The vector kernels are strip-mined loops over the whole block: each iteration lets
+    // `vsetvli` pick how many elements fit in the vector registers, using register groups of
+    // four 16-bit and eight 32-bit registers. The narrowing conversion rounds with the dynamic
+    // rounding mode, which is round to nearest even unless changed.
+
+    #[cfg(target_feature = "zvfhmin")]
+    fn f32_to_f16_slice_rvv(src: &[f32], dst: &mut [u16]) {
+        debug_assert_eq!(src.len(), dst.len());
+
+        for (src, dst) in src.chunks(BLOCK_LEN).zip(dst.chunks_mut(BLOCK_LEN)) {
+            // SAFETY: The target supports Zvfhmin, and the loop reads and writes exactly `len`
+            // elements of the equally long `src` and `dst`
+            unsafe {
+                asm!(
+                    "2:",
+                    "vsetvli {vl}, {len}, e16, m4, ta, ma",
+                    "vle32.v v8, ({src})",
+                    "vfncvt.f.f.w v16, v8",
+                    "vse16.v v16, ({dst})",
+                    "sub {len}, {len}, {vl}",
+                    "slli {tmp}, {vl}, 2",
+                    "add {src}, {src}, {tmp}",
+                    "slli {tmp}, {vl}, 1",
+                    "add {dst}, {dst}, {tmp}",
+                    "bnez {len}, 2b",
+                    len = inout(reg) src.len() => _,
+                    src = inout(reg) src.as_ptr() => _,
+                    dst = inout(reg) dst.as_mut_ptr() => _,
+                    vl = out(reg) _,
+                    tmp = out(reg) _,
+                    out("v8") _, out("v9") _, out("v10") _, out("v11") _,
+                    out("v12") _, out("v13") _, out("v14") _, out("v15") _,
+                    out("v16") _, out("v17") _, out("v18") _, out("v19") _,
+                    options(nostack)
+                );
+            }
+            fix_f32_to_f16_nans(src, dst);
+        }
+    }
+
+    #[cfg(target_feature = "zvfhmin")]
+    fn f16_to_f32_slice_rvv(src: &[u16], dst: &mut [f32]) {
+        debug_assert_eq!(src.len(), dst.len());
+
+        for (src, dst) in src.chunks(BLOCK_LEN).zip(dst.chunks_mut(BLOCK_LEN)) {
+            // SAFETY: The target supports Zvfhmin, and the loop reads and writes exactly `len`
+            // elements of the equally long `src` and `dst`
+            unsafe {
+                asm!(
+                    "2:",
+                    "vsetvli {vl}, {len}, e16, m4, ta, ma",
+                    "vle16.v v8, ({src})",
+                    "vfwcvt.f.f.v v16, v8",
+                    "vse32.v v16, ({dst})",
+                    "sub {len}, {len}, {vl}",
+                    "slli {tmp}, {vl}, 1",
+                    "add {src}, {src}, {tmp}",
+                    "slli {tmp}, {vl}, 2",
+                    "add {dst}, {dst}, {tmp}",
+                    "bnez {len}, 2b",
+                    len = inout(reg) src.len() => _,
+                    src = inout(reg) src.as_ptr() => _,
+                    dst = inout(reg) dst.as_mut_ptr() => _,
+                    vl = out(reg) _,
+                    tmp = out(reg) _,
+                    out("v8") _, out("v9") _, out("v10") _, out("v11") _,
+                    out("v16") _, out("v17") _, out("v18") _, out("v19") _,
+                    out("v20") _, out("v21") _, out("v22") _, out("v23") _,
+                    options(nostack)
+                );
+            }
+            fix_f16_to_f32_nans(src, dst);
+        }
+    }
+
+    // Without the vector extension, the scalar Zfhmin conversions still beat the software ones.
+
+    #[cfg(target_feature = "zfhmin")]
+    #[inline]
+    fn f32_to_f16_zfhmin(f: f32) -> u16 {
+        let bits: u64;
+        // SAFETY: The target supports Zfhmin
+        unsafe {
+            asm!(
+                "fcvt.h.s {h}, {f}, rne",
+                "fmv.x.h {bits}, {h}",
+                f = in(freg) f,
+                h = out(freg) _,
+                bits = lateout(reg) bits,
+                options(pure, nomem, nostack)
+            );
+        }
+        bits as u16
+    }
+
+    #[cfg(target_feature = "zfhmin")]
+    #[inline]
+    fn f16_to_f32_zfhmin(i: u16) -> f32 {
+        let f: f32;
+        // SAFETY: The target supports Zfhmin
+        unsafe {
+            asm!(
+                "fmv.h.x {h}, {i}",
+                "fcvt.s.h {f}, {h}",
+                i = in(reg) i as u64,
+                h = out(freg) _,
+                f = lateout(freg) f,
+                options(pure, nomem, nostack)
+            );
+        }
+        f
+    }
+
+    #[cfg(target_feature = "zfhmin")]
+    fn f32_to_f16_slice_zfhmin(src: &[f32], dst: &mut [u16]) {
+        for (dst, &value) in dst.iter_mut().zip(src) {
+            *dst = if value.is_nan() {
+                f32_to_f16_fallback(value)
+            } else {
+                f32_to_f16_zfhmin(value)
+            };
+        }
+    }
+
+    #[cfg(target_feature = "zfhmin")]
+    fn f16_to_f32_slice_zfhmin(src: &[u16], dst: &mut [f32]) {
+        for (dst, &value) in dst.iter_mut().zip(src) {
+            *dst = if value & 0x7FFF > 0x7C00 {
+                f16_to_f32_fallback(value)
+            } else {
+                f16_to_f32_zfhmin(value)
+            };
+        }
+    }
+
+    #[cfg(all(test, feature = "std"))]
+    mod test {
+        use super::*;
+        use std::vec::Vec;
+
+        #[test]
+        fn test_kernels_match_fallback() {
+            let halves: Vec<u16> = (0..=u16::MAX).chain(0..5).collect();
+            let floats: Vec<f32> = (0..halves.len() as u32)
+                .map(|i| f32::from_bits(i.wrapping_mul(0x9E37_79B9)))
+                .chain([0.5, 65520., f32::from_bits(0x3300_0001), f32::NAN])
+                .collect();
+
+            let mut expected = std::vec![0f32; halves.len()];
+            let mut actual = std::vec![0f32; halves.len()];
+            f16_to_f32_slice_fallback(&halves, &mut expected);
+            f16_to_f32_slice(&halves, &mut actual);
+            for (a, e) in actual.iter().zip(&expected) {
+                assert_eq!(a.to_bits(), e.to_bits());
+            }
+
+            let mut expected = std::vec![0u16; floats.len()];
+            let mut actual = std::vec![0u16; floats.len()];
+            f32_to_f16_slice_fallback(&floats, &mut expected);
+            f32_to_f16_slice(&floats, &mut actual);
+            assert_eq!(actual, expected);
+        }
     }
 }
@@ -0,0 +1,65 @@
+//! Interoperability with the unstable `f16` primitive type.
+//!
+//! Inside this module, `f16` refers to the primitive type while [`Half`] is this crate's type.
+
+use crate::f16 as Half;
+
+impl From<Half> for f16 {
+    /// Reinterprets the bits of a [`half::f16`][Half] as the primitive `f16` type.
+    #[inline]
+    fn from(x: Half) -> f16 {
+        f16::from_bits(x.to_bits())
+    }
+}
+
+impl From<f16> for Half {
+    /// Reinterprets the bits of a primitive `f16` as a [`half::f16`][Half].
+    #[inline]
+    fn from(x: f16) -> Half {
+        Half::from_bits(x.to_bits())
+    }
+}
+
+// The softfloat backend takes precedence for arithmetic when both features are enabled
+#[cfg(not(feature = "softfloat"))]
+#[inline]
+pub(crate) fn add(a: Half, b: Half) -> Half {
+    (f16::from(a) + f16::from(b)).into()
+}
+
+#[cfg(not(feature = "softfloat"))]
+#[inline]
+pub(crate) fn sub(a: Half, b: Half) -> Half {
+    (f16::from(a) - f16::from(b)).into()
+}
+
+#[cfg(not(feature = "softfloat"))]
+#[inline]
+pub(crate) fn mul(a: Half, b: Half) -> Half {
+    (f16::from(a) * f16::from(b)).into()
+}
+
+#[cfg(not(feature = "softfloat"))]
+#[inline]
+pub(crate) fn div(a: Half, b: Half) -> Half {
+    (f16::from(a) / f16::from(b)).into()
+}
+
+#[cfg(not(feature = "softfloat"))]
+#[inline]
+pub(crate) fn mul_add(a: Half, b: Half, c: Half) -> Half {
+    f16::from(a).mul_add(f16::from(b), f16::from(c)).into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_primitive_conversions() {
+        let x: f16 = Half::from_f32(-1.5).into();
+        assert_eq!(x, -1.5);
+        assert_eq!(Half::from(x), Half::from_f32(-1.5));
+        assert_eq!(Half::from(f16::NAN).to_bits(), f16::NAN.to_bits());
+    }
+}
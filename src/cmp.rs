@@ -0,0 +1,102 @@
+//! Comparisons of [`f16`] and [`bf16`] with each other and with the wider float types.
+//!
+//! Both sides are converted to a type that represents them exactly, so the results are the same as
+//! comparing the mathematical values, with NaN unordered.
+
+use crate::{bf16, f16};
+use core::cmp::Ordering;
+
+macro_rules! impl_cmp_widened {
+    ($lhs:ty, $rhs:ty, |$x:ident, $y:ident| ($lhs_wide:expr, $rhs_wide:expr)) => {
+        impl PartialEq<$rhs> for $lhs {
+            #[inline]
+            fn eq(&self, other: &$rhs) -> bool {
+                let ($x, $y) = (*self, *other);
+                $lhs_wide == $rhs_wide
+            }
+        }
+
+        impl PartialOrd<$rhs> for $lhs {
+            #[inline]
+            fn partial_cmp(&self, other: &$rhs) -> Option<Ordering> {
+                let ($x, $y) = (*self, *other);
+                $lhs_wide.partial_cmp(&$rhs_wide)
+            }
+
+            #[inline]
+            fn lt(&self, other: &$rhs) -> bool {
+                let ($x, $y) = (*self, *other);
+                $lhs_wide < $rhs_wide
+            }
+
+            #[inline]
+            fn le(&self, other: &$rhs) -> bool {
+                let ($x, $y) = (*self, *other);
+                $lhs_wide <= $rhs_wide
+            }
+
+            #[inline]
+            fn gt(&self, other: &$rhs) -> bool {
+                let ($x, $y) = (*self, *other);
+                $lhs_wide > $rhs_wide
+            }
+
+            #[inline]
+            fn ge(&self, other: &$rhs) -> bool {
+                let ($x, $y) = (*self, *other);
+                $lhs_wide >= $rhs_wide
+            }
+        }
+    };
+}
+
+impl_cmp_widened!(f16, f32, |x, y| (x.to_f32(), y));
+impl_cmp_widened!(f32, f16, |x, y| (x, y.to_f32()));
+impl_cmp_widened!(f16, f64, |x, y| (x.to_f64(), y));
+impl_cmp_widened!(f64, f16, |x, y| (x, y.to_f64()));
+impl_cmp_widened!(bf16, f32, |x, y| (x.to_f32(), y));
+impl_cmp_widened!(f32, bf16, |x, y| (x, y.to_f32()));
+impl_cmp_widened!(bf16, f64, |x, y| (x.to_f64(), y));
+impl_cmp_widened!(f64, bf16, |x, y| (x, y.to_f64()));
+impl_cmp_widened!(f16, bf16, |x, y| (x.to_f32(), y.to_f32()));
+impl_cmp_widened!(bf16, f16, |x, y| (x.to_f32(), y.to_f32()));
+
+#[allow(clippy::float_cmp)]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cmp_f32_f64() {
+        let x = f16::from_f32(0.1);
+        assert!(x != 0.1f32);
+        assert!(x < 0.1f32);
+        assert!(x == 0.099975586f32);
+        assert!(0.0999755859375f64 == x);
+        assert!(x < 0.5f64);
+        assert!(1.0f32 >= f16::ONE);
+        assert_eq!(x.partial_cmp(&0.1f64), Some(Ordering::Less));
+
+        let y = bf16::from_f32(0.1);
+        assert!(y > 0.1f32);
+        assert!(0.1f64 < y);
+        assert!(bf16::ONE <= 1.0f64);
+
+        assert!(!f16::NAN.eq(&f32::NAN));
+        assert!(!f16::NAN.lt(&1.0f32) && !f16::NAN.ge(&1.0f32));
+        assert_eq!(bf16::NAN.partial_cmp(&0.0f64), None);
+        assert!(f16::INFINITY == f64::INFINITY);
+        assert!(f16::MAX < f32::MAX);
+        assert!(-0.0f32 == f16::ZERO);
+    }
+
+    #[test]
+    fn test_cmp_f16_bf16() {
+        assert!(f16::ONE == bf16::ONE);
+        assert!(f16::from_f32(0.1) < bf16::from_f32(0.1));
+        assert!(bf16::MAX > f16::MAX);
+        assert!(bf16::MIN_POSITIVE < f16::MIN_POSITIVE);
+        assert_eq!(f16::NAN.partial_cmp(&bf16::NAN), None);
+        assert!(bf16::from_f32(3.0) >= f16::from_f32(3.0));
+    }
+}
@@ -0,0 +1,342 @@
+//! Contains utility traits to convert between [`Complex`] numbers with [`f16`] or [`bf16`] parts
+//! and their single or double precision counterparts.
+//!
+//! The [`HalfComplexExt`] sealed extension trait is implemented for `Complex<f16>` and
+//! `Complex<bf16>` values, while the [`HalfComplexSliceExt`] sealed extension trait is implemented
+//! for slices of them. Because [`Complex`] is `#[repr(C)]`, a slice of complex numbers is laid out
+//! as interleaved real and imaginary parts, so the slice conversions reuse the vectorized
+//! conversions of [`HalfFloatSliceExt`] and can also view the data as a plain interleaved slice.
+//!
+//! Arithmetic on `Complex<f16>` and `Complex<bf16>` requires the `Num` family of traits, which are
+//! implemented by the `num-traits` feature of this crate.
+//!
+//! This module is only available with the `num-complex` feature.
+
+use crate::{bf16, f16, slice::HalfFloatSliceExt};
+use core::slice;
+use num_complex::Complex;
+
+/// Extensions to `Complex<f16>` and `Complex<bf16>` to convert to and from wider complex types.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait HalfComplexExt: private::SealedHalfComplex + Sized {
+    /// Converts a `Complex<f32>` into a complex number with [`f16`] or [`bf16`] parts, rounding
+    /// each part to the nearest representable value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::{complex::HalfComplexExt, f16};
+    /// # use num_complex::Complex;
+    /// let c = Complex::<f16>::from_complex_f32(Complex::new(1.5, -0.25));
+    /// assert_eq!(c, Complex::new(f16::from_f32(1.5), f16::from_f32(-0.25)));
+    /// ```
+    #[must_use]
+    fn from_complex_f32(value: Complex<f32>) -> Self;
+
+    /// Converts a `Complex<f64>` into a complex number with [`f16`] or [`bf16`] parts, rounding
+    /// each part to the nearest representable value.
+    #[must_use]
+    fn from_complex_f64(value: Complex<f64>) -> Self;
+
+    /// Converts `self` into a `Complex<f32>`. This conversion is lossless.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::{complex::HalfComplexExt, bf16};
+    /// # use num_complex::Complex;
+    /// let c = Complex::new(bf16::from_f32(2.), bf16::from_f32(-3.));
+    /// assert_eq!(c.to_complex_f32(), Complex::new(2., -3.));
+    /// ```
+    #[must_use]
+    fn to_complex_f32(self) -> Complex<f32>;
+
+    /// Converts `self` into a `Complex<f64>`. This conversion is lossless.
+    #[must_use]
+    fn to_complex_f64(self) -> Complex<f64>;
+}
+
+/// Extensions to `[Complex<f16>]` and `[Complex<bf16>]` slices to support interleaved views and
+/// conversions to and from wider complex types.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait HalfComplexSliceExt: private::SealedHalfComplexSlice {
+    /// The [`f16`] or [`bf16`] type of the real and imaginary parts.
+    type Half;
+
+    /// Reinterprets a slice of complex numbers as a slice of interleaved real and imaginary parts,
+    /// twice as long as `self`.
+    ///
+    /// This is a zero-copy operation. The reinterpreted slice has the same lifetime and memory
+    /// location as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::{complex::HalfComplexSliceExt, f16};
+    /// # use num_complex::Complex;
+    /// let buffer = [Complex::new(f16::ONE, f16::ZERO), Complex::new(f16::NEG_ONE, f16::ONE)];
+    /// assert_eq!(buffer.as_interleaved(), [f16::ONE, f16::ZERO, f16::NEG_ONE, f16::ONE]);
+    /// ```
+    #[must_use]
+    fn as_interleaved(&self) -> &[Self::Half];
+
+    /// Reinterprets a mutable slice of complex numbers as a mutable slice of interleaved real and
+    /// imaginary parts, twice as long as `self`.
+    ///
+    /// This is a zero-copy operation. The reinterpreted slice has the same lifetime as the
+    /// original, which prevents mutating `self` as long as the returned slice is borrowed.
+    #[must_use]
+    fn as_interleaved_mut(&mut self) -> &mut [Self::Half];
+
+    /// Converts all of the elements of a `[Complex<f32>]` slice into complex numbers with [`f16`]
+    /// or [`bf16`] parts in `self`.
+    ///
+    /// The length of `src` must be the same as `self`. The conversion is vectorized over the
+    /// interleaved parts, like [`HalfFloatSliceExt::convert_from_f32_slice`].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::{complex::HalfComplexSliceExt, f16};
+    /// # use num_complex::Complex;
+    /// let mut buffer = [Complex::new(f16::ZERO, f16::ZERO); 2];
+    /// buffer.convert_from_complex_f32_slice(&[Complex::new(1., 2.), Complex::new(3., 4.)]);
+    ///
+    /// assert_eq!(buffer[1], Complex::new(f16::from_f32(3.), f16::from_f32(4.)));
+    /// ```
+    fn convert_from_complex_f32_slice(&mut self, src: &[Complex<f32>]);
+
+    /// Converts all of the elements of a `[Complex<f64>]` slice into complex numbers with [`f16`]
+    /// or [`bf16`] parts in `self`.
+    ///
+    /// The length of `src` must be the same as `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    fn convert_from_complex_f64_slice(&mut self, src: &[Complex<f64>]);
+
+    /// Converts all of the elements of `self` into `Complex<f32>` values in `dst`.
+    ///
+    /// The length of `dst` must be the same as `self`. The conversion is vectorized over the
+    /// interleaved parts, like [`HalfFloatSliceExt::convert_to_f32_slice`].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::{complex::HalfComplexSliceExt, bf16};
+    /// # use num_complex::Complex;
+    /// let buffer = [Complex::new(bf16::from_f32(1.), bf16::from_f32(-1.))];
+    /// let mut wide = [Complex::new(0f32, 0.)];
+    /// buffer.convert_to_complex_f32_slice(&mut wide);
+    ///
+    /// assert_eq!(wide, [Complex::new(1., -1.)]);
+    /// ```
+    fn convert_to_complex_f32_slice(&self, dst: &mut [Complex<f32>]);
+
+    /// Converts all of the elements of `self` into `Complex<f64>` values in `dst`.
+    ///
+    /// The length of `dst` must be the same as `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    fn convert_to_complex_f64_slice(&self, dst: &mut [Complex<f64>]);
+}
+
+/// Views a slice of complex numbers as its interleaved parts.
+#[inline]
+fn interleaved<T>(values: &[Complex<T>]) -> &[T] {
+    let pointer = values.as_ptr().cast::<T>();
+    let length = values.len() * 2;
+    // SAFETY: `Complex<T>` is `repr(C)` with exactly two `T` fields, so it has the size and
+    // alignment of `[T; 2]`, and the lifetime is tied to `values`.
+    unsafe { slice::from_raw_parts(pointer, length) }
+}
+
+/// Views a mutable slice of complex numbers as its interleaved parts.
+#[inline]
+fn interleaved_mut<T>(values: &mut [Complex<T>]) -> &mut [T] {
+    let pointer = values.as_mut_ptr().cast::<T>();
+    let length = values.len() * 2;
+    // SAFETY: See `interleaved`, the mutable borrow of `values` is held by the result.
+    unsafe { slice::from_raw_parts_mut(pointer, length) }
+}
+
+macro_rules! impl_complex {
+    ($ty:ident) => {
+        impl HalfComplexExt for Complex<$ty> {
+            #[inline]
+            fn from_complex_f32(value: Complex<f32>) -> Self {
+                Complex::new($ty::from_f32(value.re), $ty::from_f32(value.im))
+            }
+
+            #[inline]
+            fn from_complex_f64(value: Complex<f64>) -> Self {
+                Complex::new($ty::from_f64(value.re), $ty::from_f64(value.im))
+            }
+
+            #[inline]
+            fn to_complex_f32(self) -> Complex<f32> {
+                Complex::new(self.re.to_f32(), self.im.to_f32())
+            }
+
+            #[inline]
+            fn to_complex_f64(self) -> Complex<f64> {
+                Complex::new(self.re.to_f64(), self.im.to_f64())
+            }
+        }
+
+        impl HalfComplexSliceExt for [Complex<$ty>] {
+            type Half = $ty;
+
+            #[inline]
+            fn as_interleaved(&self) -> &[$ty] {
+                interleaved(self)
+            }
+
+            #[inline]
+            fn as_interleaved_mut(&mut self) -> &mut [$ty] {
+                interleaved_mut(self)
+            }
+
+            fn convert_from_complex_f32_slice(&mut self, src: &[Complex<f32>]) {
+                assert_eq!(
+                    self.len(),
+                    src.len(),
+                    "destination and source slices have different lengths"
+                );
+                interleaved_mut(self).convert_from_f32_slice(interleaved(src));
+            }
+
+            fn convert_from_complex_f64_slice(&mut self, src: &[Complex<f64>]) {
+                assert_eq!(
+                    self.len(),
+                    src.len(),
+                    "destination and source slices have different lengths"
+                );
+                interleaved_mut(self).convert_from_f64_slice(interleaved(src));
+            }
+
+            fn convert_to_complex_f32_slice(&self, dst: &mut [Complex<f32>]) {
+                assert_eq!(
+                    self.len(),
+                    dst.len(),
+                    "destination and source slices have different lengths"
+                );
+                interleaved(self).convert_to_f32_slice(interleaved_mut(dst));
+            }
+
+            fn convert_to_complex_f64_slice(&self, dst: &mut [Complex<f64>]) {
+                assert_eq!(
+                    self.len(),
+                    dst.len(),
+                    "destination and source slices have different lengths"
+                );
+                interleaved(self).convert_to_f64_slice(interleaved_mut(dst));
+            }
+        }
+    };
+}
+
+impl_complex!(f16);
+impl_complex!(bf16);
+
+mod private {
+    use crate::{bf16, f16};
+    use num_complex::Complex;
+
+    pub trait SealedHalfComplex {}
+    impl SealedHalfComplex for Complex<f16> {}
+    impl SealedHalfComplex for Complex<bf16> {}
+
+    pub trait SealedHalfComplexSlice {}
+    impl SealedHalfComplexSlice for [Complex<f16>] {}
+    impl SealedHalfComplexSlice for [Complex<bf16>] {}
+}
+
+#[cfg(test)]
+mod test {
+    use super::{HalfComplexExt, HalfComplexSliceExt};
+    use crate::{bf16, f16};
+    use num_complex::Complex;
+
+    #[test]
+    fn scalar_conversions() {
+        let c = Complex::<f16>::from_complex_f32(Complex::new(0.1, -65504.));
+        assert_eq!(c.re, f16::from_f32(0.1));
+        assert_eq!(c.im, f16::MIN);
+        assert_eq!(
+            c.to_complex_f64(),
+            Complex::new(f16::from_f32(0.1).to_f64(), -65504.)
+        );
+
+        let c = Complex::<bf16>::from_complex_f64(Complex::new(3.0, f64::INFINITY));
+        assert_eq!(c, Complex::new(bf16::from_f32(3.), bf16::INFINITY));
+        assert_eq!(c.to_complex_f32(), Complex::new(3., f32::INFINITY));
+    }
+
+    #[test]
+    fn slice_conversions() {
+        let wide: [Complex<f32>; 5] = [
+            Complex::new(1., 2.),
+            Complex::new(-0.5, 0.25),
+            Complex::new(1e-7, 1e7),
+            Complex::new(0., -0.),
+            Complex::new(100., 7.),
+        ];
+        let mut buffer = [Complex::new(f16::ZERO, f16::ZERO); 5];
+        buffer.convert_from_complex_f32_slice(&wide);
+        for (h, w) in buffer.iter().zip(&wide) {
+            assert_eq!(*h, Complex::<f16>::from_complex_f32(*w));
+        }
+        assert_eq!(buffer.as_interleaved()[2], f16::from_f32(-0.5));
+
+        let mut back = [Complex::new(0f32, 0.); 5];
+        buffer.convert_to_complex_f32_slice(&mut back);
+        assert_eq!(back[1], wide[1]);
+        assert_eq!(back[4], wide[4]);
+
+        let mut buffer = [Complex::new(bf16::ZERO, bf16::ZERO); 5];
+        let wide = wide.map(|c| Complex::new(f64::from(c.re), f64::from(c.im)));
+        buffer.convert_from_complex_f64_slice(&wide);
+        buffer.as_interleaved_mut()[9] = bf16::NEG_ONE;
+        let mut back = [Complex::new(0f64, 0.); 5];
+        buffer.convert_to_complex_f64_slice(&mut back);
+        assert_eq!(back[0], wide[0]);
+        assert_eq!(back[4], Complex::new(100., -1.));
+    }
+
+    #[test]
+    #[should_panic]
+    fn convert_from_complex_f32_slice_len_mismatch_panics() {
+        let mut buffer = [Complex::new(f16::ZERO, f16::ZERO); 2];
+        buffer.convert_from_complex_f32_slice(&[Complex::new(1., 2.)]);
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn arithmetic() {
+        let a = Complex::new(f16::from_f32(1.), f16::from_f32(2.));
+        let b = Complex::new(f16::from_f32(3.), f16::from_f32(-1.));
+        assert_eq!(a + b, Complex::new(f16::from_f32(4.), f16::from_f32(1.)));
+        assert_eq!(a * b, Complex::new(f16::from_f32(5.), f16::from_f32(5.)));
+        assert_eq!(-a, Complex::new(f16::from_f32(-1.), f16::from_f32(-2.)));
+        assert_eq!(a.norm_sqr(), f16::from_f32(5.));
+        let c = Complex::new(bf16::from_f32(3.), bf16::from_f32(4.));
+        assert_eq!(
+            c / c.conj(),
+            Complex::new(bf16::from_f32(-0.28), bf16::from_f32(0.96))
+        );
+    }
+}
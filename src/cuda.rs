@@ -0,0 +1,34 @@
+use crate::{bf16, f16};
+use core::mem::{align_of, size_of};
+use cust_core::DeviceCopy;
+
+// CUDA's `__half` and `__nv_bfloat16` are structs around an `unsigned short`, so these types can be
+// copied to and from device memory as they are.
+const _: () = assert!(size_of::<f16>() == 2 && align_of::<f16>() == 2);
+const _: () = assert!(size_of::<bf16>() == 2 && align_of::<bf16>() == 2);
+
+// SAFETY: both types are `repr(transparent)` over `u16`, have no padding, and hold no references.
+unsafe impl DeviceCopy for f16 {}
+unsafe impl DeviceCopy for bf16 {}
+
+#[cfg(test)]
+mod test {
+    use crate::{bf16, f16};
+    use cust_core::DeviceCopy;
+
+    #[derive(Clone, Copy, DeviceCopy)]
+    #[allow(dead_code)]
+    struct Vertex {
+        position: [f16; 4],
+        weight: bf16,
+    }
+
+    fn assert_device_copy<T: DeviceCopy>() {}
+
+    #[test]
+    fn device_copy() {
+        assert_device_copy::<f16>();
+        assert_device_copy::<[bf16; 8]>();
+        assert_device_copy::<Vertex>();
+    }
+}
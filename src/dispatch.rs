@@ -0,0 +1,109 @@
+//! Helpers shared by the vectorized slice conversion kernels of [`f16`][crate::f16] and
+//! [`bf16`][crate::bf16].
+
+#![allow(unused_macros, unused_imports)]
+
+/// Converts `$src` into `$dst` in chunks of `$n` elements with `$convert`, which converts the first
+/// `$n` elements of its source slice into its destination slice. The remainder is padded with
+/// default values into a full chunk.
+///
+/// This is a macro rather than a function so the whole loop is expanded inside the calling
+/// kernel, which lets `$convert` be inlined when the kernel enables a target feature.
+macro_rules! convert_chunks {
+    ($src:expr, $dst:expr, $n:literal, $convert:expr) => {{
+        let (src, dst) = ($src, $dst);
+        debug_assert_eq!(src.len(), dst.len());
+
+        let mut src_chunks = src.chunks_exact($n);
+        let mut dst_chunks = dst.chunks_exact_mut($n);
+        for (src, dst) in (&mut src_chunks).zip(&mut dst_chunks) {
+            $convert(src, dst);
+        }
+
+        let remainder = src_chunks.remainder();
+        if !remainder.is_empty() {
+            let mut src_buf = [Default::default(); $n];
+            let mut dst_buf = [Default::default(); $n];
+            src_buf[..remainder.len()].copy_from_slice(remainder);
+            $convert(&src_buf, &mut dst_buf);
+            dst_chunks
+                .into_remainder()
+                .copy_from_slice(&dst_buf[..remainder.len()]);
+        }
+    }};
+}
+
+/// Defines a slice conversion function that selects its kernel with `$select` on the first call,
+/// and caches the selected function pointer so that later calls skip the CPU feature detection.
+///
+/// Racing first calls may each run `$select`, which is harmless since they all pick the same
+/// kernel.
+macro_rules! cached_kernel {
+    ($(#[$attr:meta])* $vis:vis fn $name:ident($src:ident: &[$srcty:ty], $dst:ident: &mut [$dstty:ty])
+        = $select:expr;) => {
+        $(#[$attr])*
+        $vis fn $name($src: &[$srcty], $dst: &mut [$dstty]) {
+            use core::sync::atomic::{AtomicPtr, Ordering};
+
+            type Kernel = fn(&[$srcty], &mut [$dstty]);
+            static KERNEL: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+
+            let cached = KERNEL.load(Ordering::Relaxed);
+            let kernel: Kernel = if cached.is_null() {
+                let kernel: Kernel = $select;
+                KERNEL.store(kernel as *mut (), Ordering::Relaxed);
+                kernel
+            } else {
+                // SAFETY: Only `Kernel` function pointers are ever stored in `KERNEL`
+                unsafe { core::mem::transmute::<*mut (), Kernel>(cached) }
+            };
+            kernel($src, $dst)
+        }
+    };
+}
+
+/// Defines a slice conversion function with several versions of its kernel, each compiled for
+/// different CPU features. The kernels are tried in order, and the first one whose condition
+/// holds is selected once and called from then on, or `$fallback` if none of them do.
+///
+/// Each kernel is an `unsafe` function that may only be called when its condition holds. With
+/// `std`, the conditions detect CPU features at runtime, and the selection is cached with
+/// [`cached_kernel!`]. Without it they only depend on the compile target features, so the
+/// selection folds into a direct call.
+///
+/// Supporting another instruction set is a matter of adding its kernel to the list.
+macro_rules! multiversion {
+    (@select $($available:expr => $kernel:path,)* _ => $fallback:path) => {
+        $(
+            if $available {
+                // SAFETY: The CPU supports the features the kernel was compiled for
+                |src, dst| unsafe { $kernel(src, dst) }
+            } else
+        )* {
+            $fallback
+        }
+    };
+    ($(#[$attr:meta])* $vis:vis fn $name:ident($src:ident: &[$srcty:ty], $dst:ident: &mut [$dstty:ty]) {
+        $($available:expr => $kernel:path,)*
+        _ => $fallback:path $(,)?
+    }) => {
+        #[cfg(feature = "std")]
+        $crate::dispatch::cached_kernel! {
+            $(#[$attr])*
+            $vis fn $name($src: &[$srcty], $dst: &mut [$dstty]) = $crate::dispatch::multiversion!(
+                @select $($available => $kernel,)* _ => $fallback
+            );
+        }
+
+        #[cfg(not(feature = "std"))]
+        $(#[$attr])*
+        $vis fn $name($src: &[$srcty], $dst: &mut [$dstty]) {
+            let kernel: fn(&[$srcty], &mut [$dstty]) = $crate::dispatch::multiversion!(
+                @select $($available => $kernel,)* _ => $fallback
+            );
+            kernel($src, $dst)
+        }
+    };
+}
+
+pub(crate) use {cached_kernel, convert_chunks, multiversion};
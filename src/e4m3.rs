@@ -0,0 +1,806 @@
+// `f32::to_bits`/`f64::to_bits` are not `const` on our MSRV
+#![allow(
+    unknown_lints,
+    unnecessary_transmutes,
+    clippy::missing_transmute_annotations
+)]
+#[cfg(not(target_arch = "spirv"))]
+use crate::fmt;
+use crate::{
+    bf16, f16,
+    rounding::{self, Rounding},
+    RoundingMode,
+};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt::{
+    Binary, Debug, Display, Error, Formatter, LowerExp, LowerHex, Octal, UpperExp, UpperHex,
+};
+use core::{cmp::Ordering, mem, num::FpCategory, ops::Neg};
+#[cfg(feature = "zerocopy")]
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
+
+/// An 8-bit floating point type implementing the OCP [`FP8`] E4M3 format, also known as
+/// `float8_e4m3fn`.
+///
+/// The format has 1 sign bit, 4 exponent bits with a bias of 7 and 3 mantissa bits. Unlike the
+/// IEEE 754 formats, it has no infinities: the largest exponent is used for finite values, except
+/// for the all-ones bit pattern of either sign which is the only NaN. This extends the range up
+/// to ±448, which makes it a popular format for the weights and activations of machine learning
+/// models.
+///
+/// Like [`f16`], [`f8e4m3`] is a storage format and does not implement arithmetic. Converting to
+/// any of the wider float types is exact, so operations should be performed with [`f32`] or
+/// another wider type and the result converted back.
+///
+/// Because there is no infinity, conversions to [`f8e4m3`] turn values that are too large into
+/// NaN, as recommended by the OCP specification. Use the `_saturating` conversions to clamp them
+/// to [`f8e4m3::MAX`] or [`f8e4m3::MIN`] instead.
+///
+/// [`FP8`]: https://www.opencompute.org/documents/ocp-8-bit-floating-point-specification-ofp8-revision-1-0-2023-12-01-pdf-1
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+#[cfg_attr(feature = "bytemuck", derive(Zeroable, Pod))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned)
+)]
+pub struct f8e4m3(u8);
+
+impl f8e4m3 {
+    /// Constructs an [`f8e4m3`] value from the raw bits.
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: u8) -> f8e4m3 {
+        f8e4m3(bits)
+    }
+
+    /// Converts an [`f8e4m3`] into the underlying bit representation.
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> u8 {
+        self.0
+    }
+
+    /// Constructs an [`f8e4m3`] value from a 32-bit floating point value.
+    ///
+    /// The value is rounded to the nearest representable value, with ties to even. Values whose
+    /// rounded magnitude is larger than [`f8e4m3::MAX`], as well as ±∞, become NaN since the
+    /// format has no infinities. NaN values become the NaN of the same sign.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::f8e4m3;
+    /// assert_eq!(f8e4m3::from_f32(1.0), f8e4m3::ONE);
+    /// assert_eq!(f8e4m3::from_f32(0.3).to_f32(), 0.3125);
+    /// assert_eq!(f8e4m3::from_f32(460.0), f8e4m3::MAX);
+    /// assert!(f8e4m3::from_f32(500.0).is_nan());
+    /// assert!(f8e4m3::from_f32(f32::INFINITY).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_f32(value: f32) -> f8e4m3 {
+        f8e4m3(f32_to_e4m3(value, false))
+    }
+
+    /// Constructs an [`f8e4m3`] value from a 64-bit floating point value.
+    ///
+    /// The value is rounded directly to the nearest representable value, without rounding to
+    /// [`f32`] first. Out of range values and NaN are handled the same as by
+    /// [`from_f32`][Self::from_f32].
+    #[inline]
+    #[must_use]
+    pub const fn from_f64(value: f64) -> f8e4m3 {
+        f8e4m3(f64_to_e4m3(value, false))
+    }
+
+    /// Constructs an [`f8e4m3`] value from an [`f16`] value, rounding to nearest like
+    /// [`from_f32`][Self::from_f32].
+    #[inline]
+    #[must_use]
+    pub const fn from_f16(value: f16) -> f8e4m3 {
+        // Exact, so this only rounds once
+        f8e4m3::from_f32(value.to_f32_const())
+    }
+
+    /// Constructs an [`f8e4m3`] value from a [`bf16`] value, rounding to nearest like
+    /// [`from_f32`][Self::from_f32].
+    #[inline]
+    #[must_use]
+    pub const fn from_bf16(value: bf16) -> f8e4m3 {
+        f8e4m3::from_f32(value.to_f32_const())
+    }
+
+    /// Constructs an [`f8e4m3`] value from a 32-bit floating point value, saturating values that
+    /// are too large to [`f8e4m3::MAX`] or [`f8e4m3::MIN`] instead of producing NaN.
+    ///
+    /// This is the saturating conversion mode of the OCP specification, so ±∞ also saturate.
+    /// Values are otherwise rounded to nearest, and NaN values stay NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::f8e4m3;
+    /// assert_eq!(f8e4m3::from_f32_saturating(1000.0), f8e4m3::MAX);
+    /// assert_eq!(f8e4m3::from_f32_saturating(f32::NEG_INFINITY), f8e4m3::MIN);
+    /// assert!(f8e4m3::from_f32_saturating(f32::NAN).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_f32_saturating(value: f32) -> f8e4m3 {
+        f8e4m3(f32_to_e4m3(value, true))
+    }
+
+    /// Constructs an [`f8e4m3`] value from a 64-bit floating point value, saturating values that
+    /// are too large to [`f8e4m3::MAX`] or [`f8e4m3::MIN`] instead of producing NaN.
+    ///
+    /// See [`from_f32_saturating`][Self::from_f32_saturating].
+    #[inline]
+    #[must_use]
+    pub const fn from_f64_saturating(value: f64) -> f8e4m3 {
+        f8e4m3(f64_to_e4m3(value, true))
+    }
+
+    /// Constructs an [`f8e4m3`] value from an [`f16`] value, saturating values that are too large
+    /// to [`f8e4m3::MAX`] or [`f8e4m3::MIN`] instead of producing NaN.
+    ///
+    /// See [`from_f32_saturating`][Self::from_f32_saturating].
+    #[inline]
+    #[must_use]
+    pub const fn from_f16_saturating(value: f16) -> f8e4m3 {
+        f8e4m3::from_f32_saturating(value.to_f32_const())
+    }
+
+    /// Constructs an [`f8e4m3`] value from a [`bf16`] value, saturating values that are too large
+    /// to [`f8e4m3::MAX`] or [`f8e4m3::MIN`] instead of producing NaN.
+    ///
+    /// See [`from_f32_saturating`][Self::from_f32_saturating].
+    #[inline]
+    #[must_use]
+    pub const fn from_bf16_saturating(value: bf16) -> f8e4m3 {
+        f8e4m3::from_f32_saturating(value.to_f32_const())
+    }
+
+    /// Converts an [`f8e4m3`] value into an [`f32`] value.
+    ///
+    /// This conversion is lossless as all [`f8e4m3`] values can be represented exactly in
+    /// [`f32`].
+    #[inline]
+    #[must_use]
+    pub const fn to_f32(self) -> f32 {
+        let sign = ((self.0 & 0x80) as u32) << 24;
+        let bits = match self.decode() {
+            Decoded::Nan => sign | 0x7FC0_0000,
+            Decoded::Zero => sign,
+            Decoded::Finite(exp, man) => sign | (((exp + 127) as u32) << 23) | (man << 20),
+        };
+        // TODO: Replace mem::transmute with from_bits() once from_bits is const-stabilized
+        unsafe { mem::transmute(bits) }
+    }
+
+    /// Converts an [`f8e4m3`] value into an [`f64`] value.
+    ///
+    /// This conversion is lossless as all [`f8e4m3`] values can be represented exactly in
+    /// [`f64`].
+    #[inline]
+    #[must_use]
+    pub const fn to_f64(self) -> f64 {
+        let sign = ((self.0 & 0x80) as u64) << 56;
+        let bits = match self.decode() {
+            Decoded::Nan => sign | 0x7FF8_0000_0000_0000,
+            Decoded::Zero => sign,
+            Decoded::Finite(exp, man) => {
+                sign | (((exp + 1023) as u64) << 52) | ((man as u64) << 49)
+            }
+        };
+        // TODO: Replace mem::transmute with from_bits() once from_bits is const-stabilized
+        unsafe { mem::transmute(bits) }
+    }
+
+    /// Converts an [`f8e4m3`] value into an [`f16`] value.
+    ///
+    /// This conversion is lossless as all [`f8e4m3`] values can be represented exactly in
+    /// [`f16`].
+    #[inline]
+    #[must_use]
+    pub const fn to_f16(self) -> f16 {
+        f16::from_f32_const(self.to_f32())
+    }
+
+    /// Converts an [`f8e4m3`] value into a [`bf16`] value.
+    ///
+    /// This conversion is lossless as all [`f8e4m3`] values can be represented exactly in
+    /// [`bf16`].
+    #[inline]
+    #[must_use]
+    pub const fn to_bf16(self) -> bf16 {
+        bf16::from_f32_const(self.to_f32())
+    }
+
+    // The unbiased exponent and the 3 mantissa bits of a normalized nonzero finite value
+    const fn decode(self) -> Decoded {
+        let exp = ((self.0 >> 3) & 0x0F) as i32;
+        let man = (self.0 & 0x07) as u32;
+        if self.0 & 0x7F == 0x7F {
+            Decoded::Nan
+        } else if exp != 0 {
+            Decoded::Finite(exp - 7, man)
+        } else if man == 0 {
+            Decoded::Zero
+        } else {
+            // Subnormals are `man * 2^-9`, shift the leading bit out to make it the hidden bit
+            let msb = 31 - man.leading_zeros();
+            Decoded::Finite(msb as i32 - 9, (man << (3 - msb)) & 0x07)
+        }
+    }
+
+    /// Returns `true` if this value is NaN and `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::f8e4m3;
+    /// assert!(f8e4m3::NAN.is_nan());
+    /// assert!(!f8e4m3::MAX.is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_nan(self) -> bool {
+        self.0 & 0x7F == 0x7F
+    }
+
+    /// Always returns `false`, since [`f8e4m3`] has no infinities.
+    #[inline]
+    #[must_use]
+    pub const fn is_infinite(self) -> bool {
+        false
+    }
+
+    /// Returns `true` if this number is not NaN.
+    #[inline]
+    #[must_use]
+    pub const fn is_finite(self) -> bool {
+        !self.is_nan()
+    }
+
+    /// Returns `true` if the number is neither zero, subnormal, or NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::f8e4m3;
+    /// assert!(f8e4m3::MIN_POSITIVE.is_normal());
+    /// assert!(f8e4m3::MAX.is_normal());
+    ///
+    /// assert!(!f8e4m3::ZERO.is_normal());
+    /// assert!(!f8e4m3::NAN.is_normal());
+    /// assert!(!f8e4m3::MAX_SUBNORMAL.is_normal());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_normal(self) -> bool {
+        self.0 & 0x78 != 0 && !self.is_nan()
+    }
+
+    /// Returns the floating point category of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::num::FpCategory;
+    /// # use half::f8e4m3;
+    ///
+    /// assert_eq!(f8e4m3::from_f32(12.4).classify(), FpCategory::Normal);
+    /// assert_eq!(f8e4m3::MIN_POSITIVE_SUBNORMAL.classify(), FpCategory::Subnormal);
+    /// assert_eq!(f8e4m3::NAN.classify(), FpCategory::Nan);
+    /// ```
+    #[must_use]
+    pub const fn classify(self) -> FpCategory {
+        if self.is_nan() {
+            FpCategory::Nan
+        } else if self.0 & 0x7F == 0 {
+            FpCategory::Zero
+        } else if self.0 & 0x78 == 0 {
+            FpCategory::Subnormal
+        } else {
+            FpCategory::Normal
+        }
+    }
+
+    /// Returns `true` if and only if `self` has a positive sign, including `+0.0` and NaN with a
+    /// positive sign bit.
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_positive(self) -> bool {
+        self.0 & 0x80 == 0
+    }
+
+    /// Returns `true` if and only if `self` has a negative sign, including `-0.0` and NaN with a
+    /// negative sign bit.
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_negative(self) -> bool {
+        self.0 & 0x80 != 0
+    }
+
+    /// Returns a number composed of the magnitude of `self` and the sign of `sign`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::f8e4m3;
+    /// let f = f8e4m3::from_f32(3.5);
+    ///
+    /// assert_eq!(f.copysign(f8e4m3::NEG_ONE), f8e4m3::from_f32(-3.5));
+    /// assert_eq!((-f).copysign(f8e4m3::ONE), f);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn copysign(self, sign: f8e4m3) -> f8e4m3 {
+        f8e4m3((sign.0 & 0x80) | (self.0 & 0x7F))
+    }
+
+    /// Approximate number of [`f8e4m3`] significant digits in base 10
+    pub const DIGITS: u32 = 0;
+    /// [`f8e4m3`]
+    /// [machine epsilon](https://en.wikipedia.org/wiki/Machine_epsilon) value
+    ///
+    /// This is the difference between 1.0 and the next largest representable number.
+    pub const EPSILON: f8e4m3 = f8e4m3(0x20);
+    /// Number of [`f8e4m3`] significant digits in base 2
+    pub const MANTISSA_DIGITS: u32 = 4;
+    /// Largest finite [`f8e4m3`] value, 448
+    pub const MAX: f8e4m3 = f8e4m3(0x7E);
+    /// Maximum possible [`f8e4m3`] power of 10 exponent
+    pub const MAX_10_EXP: i32 = 2;
+    /// Maximum possible [`f8e4m3`] power of 2 exponent
+    pub const MAX_EXP: i32 = 9;
+    /// Smallest finite [`f8e4m3`] value, -448
+    pub const MIN: f8e4m3 = f8e4m3(0xFE);
+    /// Minimum possible normal [`f8e4m3`] power of 10 exponent
+    pub const MIN_10_EXP: i32 = -1;
+    /// One greater than the minimum possible normal [`f8e4m3`] power of 2 exponent
+    pub const MIN_EXP: i32 = -5;
+    /// Smallest positive normal [`f8e4m3`] value
+    pub const MIN_POSITIVE: f8e4m3 = f8e4m3(0x08);
+    /// [`f8e4m3`] Not a Number (NaN), the only NaN bit pattern apart from its negative
+    pub const NAN: f8e4m3 = f8e4m3(0x7F);
+    /// The radix or base of the internal representation of [`f8e4m3`]
+    pub const RADIX: u32 = 2;
+
+    /// Minimum positive subnormal [`f8e4m3`] value
+    pub const MIN_POSITIVE_SUBNORMAL: f8e4m3 = f8e4m3(0x01);
+    /// Maximum subnormal [`f8e4m3`] value
+    pub const MAX_SUBNORMAL: f8e4m3 = f8e4m3(0x07);
+
+    /// [`f8e4m3`] 1
+    pub const ONE: f8e4m3 = f8e4m3(0x38);
+    /// [`f8e4m3`] 0
+    pub const ZERO: f8e4m3 = f8e4m3(0x00);
+    /// [`f8e4m3`] -0
+    pub const NEG_ZERO: f8e4m3 = f8e4m3(0x80);
+    /// [`f8e4m3`] -1
+    pub const NEG_ONE: f8e4m3 = f8e4m3(0xB8);
+}
+
+enum Decoded {
+    Nan,
+    Zero,
+    Finite(i32, u32),
+}
+
+const fn f32_to_e4m3(value: f32, saturate: bool) -> u8 {
+    // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
+    let x: u32 = unsafe { mem::transmute(value) };
+    let sign = ((x >> 24) & 0x80) as u8;
+    let exp = (x >> 23) & 0xFF;
+    let man = (x & 0x007F_FFFF) as u64;
+
+    if exp == 0xFF {
+        return sign | if man == 0 && saturate { 0x7E } else { 0x7F };
+    }
+    if exp == 0 {
+        round_to_e4m3(sign, man, -149, saturate)
+    } else {
+        round_to_e4m3(sign, man | 0x0080_0000, exp as i32 - 150, saturate)
+    }
+}
+
+const fn f64_to_e4m3(value: f64, saturate: bool) -> u8 {
+    // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
+    let x: u64 = unsafe { mem::transmute(value) };
+    let sign = ((x >> 56) & 0x80) as u8;
+    let exp = (x >> 52) & 0x7FF;
+    let man = x & 0x000F_FFFF_FFFF_FFFF;
+
+    if exp == 0x7FF {
+        return sign | if man == 0 && saturate { 0x7E } else { 0x7F };
+    }
+    if exp == 0 {
+        round_to_e4m3(sign, man, -1074, saturate)
+    } else {
+        round_to_e4m3(
+            sign,
+            man | 0x0010_0000_0000_0000,
+            exp as i32 - 1075,
+            saturate,
+        )
+    }
+}
+
+const fn round_to_e4m3(sign: u8, man: u64, exp: i32, saturate: bool) -> u8 {
+    let rounding = Rounding::Mode(RoundingMode::NearestEven);
+    match rounding::round_to_format(sign != 0, man, exp, rounding, 3, 4, 0x7E) {
+        Some(bits) => sign | bits as u8,
+        None if saturate => sign | 0x7E,
+        None => sign | 0x7F,
+    }
+}
+
+impl From<f8e4m3> for f32 {
+    #[inline]
+    fn from(x: f8e4m3) -> f32 {
+        x.to_f32()
+    }
+}
+
+impl From<f8e4m3> for f64 {
+    #[inline]
+    fn from(x: f8e4m3) -> f64 {
+        x.to_f64()
+    }
+}
+
+impl From<f8e4m3> for f16 {
+    #[inline]
+    fn from(x: f8e4m3) -> f16 {
+        x.to_f16()
+    }
+}
+
+impl From<f8e4m3> for bf16 {
+    #[inline]
+    fn from(x: f8e4m3) -> bf16 {
+        x.to_bf16()
+    }
+}
+
+impl PartialEq for f8e4m3 {
+    fn eq(&self, other: &f8e4m3) -> bool {
+        if self.is_nan() || other.is_nan() {
+            false
+        } else {
+            (self.0 == other.0) || ((self.0 | other.0) & 0x7F == 0)
+        }
+    }
+}
+
+impl PartialOrd for f8e4m3 {
+    fn partial_cmp(&self, other: &f8e4m3) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            None
+        } else if (self.0 | other.0) & 0x7F == 0 {
+            Some(Ordering::Equal)
+        } else {
+            // Map the sign-magnitude bits to integers in the same order as the values
+            let key = |x: u8| {
+                if x & 0x80 != 0 {
+                    -((x & 0x7F) as i16)
+                } else {
+                    x as i16
+                }
+            };
+            Some(key(self.0).cmp(&key(other.0)))
+        }
+    }
+}
+
+impl Neg for f8e4m3 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self(self.0 ^ 0x80)
+    }
+}
+
+impl Neg for &f8e4m3 {
+    type Output = <f8e4m3 as Neg>::Output;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Neg::neg(*self)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl f8e4m3 {
+    fn shortest_decimal(self) -> Option<fmt::Decimal> {
+        let exp = (self.0 >> 3) & 0x0F;
+        let man = (self.0 & 0x07) as u64;
+        match exp {
+            _ if self.is_nan() => None,
+            0 if man == 0 => Some(fmt::Decimal { digits: 0, exp: 0 }),
+            0 => Some(fmt::shortest_decimal(man, -9, false)),
+            _ => Some(fmt::shortest_decimal(
+                man | 0x08,
+                exp as i32 - 10,
+                man == 0 && exp > 1,
+            )),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl Debug for f8e4m3 {
+    /// Formats the numeric value like [`Display`], but always includes a fractional part or
+    /// exponent like [`f32`] does.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self.shortest_decimal() {
+            Some(decimal) if f.precision().is_none() => {
+                fmt::write_debug(f, self.is_sign_negative(), decimal)
+            }
+            _ => Debug::fmt(&self.to_f32(), f),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl Display for f8e4m3 {
+    /// Formats the value with the fewest decimal digits that convert back to the same
+    /// [`f8e4m3`], or the exact value rounded to the given precision. The other formatting flags
+    /// work the same as for [`f32`].
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self.shortest_decimal() {
+            Some(decimal) if f.precision().is_none() => {
+                fmt::write_decimal(f, self.is_sign_negative(), decimal)
+            }
+            _ => Display::fmt(&self.to_f32(), f),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl LowerExp for f8e4m3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self.shortest_decimal() {
+            Some(decimal) if f.precision().is_none() => {
+                fmt::write_exp(f, self.is_sign_negative(), decimal, false)
+            }
+            _ => LowerExp::fmt(&self.to_f32(), f),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl UpperExp for f8e4m3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self.shortest_decimal() {
+            Some(decimal) if f.precision().is_none() => {
+                fmt::write_exp(f, self.is_sign_negative(), decimal, true)
+            }
+            _ => UpperExp::fmt(&self.to_f32(), f),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl Binary for f8e4m3 {
+    /// Formats the raw bits of the value like the same formatting of [`u8`] would. [`Octal`],
+    /// [`LowerHex`] and [`UpperHex`] do the same.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        Binary::fmt(&self.0, f)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl Octal for f8e4m3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        Octal::fmt(&self.0, f)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl LowerHex for f8e4m3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        LowerHex::fmt(&self.0, f)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl UpperHex for f8e4m3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        UpperHex::fmt(&self.0, f)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for f8e4m3 {
+    /// Generates any bit pattern, including NaNs and subnormals.
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        <u8 as arbitrary::Arbitrary>::arbitrary(u).map(Self)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <u8 as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+#[allow(
+    clippy::cognitive_complexity,
+    clippy::float_cmp,
+    clippy::neg_cmp_op_on_partial_ord
+)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use quickcheck_macros::quickcheck;
+
+    // All finite non-negative values in increasing order
+    fn positive_values() -> impl Iterator<Item = f8e4m3> {
+        (0..0x7F).map(f8e4m3::from_bits)
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut bytes = [0u8; 64];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = (i * 37) as u8;
+        }
+        let mut u = Unstructured::new(&bytes);
+        let mut bits = Unstructured::new(&bytes);
+        while !u.is_empty() {
+            let x = f8e4m3::arbitrary(&mut u).unwrap();
+            assert_eq!(x.to_bits(), u8::arbitrary(&mut bits).unwrap());
+        }
+        assert_eq!(f8e4m3::size_hint(0), u8::size_hint(0));
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(f8e4m3::ONE.to_f32(), 1.0);
+        assert_eq!(f8e4m3::NEG_ONE.to_f32(), -1.0);
+        assert_eq!(f8e4m3::ZERO.to_f32(), 0.0);
+        assert!(f8e4m3::NEG_ZERO.to_f32().is_sign_negative());
+        assert_eq!(f8e4m3::MAX.to_f32(), 448.0);
+        assert_eq!(f8e4m3::MIN.to_f32(), -448.0);
+        assert_eq!(f8e4m3::EPSILON.to_f32(), 0.125);
+        assert_eq!(f8e4m3::MIN_POSITIVE.to_f32(), 0.015625);
+        assert_eq!(f8e4m3::MIN_POSITIVE_SUBNORMAL.to_f32(), 0.001953125);
+        assert_eq!(f8e4m3::MAX_SUBNORMAL.to_f32(), 0.013671875);
+        assert!(f8e4m3::NAN.to_f32().is_nan());
+        assert!((-f8e4m3::NAN).is_nan());
+    }
+
+    #[cfg(feature = "zerocopy")]
+    #[test]
+    fn test_zerocopy_unaligned() {
+        use zerocopy::FromBytes;
+
+        let bytes = [0, 0x38, 0xB8, 0x7E];
+        let values = <[f8e4m3]>::ref_from_bytes(&bytes[1..]).unwrap();
+        assert_eq!(values, [f8e4m3::ONE, f8e4m3::NEG_ONE, f8e4m3::MAX]);
+    }
+
+    #[test]
+    fn test_roundtrip_all() {
+        for bits in 0..=u8::MAX {
+            let x = f8e4m3::from_bits(bits);
+            let f = x.to_f32();
+            if x.is_nan() {
+                assert!(f.is_nan() && x.to_f64().is_nan() && x.to_f16().is_nan());
+                assert!(x.to_bf16().is_nan());
+                assert_eq!(f.is_sign_negative(), x.is_sign_negative());
+                assert_eq!(f8e4m3::from_f32(f).to_bits(), bits);
+                continue;
+            }
+            assert_eq!(x.to_f64(), f as f64);
+            assert_eq!(x.to_f16().to_f32(), f);
+            assert_eq!(x.to_bf16().to_f32(), f);
+            assert_eq!(f8e4m3::from_f32(f).to_bits(), bits);
+            assert_eq!(f8e4m3::from_f64(f as f64).to_bits(), bits);
+            assert_eq!(f8e4m3::from_f16(x.to_f16()).to_bits(), bits);
+            assert_eq!(f8e4m3::from_bf16(x.to_bf16()).to_bits(), bits);
+            assert_eq!(f.is_sign_negative(), x.is_sign_negative());
+            let subnormal = bits & 0x78 == 0 && bits & 0x07 != 0;
+            assert_eq!(x.classify() == FpCategory::Subnormal, subnormal);
+            assert_eq!(x.classify() == FpCategory::Zero, f == 0.0);
+        }
+    }
+
+    #[test]
+    fn test_round_to_nearest_even() {
+        for (low, high) in positive_values().zip(positive_values().skip(1)) {
+            let mid = (low.to_f64() + high.to_f64()) / 2.0;
+            let even = if low.0 & 1 == 0 { low } else { high };
+            for (value, expected) in [
+                (mid, even),
+                (mid - mid * 1e-6, low),
+                (mid + mid * 1e-6, high),
+            ] {
+                assert_eq!(f8e4m3::from_f64(value).to_bits(), expected.0, "{}", value);
+                assert_eq!(f8e4m3::from_f32(value as f32).to_bits(), expected.0);
+                assert_eq!(f8e4m3::from_f64(-value).to_bits(), expected.0 | 0x80);
+            }
+        }
+        // Halfway to the smallest subnormal rounds to even, which is zero
+        assert_eq!(f8e4m3::from_f32(0.0009765625).to_bits(), 0);
+        assert_eq!(f8e4m3::from_f32(0.0009766).to_bits(), 1);
+        assert_eq!(f8e4m3::from_f32(1e-30).to_bits(), 0);
+        assert_eq!(f8e4m3::from_f32(-1e-30).to_bits(), 0x80);
+    }
+
+    #[test]
+    fn test_overflow() {
+        // 464 is halfway between MAX and the next, unrepresentable, value and rounds to even
+        assert_eq!(f8e4m3::from_f32(464.0), f8e4m3::MAX);
+        assert_eq!(f8e4m3::from_f32(-464.0), f8e4m3::MIN);
+        assert!(f8e4m3::from_f32(464.5).is_nan());
+        assert!(f8e4m3::from_f64(f64::MAX).is_nan());
+        assert!(f8e4m3::from_f32(-1e10).is_sign_negative());
+        assert!(f8e4m3::from_f32(f32::INFINITY).is_nan());
+
+        assert_eq!(f8e4m3::from_f32_saturating(464.5), f8e4m3::MAX);
+        assert_eq!(f8e4m3::from_f16_saturating(f16::NEG_INFINITY), f8e4m3::MIN);
+        assert_eq!(f8e4m3::from_bf16_saturating(bf16::MAX), f8e4m3::MAX);
+        assert_eq!(f8e4m3::from_f64_saturating(-1e300), f8e4m3::MIN);
+        assert_eq!(f8e4m3::from_f32_saturating(f32::INFINITY), f8e4m3::MAX);
+        assert_eq!(f8e4m3::from_f64_saturating(f64::NEG_INFINITY), f8e4m3::MIN);
+        assert!(f8e4m3::from_f32_saturating(f32::NAN).is_nan());
+        assert!(f8e4m3::from_f64_saturating(-f64::NAN).is_sign_negative());
+    }
+
+    #[test]
+    fn test_comparisons() {
+        for a in positive_values().flat_map(|x| [x, -x]) {
+            for b in positive_values().flat_map(|x| [x, -x]) {
+                assert_eq!(a.partial_cmp(&b), a.to_f32().partial_cmp(&b.to_f32()));
+                assert_eq!(a == b, a.to_f32() == b.to_f32());
+            }
+            assert!(a != f8e4m3::NAN);
+            assert_eq!(a.partial_cmp(&-f8e4m3::NAN), None);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_formatting() {
+        assert_eq!(f8e4m3::MAX.to_string(), "450");
+        assert_eq!(f8e4m3::from_f32(0.1).to_string(), "0.1");
+        assert_eq!(f8e4m3::MIN_POSITIVE_SUBNORMAL.to_string(), "0.002");
+        assert_eq!(f8e4m3::NEG_ZERO.to_string(), "-0");
+        assert_eq!(f8e4m3::NAN.to_string(), "NaN");
+        assert_eq!(format!("{:?}", f8e4m3::ONE), "1.0");
+        assert_eq!(format!("{:e}", f8e4m3::from_f32(-3.0)), "-3e0");
+        assert_eq!(format!("{:.3}", f8e4m3::from_f32(0.1)), "0.102");
+        assert_eq!(format!("{:>6}", f8e4m3::ONE), "     1");
+        assert_eq!(format!("{:#04x}", f8e4m3::ONE), "0x38");
+
+        // The shortest digits must round back to the same value
+        for x in positive_values() {
+            let parsed: f32 = x.to_string().parse().unwrap();
+            assert_eq!(f8e4m3::from_f32(parsed).to_bits(), x.0, "{}", x);
+        }
+    }
+
+    #[quickcheck]
+    fn qc_from_f32_nearest(x: f32) -> bool {
+        let y = f8e4m3::from_f32(x);
+        if x.is_nan() || x.abs() > 464.0 {
+            return y.is_nan() && y.is_sign_negative() == x.is_sign_negative();
+        }
+        // No other value is closer
+        let error = (y.to_f64() - x as f64).abs();
+        positive_values()
+            .flat_map(|v| [v, -v])
+            .all(|v| (v.to_f64() - x as f64).abs() >= error)
+    }
+}
@@ -0,0 +1,746 @@
+#[cfg(not(target_arch = "spirv"))]
+use crate::fmt;
+use crate::{
+    bf16, f16,
+    rounding::{self, Rounding},
+    RoundingMode,
+};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt::{
+    Binary, Debug, Display, Error, Formatter, LowerExp, LowerHex, Octal, UpperExp, UpperHex,
+};
+use core::{cmp::Ordering, num::FpCategory, ops::Neg};
+#[cfg(feature = "zerocopy")]
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
+
+/// An 8-bit floating point type implementing the OCP [`FP8`] E5M2 format, also known as
+/// `float8_e5m2`.
+///
+/// The format has 1 sign bit, 5 exponent bits with a bias of 15 and 2 mantissa bits. It follows
+/// the IEEE 754 conventions, with infinities and NaN, and is exactly the upper half of the bits of
+/// an [`f16`] with the same value. It has the range of [`f16`] with less precision than
+/// [`f8e4m3`][crate::f8e4m3], which makes it a popular format for gradients when training machine
+/// learning models.
+///
+/// Like [`f16`], [`f8e5m2`] is a storage format and does not implement arithmetic. Converting to
+/// any of the wider float types is exact, so operations should be performed with [`f32`] or
+/// another wider type and the result converted back.
+///
+/// [`FP8`]: https://www.opencompute.org/documents/ocp-8-bit-floating-point-specification-ofp8-revision-1-0-2023-12-01-pdf-1
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+#[cfg_attr(feature = "bytemuck", derive(Zeroable, Pod))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned)
+)]
+pub struct f8e5m2(u8);
+
+impl f8e5m2 {
+    /// Constructs an [`f8e5m2`] value from the raw bits.
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: u8) -> f8e5m2 {
+        f8e5m2(bits)
+    }
+
+    /// Converts an [`f8e5m2`] into the underlying bit representation.
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> u8 {
+        self.0
+    }
+
+    /// Constructs an [`f8e5m2`] value from a 32-bit floating point value.
+    ///
+    /// The value is rounded to the nearest representable value, with ties to even. Values whose
+    /// rounded magnitude is larger than [`f8e5m2::MAX`] become ±∞. NaN values are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::f8e5m2;
+    /// assert_eq!(f8e5m2::from_f32(1.0), f8e5m2::ONE);
+    /// assert_eq!(f8e5m2::from_f32(0.3).to_f32(), 0.3125);
+    /// assert_eq!(f8e5m2::from_f32(1e5), f8e5m2::INFINITY);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_f32(value: f32) -> f8e5m2 {
+        let rounding = Rounding::Mode(RoundingMode::NearestEven);
+        f8e5m2::from_ieee16(rounding::f32_to_ieee_round(value, rounding, 2, 5))
+    }
+
+    /// Constructs an [`f8e5m2`] value from a 64-bit floating point value.
+    ///
+    /// The value is rounded directly to the nearest representable value, without rounding to
+    /// [`f32`] first. Values that are too large become ±∞, and NaN values are preserved.
+    #[inline]
+    #[must_use]
+    pub const fn from_f64(value: f64) -> f8e5m2 {
+        let rounding = Rounding::Mode(RoundingMode::NearestEven);
+        f8e5m2::from_ieee16(rounding::f64_to_ieee_round(value, rounding, 2, 5))
+    }
+
+    /// Constructs an [`f8e5m2`] value from an [`f16`] value, rounding to nearest like
+    /// [`from_f32`][Self::from_f32].
+    ///
+    /// Since both formats have the same exponent range, this only rounds away the low 8 bits of
+    /// the [`f16`] mantissa.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::{f16, f8e5m2};
+    /// assert_eq!(f8e5m2::from_f16(f16::PI).to_f32(), 3.0);
+    /// assert_eq!(f8e5m2::from_f16(f16::MAX), f8e5m2::INFINITY);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_f16(value: f16) -> f8e5m2 {
+        let rounding = Rounding::Mode(RoundingMode::NearestEven);
+        f8e5m2::from_ieee16(rounding::ieee16_to_ieee_round(
+            value.to_bits(),
+            10,
+            5,
+            rounding,
+            2,
+            5,
+        ))
+    }
+
+    /// Constructs an [`f8e5m2`] value from a [`bf16`] value, rounding to nearest like
+    /// [`from_f32`][Self::from_f32].
+    #[inline]
+    #[must_use]
+    pub const fn from_bf16(value: bf16) -> f8e5m2 {
+        // Exact, so this only rounds once
+        f8e5m2::from_f32(value.to_f32_const())
+    }
+
+    /// Constructs an [`f8e5m2`] value from a 32-bit floating point value, saturating values that
+    /// are too large to [`f8e5m2::MAX`] or [`f8e5m2::MIN`] instead of producing ±∞.
+    ///
+    /// This is the saturating conversion mode of the OCP specification, so unlike
+    /// [`f16::from_f32_saturating`][crate::f16::from_f32_saturating], ±∞ also saturate. Values are otherwise rounded to nearest,
+    /// and NaN values are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::f8e5m2;
+    /// assert_eq!(f8e5m2::from_f32_saturating(1e5), f8e5m2::MAX);
+    /// assert_eq!(f8e5m2::from_f32_saturating(f32::NEG_INFINITY), f8e5m2::MIN);
+    /// assert!(f8e5m2::from_f32_saturating(f32::NAN).is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_f32_saturating(value: f32) -> f8e5m2 {
+        f8e5m2::from_f32(value).saturate()
+    }
+
+    /// Constructs an [`f8e5m2`] value from a 64-bit floating point value, saturating values that
+    /// are too large to [`f8e5m2::MAX`] or [`f8e5m2::MIN`] instead of producing ±∞.
+    ///
+    /// See [`from_f32_saturating`][Self::from_f32_saturating].
+    #[inline]
+    #[must_use]
+    pub const fn from_f64_saturating(value: f64) -> f8e5m2 {
+        f8e5m2::from_f64(value).saturate()
+    }
+
+    /// Constructs an [`f8e5m2`] value from an [`f16`] value, saturating values that are too large
+    /// to [`f8e5m2::MAX`] or [`f8e5m2::MIN`] instead of producing ±∞.
+    ///
+    /// See [`from_f32_saturating`][Self::from_f32_saturating].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::{f16, f8e5m2};
+    /// assert_eq!(f8e5m2::from_f16_saturating(f16::MAX), f8e5m2::MAX);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_f16_saturating(value: f16) -> f8e5m2 {
+        f8e5m2::from_f16(value).saturate()
+    }
+
+    /// Constructs an [`f8e5m2`] value from a [`bf16`] value, saturating values that are too large
+    /// to [`f8e5m2::MAX`] or [`f8e5m2::MIN`] instead of producing ±∞.
+    ///
+    /// See [`from_f32_saturating`][Self::from_f32_saturating].
+    #[inline]
+    #[must_use]
+    pub const fn from_bf16_saturating(value: bf16) -> f8e5m2 {
+        f8e5m2::from_f32_saturating(value.to_f32_const())
+    }
+
+    // Moves the sign of a result of the 16-bit rounding functions into place
+    const fn from_ieee16(bits: u16) -> f8e5m2 {
+        f8e5m2(((bits >> 8) as u8 & 0x80) | (bits as u8 & 0x7F))
+    }
+
+    const fn saturate(self) -> f8e5m2 {
+        if self.is_infinite() {
+            f8e5m2(self.0 & 0x80 | f8e5m2::MAX.0)
+        } else {
+            self
+        }
+    }
+
+    /// Converts an [`f8e5m2`] value into an [`f32`] value.
+    ///
+    /// This conversion is lossless as all [`f8e5m2`] values can be represented exactly in
+    /// [`f32`].
+    #[inline]
+    #[must_use]
+    pub const fn to_f32(self) -> f32 {
+        self.to_f16().to_f32_const()
+    }
+
+    /// Converts an [`f8e5m2`] value into an [`f64`] value.
+    ///
+    /// This conversion is lossless as all [`f8e5m2`] values can be represented exactly in
+    /// [`f64`].
+    #[inline]
+    #[must_use]
+    pub const fn to_f64(self) -> f64 {
+        self.to_f16().to_f64_const()
+    }
+
+    /// Converts an [`f8e5m2`] value into an [`f16`] value.
+    ///
+    /// This conversion is lossless, and only appends 8 zero bits to the [`f8e5m2`] bits.
+    #[inline]
+    #[must_use]
+    pub const fn to_f16(self) -> f16 {
+        f16::from_bits((self.0 as u16) << 8)
+    }
+
+    /// Converts an [`f8e5m2`] value into a [`bf16`] value.
+    ///
+    /// This conversion is lossless as all [`f8e5m2`] values can be represented exactly in
+    /// [`bf16`].
+    #[inline]
+    #[must_use]
+    pub const fn to_bf16(self) -> bf16 {
+        bf16::from_f32_const(self.to_f32())
+    }
+
+    /// Returns `true` if this value is NaN and `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::f8e5m2;
+    /// assert!(f8e5m2::NAN.is_nan());
+    /// assert!(!f8e5m2::INFINITY.is_nan());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_nan(self) -> bool {
+        self.0 & 0x7F > 0x7C
+    }
+
+    /// Returns `true` if this value is ±∞ and `false` otherwise.
+    #[inline]
+    #[must_use]
+    pub const fn is_infinite(self) -> bool {
+        self.0 & 0x7F == 0x7C
+    }
+
+    /// Returns `true` if this number is neither infinite nor NaN.
+    #[inline]
+    #[must_use]
+    pub const fn is_finite(self) -> bool {
+        self.0 & 0x7C != 0x7C
+    }
+
+    /// Returns `true` if the number is neither zero, infinite, subnormal, or NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::f8e5m2;
+    /// assert!(f8e5m2::MIN_POSITIVE.is_normal());
+    /// assert!(f8e5m2::MAX.is_normal());
+    ///
+    /// assert!(!f8e5m2::ZERO.is_normal());
+    /// assert!(!f8e5m2::INFINITY.is_normal());
+    /// assert!(!f8e5m2::MAX_SUBNORMAL.is_normal());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_normal(self) -> bool {
+        let exp = self.0 & 0x7C;
+        exp != 0x7C && exp != 0
+    }
+
+    /// Returns the floating point category of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::num::FpCategory;
+    /// # use half::f8e5m2;
+    ///
+    /// assert_eq!(f8e5m2::from_f32(12.4).classify(), FpCategory::Normal);
+    /// assert_eq!(f8e5m2::NEG_INFINITY.classify(), FpCategory::Infinite);
+    /// ```
+    #[must_use]
+    pub const fn classify(self) -> FpCategory {
+        let exp = self.0 & 0x7C;
+        let man = self.0 & 0x03;
+        match (exp, man) {
+            (0, 0) => FpCategory::Zero,
+            (0, _) => FpCategory::Subnormal,
+            (0x7C, 0) => FpCategory::Infinite,
+            (0x7C, _) => FpCategory::Nan,
+            _ => FpCategory::Normal,
+        }
+    }
+
+    /// Returns `true` if and only if `self` has a positive sign, including `+0.0`, NaN with a
+    /// positive sign bit and +∞.
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_positive(self) -> bool {
+        self.0 & 0x80 == 0
+    }
+
+    /// Returns `true` if and only if `self` has a negative sign, including `-0.0`, NaN with a
+    /// negative sign bit and −∞.
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_negative(self) -> bool {
+        self.0 & 0x80 != 0
+    }
+
+    /// Returns a number composed of the magnitude of `self` and the sign of `sign`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::f8e5m2;
+    /// let f = f8e5m2::from_f32(3.0);
+    ///
+    /// assert_eq!(f.copysign(f8e5m2::NEG_ONE), f8e5m2::from_f32(-3.0));
+    /// assert_eq!((-f).copysign(f8e5m2::ONE), f);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn copysign(self, sign: f8e5m2) -> f8e5m2 {
+        f8e5m2((sign.0 & 0x80) | (self.0 & 0x7F))
+    }
+
+    /// Approximate number of [`f8e5m2`] significant digits in base 10
+    pub const DIGITS: u32 = 0;
+    /// [`f8e5m2`]
+    /// [machine epsilon](https://en.wikipedia.org/wiki/Machine_epsilon) value
+    ///
+    /// This is the difference between 1.0 and the next largest representable number.
+    pub const EPSILON: f8e5m2 = f8e5m2(0x34);
+    /// [`f8e5m2`] positive Infinity (+∞)
+    pub const INFINITY: f8e5m2 = f8e5m2(0x7C);
+    /// Number of [`f8e5m2`] significant digits in base 2
+    pub const MANTISSA_DIGITS: u32 = 3;
+    /// Largest finite [`f8e5m2`] value, 57344
+    pub const MAX: f8e5m2 = f8e5m2(0x7B);
+    /// Maximum possible [`f8e5m2`] power of 10 exponent
+    pub const MAX_10_EXP: i32 = 4;
+    /// Maximum possible [`f8e5m2`] power of 2 exponent
+    pub const MAX_EXP: i32 = 16;
+    /// Smallest finite [`f8e5m2`] value, -57344
+    pub const MIN: f8e5m2 = f8e5m2(0xFB);
+    /// Minimum possible normal [`f8e5m2`] power of 10 exponent
+    pub const MIN_10_EXP: i32 = -4;
+    /// One greater than the minimum possible normal [`f8e5m2`] power of 2 exponent
+    pub const MIN_EXP: i32 = -13;
+    /// Smallest positive normal [`f8e5m2`] value
+    pub const MIN_POSITIVE: f8e5m2 = f8e5m2(0x04);
+    /// [`f8e5m2`] Not a Number (NaN)
+    pub const NAN: f8e5m2 = f8e5m2(0x7E);
+    /// [`f8e5m2`] negative infinity (-∞)
+    pub const NEG_INFINITY: f8e5m2 = f8e5m2(0xFC);
+    /// The radix or base of the internal representation of [`f8e5m2`]
+    pub const RADIX: u32 = 2;
+
+    /// Minimum positive subnormal [`f8e5m2`] value
+    pub const MIN_POSITIVE_SUBNORMAL: f8e5m2 = f8e5m2(0x01);
+    /// Maximum subnormal [`f8e5m2`] value
+    pub const MAX_SUBNORMAL: f8e5m2 = f8e5m2(0x03);
+
+    /// [`f8e5m2`] 1
+    pub const ONE: f8e5m2 = f8e5m2(0x3C);
+    /// [`f8e5m2`] 0
+    pub const ZERO: f8e5m2 = f8e5m2(0x00);
+    /// [`f8e5m2`] -0
+    pub const NEG_ZERO: f8e5m2 = f8e5m2(0x80);
+    /// [`f8e5m2`] -1
+    pub const NEG_ONE: f8e5m2 = f8e5m2(0xBC);
+}
+
+impl From<f8e5m2> for f32 {
+    #[inline]
+    fn from(x: f8e5m2) -> f32 {
+        x.to_f32()
+    }
+}
+
+impl From<f8e5m2> for f64 {
+    #[inline]
+    fn from(x: f8e5m2) -> f64 {
+        x.to_f64()
+    }
+}
+
+impl From<f8e5m2> for f16 {
+    #[inline]
+    fn from(x: f8e5m2) -> f16 {
+        x.to_f16()
+    }
+}
+
+impl From<f8e5m2> for bf16 {
+    #[inline]
+    fn from(x: f8e5m2) -> bf16 {
+        x.to_bf16()
+    }
+}
+
+impl PartialEq for f8e5m2 {
+    fn eq(&self, other: &f8e5m2) -> bool {
+        if self.is_nan() || other.is_nan() {
+            false
+        } else {
+            (self.0 == other.0) || ((self.0 | other.0) & 0x7F == 0)
+        }
+    }
+}
+
+impl PartialOrd for f8e5m2 {
+    fn partial_cmp(&self, other: &f8e5m2) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            None
+        } else if (self.0 | other.0) & 0x7F == 0 {
+            Some(Ordering::Equal)
+        } else {
+            // Map the sign-magnitude bits to integers in the same order as the values
+            let key = |x: u8| {
+                if x & 0x80 != 0 {
+                    -((x & 0x7F) as i16)
+                } else {
+                    x as i16
+                }
+            };
+            Some(key(self.0).cmp(&key(other.0)))
+        }
+    }
+}
+
+impl Neg for f8e5m2 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self(self.0 ^ 0x80)
+    }
+}
+
+impl Neg for &f8e5m2 {
+    type Output = <f8e5m2 as Neg>::Output;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Neg::neg(*self)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl f8e5m2 {
+    fn shortest_decimal(self) -> Option<fmt::Decimal> {
+        let exp = (self.0 >> 2) & 0x1F;
+        let man = (self.0 & 0x03) as u64;
+        match exp {
+            0x1F => None,
+            0 if man == 0 => Some(fmt::Decimal { digits: 0, exp: 0 }),
+            0 => Some(fmt::shortest_decimal(man, -16, false)),
+            _ => Some(fmt::shortest_decimal(
+                man | 0x04,
+                exp as i32 - 17,
+                man == 0 && exp > 1,
+            )),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl Debug for f8e5m2 {
+    /// Formats the numeric value like [`Display`], but always includes a fractional part or
+    /// exponent like [`f32`] does.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self.shortest_decimal() {
+            Some(decimal) if f.precision().is_none() => {
+                fmt::write_debug(f, self.is_sign_negative(), decimal)
+            }
+            _ => Debug::fmt(&self.to_f32(), f),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl Display for f8e5m2 {
+    /// Formats the value with the fewest decimal digits that convert back to the same
+    /// [`f8e5m2`], or the exact value rounded to the given precision. The other formatting flags
+    /// work the same as for [`f32`].
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self.shortest_decimal() {
+            Some(decimal) if f.precision().is_none() => {
+                fmt::write_decimal(f, self.is_sign_negative(), decimal)
+            }
+            _ => Display::fmt(&self.to_f32(), f),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl LowerExp for f8e5m2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self.shortest_decimal() {
+            Some(decimal) if f.precision().is_none() => {
+                fmt::write_exp(f, self.is_sign_negative(), decimal, false)
+            }
+            _ => LowerExp::fmt(&self.to_f32(), f),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl UpperExp for f8e5m2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self.shortest_decimal() {
+            Some(decimal) if f.precision().is_none() => {
+                fmt::write_exp(f, self.is_sign_negative(), decimal, true)
+            }
+            _ => UpperExp::fmt(&self.to_f32(), f),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl Binary for f8e5m2 {
+    /// Formats the raw bits of the value like the same formatting of [`u8`] would. [`Octal`],
+    /// [`LowerHex`] and [`UpperHex`] do the same.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        Binary::fmt(&self.0, f)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl Octal for f8e5m2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        Octal::fmt(&self.0, f)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl LowerHex for f8e5m2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        LowerHex::fmt(&self.0, f)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl UpperHex for f8e5m2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        UpperHex::fmt(&self.0, f)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for f8e5m2 {
+    /// Generates any bit pattern, including NaNs, infinities and subnormals.
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        <u8 as arbitrary::Arbitrary>::arbitrary(u).map(Self)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <u8 as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+#[allow(clippy::float_cmp)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use quickcheck_macros::quickcheck;
+
+    // All finite non-negative values in increasing order
+    fn positive_values() -> impl Iterator<Item = f8e5m2> {
+        (0..0x7C).map(f8e5m2::from_bits)
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut bytes = [0u8; 64];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = (i * 37) as u8;
+        }
+        let mut u = Unstructured::new(&bytes);
+        let mut bits = Unstructured::new(&bytes);
+        while !u.is_empty() {
+            let x = f8e5m2::arbitrary(&mut u).unwrap();
+            assert_eq!(x.to_bits(), u8::arbitrary(&mut bits).unwrap());
+        }
+        assert_eq!(f8e5m2::size_hint(0), u8::size_hint(0));
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(f8e5m2::ONE.to_f32(), 1.0);
+        assert_eq!(f8e5m2::NEG_ONE.to_f32(), -1.0);
+        assert_eq!(f8e5m2::ZERO.to_f32(), 0.0);
+        assert!(f8e5m2::NEG_ZERO.to_f32().is_sign_negative());
+        assert_eq!(f8e5m2::MAX.to_f32(), 57344.0);
+        assert_eq!(f8e5m2::MIN.to_f32(), -57344.0);
+        assert_eq!(f8e5m2::EPSILON.to_f32(), 0.25);
+        assert_eq!(f8e5m2::MIN_POSITIVE.to_f32(), f16::MIN_POSITIVE.to_f32());
+        let min_subnormal = f32::from_bits((127 - 16) << 23);
+        assert_eq!(f8e5m2::MIN_POSITIVE_SUBNORMAL.to_f32(), min_subnormal);
+        assert_eq!(f8e5m2::MAX_SUBNORMAL.to_f32(), 3.0 * min_subnormal);
+        assert_eq!(f8e5m2::INFINITY.to_f32(), f32::INFINITY);
+        assert_eq!(f8e5m2::NEG_INFINITY.to_f32(), f32::NEG_INFINITY);
+        assert!(f8e5m2::NAN.to_f32().is_nan());
+    }
+
+    #[test]
+    fn test_roundtrip_all() {
+        for bits in 0..=u8::MAX {
+            let x = f8e5m2::from_bits(bits);
+            let f = x.to_f32();
+            assert_eq!(x.to_f16().to_bits(), (bits as u16) << 8);
+            assert_eq!(f.is_sign_negative(), x.is_sign_negative());
+            if x.is_nan() {
+                assert!(f.is_nan() && x.to_f64().is_nan() && x.to_bf16().is_nan());
+                assert!(f8e5m2::from_f32(f).is_nan());
+                assert!(f8e5m2::from_f16(x.to_f16()).is_nan());
+                continue;
+            }
+            assert_eq!(x.to_f64(), f as f64);
+            assert_eq!(x.to_bf16().to_f32(), f);
+            assert_eq!(f8e5m2::from_f32(f).to_bits(), bits);
+            assert_eq!(f8e5m2::from_f64(f as f64).to_bits(), bits);
+            assert_eq!(f8e5m2::from_f16(x.to_f16()).to_bits(), bits);
+            assert_eq!(f8e5m2::from_bf16(x.to_bf16()).to_bits(), bits);
+            assert_eq!(x.classify(), x.to_f16().classify(), "{:#x}", bits);
+        }
+    }
+
+    #[test]
+    fn test_round_to_nearest_even() {
+        for (low, high) in positive_values().zip(positive_values().skip(1)) {
+            let mid = (low.to_f64() + high.to_f64()) / 2.0;
+            let even = if low.0 & 1 == 0 { low } else { high };
+            for (value, expected) in [
+                (mid, even),
+                (mid - mid * 1e-6, low),
+                (mid + mid * 1e-6, high),
+            ] {
+                assert_eq!(f8e5m2::from_f64(value).to_bits(), expected.0, "{}", value);
+                assert_eq!(f8e5m2::from_f32(value as f32).to_bits(), expected.0);
+                assert_eq!(f8e5m2::from_f64(-value).to_bits(), expected.0 | 0x80);
+            }
+            // The midpoint is exact in f16, but the values next to it are not
+            assert_eq!(f8e5m2::from_f16(f16::from_f64(mid)).to_bits(), even.0);
+        }
+        // Halfway to the smallest subnormal rounds to even, which is zero
+        assert_eq!(
+            f8e5m2::from_f32(f32::from_bits((127 - 17) << 23)).to_bits(),
+            0
+        );
+        assert_eq!(f8e5m2::from_f32(0.0000077).to_bits(), 1);
+        assert_eq!(f8e5m2::from_f32(-1e-30).to_bits(), 0x80);
+    }
+
+    #[test]
+    fn test_overflow() {
+        // 61440 is halfway between MAX and the next power of two, and rounds to even
+        assert_eq!(f8e5m2::from_f32(61440.0), f8e5m2::INFINITY);
+        assert_eq!(f8e5m2::from_f32(61439.0), f8e5m2::MAX);
+        assert_eq!(f8e5m2::from_f64(-1e300), f8e5m2::NEG_INFINITY);
+        assert_eq!(f8e5m2::from_f16(f16::MAX), f8e5m2::INFINITY);
+
+        assert_eq!(f8e5m2::from_f32_saturating(61440.0), f8e5m2::MAX);
+        assert_eq!(f8e5m2::from_f64_saturating(-1e300), f8e5m2::MIN);
+        assert_eq!(f8e5m2::from_f16_saturating(-f16::MAX), f8e5m2::MIN);
+        assert_eq!(f8e5m2::from_f32_saturating(f32::INFINITY), f8e5m2::MAX);
+        assert_eq!(f8e5m2::from_f16_saturating(f16::NEG_INFINITY), f8e5m2::MIN);
+        assert_eq!(f8e5m2::from_bf16_saturating(bf16::MAX), f8e5m2::MAX);
+        assert_eq!(
+            f8e5m2::from_bf16_saturating(bf16::NEG_INFINITY),
+            f8e5m2::MIN
+        );
+        assert!(f8e5m2::from_f32_saturating(f32::NAN).is_nan());
+        assert!(f8e5m2::from_f64_saturating(-f64::NAN).is_sign_negative());
+    }
+
+    #[test]
+    fn test_comparisons() {
+        let all = || {
+            positive_values()
+                .chain([f8e5m2::INFINITY])
+                .flat_map(|x| [x, -x])
+        };
+        for a in all() {
+            for b in all() {
+                assert_eq!(a.partial_cmp(&b), a.to_f32().partial_cmp(&b.to_f32()));
+                assert_eq!(a == b, a.to_f32() == b.to_f32());
+            }
+            assert!(a != f8e5m2::NAN);
+            assert_eq!(a.partial_cmp(&-f8e5m2::NAN), None);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_formatting() {
+        assert_eq!(f8e5m2::MAX.to_string(), "60000");
+        assert_eq!(f8e5m2::from_f32(0.1).to_string(), "0.09");
+        assert_eq!(f8e5m2::INFINITY.to_string(), "inf");
+        assert_eq!(f8e5m2::NAN.to_string(), "NaN");
+        assert_eq!(format!("{:?}", f8e5m2::ONE), "1.0");
+        assert_eq!(format!("{:e}", f8e5m2::from_f32(-3.0)), "-3e0");
+        assert_eq!(format!("{:08b}", f8e5m2::ONE), "00111100");
+
+        // The shortest digits must round back to the same value
+        for x in positive_values() {
+            let parsed: f32 = x.to_string().parse().unwrap();
+            assert_eq!(f8e5m2::from_f32(parsed).to_bits(), x.0, "{}", x);
+        }
+    }
+
+    #[quickcheck]
+    fn qc_from_f32_nearest(x: f32) -> bool {
+        let y = f8e5m2::from_f32(x);
+        if x.is_nan() {
+            return y.is_nan() && y.is_sign_negative() == x.is_sign_negative();
+        }
+        if x.abs() >= 61440.0 {
+            return y.is_infinite() && y.is_sign_negative() == x.is_sign_negative();
+        }
+        // No other value is closer
+        let error = (y.to_f64() - x as f64).abs();
+        positive_values()
+            .flat_map(|v| [v, -v])
+            .all(|v| (v.to_f64() - x as f64).abs() >= error)
+    }
+}
@@ -0,0 +1,147 @@
+//! Error types for fallible conversions and parsing.
+
+use core::fmt::{Display, Formatter};
+
+/// The error type returned when a checked conversion to [`f16`][crate::f16] fails because the
+/// value cannot be represented exactly.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::prelude::*;
+/// use std::convert::TryFrom;
+///
+/// assert!(f16::try_from(0.5f32).is_ok());
+/// let err = f16::try_from(0.1f32).unwrap_err();
+/// assert_eq!(err.to_string(), "value is not exactly representable in the target format");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromFloatError {
+    kind: TryFromFloatErrorKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TryFromFloatErrorKind {
+    Overflow,
+    Inexact,
+}
+
+impl TryFromFloatError {
+    pub(crate) const fn overflow() -> Self {
+        Self {
+            kind: TryFromFloatErrorKind::Overflow,
+        }
+    }
+
+    pub(crate) const fn inexact() -> Self {
+        Self {
+            kind: TryFromFloatErrorKind::Inexact,
+        }
+    }
+
+    /// Returns `true` if the conversion failed because the value is outside the finite range of
+    /// the target format, rather than because it would have been rounded.
+    #[inline]
+    #[must_use]
+    pub const fn is_overflow(&self) -> bool {
+        matches!(self.kind, TryFromFloatErrorKind::Overflow)
+    }
+}
+
+impl Display for TryFromFloatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self.kind {
+            TryFromFloatErrorKind::Overflow => "value is out of range of the target format",
+            TryFromFloatErrorKind::Inexact => {
+                "value is not exactly representable in the target format"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromFloatError {}
+
+/// The error type returned by [`f16::parse_strict`][crate::f16::parse_strict] and
+/// [`bf16::parse_strict`][crate::bf16::parse_strict].
+///
+/// Besides strings that are not valid floats, this also reports finite values that are out of
+/// range of the target format, which [`FromStr`][core::str::FromStr] would silently round to
+/// infinity or zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::prelude::*;
+/// let err = f16::parse_strict("1e5").unwrap_err();
+/// assert!(err.is_overflow());
+/// assert_eq!(err.to_string(), "number is too large for the target format");
+/// assert!(f16::parse_strict("1e-10").unwrap_err().is_underflow());
+/// assert_eq!(f16::parse_strict("1.5x").unwrap_err().to_string(), "invalid float literal");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ParseErrorKind {
+    Empty,
+    Invalid,
+    Overflow,
+    Underflow,
+}
+
+impl ParseError {
+    pub(crate) fn syntax(src: &str) -> Self {
+        Self {
+            kind: if src.is_empty() {
+                ParseErrorKind::Empty
+            } else {
+                ParseErrorKind::Invalid
+            },
+        }
+    }
+
+    pub(crate) const fn overflow() -> Self {
+        Self {
+            kind: ParseErrorKind::Overflow,
+        }
+    }
+
+    pub(crate) const fn underflow() -> Self {
+        Self {
+            kind: ParseErrorKind::Underflow,
+        }
+    }
+
+    /// Returns `true` if the string is a valid float, but its magnitude is too large for the
+    /// target format and would round to infinity.
+    #[inline]
+    #[must_use]
+    pub const fn is_overflow(&self) -> bool {
+        matches!(self.kind, ParseErrorKind::Overflow)
+    }
+
+    /// Returns `true` if the string is a valid nonzero float, but its magnitude is too small for
+    /// the target format and would round to zero.
+    #[inline]
+    #[must_use]
+    pub const fn is_underflow(&self) -> bool {
+        matches!(self.kind, ParseErrorKind::Underflow)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self.kind {
+            ParseErrorKind::Empty => "cannot parse float from empty string",
+            ParseErrorKind::Invalid => "invalid float literal",
+            ParseErrorKind::Overflow => "number is too large for the target format",
+            ParseErrorKind::Underflow => "number is too small for the target format",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
@@ -0,0 +1,215 @@
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt::{Debug, Error, Formatter};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
+
+/// A set of the IEEE 754 floating point exceptions raised by an operation.
+///
+/// Functions such as [`f16::from_f32_with_flags`] and [`softfloat::add_with_flags`] return the
+/// exceptions raised while computing their result. Like the sticky status flags of a floating
+/// point unit, the sets of several operations can be accumulated with `|=` and checked at the end.
+///
+/// [`UNDERFLOW`][Self::UNDERFLOW] is raised when the exact result is nonzero and smaller in
+/// magnitude than the smallest normal number (tininess before rounding), and the result is
+/// inexact.
+///
+/// # Examples
+///
+/// ```rust
+/// use half::{f16, softfloat, ExceptionFlags};
+///
+/// let mut flags = ExceptionFlags::empty();
+/// let (x, raised) = f16::from_f32_with_flags(0.1);
+/// flags |= raised;
+/// let (y, raised) = softfloat::mul_with_flags(x, f16::MAX);
+/// flags |= raised;
+///
+/// assert!(y.is_finite());
+/// assert_eq!(flags, ExceptionFlags::INEXACT);
+///
+/// let (_, raised) = softfloat::div_with_flags(f16::ONE, f16::ZERO);
+/// assert!(raised.contains(ExceptionFlags::DIVIDE_BY_ZERO));
+/// ```
+///
+/// [`f16::from_f32_with_flags`]: crate::f16::from_f32_with_flags
+/// [`softfloat::add_with_flags`]: crate::softfloat::add_with_flags
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ExceptionFlags(u8);
+
+impl ExceptionFlags {
+    /// The operation has no meaningful result, such as `0 / 0` or `∞ - ∞`, or an operand was a
+    /// signaling NaN. The result is NaN.
+    pub const INVALID: ExceptionFlags = ExceptionFlags(1);
+    /// A finite nonzero value was divided by zero, giving an infinite result.
+    pub const DIVIDE_BY_ZERO: ExceptionFlags = ExceptionFlags(1 << 1);
+    /// The rounded result is too large to be finite, and was rounded to infinity.
+    pub const OVERFLOW: ExceptionFlags = ExceptionFlags(1 << 2);
+    /// The result is tiny and inexact.
+    pub const UNDERFLOW: ExceptionFlags = ExceptionFlags(1 << 3);
+    /// The result had to be rounded.
+    pub const INEXACT: ExceptionFlags = ExceptionFlags(1 << 4);
+
+    const NAMES: [(ExceptionFlags, &'static str); 5] = [
+        (Self::INVALID, "INVALID"),
+        (Self::DIVIDE_BY_ZERO, "DIVIDE_BY_ZERO"),
+        (Self::OVERFLOW, "OVERFLOW"),
+        (Self::UNDERFLOW, "UNDERFLOW"),
+        (Self::INEXACT, "INEXACT"),
+    ];
+
+    /// Returns the set without any exceptions.
+    #[inline]
+    #[must_use]
+    pub const fn empty() -> ExceptionFlags {
+        ExceptionFlags(0)
+    }
+
+    /// Returns the set of all exceptions.
+    #[inline]
+    #[must_use]
+    pub const fn all() -> ExceptionFlags {
+        ExceptionFlags(0x1F)
+    }
+
+    /// Returns the bits of the set, with the exceptions in the order of the constants from the
+    /// least significant bit.
+    #[inline]
+    #[must_use]
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Returns `true` if no exceptions are in the set.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if all exceptions of `other` are in the set.
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: ExceptionFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the exceptions that are in either set.
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: ExceptionFlags) -> ExceptionFlags {
+        ExceptionFlags(self.0 | other.0)
+    }
+
+    /// Returns the exceptions that are in both sets.
+    #[inline]
+    #[must_use]
+    pub const fn intersection(self, other: ExceptionFlags) -> ExceptionFlags {
+        ExceptionFlags(self.0 & other.0)
+    }
+}
+
+// The exceptions raised by converting `value` to `result`, where `min_positive` is the smallest
+// normal number of the result type. Both values are exact, and `signaling` tells whether `value`
+// is a signaling NaN, which the conversion to `f64` may have quieted.
+pub(crate) fn conversion_flags(
+    value: f64,
+    signaling: bool,
+    result: f64,
+    min_positive: f64,
+) -> ExceptionFlags {
+    if signaling {
+        ExceptionFlags::INVALID
+    } else if value.is_nan() || value == result {
+        ExceptionFlags::empty()
+    } else if result.is_infinite() && value.is_finite() {
+        ExceptionFlags::OVERFLOW.union(ExceptionFlags::INEXACT)
+    } else if value != 0.0 && value < min_positive && value > -min_positive {
+        ExceptionFlags::UNDERFLOW.union(ExceptionFlags::INEXACT)
+    } else {
+        ExceptionFlags::INEXACT
+    }
+}
+
+impl BitOr for ExceptionFlags {
+    type Output = ExceptionFlags;
+
+    #[inline]
+    fn bitor(self, rhs: ExceptionFlags) -> ExceptionFlags {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for ExceptionFlags {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: ExceptionFlags) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitAnd for ExceptionFlags {
+    type Output = ExceptionFlags;
+
+    #[inline]
+    fn bitand(self, rhs: ExceptionFlags) -> ExceptionFlags {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for ExceptionFlags {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: ExceptionFlags) {
+        *self = self.intersection(rhs);
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl Debug for ExceptionFlags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str("ExceptionFlags(")?;
+        let mut names = Self::NAMES
+            .iter()
+            .filter(|(flag, _)| self.contains(*flag))
+            .map(|(_, name)| name);
+        match names.next() {
+            Some(first) => {
+                f.write_str(first)?;
+                for name in names {
+                    write!(f, " | {}", name)?;
+                }
+            }
+            None => f.write_str("empty")?,
+        }
+        f.write_str(")")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_set_operations() {
+        let mut flags = ExceptionFlags::empty();
+        assert!(flags.is_empty());
+        flags |= ExceptionFlags::INEXACT;
+        flags |= ExceptionFlags::OVERFLOW;
+        assert!(flags.contains(ExceptionFlags::INEXACT | ExceptionFlags::OVERFLOW));
+        assert!(!flags.contains(ExceptionFlags::INVALID));
+        assert_eq!(flags & ExceptionFlags::OVERFLOW, ExceptionFlags::OVERFLOW);
+        assert_eq!(flags.bits(), 0x14);
+        assert!(ExceptionFlags::all().contains(flags));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_debug() {
+        use std::format;
+        assert_eq!(
+            format!("{:?}", ExceptionFlags::INEXACT | ExceptionFlags::UNDERFLOW),
+            "ExceptionFlags(UNDERFLOW | INEXACT)"
+        );
+        assert_eq!(
+            format!("{:?}", ExceptionFlags::empty()),
+            "ExceptionFlags(empty)"
+        );
+    }
+}
@@ -0,0 +1,170 @@
+//! Contains `extern "C"` conversion functions, so C and C++ code can link the conversions of this
+//! crate directly.
+//!
+//! This module is only available with the `ffi` feature. The functions are exported unmangled
+//! under the names below, so a static or dynamic library built from a crate depending on `half`
+//! with this feature provides them to C. [`f16`] and [`bf16`] are `#[repr(transparent)]` wrappers
+//! around [`u16`], which is guaranteed to have the same size, alignment and calling convention as
+//! the C `uint16_t` type, so they are declared with that type on the C side:
+//!
+//! ```c
+//! #include <stddef.h>
+//! #include <stdint.h>
+//!
+//! float half_to_float(uint16_t value);
+//! uint16_t float_to_half(float value);
+//! double half_to_double(uint16_t value);
+//! uint16_t double_to_half(double value);
+//! void half_to_float_array(const uint16_t *src, float *dst, size_t len);
+//! void float_to_half_array(const float *src, uint16_t *dst, size_t len);
+//!
+//! float bfloat16_to_float(uint16_t value);
+//! uint16_t float_to_bfloat16(float value);
+//! void bfloat16_to_float_array(const uint16_t *src, float *dst, size_t len);
+//! void float_to_bfloat16_array(const float *src, uint16_t *dst, size_t len);
+//! ```
+//!
+//! The conversions are the same as [`f16::from_f32`][crate::f16::from_f32],
+//! [`f16::to_f32`][crate::f16::to_f32] and the slice conversions of [`HalfFloatSliceExt`],
+//! including the use of hardware instructions with the `use-intrinsics` feature. Values are
+//! rounded to nearest, ties to even.
+
+use crate::{bf16, f16, slice::HalfFloatSliceExt};
+use core::slice;
+
+/// Converts the bits of a [`f16`] value into a `float`.
+#[no_mangle]
+pub extern "C" fn half_to_float(value: f16) -> f32 {
+    value.to_f32()
+}
+
+/// Converts a `float` into the bits of the nearest [`f16`] value.
+#[no_mangle]
+pub extern "C" fn float_to_half(value: f32) -> f16 {
+    f16::from_f32(value)
+}
+
+/// Converts the bits of a [`f16`] value into a `double`.
+#[no_mangle]
+pub extern "C" fn half_to_double(value: f16) -> f64 {
+    value.to_f64()
+}
+
+/// Converts a `double` into the bits of the nearest [`f16`] value, rounding only once.
+#[no_mangle]
+pub extern "C" fn double_to_half(value: f64) -> f16 {
+    f16::from_f64(value)
+}
+
+/// Converts `len` [`f16`] values at `src` into `float` values at `dst`, with the vectorized
+/// [`HalfFloatSliceExt::convert_to_f32_slice`].
+///
+/// # Safety
+///
+/// Unless `len` is zero, `src` must be valid for reading `len` values and `dst` for writing `len`
+/// values, both properly aligned, and the two ranges must not overlap.
+#[no_mangle]
+pub unsafe extern "C" fn half_to_float_array(src: *const f16, dst: *mut f32, len: usize) {
+    if len == 0 {
+        return;
+    }
+    slice::from_raw_parts(src, len).convert_to_f32_slice(slice::from_raw_parts_mut(dst, len));
+}
+
+/// Converts `len` `float` values at `src` into [`f16`] values at `dst`, with the vectorized
+/// [`HalfFloatSliceExt::convert_from_f32_slice`].
+///
+/// # Safety
+///
+/// Unless `len` is zero, `src` must be valid for reading `len` values and `dst` for writing `len`
+/// values, both properly aligned, and the two ranges must not overlap.
+#[no_mangle]
+pub unsafe extern "C" fn float_to_half_array(src: *const f32, dst: *mut f16, len: usize) {
+    if len == 0 {
+        return;
+    }
+    slice::from_raw_parts_mut(dst, len).convert_from_f32_slice(slice::from_raw_parts(src, len));
+}
+
+/// Converts the bits of a [`bf16`] value into a `float`.
+#[no_mangle]
+pub extern "C" fn bfloat16_to_float(value: bf16) -> f32 {
+    value.to_f32()
+}
+
+/// Converts a `float` into the bits of the nearest [`bf16`] value.
+#[no_mangle]
+pub extern "C" fn float_to_bfloat16(value: f32) -> bf16 {
+    bf16::from_f32(value)
+}
+
+/// Converts `len` [`bf16`] values at `src` into `float` values at `dst`.
+///
+/// # Safety
+///
+/// Unless `len` is zero, `src` must be valid for reading `len` values and `dst` for writing `len`
+/// values, both properly aligned, and the two ranges must not overlap.
+#[no_mangle]
+pub unsafe extern "C" fn bfloat16_to_float_array(src: *const bf16, dst: *mut f32, len: usize) {
+    if len == 0 {
+        return;
+    }
+    slice::from_raw_parts(src, len).convert_to_f32_slice(slice::from_raw_parts_mut(dst, len));
+}
+
+/// Converts `len` `float` values at `src` into [`bf16`] values at `dst`.
+///
+/// # Safety
+///
+/// Unless `len` is zero, `src` must be valid for reading `len` values and `dst` for writing `len`
+/// values, both properly aligned, and the two ranges must not overlap.
+#[no_mangle]
+pub unsafe extern "C" fn float_to_bfloat16_array(src: *const f32, dst: *mut bf16, len: usize) {
+    if len == 0 {
+        return;
+    }
+    slice::from_raw_parts_mut(dst, len).convert_from_f32_slice(slice::from_raw_parts(src, len));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::ptr;
+
+    #[test]
+    fn scalar() {
+        assert_eq!(half_to_float(f16::from_bits(0x3C00)), 1.0);
+        assert_eq!(float_to_half(-2.0).to_bits(), 0xC000);
+        assert_eq!(half_to_double(f16::MAX), 65504.0);
+        assert_eq!(double_to_half(0.1), f16::from_f64(0.1));
+        assert_eq!(bfloat16_to_float(bf16::from_bits(0x3F80)), 1.0);
+        assert_eq!(float_to_bfloat16(3.0).to_bits(), 0x4040);
+    }
+
+    #[test]
+    fn arrays() {
+        let floats = [1.0, -0.5, 65504.0, 1e-7, 3.25];
+        let mut halves = [f16::ZERO; 5];
+        let mut back = [0f32; 5];
+        unsafe {
+            float_to_half_array(floats.as_ptr(), halves.as_mut_ptr(), 5);
+            half_to_float_array(halves.as_ptr(), back.as_mut_ptr(), 5);
+        }
+        assert_eq!(halves, floats.map(f16::from_f32));
+        assert_eq!(back, halves.map(f16::to_f32));
+
+        let mut bf16s = [bf16::ZERO; 5];
+        unsafe {
+            float_to_bfloat16_array(floats.as_ptr(), bf16s.as_mut_ptr(), 5);
+            bfloat16_to_float_array(bf16s.as_ptr(), back.as_mut_ptr(), 5);
+        }
+        assert_eq!(bf16s, floats.map(bf16::from_f32));
+        assert_eq!(back, bf16s.map(bf16::to_f32));
+
+        // Null pointers are allowed for empty arrays
+        unsafe {
+            half_to_float_array(ptr::null(), ptr::null_mut(), 0);
+            float_to_bfloat16_array(ptr::null(), ptr::null_mut(), 0);
+        }
+    }
+}
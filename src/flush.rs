@@ -0,0 +1,69 @@
+/// How conversions such as [`f16::from_f32_flush`] treat subnormal values.
+///
+/// Many GPUs and DSPs, and CPUs running with the x86 `FTZ`/`DAZ` or Arm `FZ` control bits set,
+/// replace subnormal values with a zero of the same sign instead of implementing gradual
+/// underflow. The modes other than [`FlushMode::Preserve`] reproduce that behavior, so values
+/// computed on those devices can be matched bit for bit.
+///
+/// [`f16::from_f32_flush`]: crate::f16::from_f32_flush
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(not(target_arch = "spirv"), derive(Debug))]
+pub enum FlushMode {
+    /// Keep subnormal inputs and results, as IEEE 754 requires.
+    Preserve,
+    /// Flush subnormal results to zero, a.k.a. FTZ. Whether a result is subnormal is decided
+    /// after rounding, so a value that rounds up to the smallest normal number is kept.
+    FlushToZero,
+    /// Treat subnormal inputs as zero, a.k.a. DAZ.
+    DenormalsAreZero,
+    /// Treat subnormal inputs as zero and flush subnormal results to zero. This is what most
+    /// hardware does when flushing is enabled.
+    Both,
+}
+
+impl FlushMode {
+    #[inline]
+    pub(crate) const fn flushes_inputs(self) -> bool {
+        matches!(self, FlushMode::DenormalsAreZero | FlushMode::Both)
+    }
+
+    #[inline]
+    pub(crate) const fn flushes_outputs(self) -> bool {
+        matches!(self, FlushMode::FlushToZero | FlushMode::Both)
+    }
+}
+
+impl Default for FlushMode {
+    #[inline]
+    fn default() -> Self {
+        FlushMode::Preserve
+    }
+}
+
+// Returns the signed zero in place of `bits` if its exponent field is zero. Written as a mask so
+// the slice kernels built on it vectorize.
+#[inline(always)]
+pub(crate) const fn flush_bits(bits: u16, exp_mask: u16) -> u16 {
+    let keep = if bits & exp_mask == 0 { 0x8000 } else { 0xFFFF };
+    bits & keep
+}
+
+#[inline]
+pub(crate) fn flush_f32(value: f32) -> f32 {
+    let bits = value.to_bits();
+    if bits & 0x7F80_0000 == 0 {
+        f32::from_bits(bits & 0x8000_0000)
+    } else {
+        value
+    }
+}
+
+#[inline]
+pub(crate) fn flush_f64(value: f64) -> f64 {
+    let bits = value.to_bits();
+    if bits & 0x7FF0_0000_0000_0000 == 0 {
+        f64::from_bits(bits & 0x8000_0000_0000_0000)
+    } else {
+        value
+    }
+}
@@ -0,0 +1,384 @@
+//! Decimal formatting shared by the half-precision types.
+
+use core::{
+    cmp::Ordering,
+    fmt::{Formatter, Result, Write},
+};
+
+// A positive decimal number `digits * 10^exp`, without trailing zeros in `digits`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Decimal {
+    pub(crate) digits: u64,
+    pub(crate) exp: i32,
+}
+
+// An unsigned integer wide enough to scale any half-precision value by powers of two and ten
+// exactly, stored as 32-bit limbs with the least significant first.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Big([u32; 8]);
+
+impl Big {
+    fn new(value: u128) -> Big {
+        let mut limbs = [0; 8];
+        for (i, limb) in limbs.iter_mut().take(4).enumerate() {
+            *limb = (value >> (32 * i)) as u32;
+        }
+        Big(limbs)
+    }
+
+    fn low_u128(self) -> u128 {
+        (0..4).fold(0, |acc, i| acc | (self.0[i] as u128) << (32 * i))
+    }
+
+    fn mul_pow10(mut self, exp: u32) -> Big {
+        for _ in 0..exp {
+            let mut carry = 0u64;
+            for limb in self.0.iter_mut() {
+                let x = *limb as u64 * 10 + carry;
+                *limb = x as u32;
+                carry = x >> 32;
+            }
+        }
+        self
+    }
+
+    fn div_pow10(mut self, exp: u32) -> Big {
+        for _ in 0..exp {
+            let mut rem = 0u64;
+            for limb in self.0.iter_mut().rev() {
+                let x = rem << 32 | *limb as u64;
+                *limb = (x / 10) as u32;
+                rem = x % 10;
+            }
+        }
+        self
+    }
+
+    fn shl(self, shift: u32) -> Big {
+        let (limbs, bits) = ((shift / 32) as usize, shift % 32);
+        let mut result = [0; 8];
+        for i in limbs..8 {
+            let x = (self.0[i - limbs] as u64) << bits;
+            result[i] |= x as u32;
+            if i + 1 < 8 {
+                result[i + 1] |= (x >> 32) as u32;
+            }
+        }
+        Big(result)
+    }
+
+    fn shr(self, shift: u32) -> Big {
+        let (limbs, bits) = ((shift / 32) as usize, shift % 32);
+        let mut result = [0; 8];
+        for i in limbs..8 {
+            let x = ((self.0[i] as u64) << 32) >> bits;
+            result[i - limbs] |= (x >> 32) as u32;
+            if i > limbs {
+                result[i - limbs - 1] |= x as u32;
+            }
+        }
+        Big(result)
+    }
+}
+
+impl Ord for Big {
+    fn cmp(&self, other: &Big) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl PartialOrd for Big {
+    fn partial_cmp(&self, other: &Big) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// `value * 2^bin_exp` compared to `digits * 10^exp`, using exact integer arithmetic.
+fn cmp_scaled(value: u128, bin_exp: i32, digits: u128, exp: i32) -> Ordering {
+    let (mut lhs, mut rhs) = (Big::new(value), Big::new(digits));
+    if bin_exp >= 0 {
+        lhs = lhs.shl(bin_exp as u32);
+    } else {
+        rhs = rhs.shl(-bin_exp as u32);
+    }
+    if exp >= 0 {
+        rhs = rhs.mul_pow10(exp as u32);
+    } else {
+        lhs = lhs.mul_pow10(-exp as u32);
+    }
+    lhs.cmp(&rhs)
+}
+
+// The largest `digits` with `digits * 10^exp <= value * 2^bin_exp`.
+fn floor_scaled(value: u128, bin_exp: i32, exp: i32) -> u128 {
+    let mut value = Big::new(value);
+    if exp < 0 {
+        value = value.mul_pow10(-exp as u32);
+    }
+    value = if bin_exp >= 0 {
+        value.shl(bin_exp as u32)
+    } else {
+        value.shr(-bin_exp as u32)
+    };
+    if exp > 0 {
+        value = value.div_pow10(exp as u32);
+    }
+    value.low_u128()
+}
+
+/// Finds the shortest decimal that rounds back to the binary floating point value
+/// `man * 2^exp`, picking the closest one if there are several with the same number of digits.
+///
+/// `man` is the significand including the hidden bit and must be nonzero. If `asymmetric` is set,
+/// the value is a power of two whose next smaller neighbour is only half as far away as its next
+/// larger one, as happens at the bottom of each binade above the subnormals.
+///
+/// The arithmetic is exact, with enough bits for the exponent ranges of both [`f16`][crate::f16]
+/// and [`bf16`][crate::bf16].
+pub(crate) fn shortest_decimal(man: u64, exp: i32, asymmetric: bool) -> Decimal {
+    // Work in quarter units of the last place so the rounding boundaries are integers, with
+    // values represented as `n * 2^bin_exp`.
+    let even = man & 1 == 0;
+    let man = man as u128;
+    let (value, low, high) = (
+        4 * man,
+        4 * man - if asymmetric { 1 } else { 2 },
+        4 * man + 2,
+    );
+    let bin_exp = exp - 2;
+
+    // Decimal exponent of the leading digit
+    let mut leading = 0;
+    while cmp_scaled(value, bin_exp, 1, leading + 1) != Ordering::Less {
+        leading += 1;
+    }
+    while cmp_scaled(value, bin_exp, 1, leading) == Ordering::Less {
+        leading -= 1;
+    }
+
+    // Rounding to nearest even accepts values exactly on the boundary if the significand is even
+    let above_low = |digits, exp| match cmp_scaled(low, bin_exp, digits, exp) {
+        Ordering::Less => true,
+        Ordering::Equal => even,
+        Ordering::Greater => false,
+    };
+    let below_high = |digits, exp| match cmp_scaled(high, bin_exp, digits, exp) {
+        Ordering::Greater => true,
+        Ordering::Equal => even,
+        Ordering::Less => false,
+    };
+
+    let mut count = 1;
+    let (digits, exp) = loop {
+        let exp = leading - count + 1;
+        let down = floor_scaled(value, bin_exp, exp);
+        if cmp_scaled(value, bin_exp, down, exp) == Ordering::Equal {
+            break (down, exp);
+        }
+
+        // Out of the two closest candidates, take whichever is in the rounding interval,
+        // preferring the nearest one, then the even one.
+        let up = down + 1;
+        match (above_low(down, exp), below_high(up, exp)) {
+            (true, true) => match cmp_scaled(2 * value, bin_exp, down + up, exp) {
+                Ordering::Less => break (down, exp),
+                Ordering::Greater => break (up, exp),
+                Ordering::Equal => break (if down & 1 == 0 { down } else { up }, exp),
+            },
+            (true, false) => break (down, exp),
+            (false, true) => break (up, exp),
+            (false, false) => count += 1,
+        }
+    };
+
+    let mut decimal = Decimal {
+        digits: digits as u64,
+        exp,
+    };
+    while decimal.digits % 10 == 0 {
+        decimal.digits /= 10;
+        decimal.exp += 1;
+    }
+    decimal
+}
+
+// Formats the digits of a decimal without an exponent, like the `Display` output of primitive
+// floats.
+pub(crate) fn write_decimal(f: &mut Formatter<'_>, negative: bool, decimal: Decimal) -> Result {
+    let mut buf = Buffer::new();
+    push_decimal(&mut buf, decimal)?;
+    f.pad_integral(!negative, "", buf.as_str())
+}
+
+// Formats a decimal like the `Debug` output of primitive floats: without an exponent and with at
+// least one fractional digit for moderately sized values, and in scientific notation otherwise.
+pub(crate) fn write_debug(f: &mut Formatter<'_>, negative: bool, decimal: Decimal) -> Result {
+    let mut buf = Buffer::new();
+    let leading = decimal.exp + digit_count(decimal.digits) - 1;
+    if decimal.digits != 0 && !(-4..16).contains(&leading) {
+        push_exp(&mut buf, decimal, false)?;
+    } else {
+        push_decimal(&mut buf, decimal)?;
+        if decimal.exp >= 0 {
+            buf.write_str(".0")?;
+        }
+    }
+    f.pad_integral(!negative, "", buf.as_str())
+}
+
+// Formats the digits of a decimal in scientific notation, like the `LowerExp` and `UpperExp`
+// output of primitive floats.
+pub(crate) fn write_exp(
+    f: &mut Formatter<'_>,
+    negative: bool,
+    decimal: Decimal,
+    upper: bool,
+) -> Result {
+    let mut buf = Buffer::new();
+    push_exp(&mut buf, decimal, upper)?;
+    f.pad_integral(!negative, "", buf.as_str())
+}
+
+// The formatted digits are collected here first so the sign, width and fill flags of the
+// `Formatter` can be applied to the whole number at once.
+struct Buffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Buffer {
+    fn new() -> Self {
+        Buffer {
+            bytes: [0; 64],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        // SAFETY: Only whole `str`s are ever written
+        unsafe { core::str::from_utf8_unchecked(&self.bytes[..self.len]) }
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> Result {
+        let end = self.len + s.len();
+        if end > self.bytes.len() {
+            return Err(core::fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+fn push_decimal(buf: &mut Buffer, decimal: Decimal) -> Result {
+    let mut digit_buf = [0u8; 20];
+    let digits = digit_str(&mut digit_buf, decimal.digits);
+
+    let len = digits.len() as i32;
+    if decimal.exp >= 0 {
+        buf.write_str(digits)?;
+        for _ in 0..decimal.exp {
+            buf.write_str("0")?;
+        }
+    } else if len + decimal.exp > 0 {
+        let point = (len + decimal.exp) as usize;
+        buf.write_str(&digits[..point])?;
+        buf.write_str(".")?;
+        buf.write_str(&digits[point..])?;
+    } else {
+        buf.write_str("0.")?;
+        for _ in 0..-(len + decimal.exp) {
+            buf.write_str("0")?;
+        }
+        buf.write_str(digits)?;
+    }
+    Ok(())
+}
+
+fn push_exp(buf: &mut Buffer, decimal: Decimal, upper: bool) -> Result {
+    let mut digit_buf = [0u8; 20];
+    let digits = digit_str(&mut digit_buf, decimal.digits);
+
+    buf.write_str(&digits[..1])?;
+    if digits.len() > 1 {
+        buf.write_str(".")?;
+        buf.write_str(&digits[1..])?;
+    }
+    buf.write_str(if upper { "E" } else { "e" })?;
+    write!(buf, "{}", decimal.exp + digits.len() as i32 - 1)
+}
+
+fn digit_count(mut digits: u64) -> i32 {
+    let mut count = 1;
+    while digits >= 10 {
+        digits /= 10;
+        count += 1;
+    }
+    count
+}
+
+fn digit_str(buf: &mut [u8; 20], mut digits: u64) -> &str {
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (digits % 10) as u8;
+        digits /= 10;
+        if digits == 0 {
+            break;
+        }
+    }
+    // SAFETY: Only ASCII digits were written
+    unsafe { core::str::from_utf8_unchecked(&buf[start..]) }
+}
+
+// Displays the bits of an IEEE 754 format as a hexadecimal float in the style of C's `%a`, such
+// as `0x1.8p-2`. Subnormals keep a leading `0` digit.
+#[cfg(feature = "alloc")]
+pub(crate) struct HexFloat {
+    pub(crate) bits: u16,
+    pub(crate) man_bits: u32,
+    pub(crate) exp_bits: u32,
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for HexFloat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let max_exp = (1u16 << self.exp_bits) - 1;
+        let bias = (1i32 << (self.exp_bits - 1)) - 1;
+        let exp = (self.bits >> self.man_bits) & max_exp;
+        let man = self.bits & ((1 << self.man_bits) - 1);
+
+        if exp == max_exp {
+            return match (man, self.bits & 0x8000) {
+                (0, 0) => f.write_str("inf"),
+                (0, _) => f.write_str("-inf"),
+                _ => f.write_str("NaN"),
+            };
+        }
+        if self.bits & 0x8000 != 0 {
+            f.write_str("-")?;
+        }
+
+        let (leading, exp) = match (exp, man) {
+            (0, 0) => (0, 0),
+            (0, _) => (0, 1 - bias),
+            _ => (1, exp as i32 - bias),
+        };
+        write!(f, "0x{}", leading)?;
+
+        // Left align the mantissa in whole hex digits and drop trailing zeros
+        let pad = (4 - self.man_bits % 4) % 4;
+        let mut digits = ((self.man_bits + pad) / 4) as usize;
+        let mut man = (man as u32) << pad;
+        while digits > 0 && man & 0xF == 0 {
+            man >>= 4;
+            digits -= 1;
+        }
+        if digits > 0 {
+            write!(f, ".{:01$x}", man, digits)?;
+        }
+        write!(f, "p{:+}", exp)
+    }
+}
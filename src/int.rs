@@ -0,0 +1,94 @@
+use crate::{
+    private::SealedInt,
+    rounding::{self, Rounding},
+    RoundingMode,
+};
+
+/// Primitive integer types that half-precision floating point values can be converted to and
+/// from.
+///
+/// This trait is sealed and cannot be implemented outside of this crate. It is used by
+/// [`f16::to_int`][crate::f16::to_int], [`f16::to_int_unchecked`][crate::f16::to_int_unchecked]
+/// and the fixed-point conversions such as [`f16::to_fixed`][crate::f16::to_fixed].
+pub trait FloatToInt: SealedInt {}
+
+macro_rules! impl_float_to_int {
+    ($($ty:ty)*; $abs:expr) => {$(
+        impl SealedInt for $ty {
+            #[inline]
+            fn saturating_from_f32(value: f32) -> Self {
+                value as $ty
+            }
+
+            #[inline]
+            fn saturating_from_f64(value: f64) -> Self {
+                value as $ty
+            }
+
+            #[inline]
+            unsafe fn unchecked_from_f32(value: f32) -> Self {
+                value.to_int_unchecked()
+            }
+
+            #[inline]
+            #[allow(unused_comparisons, trivial_numeric_casts, clippy::unnecessary_cast)]
+            fn into_sign_magnitude(self) -> (bool, u128) {
+                (self < 0, $abs(self))
+            }
+        }
+
+        impl FloatToInt for $ty {}
+    )*};
+}
+
+impl_float_to_int!(i8 i16 i32 i64 i128 isize; |x| (x as i128).unsigned_abs());
+impl_float_to_int!(u8 u16 u32 u64 u128 usize; |x| x as u128);
+
+// Scales a value by 2^frac_bits and rounds it to nearest even, saturating like `as` casts.
+pub(crate) fn to_fixed<T: FloatToInt>(value: f64, frac_bits: u32) -> T {
+    // Any nonzero half-precision value saturates long before the scale factor overflows
+    let scale = f64::from_bits(((1023 + frac_bits.min(1023)) as u64) << 52);
+    let scaled = value * scale;
+
+    // Adding 2^52 leaves no room for fractional bits, so the FPU rounds to nearest even for us
+    const ROUNDING: f64 = 4_503_599_627_370_496.0;
+    let magnitude = if scaled < 0.0 { -scaled } else { scaled };
+    let rounded = if magnitude < ROUNDING {
+        (magnitude + ROUNDING) - ROUNDING
+    } else {
+        magnitude
+    };
+    T::saturating_from_f64(if scaled < 0.0 { -rounded } else { rounded })
+}
+
+// Converts a fixed-point value with `frac_bits` fractional bits to the bits of an IEEE 754 format,
+// rounding to nearest even.
+pub(crate) fn from_fixed<T: FloatToInt>(
+    value: T,
+    frac_bits: u32,
+    man_bits: u32,
+    exp_bits: u32,
+) -> u16 {
+    let (negative, magnitude) = value.into_sign_magnitude();
+
+    // Fold any bits past the low 64 into a sticky bit, they are far below the rounding position
+    let (man, shift) = if magnitude >> 64 == 0 {
+        (magnitude as u64, 0)
+    } else {
+        let shift = 64 - magnitude.leading_zeros();
+        let sticky = magnitude & ((1 << shift) - 1) != 0;
+        ((magnitude >> shift) as u64 | sticky as u64, shift as i32)
+    };
+
+    // Anything shifted this far is rounded to zero anyway
+    let exp = shift - frac_bits.min(512) as i32;
+    let sign = if negative { 0x8000 } else { 0 };
+    sign | rounding::round_to_ieee(
+        negative,
+        man,
+        exp,
+        Rounding::Mode(RoundingMode::NearestEven),
+        man_bits,
+        exp_bits,
+    )
+}
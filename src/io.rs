@@ -0,0 +1,797 @@
+//! Contains extension traits to read and write [`f16`] and [`bf16`] values with [`std::io`].
+//!
+//! [`ReadHalfExt`] is implemented for every [`Read`] type and [`WriteHalfExt`] for every [`Write`]
+//! type, in the style of the [`byteorder`] crate: each value is two bytes in the [`ByteOrder`]
+//! given as a type parameter, such as [`LE`] or [`BE`]. With the `byteorder` feature, the byte
+//! order types of the [`byteorder`] crate can be used as well, so half precision fields are read
+//! with the same type parameters as the other numbers of a format.
+//!
+//! ```rust
+//! # fn main() -> std::io::Result<()> {
+//! use half::io::{ReadHalfExt, WriteHalfExt, BE, LE};
+//! # use half::prelude::*;
+//!
+//! let mut buffer = Vec::new();
+//! buffer.write_f16::<LE>(f16::ONE)?;
+//! buffer.write_bf16::<BE>(bf16::MAX)?;
+//! assert_eq!(buffer, [0x00, 0x3C, 0x7F, 0x7F]);
+//!
+//! let mut reader = &buffer[..];
+//! assert_eq!(reader.read_f16::<LE>()?, f16::ONE);
+//! assert_eq!(reader.read_bf16::<BE>()?, bf16::MAX);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The [`ConvertReader`] and [`ConvertWriter`] adaptors convert whole streams of little-endian
+//! [`f32`] or [`f64`] values to [`f16`] on the fly, in chunks with the vectorized conversions of
+//! [`HalfFloatSliceExt`], so large datasets can be downconverted without loading them into memory.
+//!
+//! [`byteorder`]: https://crates.io/crates/byteorder
+
+use crate::{bf16, f16, slice::HalfFloatSliceExt};
+use core::{fmt, slice};
+use std::io::{self, Read, Write};
+
+/// Number of elements the slice writes encode at a time into a stack buffer.
+const CHUNK_LEN: usize = 256;
+
+/// The order of the two bytes of an encoded [`f16`] or [`bf16`] value.
+///
+/// This trait is sealed and implemented by the [`LittleEndian`], [`BigEndian`] and
+/// [`NativeEndian`] types, which are only used as type parameters. With the `byteorder` feature,
+/// it is also implemented by the `LittleEndian` and `BigEndian` types of the [`byteorder`] crate.
+///
+/// [`byteorder`]: https://crates.io/crates/byteorder
+pub trait ByteOrder: private::SealedByteOrder {
+    /// Decodes the bits of a value from its two bytes.
+    fn read_u16(bytes: [u8; 2]) -> u16;
+
+    /// Encodes the bits of a value as two bytes.
+    fn write_u16(bits: u16) -> [u8; 2];
+}
+
+/// Little-endian byte order, with the least significant byte first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LittleEndian {}
+
+/// Big-endian byte order, with the most significant byte first. Also known as network byte order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BigEndian {}
+
+/// The byte order of the target platform, either [`LittleEndian`] or [`BigEndian`].
+#[cfg(target_endian = "little")]
+pub type NativeEndian = LittleEndian;
+/// The byte order of the target platform, either [`LittleEndian`] or [`BigEndian`].
+#[cfg(target_endian = "big")]
+pub type NativeEndian = BigEndian;
+
+/// A short alias for [`LittleEndian`].
+pub type LE = LittleEndian;
+/// A short alias for [`BigEndian`].
+pub type BE = BigEndian;
+/// A short alias for [`NativeEndian`].
+pub type NE = NativeEndian;
+
+impl ByteOrder for LittleEndian {
+    #[inline]
+    fn read_u16(bytes: [u8; 2]) -> u16 {
+        u16::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    fn write_u16(bits: u16) -> [u8; 2] {
+        bits.to_le_bytes()
+    }
+}
+
+impl ByteOrder for BigEndian {
+    #[inline]
+    fn read_u16(bytes: [u8; 2]) -> u16 {
+        u16::from_be_bytes(bytes)
+    }
+
+    #[inline]
+    fn write_u16(bits: u16) -> [u8; 2] {
+        bits.to_be_bytes()
+    }
+}
+
+#[cfg(feature = "byteorder")]
+impl ByteOrder for byteorder::LittleEndian {
+    #[inline]
+    fn read_u16(bytes: [u8; 2]) -> u16 {
+        u16::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    fn write_u16(bits: u16) -> [u8; 2] {
+        bits.to_le_bytes()
+    }
+}
+
+#[cfg(feature = "byteorder")]
+impl ByteOrder for byteorder::BigEndian {
+    #[inline]
+    fn read_u16(bytes: [u8; 2]) -> u16 {
+        u16::from_be_bytes(bytes)
+    }
+
+    #[inline]
+    fn write_u16(bits: u16) -> [u8; 2] {
+        bits.to_be_bytes()
+    }
+}
+
+/// Extends [`Read`] with methods to read [`f16`] and [`bf16`] values.
+///
+/// This trait is implemented for every type that implements [`Read`].
+pub trait ReadHalfExt: Read {
+    /// Reads a [`f16`] value encoded as two bytes in the byte order `B`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of [`Read::read_exact`], including [`io::ErrorKind::UnexpectedEof`] if
+    /// fewer than two bytes are left.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> std::io::Result<()> {
+    /// use half::{f16, io::{ReadHalfExt, BE}};
+    ///
+    /// let mut reader = &[0xC0, 0x00][..];
+    /// assert_eq!(reader.read_f16::<BE>()?, f16::from_f32(-2.));
+    /// assert!(reader.read_f16::<BE>().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn read_f16<B: ByteOrder>(&mut self) -> io::Result<f16> {
+        let mut bytes = [0; 2];
+        self.read_exact(&mut bytes)?;
+        Ok(f16::from_bits(B::read_u16(bytes)))
+    }
+
+    /// Reads a [`bf16`] value encoded as two bytes in the byte order `B`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of [`Read::read_exact`], including [`io::ErrorKind::UnexpectedEof`] if
+    /// fewer than two bytes are left.
+    #[inline]
+    fn read_bf16<B: ByteOrder>(&mut self) -> io::Result<bf16> {
+        let mut bytes = [0; 2];
+        self.read_exact(&mut bytes)?;
+        Ok(bf16::from_bits(B::read_u16(bytes)))
+    }
+
+    /// Fills `dst` with [`f16`] values each encoded as two bytes in the byte order `B`.
+    ///
+    /// The bytes are read directly into the memory of `dst` and then swapped in place if `B` is
+    /// not the native byte order, so large arrays are read without an intermediate buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of [`Read::read_exact`]. The contents of `dst` are unspecified if an
+    /// error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> std::io::Result<()> {
+    /// use half::{f16, io::{ReadHalfExt, LE}};
+    ///
+    /// let mut reader = &[0x00, 0x3C, 0x00, 0x40, 0x00, 0xC2][..];
+    /// let mut values = [f16::ZERO; 3];
+    /// reader.read_f16_into::<LE>(&mut values)?;
+    /// assert_eq!(values, [1., 2., -3.].map(f16::from_f32));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn read_f16_into<B: ByteOrder>(&mut self, dst: &mut [f16]) -> io::Result<()> {
+        read_bits_into::<B, _>(self, dst.reinterpret_cast_mut())
+    }
+
+    /// Fills `dst` with [`bf16`] values each encoded as two bytes in the byte order `B`.
+    ///
+    /// See [`read_f16_into`][Self::read_f16_into] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of [`Read::read_exact`]. The contents of `dst` are unspecified if an
+    /// error is returned.
+    #[inline]
+    fn read_bf16_into<B: ByteOrder>(&mut self, dst: &mut [bf16]) -> io::Result<()> {
+        read_bits_into::<B, _>(self, dst.reinterpret_cast_mut())
+    }
+}
+
+impl<R: Read + ?Sized> ReadHalfExt for R {}
+
+/// Extends [`Write`] with methods to write [`f16`] and [`bf16`] values.
+///
+/// This trait is implemented for every type that implements [`Write`].
+pub trait WriteHalfExt: Write {
+    /// Writes a [`f16`] value as two bytes in the byte order `B`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of [`Write::write_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> std::io::Result<()> {
+    /// use half::{f16, io::{WriteHalfExt, BE}};
+    ///
+    /// let mut buffer = Vec::new();
+    /// buffer.write_f16::<BE>(f16::from_f32(-2.))?;
+    /// assert_eq!(buffer, [0xC0, 0x00]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn write_f16<B: ByteOrder>(&mut self, value: f16) -> io::Result<()> {
+        self.write_all(&B::write_u16(value.to_bits()))
+    }
+
+    /// Writes a [`bf16`] value as two bytes in the byte order `B`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of [`Write::write_all`].
+    #[inline]
+    fn write_bf16<B: ByteOrder>(&mut self, value: bf16) -> io::Result<()> {
+        self.write_all(&B::write_u16(value.to_bits()))
+    }
+
+    /// Writes all of the [`f16`] values of `src`, each as two bytes in the byte order `B`.
+    ///
+    /// The values are encoded in chunks into a buffer on the stack, so large arrays are written
+    /// with few calls to [`Write::write_all`] without allocating.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of [`Write::write_all`]. Part of `src` may have been written if an
+    /// error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # fn main() -> std::io::Result<()> {
+    /// use half::{f16, io::{WriteHalfExt, LE}};
+    ///
+    /// let mut buffer = Vec::new();
+    /// buffer.write_f16_slice::<LE>(&[1., 2., -3.].map(f16::from_f32))?;
+    /// assert_eq!(buffer, [0x00, 0x3C, 0x00, 0x40, 0x00, 0xC2]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn write_f16_slice<B: ByteOrder>(&mut self, src: &[f16]) -> io::Result<()> {
+        write_bits::<B, _>(self, src.reinterpret_cast())
+    }
+
+    /// Writes all of the [`bf16`] values of `src`, each as two bytes in the byte order `B`.
+    ///
+    /// See [`write_f16_slice`][Self::write_f16_slice] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of [`Write::write_all`]. Part of `src` may have been written if an
+    /// error is returned.
+    #[inline]
+    fn write_bf16_slice<B: ByteOrder>(&mut self, src: &[bf16]) -> io::Result<()> {
+        write_bits::<B, _>(self, src.reinterpret_cast())
+    }
+}
+
+impl<W: Write + ?Sized> WriteHalfExt for W {}
+
+/// The format of the values of a stream converted by [`ConvertReader`] or [`ConvertWriter`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Source {
+    F32,
+    F64,
+}
+
+impl Source {
+    /// Number of bytes of each value.
+    #[inline]
+    fn size(self) -> usize {
+        match self {
+            Source::F32 => 4,
+            Source::F64 => 8,
+        }
+    }
+
+    /// Converts the little-endian values of `src`, at most [`CHUNK_LEN`] of them, into
+    /// little-endian [`f16`] values in `dst`.
+    fn convert(self, src: &[u8], dst: &mut [u8]) {
+        let mut halves = [f16::ZERO; CHUNK_LEN];
+        let halves = &mut halves[..dst.len() / 2];
+        match self {
+            Source::F32 => {
+                let mut values = [0f32; CHUNK_LEN];
+                let values = &mut values[..halves.len()];
+                for (x, bytes) in values.iter_mut().zip(src.chunks_exact(4)) {
+                    *x = f32::from_le_bytes(bytes.try_into().unwrap());
+                }
+                halves.convert_from_f32_slice(values);
+            }
+            Source::F64 => {
+                let mut values = [0f64; CHUNK_LEN];
+                let values = &mut values[..halves.len()];
+                for (x, bytes) in values.iter_mut().zip(src.chunks_exact(8)) {
+                    *x = f64::from_le_bytes(bytes.try_into().unwrap());
+                }
+                halves.convert_from_f64_slice(values);
+            }
+        }
+        halves.copy_to_le_bytes(dst);
+    }
+}
+
+/// A reader that converts a stream of little-endian [`f32`] or [`f64`] values read from an inner
+/// reader into a stream of little-endian [`f16`] values.
+///
+/// The values are read and converted in chunks, rounding to nearest like
+/// [`convert_from_f32_slice`][HalfFloatSliceExt::convert_from_f32_slice]. Reading from the inner
+/// reader is not buffered beyond a chunk, so wrap it in a [`BufReader`][std::io::BufReader] if it
+/// is slow to read small amounts.
+///
+/// # Examples
+///
+/// ```rust
+/// # fn main() -> std::io::Result<()> {
+/// use half::{f16, io::{ConvertReader, ReadHalfExt, LE}};
+/// use std::io::Read;
+///
+/// let data: Vec<u8> = [1f32, 0.1, -65536.].iter().flat_map(|x| x.to_le_bytes()).collect();
+/// let mut reader = ConvertReader::from_f32(&data[..]);
+///
+/// let mut values = [f16::ZERO; 3];
+/// reader.read_f16_into::<LE>(&mut values)?;
+/// assert_eq!(values, [f16::ONE, f16::from_f32(0.1), f16::NEG_INFINITY]);
+/// assert_eq!(reader.read(&mut [0; 2])?, 0);
+/// # Ok(())
+/// # }
+/// ```
+pub struct ConvertReader<R> {
+    inner: R,
+    source: Source,
+    input: [u8; CHUNK_LEN * 8],
+    input_len: usize,
+    output: [u8; CHUNK_LEN * 2],
+    output_pos: usize,
+    output_len: usize,
+}
+
+impl<R: Read> ConvertReader<R> {
+    /// Creates a reader converting the little-endian [`f32`] values read from `inner` to
+    /// [`f16`].
+    #[inline]
+    pub fn from_f32(inner: R) -> Self {
+        Self::new(inner, Source::F32)
+    }
+
+    /// Creates a reader converting the little-endian [`f64`] values read from `inner` to
+    /// [`f16`].
+    #[inline]
+    pub fn from_f64(inner: R) -> Self {
+        Self::new(inner, Source::F64)
+    }
+
+    fn new(inner: R, source: Source) -> Self {
+        Self {
+            inner,
+            source,
+            input: [0; CHUNK_LEN * 8],
+            input_len: 0,
+            output: [0; CHUNK_LEN * 2],
+            output_pos: 0,
+            output_len: 0,
+        }
+    }
+
+    /// Reads and converts the next chunk of values, returning `false` at the end of the stream.
+    fn fill(&mut self) -> io::Result<bool> {
+        let size = self.source.size();
+        let capacity = CHUNK_LEN * size;
+        while self.input_len < size {
+            let read = match self.inner.read(&mut self.input[self.input_len..capacity]) {
+                Ok(read) => read,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            if read == 0 {
+                if self.input_len == 0 {
+                    return Ok(false);
+                }
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "stream ended in the middle of a value",
+                ));
+            }
+            self.input_len += read;
+        }
+
+        let count = self.input_len / size;
+        let whole = count * size;
+        self.source
+            .convert(&self.input[..whole], &mut self.output[..count * 2]);
+        self.input.copy_within(whole..self.input_len, 0);
+        self.input_len -= whole;
+        self.output_pos = 0;
+        self.output_len = count * 2;
+        Ok(true)
+    }
+}
+
+impl<R> ConvertReader<R> {
+    /// Returns a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner reader.
+    ///
+    /// Reading from the inner reader directly may skip values or split one in two.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the inner reader. Values that were read from it but not yet returned are lost.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for ConvertReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() || (self.output_pos == self.output_len && !self.fill()?) {
+            return Ok(0);
+        }
+        let available = &self.output[self.output_pos..self.output_len];
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.output_pos += len;
+        Ok(len)
+    }
+}
+
+impl<R: fmt::Debug> fmt::Debug for ConvertReader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConvertReader")
+            .field("inner", &self.inner)
+            .field("source", &self.source)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A writer that converts a stream of little-endian [`f32`] or [`f64`] values written to it into
+/// little-endian [`f16`] values written to an inner writer.
+///
+/// Each call to [`write`][Write::write] converts all of the whole values it is given in chunks,
+/// rounding to nearest like [`convert_from_f32_slice`][HalfFloatSliceExt::convert_from_f32_slice],
+/// and writes them to the inner writer with [`Write::write_all`]. Only the bytes of a value split
+/// across calls are kept until the next call, so wrap the inner writer in a
+/// [`BufWriter`][std::io::BufWriter] if it is slow to write small amounts. Call
+/// [`finish`][Self::finish] at the end of the stream to check that no such partial value is left.
+///
+/// # Examples
+///
+/// ```rust
+/// # fn main() -> std::io::Result<()> {
+/// use half::{f16, io::ConvertWriter};
+/// use std::io::Write;
+///
+/// let mut writer = ConvertWriter::from_f64(Vec::new());
+/// for x in [1f64, -2.5, 1e-10] {
+///     writer.write_all(&x.to_le_bytes())?;
+/// }
+///
+/// let data = writer.finish()?;
+/// assert_eq!(data, [0x00, 0x3C, 0x00, 0xC1, 0x00, 0x00]);
+/// # Ok(())
+/// # }
+/// ```
+pub struct ConvertWriter<W> {
+    inner: W,
+    source: Source,
+    pending: [u8; 8],
+    pending_len: usize,
+}
+
+impl<W: Write> ConvertWriter<W> {
+    /// Creates a writer converting the little-endian [`f32`] values written to it to [`f16`]
+    /// values written to `inner`.
+    #[inline]
+    pub fn from_f32(inner: W) -> Self {
+        Self::new(inner, Source::F32)
+    }
+
+    /// Creates a writer converting the little-endian [`f64`] values written to it to [`f16`]
+    /// values written to `inner`.
+    #[inline]
+    pub fn from_f64(inner: W) -> Self {
+        Self::new(inner, Source::F64)
+    }
+
+    fn new(inner: W, source: Source) -> Self {
+        Self {
+            inner,
+            source,
+            pending: [0; 8],
+            pending_len: 0,
+        }
+    }
+
+    /// Flushes the inner writer and returns it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] if the bytes written so far end in
+    /// the middle of a value, and any error of [`Write::flush`].
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.pending_len != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "stream ended in the middle of a value",
+            ));
+        }
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+
+    fn write_values(&mut self, src: &[u8]) -> io::Result<()> {
+        let mut output = [0u8; CHUNK_LEN * 2];
+        for src in src.chunks(CHUNK_LEN * self.source.size()) {
+            let output = &mut output[..src.len() / self.source.size() * 2];
+            self.source.convert(src, output);
+            self.inner.write_all(output)?;
+        }
+        Ok(())
+    }
+}
+
+impl<W> ConvertWriter<W> {
+    /// Returns a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner writer.
+    ///
+    /// Writing to the inner writer directly while part of a value is pending places the data
+    /// before that value.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+}
+
+impl<W: Write> Write for ConvertWriter<W> {
+    fn write(&mut self, mut buf: &[u8]) -> io::Result<usize> {
+        let written = buf.len();
+        let size = self.source.size();
+        if self.pending_len != 0 {
+            let len = (size - self.pending_len).min(buf.len());
+            self.pending[self.pending_len..self.pending_len + len].copy_from_slice(&buf[..len]);
+            self.pending_len += len;
+            buf = &buf[len..];
+            if self.pending_len < size {
+                return Ok(written);
+            }
+            let pending = self.pending;
+            self.pending_len = 0;
+            self.write_values(&pending[..size])?;
+        }
+
+        let whole = buf.len() / size * size;
+        self.write_values(&buf[..whole])?;
+        let rest = &buf[whole..];
+        self.pending[..rest.len()].copy_from_slice(rest);
+        self.pending_len = rest.len();
+        Ok(written)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<W: fmt::Debug> fmt::Debug for ConvertWriter<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConvertWriter")
+            .field("inner", &self.inner)
+            .field("source", &self.source)
+            .finish_non_exhaustive()
+    }
+}
+
+fn read_bits_into<B: ByteOrder, R: Read + ?Sized>(
+    reader: &mut R,
+    dst: &mut [u16],
+) -> io::Result<()> {
+    // SAFETY: every byte pattern is a valid `u16`, and the byte slice covers exactly the memory of
+    // `dst`, which it borrows mutably.
+    let bytes = unsafe { slice::from_raw_parts_mut(dst.as_mut_ptr().cast::<u8>(), dst.len() * 2) };
+    reader.read_exact(bytes)?;
+    for bits in dst {
+        *bits = B::read_u16(bits.to_ne_bytes());
+    }
+    Ok(())
+}
+
+fn write_bits<B: ByteOrder, W: Write + ?Sized>(writer: &mut W, src: &[u16]) -> io::Result<()> {
+    let mut buffer = [0u8; CHUNK_LEN * 2];
+    for src in src.chunks(CHUNK_LEN) {
+        let buffer = &mut buffer[..src.len() * 2];
+        for (bytes, bits) in buffer.chunks_exact_mut(2).zip(src) {
+            bytes.copy_from_slice(&B::write_u16(*bits));
+        }
+        writer.write_all(buffer)?;
+    }
+    Ok(())
+}
+
+mod private {
+    use super::{BigEndian, LittleEndian};
+
+    pub trait SealedByteOrder {}
+    impl SealedByteOrder for LittleEndian {}
+    impl SealedByteOrder for BigEndian {}
+    #[cfg(feature = "byteorder")]
+    impl SealedByteOrder for byteorder::LittleEndian {}
+    #[cfg(feature = "byteorder")]
+    impl SealedByteOrder for byteorder::BigEndian {}
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ConvertReader, ConvertWriter, ReadHalfExt, WriteHalfExt, BE, LE, NE};
+    use crate::{bf16, f16};
+    use std::{
+        io::{self, ErrorKind, Read, Write},
+        vec::Vec,
+    };
+
+    /// A reader returning at most a few bytes at a time, to split values across reads.
+    struct Trickle<'a>(&'a [u8], usize);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.1 = self.1 % 7 + 1;
+            let len = self.1.min(buf.len()).min(self.0.len());
+            buf[..len].copy_from_slice(&self.0[..len]);
+            self.0 = &self.0[len..];
+            Ok(len)
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        // Longer than a chunk of the slice writes
+        let values: Vec<f16> = (0..1000u16).map(|i| f16::from_bits(i * 65)).collect();
+        let bf16s: Vec<bf16> = values
+            .iter()
+            .map(|x| bf16::from_bits(!x.to_bits()))
+            .collect();
+
+        let mut buffer = Vec::new();
+        buffer.write_f16_slice::<LE>(&values).unwrap();
+        buffer.write_bf16_slice::<BE>(&bf16s).unwrap();
+        buffer.write_f16::<NE>(f16::NAN).unwrap();
+        assert_eq!(buffer.len(), 4002);
+        assert_eq!(buffer[2..4], (65u16).to_le_bytes());
+        assert_eq!(buffer[2002..2004], (!65u16).to_be_bytes());
+
+        let mut reader = &buffer[..];
+        let mut f16s = std::vec![f16::ZERO; 1000];
+        reader.read_f16_into::<LE>(&mut f16s).unwrap();
+        assert_eq!(f16s.len(), values.len());
+        assert!(f16s
+            .iter()
+            .zip(&values)
+            .all(|(a, b)| a.to_bits() == b.to_bits()));
+        let mut read = std::vec![bf16::ZERO; 999];
+        reader.read_bf16_into::<BE>(&mut read).unwrap();
+        assert!(read
+            .iter()
+            .zip(&bf16s)
+            .all(|(a, b)| a.to_bits() == b.to_bits()));
+        assert_eq!(reader.read_bf16::<BE>().unwrap().to_bits(), !(999u16 * 65));
+        assert!(reader.read_f16::<NE>().unwrap().is_nan());
+
+        let error = reader.read_f16::<LE>().unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+        let error = (&[0u8; 3][..])
+            .read_bf16_into::<LE>(&mut [bf16::ZERO; 2])
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[cfg(feature = "byteorder")]
+    #[test]
+    fn byteorder_types() {
+        use byteorder::{ReadBytesExt, WriteBytesExt};
+
+        let mut buffer = Vec::new();
+        buffer.write_u16::<byteorder::BE>(7).unwrap();
+        buffer.write_f16::<byteorder::BE>(f16::ONE).unwrap();
+        buffer
+            .write_bf16_slice::<byteorder::NativeEndian>(&[bf16::MAX, bf16::MIN])
+            .unwrap();
+        assert_eq!(buffer[2..4], [0x3C, 0x00]);
+
+        let mut reader = &buffer[..];
+        assert_eq!(reader.read_u16::<byteorder::BE>().unwrap(), 7);
+        assert_eq!(reader.read_f16::<byteorder::BE>().unwrap(), f16::ONE);
+        let mut values = [bf16::ZERO; 2];
+        reader.read_bf16_into::<NE>(&mut values).unwrap();
+        assert_eq!(values, [bf16::MAX, bf16::MIN]);
+    }
+
+    #[test]
+    fn convert_streams() {
+        // Longer than a chunk, and including values that round, overflow and underflow
+        let values: Vec<f64> = (0..1000)
+            .map(|i| f64::from(i - 500) * 1.37f64.powi(i % 50 - 30))
+            .collect();
+        let expected: Vec<u8> = values
+            .iter()
+            .flat_map(|&x| f16::from_f64(x).to_le_bytes())
+            .collect();
+        let expected32: Vec<u8> = values
+            .iter()
+            .flat_map(|&x| f16::from_f32(x as f32).to_le_bytes())
+            .collect();
+        let bytes64: Vec<u8> = values.iter().flat_map(|x| x.to_le_bytes()).collect();
+        let bytes32: Vec<u8> = values
+            .iter()
+            .flat_map(|&x| (x as f32).to_le_bytes())
+            .collect();
+
+        let mut converted = Vec::new();
+        ConvertReader::from_f64(&bytes64[..])
+            .read_to_end(&mut converted)
+            .unwrap();
+        assert_eq!(converted, expected);
+        let mut converted = Vec::new();
+        ConvertReader::from_f32(Trickle(&bytes32, 0))
+            .read_to_end(&mut converted)
+            .unwrap();
+        assert_eq!(converted, expected32);
+
+        let mut writer = ConvertWriter::from_f32(Vec::new());
+        for (i, chunk) in bytes32.chunks(1 + 3 * 31).enumerate() {
+            let (a, b) = chunk.split_at(chunk.len().min(i % 9));
+            writer.write_all(a).unwrap();
+            writer.write_all(b).unwrap();
+        }
+        assert_eq!(writer.finish().unwrap(), expected32);
+        let mut writer = ConvertWriter::from_f64(Vec::new());
+        writer.write_all(&bytes64).unwrap();
+        assert_eq!(writer.get_ref().len(), 2000);
+        assert_eq!(writer.finish().unwrap(), expected);
+
+        // Partial values at the end of the stream
+        let mut reader = ConvertReader::from_f32(&bytes32[..6]);
+        assert_eq!(reader.read(&mut [0; 4]).unwrap(), 2);
+        let error = reader.read(&mut [0; 4]).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+        let mut writer = ConvertWriter::from_f64(Vec::new());
+        writer.write_all(&bytes64[..12]).unwrap();
+        assert_eq!(writer.get_ref().len(), 2);
+        let error = writer.finish().unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+}
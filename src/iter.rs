@@ -0,0 +1,757 @@
+//! Contains iterator adaptors to convert streams of [`f32`] values to and from [`f16`] or [`bf16`]
+//! numbers.
+//!
+//! The utility [`F32IteratorExt`] extension trait is implemented for all iterators of [`f32`],
+//! while the utility [`HalfIteratorExt`] extension trait is implemented for all iterators of
+//! [`f16`] or [`bf16`]. These traits are automatically included in the [`prelude`][crate::prelude]
+//! module.
+//!
+//! Rather than converting one element at a time, the adaptors pull small batches of elements from
+//! the underlying iterator and convert each batch with the vectorized conversions of
+//! [`HalfFloatSliceExt`]. This means the underlying iterator may be advanced up to 15 elements
+//! ahead of the values returned by the adaptor.
+//!
+//! The [`linspace`] and [`arange`] functions create iterators over evenly spaced [`f16`] or
+//! [`bf16`] values. Stepping in half precision accumulates a rounding error with every step, and
+//! can get stuck on the same value once the step is less than half the spacing of representable
+//! numbers. Instead, these compute each position in [`f32`] from the start and round it once.
+
+use crate::{bf16, f16, private::SealedHalf, slice::HalfFloatSliceExt};
+use core::{
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{Range, RangeInclusive},
+};
+
+/// Number of elements converted at a time by the adaptors, a multiple of the SIMD kernel widths.
+const BATCH_LEN: usize = 16;
+
+/// Extensions to iterators of [`f32`] to convert them into [`f16`] or [`bf16`] numbers.
+pub trait F32IteratorExt: Iterator<Item = f32> + Sized {
+    /// Creates an iterator which converts each [`f32`] value into [`f16`], rounding to the nearest
+    /// value like [`f16::from_f32`][crate::f16::from_f32].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let halves = [1., 2.5, -3.].iter().copied().map_to_f16();
+    ///
+    /// assert!(halves.eq([f16::from_f32(1.), f16::from_f32(2.5), f16::from_f32(-3.)]));
+    /// ```
+    fn map_to_f16(self) -> ToHalf<Self, f16>;
+
+    /// Creates an iterator which converts each [`f32`] value into [`bf16`], rounding to the
+    /// nearest value like [`bf16::from_f32`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let halves = (0..100).map(|i| i as f32 / 8.).map_to_bf16();
+    ///
+    /// assert!(halves.eq((0..100).map(|i| bf16::from_f32(i as f32 / 8.))));
+    /// ```
+    fn map_to_bf16(self) -> ToHalf<Self, bf16>;
+}
+
+impl<I: Iterator<Item = f32>> F32IteratorExt for I {
+    #[inline]
+    fn map_to_f16(self) -> ToHalf<Self, f16> {
+        ToHalf::new(self)
+    }
+
+    #[inline]
+    fn map_to_bf16(self) -> ToHalf<Self, bf16> {
+        ToHalf::new(self)
+    }
+}
+
+/// Extensions to iterators of [`f16`] or [`bf16`] to convert them into [`f32`] values.
+pub trait HalfIteratorExt: Iterator + Sized {
+    /// Creates an iterator which losslessly converts each [`f16`] or [`bf16`] number into [`f32`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let halves = [f16::from_f32(0.5), f16::INFINITY, f16::MIN];
+    /// let floats = halves.iter().copied().map_to_f32();
+    ///
+    /// assert!(floats.eq([0.5, f32::INFINITY, -65504.]));
+    /// ```
+    fn map_to_f32(self) -> ToF32<Self>;
+}
+
+impl<I, H> HalfIteratorExt for I
+where
+    I: Iterator<Item = H>,
+    H: SealedHalf,
+{
+    #[inline]
+    fn map_to_f32(self) -> ToF32<Self> {
+        ToF32::new(self)
+    }
+}
+
+/// An iterator that converts [`f32`] values into [`f16`] or [`bf16`] numbers in batches.
+///
+/// This `struct` is created by the [`map_to_f16`][F32IteratorExt::map_to_f16] and
+/// [`map_to_bf16`][F32IteratorExt::map_to_bf16] methods on [`F32IteratorExt`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ToHalf<I, H> {
+    iter: I,
+    buf: [H; BATCH_LEN],
+    pos: usize,
+    len: usize,
+}
+
+impl<I, H: SealedHalf + Copy + Default> ToHalf<I, H> {
+    fn new(iter: I) -> Self {
+        Self {
+            iter,
+            buf: [H::default(); BATCH_LEN],
+            pos: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<I, H> Iterator for ToHalf<I, H>
+where
+    I: Iterator<Item = f32>,
+    H: SealedHalf + Copy,
+    [H]: HalfFloatSliceExt,
+{
+    type Item = H;
+
+    #[inline]
+    fn next(&mut self) -> Option<H> {
+        if self.pos == self.len {
+            let mut src = [0f32; BATCH_LEN];
+            let mut len = 0;
+            // Zip the buffer first so no element is pulled from the iterator once it is full
+            for (slot, value) in src.iter_mut().zip(&mut self.iter) {
+                *slot = value;
+                len += 1;
+            }
+            if len == 0 {
+                return None;
+            }
+            self.buf[..len].convert_from_f32_slice(&src[..len]);
+            self.pos = 0;
+            self.len = len;
+        }
+
+        let value = self.buf[self.pos];
+        self.pos += 1;
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        buffered_size_hint(self.iter.size_hint(), self.len - self.pos)
+    }
+}
+
+impl<I, H> ExactSizeIterator for ToHalf<I, H>
+where
+    I: ExactSizeIterator<Item = f32>,
+    H: SealedHalf + Copy,
+    [H]: HalfFloatSliceExt,
+{
+}
+
+impl<I, H> FusedIterator for ToHalf<I, H>
+where
+    I: FusedIterator<Item = f32>,
+    H: SealedHalf + Copy,
+    [H]: HalfFloatSliceExt,
+{
+}
+
+/// An iterator that converts [`f16`] or [`bf16`] numbers into [`f32`] values in batches.
+///
+/// This `struct` is created by the [`map_to_f32`][HalfIteratorExt::map_to_f32] method on
+/// [`HalfIteratorExt`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ToF32<I> {
+    iter: I,
+    buf: [f32; BATCH_LEN],
+    pos: usize,
+    len: usize,
+}
+
+impl<I> ToF32<I> {
+    fn new(iter: I) -> Self {
+        Self {
+            iter,
+            buf: [0.; BATCH_LEN],
+            pos: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<I, H> Iterator for ToF32<I>
+where
+    I: Iterator<Item = H>,
+    H: SealedHalf + Copy + Default,
+    [H]: HalfFloatSliceExt,
+{
+    type Item = f32;
+
+    #[inline]
+    fn next(&mut self) -> Option<f32> {
+        if self.pos == self.len {
+            let mut src = [H::default(); BATCH_LEN];
+            let mut len = 0;
+            // Zip the buffer first so no element is pulled from the iterator once it is full
+            for (slot, value) in src.iter_mut().zip(&mut self.iter) {
+                *slot = value;
+                len += 1;
+            }
+            if len == 0 {
+                return None;
+            }
+            src[..len].convert_to_f32_slice(&mut self.buf[..len]);
+            self.pos = 0;
+            self.len = len;
+        }
+
+        let value = self.buf[self.pos];
+        self.pos += 1;
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        buffered_size_hint(self.iter.size_hint(), self.len - self.pos)
+    }
+}
+
+impl<I, H> ExactSizeIterator for ToF32<I>
+where
+    I: ExactSizeIterator<Item = H>,
+    H: SealedHalf + Copy + Default,
+    [H]: HalfFloatSliceExt,
+{
+}
+
+impl<I, H> FusedIterator for ToF32<I>
+where
+    I: FusedIterator<Item = H>,
+    H: SealedHalf + Copy + Default,
+    [H]: HalfFloatSliceExt,
+{
+}
+
+/// Creates an iterator over `n` evenly spaced values from `start` to `end`, both inclusive.
+///
+/// Each value is interpolated in [`f32`] and then rounded to the nearest [`f16`] or [`bf16`], so the
+/// first and last values are exactly `start` and `end`. With `n` of 1 the only value is `start`.
+/// When the range holds fewer representable values than `n`, neighboring values may round to the
+/// same number.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::f16;
+/// use half::iter::linspace;
+///
+/// let values = linspace(f16::ZERO, f16::ONE, 5);
+/// assert!(values.eq([0., 0.25, 0.5, 0.75, 1.].map(f16::from_f32)));
+///
+/// // Naively adding 0.1 would accumulate error, but each value is rounded only once
+/// let tenths: Vec<f16> = linspace(f16::ZERO, f16::from_f32(100.), 1001).collect();
+/// assert_eq!(tenths[999], f16::from_f32(99.9));
+/// ```
+#[inline]
+pub fn linspace<H: SealedHalf>(start: H, end: H, n: usize) -> Linspace<H> {
+    Linspace {
+        start: start.to_f32(),
+        end: end.to_f32(),
+        last: n.saturating_sub(1),
+        front: 0,
+        back: n,
+        marker: PhantomData,
+    }
+}
+
+/// Creates an iterator over the values from `start` up to but excluding `end`, spaced `step`
+/// apart.
+///
+/// The value at index `i` is `start + i * step` computed in [`f32`], rounded once to the nearest
+/// [`f16`] or [`bf16`], and there are `ceil((end - start) / step)` values. A negative `step` counts
+/// down from `start`. The iterator is empty if `step` points away from `end`, or if any of the
+/// arguments is not finite.
+///
+/// # Panics
+///
+/// Panics if `step` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::bf16;
+/// use half::iter::arange;
+///
+/// let values = arange(bf16::ZERO, bf16::from_f32(2.), bf16::from_f32(0.5));
+/// assert!(values.eq([0., 0.5, 1., 1.5].map(bf16::from_f32)));
+///
+/// let down = arange(bf16::ONE, bf16::from_f32(-1.), bf16::from_f32(-0.75));
+/// assert!(down.eq([1., 0.25, -0.5].map(bf16::from_f32)));
+/// ```
+#[inline]
+pub fn arange<H: SealedHalf>(start: H, end: H, step: H) -> Arange<H> {
+    let (start, end, step) = (start.to_f32(), end.to_f32(), step.to_f32());
+    assert!(step != 0., "step must not be zero");
+    // The quotient is only infinite or NaN for infinite or NaN arguments
+    let len = (f64::from(end) - f64::from(start)) / f64::from(step);
+    let len = if len > 0. && len.is_finite() {
+        // The float to integer cast saturates
+        ceil(len) as usize
+    } else {
+        0
+    };
+    Arange {
+        start,
+        step,
+        front: 0,
+        back: len,
+        marker: PhantomData,
+    }
+}
+
+// `f64::ceil` needs `std`, but the quotients in `arange` fit well within `u64`
+#[inline]
+fn ceil(x: f64) -> f64 {
+    let truncated = x as u64 as f64;
+    if truncated < x {
+        truncated + 1.
+    } else {
+        truncated
+    }
+}
+
+/// An iterator over evenly spaced [`f16`] or [`bf16`] values, including both endpoints.
+///
+/// This `struct` is created by the [`linspace`] function.
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Linspace<H> {
+    start: f32,
+    end: f32,
+    last: usize,
+    front: usize,
+    back: usize,
+    marker: PhantomData<H>,
+}
+
+impl<H: SealedHalf> Linspace<H> {
+    #[inline]
+    fn get(&self, i: usize) -> H {
+        // Interpolating from both ends is exact at the endpoints and cannot overflow
+        let value = if i == 0 {
+            self.start
+        } else if i == self.last {
+            self.end
+        } else {
+            let t = i as f32 / self.last as f32;
+            self.start * (1. - t) + self.end * t
+        };
+        H::from_f32(value)
+    }
+}
+
+impl<H: SealedHalf> Iterator for Linspace<H> {
+    type Item = H;
+
+    #[inline]
+    fn next(&mut self) -> Option<H> {
+        if self.front == self.back {
+            return None;
+        }
+        let value = self.get(self.front);
+        self.front += 1;
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<H> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+
+impl<H: SealedHalf> DoubleEndedIterator for Linspace<H> {
+    #[inline]
+    fn next_back(&mut self) -> Option<H> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.get(self.back))
+    }
+}
+
+impl<H: SealedHalf> ExactSizeIterator for Linspace<H> {}
+
+impl<H: SealedHalf> FusedIterator for Linspace<H> {}
+
+/// An iterator over [`f16`] or [`bf16`] values spaced by a fixed step, excluding the end.
+///
+/// This `struct` is created by the [`arange`] function.
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Arange<H> {
+    start: f32,
+    step: f32,
+    front: usize,
+    back: usize,
+    marker: PhantomData<H>,
+}
+
+impl<H: SealedHalf> Iterator for Arange<H> {
+    type Item = H;
+
+    #[inline]
+    fn next(&mut self) -> Option<H> {
+        if self.front == self.back {
+            return None;
+        }
+        let value = H::from_f32(self.start + self.front as f32 * self.step);
+        self.front += 1;
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<H> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+
+impl<H: SealedHalf> DoubleEndedIterator for Arange<H> {
+    #[inline]
+    fn next_back(&mut self) -> Option<H> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(H::from_f32(self.start + self.back as f32 * self.step))
+    }
+}
+
+impl<H: SealedHalf> ExactSizeIterator for Arange<H> {}
+
+impl<H: SealedHalf> FusedIterator for Arange<H> {}
+
+/// An iterator over every bit pattern of [`f16`] or [`bf16`], in increasing order of the bits.
+///
+/// This `struct` is created by the [`f16::all_values`][crate::f16::all_values] and [`bf16::all_values`] methods.
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct AllValues<H> {
+    bits: RangeInclusive<u16>,
+    marker: PhantomData<H>,
+}
+
+impl<H> AllValues<H> {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self {
+            bits: 0..=u16::MAX,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<H: SealedHalf> Iterator for AllValues<H> {
+    type Item = H;
+
+    #[inline]
+    fn next(&mut self) -> Option<H> {
+        self.bits.next().map(H::from_bits)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.bits.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<H> {
+        self.bits.nth(n).map(H::from_bits)
+    }
+}
+
+impl<H: SealedHalf> DoubleEndedIterator for AllValues<H> {
+    #[inline]
+    fn next_back(&mut self) -> Option<H> {
+        self.bits.next_back().map(H::from_bits)
+    }
+}
+
+impl<H: SealedHalf> ExactSizeIterator for AllValues<H> {}
+
+impl<H: SealedHalf> FusedIterator for AllValues<H> {}
+
+/// An iterator over every finite [`f16`] or [`bf16`] value, in increasing order of the bits.
+///
+/// This `struct` is created by the [`f16::all_finite`][crate::f16::all_finite] and [`bf16::all_finite`] methods.
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct AllFinite<H> {
+    // Indices into the positive finite values followed by the negative ones, which keeps the
+    // iterator exact size unlike a chain of the two ranges of bits
+    indices: Range<u16>,
+    marker: PhantomData<H>,
+}
+
+impl<H: SealedHalf> AllFinite<H> {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self {
+            indices: 0..2 * H::FINITE_PER_SIGN,
+            marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn get(i: u16) -> H {
+        if i < H::FINITE_PER_SIGN {
+            H::from_bits(i)
+        } else {
+            H::from_bits((i - H::FINITE_PER_SIGN) | 0x8000)
+        }
+    }
+}
+
+impl<H: SealedHalf> Iterator for AllFinite<H> {
+    type Item = H;
+
+    #[inline]
+    fn next(&mut self) -> Option<H> {
+        self.indices.next().map(Self::get)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<H> {
+        self.indices.nth(n).map(Self::get)
+    }
+}
+
+impl<H: SealedHalf> DoubleEndedIterator for AllFinite<H> {
+    #[inline]
+    fn next_back(&mut self) -> Option<H> {
+        self.indices.next_back().map(Self::get)
+    }
+}
+
+impl<H: SealedHalf> ExactSizeIterator for AllFinite<H> {}
+
+impl<H: SealedHalf> FusedIterator for AllFinite<H> {}
+
+#[inline]
+fn buffered_size_hint(
+    (lower, upper): (usize, Option<usize>),
+    buffered: usize,
+) -> (usize, Option<usize>) {
+    (
+        lower.saturating_add(buffered),
+        upper.and_then(|upper| upper.checked_add(buffered)),
+    )
+}
+
+#[allow(clippy::float_cmp)]
+#[cfg(test)]
+mod test {
+    use super::{arange, linspace, F32IteratorExt, HalfIteratorExt};
+    use crate::{bf16, f16};
+
+    #[test]
+    fn test_map_to_half() {
+        for len in [0usize, 1, 7, 15, 16, 17, 100] {
+            let floats = (0..len).map(|i| i as f32 * 0.1 - 3.);
+
+            let halves = floats.clone().map_to_f16();
+            assert_eq!(halves.len(), len);
+            for (h, f) in halves.zip(floats.clone()) {
+                assert_eq!(h.to_bits(), f16::from_f32(f).to_bits());
+            }
+
+            let mut halves = floats.clone().map_to_bf16();
+            assert_eq!(halves.len(), len);
+            for f in floats {
+                assert_eq!(
+                    halves.next().unwrap().to_bits(),
+                    bf16::from_f32(f).to_bits()
+                );
+            }
+            assert!(halves.next().is_none());
+        }
+    }
+
+    #[test]
+    fn test_map_to_f32() {
+        for len in [0u16, 1, 7, 15, 16, 17, 100] {
+            let halves = (0..len).map(|i| f16::from_bits(i.wrapping_mul(0x9E37)));
+
+            let mut floats = halves.clone().map_to_f32();
+            assert_eq!(floats.len(), len as usize);
+            for h in halves {
+                assert_eq!(floats.next().unwrap().to_bits(), h.to_f32().to_bits());
+            }
+            assert_eq!(floats.len(), 0);
+            assert!(floats.next().is_none());
+
+            let halves = (0..len).map(|i| bf16::from_bits(i.wrapping_mul(0x9E37)));
+            for (f, h) in halves.clone().map_to_f32().zip(halves) {
+                assert_eq!(f.to_bits(), h.to_f32().to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn test_size_hint_while_buffered() {
+        let mut halves = (0..40).map(|i| i as f32).map_to_f16();
+        assert_eq!(halves.size_hint(), (40, Some(40)));
+        halves.next();
+        assert_eq!(halves.size_hint(), (39, Some(39)));
+        assert_eq!(halves.nth(20), Some(f16::from_f32(21.)));
+        assert_eq!(halves.size_hint(), (18, Some(18)));
+
+        let mut floats = core::iter::repeat(bf16::ONE).map_to_f32();
+        assert_eq!(floats.next(), Some(1.));
+        assert_eq!(floats.size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    fn test_linspace() {
+        let values = linspace(f16::from_f32(-1.), f16::ONE, 9);
+        assert_eq!(values.len(), 9);
+        assert!(values
+            .clone()
+            .eq((0..9).map(|i| f16::from_f32(i as f32 * 0.25 - 1.))));
+        assert!(values
+            .rev()
+            .eq((0..9).rev().map(|i| f16::from_f32(i as f32 * 0.25 - 1.))));
+
+        assert_eq!(linspace(f16::ONE, f16::ZERO, 0).next(), None);
+        assert!(linspace(f16::ONE, f16::ZERO, 1).eq([f16::ONE]));
+        assert!(linspace(f16::ONE, f16::ZERO, 2).eq([f16::ONE, f16::ZERO]));
+        assert_eq!(
+            linspace(bf16::ZERO, bf16::ONE, 11).nth(3),
+            Some(bf16::from_f32(0.3))
+        );
+
+        // The endpoints are exact even across the whole range
+        let mut full = linspace(bf16::MIN, bf16::MAX, 7);
+        assert_eq!(full.next(), Some(bf16::MIN));
+        assert_eq!(full.next_back(), Some(bf16::MAX));
+        assert_eq!(full.nth(2), Some(bf16::ZERO));
+        assert_eq!(full.len(), 2);
+
+        // Stepping by 0.1 in f16 drifts away from the nearest values long before 100
+        let mut naive = f16::ZERO;
+        let tenth = f16::from_f32(0.1);
+        for (i, value) in linspace(f16::ZERO, f16::from_f32(100.), 1001).enumerate() {
+            assert_eq!(value, f16::from_f32(i as f32 / 10.));
+            if i > 0 {
+                naive += tenth;
+            }
+        }
+        assert_ne!(naive, f16::from_f32(100.));
+    }
+
+    #[test]
+    fn test_arange() {
+        let values = arange(f16::ZERO, f16::ONE, f16::from_f32(0.125));
+        assert_eq!(values.len(), 8);
+        assert!(values
+            .clone()
+            .eq((0..8).map(|i| f16::from_f32(i as f32 / 8.))));
+        assert_eq!(values.clone().next_back(), Some(f16::from_f32(0.875)));
+
+        let step = f16::from_f32(0.3);
+        assert!(arange(f16::ZERO, f16::ONE, step)
+            .eq((0..4).map(|i| f16::from_f32(i as f32 * step.to_f32()))));
+        assert!(arange(bf16::from_f32(3.), bf16::ZERO, bf16::from_f32(-1.))
+            .rev()
+            .eq([1., 2., 3.].map(bf16::from_f32)));
+
+        assert_eq!(arange(f16::ZERO, f16::ONE, f16::NEG_ONE).len(), 0);
+        assert_eq!(arange(f16::ONE, f16::ONE, f16::ONE).len(), 0);
+        assert_eq!(arange(f16::ZERO, f16::INFINITY, f16::ONE).len(), 0);
+        assert_eq!(arange(f16::NAN, f16::ONE, f16::ONE).len(), 0);
+        assert_eq!(arange(f16::MIN, f16::MAX, f16::ONE).len(), 131008);
+
+        let mut values = arange(bf16::ZERO, bf16::from_f32(10.), bf16::ONE);
+        assert_eq!(values.nth(8), Some(bf16::from_f32(8.)));
+        assert_eq!(values.nth(8), None);
+        assert_eq!(values.next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_arange_zero_step() {
+        let _ = arange(f16::ZERO, f16::ONE, f16::ZERO);
+    }
+
+    #[test]
+    fn test_all_values() {
+        let mut values = f16::all_values();
+        assert_eq!(values.len(), 0x1_0000);
+        for bits in 0..=u16::MAX {
+            assert_eq!(values.next().map(f16::to_bits), Some(bits));
+        }
+        assert_eq!(values.next(), None);
+        assert_eq!(values.len(), 0);
+
+        let mut values = bf16::all_values().rev();
+        assert_eq!(values.next().map(bf16::to_bits), Some(0xFFFF));
+        assert_eq!(values.nth(0xFFFD).map(bf16::to_bits), Some(1));
+    }
+
+    #[test]
+    fn test_all_finite() {
+        assert!(f16::all_finite().eq(f16::all_values().filter(|x| x.is_finite())));
+        assert!(bf16::all_finite().eq(bf16::all_values().filter(|x| x.is_finite())));
+        assert!(f16::all_finite()
+            .rev()
+            .eq(f16::all_values().rev().filter(|x| x.is_finite())));
+
+        let mut values = bf16::all_finite();
+        assert_eq!(values.len(), 0xFF00);
+        assert_eq!(values.nth(0x7F7F), Some(bf16::MAX));
+        assert_eq!(values.next().map(bf16::to_bits), Some(0x8000));
+        assert_eq!(values.len(), 0x7F7F);
+    }
+
+    #[test]
+    fn test_does_not_read_past_batch() {
+        let mut source = (0..20).map(|i| i as f32);
+        assert_eq!((&mut source).map_to_f16().next(), Some(f16::ZERO));
+        assert_eq!(source.next(), Some(16.));
+    }
+}
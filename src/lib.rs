@@ -10,22 +10,38 @@
 //! exponent to allow the same range as [`f32`] but with only 8 bits of precision (instead of 11
 //! bits for [`f16`]). See the [`bf16`] type for details.
 //!
+//! For even more compact storage in machine learning, the [`f8e4m3`] and [`f8e5m2`] types
+//! implement the 8-bit E4M3 and E5M2 formats of the OCP FP8 specification. [`f8e4m3`] trades
+//! infinities for a larger range, while [`f8e5m2`] follows the IEEE 754 conventions.
+//! Other small IEEE-style formats can be prototyped with the generic [`Minifloat`] type, and the
+//! [`Rg11b10Ufloat`] and [`Rgb9e5Ufloat`] types pack colors into the unsigned small floats of the
+//! GPU `R11G11B10_FLOAT` and shared exponent `RGB9E5` texture formats.
+//!
 //! Because [`f16`] and [`bf16`] are primarily for efficient storage, floating point operations such
 //! as addition, multiplication, etc. are not implemented by hardware. While this crate does provide
 //! the appropriate trait implementations for basic operations, they each convert the value to
 //! [`f32`] before performing the operation and then back afterward. When performing complex
 //! arithmetic, manually convert to and from [`f32`] before and after to reduce repeated conversions
-//! for each operation.
+//! for each operation. Comparisons with each other and with [`f32`] and [`f64`] values, such as
+//! `x > 0.5f32`, compare the exact values without any rounding.
 //!
 //! This crate also provides a [`slice`][mod@slice] module for zero-copy in-place conversions of
 //! [`u16`] slices to both [`f16`] and [`bf16`], as well as efficient vectorized conversions of
-//! larger buffers of floating point values to and from these half formats.
+//! larger buffers of floating point values to and from these half formats. The [`iter`] module
+//! provides iterator adaptors that convert streams of values in batches with the same vectorized
+//! conversions, and evenly spaced ranges of half precision values. The [`ordered`] module provides
+//! wrappers with a total order, either over all bit patterns or by excluding NaN, for use as keys of
+//! ordered or hashed collections. The [`packed`] module provides aligned vectors of four and eight
+//! [`f16`] values for graphics and signal processing code, and the [`stats`] module measures the
+//! error of converting [`f32`] data to half precision. With the `std` or `portable-atomic`
+//! feature, the [`atomic`] module provides half precision atomic types.
 //!
 //! The crate uses `#[no_std]` by default, so can be used in embedded environments without using the
 //! Rust [`std`] library. A `std` feature to enable support for the standard library is available,
 //! see the [Cargo Features](#cargo-features) section below.
 //!
-//! A [`prelude`] module is provided for easy importing of available utility traits.
+//! A [`prelude`] module is provided for easy importing of available utility traits, and
+//! [`prelude::v2`] adds more of the crate's types and macros.
 //!
 //! # Serialization
 //!
@@ -40,6 +56,11 @@
 //! attributes. For both [`f16`] and [`bf16`] a `serialize_as_f32` and `serialize_as_string` are
 //! provided for use with this attribute.
 //!
+//! The `half::serde` module also provides adapters for the `#[serde(with = "")]` attribute. For
+//! instance, `half::serde::human_readable` serializes [`f16`] or [`bf16`] as a number in
+//! human-readable formats such as JSON, while keeping the compact default serialization for binary
+//! formats.
+//!
 //! Deserialization of both float types supports deserializing from the default serialization,
 //! strings, and `f32`/`f64` values, so no additional work is required.
 //!
@@ -49,22 +70,74 @@
 //! default, even `std`.
 //!
 //! - **`use-intrinsics`** -- Use [`core::arch`] hardware intrinsics for `f16` and `bf16` conversions
-//!   if available on the compiler target. This feature currently only works on nightly Rust
-//!   until the corresponding intrinsics are stabilized.
+//!   if available on the compiler target.
 //!
 //!   When this feature is enabled and the hardware supports it, the functions and traits in the
-//!   [`slice`][mod@slice] module will use vectorized SIMD intructions for increased efficiency.
+//!   [`slice`][mod@slice] module will use vectorized SIMD intructions for increased efficiency. On
+//!   x86 targets, `f16` conversions use F16C, with wider AVX-512F kernels for slices when
+//!   available, and `f32` to `bf16` slice conversions use AVX-512 BF16 or AVX-NE-CONVERT. These
+//!   intrinsics were stabilized in Rust 1.89, so on x86 and x86_64 targets this feature requires
+//!   Rust 1.89 or newer for all conversions, above the crate's minimum of Rust 1.58.
+//!
+//!   On AArch64 targets, `f16` slice conversions to and from `f32` use NEON. With the
+//!   half-precision arithmetic extension (`FEAT_FP16`), this feature also makes the `f16`
+//!   arithmetic operators use native half-precision instructions instead of promoting to `f32`.
+//!   Both use the `asm!` macro and NEON intrinsics stabilized in Rust 1.59, so this feature
+//!   requires Rust 1.59 or newer on AArch64.
 //!
 //!   By default, without this feature, conversions are done only in software, which will also be
 //!   the fallback if the target does not have hardware support. Note that without the `std`
 //!   feature enabled, no runtime CPU feature detection is used, so the hardware support is only
 //!   compiled if the compiler target supports the CPU feature.
 //!
+//!   To select the F16C or NEON conversion kernels statically without enabling the CPU feature
+//!   for the whole program with `-Ctarget-feature`, build with `RUSTFLAGS="--cfg half_force_f16c"`
+//!   or `RUSTFLAGS="--cfg half_force_neon"`. This also gives `no_std` builds the vectorized `f16`
+//!   slice conversions, and removes the runtime detection of that CPU feature with `std`. These
+//!   are not cargo features so that only the final build, not a dependency, can opt in: the
+//!   kernels are used without any check, so running such a program on a CPU without F16C and AVX,
+//!   or without NEON, is undefined behavior.
+//!
+//! - **`lut`** -- Use lookup tables for the software `f16` conversions to and from `f32` and `f64`,
+//!   instead of computing them with branches and bit manipulation. This adds a 256 KiB table with
+//!   the `f32` value of every `f16`, and two small tables indexed by the `f32` exponent for the
+//!   other direction. The results are identical, and hardware intrinsics are still preferred when
+//!   `use-intrinsics` finds them, so this mostly helps targets without F16C or NEON. The `const`
+//!   conversion methods are unaffected.
+//!
 //! - **`alloc`** -- Enable use of the [`alloc`] crate when not using the `std` library.
 //!
 //!   Among other functions, this enables the [`vec`] module, which contains zero-copy
 //!   conversions for the [`Vec`] type. This allows fast conversion between raw `Vec<u16>` bits and
-//!   `Vec<f16>` or `Vec<bf16>` arrays, and vice versa.
+//!   `Vec<f16>` or `Vec<bf16>` arrays, and vice versa, as well as for owned slices in a `Box` or
+//!   `Rc`, and an `Arc` with the `std` feature. It also enables the `to_f32_vec` and `to_f64_vec`
+//!   slice conversions, the `to_hex_string` methods, and the sequence adapters of the
+//!   `half::serde` module.
+//!
+//!   Everything else in the crate only depends on [`core`], and nothing that only needs the
+//!   [`alloc`] crate requires the `std` feature, so `no_std` targets with an allocator have access
+//!   to all of these APIs.
+//!
+//! - **`softfloat`** -- Use the correctly rounded integer-only [`softfloat`] module as the backend
+//!   for the arithmetic operators of [`f16`], instead of promoting to [`f32`]. The functions in
+//!   [`softfloat`] are always available, regardless of this feature.
+//!
+//! - **`nightly-f16`** -- Implement zero-cost [`From`] conversions between [`f16`] and the unstable
+//!   `f16` primitive type, and perform the arithmetic operators of [`f16`] with the primitive type
+//!   so the compiler can use native half-precision instructions. Requires nightly Rust. The
+//!   `softfloat` feature still takes precedence for arithmetic.
+//!
+//! - **`portable-simd`** -- Adds the [`simd`] module of [`f16`] vectors built on the unstable
+//!   `core::simd` types, with lane-wise conversions, arithmetic and comparisons. Requires nightly
+//!   Rust.
+//!
+//! - **`math`** -- Enables the math methods of [`f16`] and [`bf16`], such as `powf`, without the
+//!   `std` feature by implementing them with the [`libm`] crate. They are evaluated in [`f64`] and
+//!   rounded once to the result type. With `std`, these methods are always available and use the
+//!   math functions of [`std`] instead.
+//!
+//!   The special functions `erf`, `erfc`, `gamma` and `ln_gamma` have no stable counterparts in
+//!   [`std`], so they always use [`libm`] and require this feature.
 //!
 //! - **`std`** -- Enable features that depend on the Rust [`std`] library. This also enables the
 //!   `alloc` feature automatically.
@@ -73,18 +146,99 @@
 //!   `use-intrsincis` feature is also enabled. Without this feature detection, intrinsics are only
 //!   used when compiler target supports the target feature.
 //!
+//!   The `std::error::Error` implementations of the error types and the native [`atomic`] types
+//!   also require this feature, as do the [`io`] extension traits to read and write [`f16`] and
+//!   [`bf16`] values with `std::io`.
+//!
 //! - **`serde`** -- Adds support for the [`serde`] crate by implementing [`Serialize`] and
 //!   [`Deserialize`] traits for both [`f16`] and [`bf16`].
 //!
 //! - **`num-traits`** -- Adds support for the [`num-traits`] crate by implementing [`ToPrimitive`],
-//!   [`FromPrimitive`], [`AsPrimitive`], [`Num`], [`Float`], [`FloatCore`], and [`Bounded`] traits
-//!   for both [`f16`] and [`bf16`].
+//!   [`FromPrimitive`], [`AsPrimitive`], [`Num`], [`Float`], [`FloatCore`], [`Bounded`], and
+//!   [`Signed`] traits for both [`f16`] and [`bf16`].
 //!
 //! - **`bytemuck`** -- Adds support for the [`bytemuck`] crate by implementing [`Zeroable`] and
 //!   [`Pod`] traits for both [`f16`] and [`bf16`].
 //!
-//! - **`zerocopy`** -- Adds support for the [`zerocopy`] crate by implementing [`AsBytes`] and
-//!   [`FromBytes`] traits for both [`f16`] and [`bf16`].
+//! - **`zerocopy`** -- Adds support for the [`zerocopy`] crate by implementing [`FromBytes`],
+//!   [`IntoBytes`], [`Immutable`] and [`KnownLayout`] traits for [`f16`], [`bf16`] and the 8-bit
+//!   float types, which also implement [`Unaligned`].
+//!
+//! - **`rayon`** -- Adds `par_` prefixed conversions of large slices and vectors to the
+//!   [`slice`][mod@slice] and [`vec`] extension traits, which split the work across the [`rayon`]
+//!   thread pool.
+//!
+//! - **`rkyv`** -- Adds support for the [`rkyv`] zero-copy deserialization framework by implementing
+//!   its `Archive`, `Serialize` and `Deserialize` traits for both [`f16`] and [`bf16`]. The archived
+//!   forms are `ArchivedF16` and `ArchivedBf16`, which store the raw bits and honor the
+//!   endianness features of [`rkyv`].
+//!
+//! - **`speedy`** -- Adds support for the [`speedy`] crate by implementing its `Readable` and
+//!   `Writable` traits for both [`f16`] and [`bf16`], which are encoded as their [`u16`] bits in the
+//!   byte order of the context.
+//!
+//! - **`rand`** -- Adds support for the [`rand`] crate by implementing [`Distribution`] for its
+//!   [`Standard`] distribution, which generates values uniformly in `[0, 1)`, and [`SampleUniform`]
+//!   so that ranges of [`f16`] and [`bf16`] can be sampled with [`Rng::gen_range`] and
+//!   [`Uniform`]. The [`Open01`] and [`OpenClosed01`] distributions are also supported. Large
+//!   buffers can be filled in vectorized batches with `fill_with_standard` and
+//!   `fill_with_distribution`.
+//!
+//! - **`rand_distr`** -- Extends the `rand` feature, which must also be enabled, with the
+//!   `StandardNormal` and `Exp1` distributions of the [`rand_distr`] crate. Together with the
+//!   `num-traits` feature, this also allows generic distributions such as `Normal<f16>`. Buffers
+//!   can be initialized with normally distributed values in bulk with `fill_with_normal`.
+//!
+//! - **`arbitrary`** -- Adds support for the [`arbitrary`] crate by implementing its `Arbitrary`
+//!   trait for [`f16`], [`bf16`] and the 8-bit float types. Any bit pattern can be generated,
+//!   including NaNs and subnormals, to give fuzz targets full coverage of the formats.
+//!
+//! - **`quickcheck`** -- Adds support for the [`quickcheck`] crate by implementing its `Arbitrary`
+//!   trait for [`f16`] and [`bf16`]. Like the `arbitrary` feature, any bit pattern can be
+//!   generated, so property tests also cover NaNs, infinities and subnormals.
+//!
+//! - **`approx`** -- Adds support for the [`approx`] crate by implementing its `AbsDiffEq`,
+//!   `RelativeEq` and `UlpsEq` traits for [`f16`] and [`bf16`], so that `assert_relative_eq!` and
+//!   friends work on half precision values. The default epsilon is the type's `EPSILON`, and ULPs
+//!   are counted on the 16-bit representation.
+//!
+//! - **`az`** -- Adds support for the [`az`] crate by implementing its `Cast`, `CheckedCast`,
+//!   `SaturatingCast` and `UnwrappedCast` traits from the primitive numbers to [`f16`] and [`bf16`],
+//!   where a finite value overflowing to infinity is the error case, and all of its cast traits
+//!   from [`f16`] and [`bf16`] to the primitive integers, which behave like the casts of [`f32`].
+//!
+//! - **`num-complex`** -- Adds the [`complex`] module with conversions between `Complex<f16>` or
+//!   `Complex<bf16>` numbers of the [`num-complex`] crate and their [`f32`] or [`f64`]
+//!   counterparts, for single values and for interleaved slices. Complex arithmetic additionally
+//!   requires the `num-traits` feature.
+//!
+//! - **`nalgebra`** -- Allows [`f16`] and [`bf16`] to be used as scalars of [`nalgebra`] vectors and
+//!   matrices by implementing the `Field`, `ComplexField` and `RealField` traits of the [`simba`]
+//!   crate. Math functions are evaluated in [`f32`] and rounded back. This feature also enables the
+//!   `num-traits` and `approx` features.
+//!
+//! - **`ndarray`** -- Implements `ScalarOperand` of the [`ndarray`] crate for [`f16`] and [`bf16`],
+//!   along with the scalar-on-the-left operators, so arrays of half precision values can be combined
+//!   with scalars. Together with the `num-traits` feature, this also enables `dot`, `sum` and
+//!   `mean` on those arrays.
+//!
+//! - **`portable-atomic`** -- Makes the [`atomic`] module available without `std`, and implements
+//!   its types with the [`portable-atomic`] crate, so they also work on targets without native
+//!   16-bit atomic operations, such as `thumbv6m`.
+//!
+//! - **`byteorder`** -- Implements the byte order trait of the [`io`] module for the
+//!   `LittleEndian` and `BigEndian` types of the [`byteorder`] crate, so the same type parameters,
+//!   such as `byteorder::LE`, can be used to read half precision fields as other numbers. Requires
+//!   the `std` feature.
+//!
+//! - **`ffi`** -- Exports `extern "C"` functions such as `half_to_float` and `float_to_half_array`
+//!   from the [`ffi`] module, so C and C++ code can call the conversions of this crate.
+//!
+//! - **`cuda`** -- Implements the `DeviceCopy` trait of [`cust`] for [`f16`] and [`bf16`], so they
+//!   can be copied to and from CUDA device buffers, where they have the same layout as the `__half`
+//!   and `__nv_bfloat16` types. The `as_bytes` and `as_bytes_mut` methods of
+//!   [`HalfFloatSliceExt`][slice::HalfFloatSliceExt] view half precision slices as the byte
+//!   buffers expected by lower level GPU APIs.
 //!
 //! [`alloc`]: https://doc.rust-lang.org/alloc/
 //! [`std`]: https://doc.rust-lang.org/std/
@@ -94,6 +248,23 @@
 //! [`bytemuck`]: https://crates.io/crates/bytemuck
 //! [`num-traits`]: https://crates.io/crates/num-traits
 //! [`zerocopy`]: https://crates.io/crates/zerocopy
+//! [`rayon`]: https://crates.io/crates/rayon
+//! [`rkyv`]: https://crates.io/crates/rkyv
+//! [`arbitrary`]: https://crates.io/crates/arbitrary
+//! [`quickcheck`]: https://crates.io/crates/quickcheck
+//! [`rand`]: https://crates.io/crates/rand
+//! [`speedy`]: https://crates.io/crates/speedy
+//! [`rand_distr`]: https://crates.io/crates/rand_distr
+//! [`approx`]: https://crates.io/crates/approx
+//! [`az`]: https://crates.io/crates/az
+//! [`num-complex`]: https://crates.io/crates/num-complex
+//! [`nalgebra`]: https://crates.io/crates/nalgebra
+//! [`simba`]: https://crates.io/crates/simba
+//! [`ndarray`]: https://crates.io/crates/ndarray
+//! [`portable-atomic`]: https://crates.io/crates/portable-atomic
+//! [`byteorder`]: https://crates.io/crates/byteorder
+//! [`cust`]: https://crates.io/crates/cust
+//! [`libm`]: https://crates.io/crates/libm
 #![cfg_attr(
     feature = "alloc",
     doc = "
@@ -105,11 +276,61 @@
 [`vec`]: #
 [`Vec`]: https://docs.rust-lang.org/stable/alloc/vec/struct.Vec.html"
 )]
+#![cfg_attr(
+    feature = "portable-simd",
+    doc = "
+[`simd`]: mod@simd"
+)]
+#![cfg_attr(
+    not(feature = "portable-simd"),
+    doc = "
+[`simd`]: #"
+)]
+#![cfg_attr(
+    feature = "ffi",
+    doc = "
+[`ffi`]: mod@ffi"
+)]
+#![cfg_attr(
+    not(feature = "ffi"),
+    doc = "
+[`ffi`]: #"
+)]
+#![cfg_attr(
+    feature = "std",
+    doc = "
+[`io`]: mod@io"
+)]
+#![cfg_attr(
+    not(feature = "std"),
+    doc = "
+[`io`]: #"
+)]
+#![cfg_attr(
+    any(feature = "std", feature = "portable-atomic"),
+    doc = "
+[`atomic`]: mod@atomic"
+)]
+#![cfg_attr(
+    not(any(feature = "std", feature = "portable-atomic")),
+    doc = "
+[`atomic`]: #"
+)]
+#![cfg_attr(
+    feature = "num-complex",
+    doc = "
+[`complex`]: mod@complex"
+)]
+#![cfg_attr(
+    not(feature = "num-complex"),
+    doc = "
+[`complex`]: #"
+)]
 #![cfg_attr(
     feature = "serde",
     doc = "
-[`Serialize`]: serde::Serialize
-[`Deserialize`]: serde::Deserialize"
+[`Serialize`]: ::serde::Serialize
+[`Deserialize`]: ::serde::Deserialize"
 )]
 #![cfg_attr(
     not(feature = "serde"),
@@ -126,7 +347,8 @@
 [`Num`]: ::num_traits::Num
 [`Float`]: ::num_traits::Float
 [`FloatCore`]: ::num_traits::float::FloatCore
-[`Bounded`]: ::num_traits::Bounded"
+[`Bounded`]: ::num_traits::Bounded
+[`Signed`]: ::num_traits::Signed"
 )]
 #![cfg_attr(
     not(feature = "num-traits"),
@@ -137,7 +359,8 @@
 [`Num`]: https://docs.rs/num-traits/*/num_traits/trait.Num.html
 [`Float`]: https://docs.rs/num-traits/*/num_traits/float/trait.Float.html
 [`FloatCore`]: https://docs.rs/num-traits/*/num_traits/float/trait.FloatCore.html
-[`Bounded`]: https://docs.rs/num-traits/*/num_traits/bounds/trait.Bounded.html"
+[`Bounded`]: https://docs.rs/num-traits/*/num_traits/bounds/trait.Bounded.html
+[`Signed`]: https://docs.rs/num-traits/*/num_traits/sign/trait.Signed.html"
 )]
 #![cfg_attr(
     feature = "bytemuck",
@@ -154,14 +377,42 @@
 #![cfg_attr(
     feature = "zerocopy",
     doc = "
-[`AsBytes`]: zerocopy::AsBytes
-[`FromBytes`]: zerocopy::FromBytes"
+[`FromBytes`]: zerocopy::FromBytes
+[`IntoBytes`]: zerocopy::IntoBytes
+[`Immutable`]: zerocopy::Immutable
+[`KnownLayout`]: zerocopy::KnownLayout
+[`Unaligned`]: zerocopy::Unaligned"
 )]
 #![cfg_attr(
     not(feature = "zerocopy"),
     doc = "
-[`AsBytes`]: https://docs.rs/zerocopy/*/zerocopy/trait.AsBytes.html
-[`FromBytes`]: https://docs.rs/zerocopy/*/zerocopy/trait.FromBytes.html"
+[`FromBytes`]: https://docs.rs/zerocopy/*/zerocopy/trait.FromBytes.html
+[`IntoBytes`]: https://docs.rs/zerocopy/*/zerocopy/trait.IntoBytes.html
+[`Immutable`]: https://docs.rs/zerocopy/*/zerocopy/trait.Immutable.html
+[`KnownLayout`]: https://docs.rs/zerocopy/*/zerocopy/trait.KnownLayout.html
+[`Unaligned`]: https://docs.rs/zerocopy/*/zerocopy/trait.Unaligned.html"
+)]
+#![cfg_attr(
+    feature = "rand",
+    doc = "
+[`Distribution`]: ::rand::distributions::Distribution
+[`Standard`]: ::rand::distributions::Standard
+[`SampleUniform`]: ::rand::distributions::uniform::SampleUniform
+[`Rng::gen_range`]: ::rand::Rng::gen_range
+[`Uniform`]: ::rand::distributions::Uniform
+[`Open01`]: ::rand::distributions::Open01
+[`OpenClosed01`]: ::rand::distributions::OpenClosed01"
+)]
+#![cfg_attr(
+    not(feature = "rand"),
+    doc = "
+[`Distribution`]: https://docs.rs/rand/0.8/rand/distributions/trait.Distribution.html
+[`Standard`]: https://docs.rs/rand/0.8/rand/distributions/struct.Standard.html
+[`SampleUniform`]: https://docs.rs/rand/0.8/rand/distributions/uniform/trait.SampleUniform.html
+[`Rng::gen_range`]: https://docs.rs/rand/0.8/rand/trait.Rng.html#method.gen_range
+[`Uniform`]: https://docs.rs/rand/0.8/rand/distributions/struct.Uniform.html
+[`Open01`]: https://docs.rs/rand/0.8/rand/distributions/struct.Open01.html
+[`OpenClosed01`]: https://docs.rs/rand/0.8/rand/distributions/struct.OpenClosed01.html"
 )]
 #![warn(
     missing_docs,
@@ -172,62 +423,268 @@
 #![cfg_attr(not(target_arch = "spirv"), warn(missing_debug_implementations))]
 #![allow(clippy::verbose_bit_mask, clippy::cast_lossless)]
 #![cfg_attr(not(feature = "std"), no_std)]
-#![cfg_attr(
-    all(
-        feature = "use-intrinsics",
-        any(target_arch = "x86", target_arch = "x86_64")
-    ),
-    feature(stdsimd, f16c_target_feature)
-)]
+#![cfg_attr(feature = "nightly-f16", feature(f16))]
+#![cfg_attr(feature = "portable-simd", feature(portable_simd))]
 #![doc(html_root_url = "https://docs.rs/half/2.1.0")]
 #![doc(test(attr(deny(warnings), allow(unused))))]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
-#[cfg(feature = "alloc")]
+// The `quickcheck` shrinkers return a `Box`, and that crate already requires `std`
+#[cfg(any(feature = "alloc", feature = "quickcheck", test))]
 extern crate alloc;
 
+#[cfg(feature = "approx")]
+mod approx;
+#[cfg(any(feature = "std", feature = "portable-atomic"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "portable-atomic"))))]
+pub mod atomic;
+#[cfg(feature = "az")]
+mod az;
 mod bfloat;
 mod binary16;
+mod cmp;
+#[cfg(all(feature = "num-complex", not(target_arch = "spirv")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-complex")))]
+pub mod complex;
+#[cfg(feature = "cuda")]
+mod cuda;
+mod dispatch;
+mod e4m3;
+mod e5m2;
+#[cfg(not(target_arch = "spirv"))]
+mod error;
+mod exceptions;
+#[cfg(feature = "ffi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ffi")))]
+pub mod ffi;
+mod flush;
+#[cfg(not(target_arch = "spirv"))]
+mod fmt;
+mod int;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod io;
+#[cfg(not(target_arch = "spirv"))]
+pub mod iter;
 mod leading_zeros;
+pub mod macros;
+#[cfg(any(feature = "std", feature = "math"))]
+mod math;
+mod minifloat;
+#[cfg(feature = "ndarray")]
+mod ndarray;
 #[cfg(feature = "num-traits")]
 mod num_traits;
+#[cfg(not(target_arch = "spirv"))]
+pub mod ordered;
+#[cfg(not(target_arch = "spirv"))]
+pub mod packed;
+mod packed_float;
+#[cfg(not(target_arch = "spirv"))]
+mod parse;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(all(feature = "rand", feature = "rand_distr"))]
+mod rand_distr;
+mod rounding;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
+#[cfg(feature = "nalgebra")]
+mod simba;
+#[cfg(feature = "portable-simd")]
+#[cfg_attr(docsrs, doc(cfg(feature = "portable-simd")))]
+pub mod simd;
 
 #[cfg(not(target_arch = "spirv"))]
 pub mod slice;
+pub mod softfloat;
+#[cfg(not(target_arch = "spirv"))]
+pub mod stats;
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 pub mod vec;
 
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+pub use self::rand::{fill_with_distribution, fill_with_standard, UniformBf16, UniformF16};
+#[cfg(all(feature = "rand", feature = "rand_distr"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand_distr")))]
+pub use self::rand_distr::fill_with_normal;
+
+#[cfg(all(feature = "rand_distr", not(feature = "rand")))]
+compile_error!("the `rand_distr` feature requires the `rand` feature to be enabled as well");
+#[cfg(all(feature = "byteorder", not(feature = "std")))]
+compile_error!("the `byteorder` feature requires the `std` feature to be enabled as well");
 pub use bfloat::bf16;
+#[cfg(feature = "rkyv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+pub use bfloat::ArchivedBf16;
 pub use binary16::f16;
+#[cfg(feature = "rkyv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv")))]
+pub use binary16::ArchivedF16;
+pub use e4m3::f8e4m3;
+pub use e5m2::f8e5m2;
+#[cfg(not(target_arch = "spirv"))]
+pub use error::{ParseError, TryFromFloatError};
+pub use exceptions::ExceptionFlags;
+pub use flush::FlushMode;
+pub use int::FloatToInt;
+pub use minifloat::Minifloat;
+pub use packed_float::{Rg11b10Ufloat, Rgb9e5Ufloat};
+pub use rounding::RoundingMode;
 
 /// A collection of the most used items and traits in this crate for easy importing.
 ///
+/// Importing `half::prelude::*` brings in the [`f16`][struct@f16] and [`bf16`] types and the extension traits
+/// that add conversions to slices, vectors and iterators of them. This is the same as
+/// [`prelude::v1`], and it will not grow, so a glob import of it keeps compiling as the crate
+/// adds more items.
+///
+/// [`prelude::v2`] is a superset for code that uses more of the crate. It also includes the
+/// 8-bit types, the ordered wrappers, the packed vectors, the [`FloatToInt`] bound, the I/O
+/// extension traits and the [`f16!`][macros::f16] and [`bf16!`][macros::bf16] macros. Since
+/// these names are more likely to clash with other crates, it has to be imported explicitly.
+///
 /// # Examples
 ///
 /// ```rust
 /// use half::prelude::*;
+///
+/// let mut buffer = [f16::ZERO; 4];
+/// buffer.convert_from_f32_slice(&[1.0, 2.0, 3.0, 4.0]);
+/// assert_eq!(buffer.sum_f32(), 10.0);
+/// ```
+///
+/// ```rust
+/// use half::prelude::v2::*;
+///
+/// const SCALE: bf16 = bf16!(0.125);
+/// let mut keys = [TotalOrdBf16(SCALE), TotalOrdBf16(-SCALE)];
+/// keys.sort();
+/// assert_eq!(keys[0].0, -SCALE);
+///
+/// fn quantize<T: FloatToInt>(x: f16) -> T {
+///     x.to_int()
+/// }
+/// assert_eq!(quantize::<u8>(f16!(300.5)), 255);
 /// ```
 pub mod prelude {
     #[doc(no_inline)]
-    pub use crate::{bf16, f16};
+    pub use self::v1::*;
 
-    #[cfg(not(target_arch = "spirv"))]
-    #[doc(no_inline)]
-    pub use crate::slice::{HalfBitsSliceExt, HalfFloatSliceExt};
+    /// The original prelude, with the half precision types and the conversion extension traits.
+    pub mod v1 {
+        #[doc(no_inline)]
+        pub use crate::{bf16, f16};
 
-    #[cfg(feature = "alloc")]
-    #[doc(no_inline)]
-    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-    pub use crate::vec::{HalfBitsVecExt, HalfFloatVecExt};
+        #[cfg(not(target_arch = "spirv"))]
+        #[doc(no_inline)]
+        pub use crate::{
+            iter::{F32IteratorExt, HalfIteratorExt},
+            slice::{HalfBitsSliceExt, HalfFloatSliceExt, HalfUninitSliceExt},
+        };
+
+        #[cfg(feature = "alloc")]
+        #[doc(no_inline)]
+        #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+        pub use crate::vec::{
+            HalfBitsOwnedSliceExt, HalfBitsVecExt, HalfCowSliceExt, HalfFloatOwnedSliceExt,
+            HalfFloatVecExt,
+        };
+
+        #[cfg(all(feature = "num-complex", not(target_arch = "spirv")))]
+        #[doc(no_inline)]
+        #[cfg_attr(docsrs, doc(cfg(feature = "num-complex")))]
+        pub use crate::complex::{HalfComplexExt, HalfComplexSliceExt};
+    }
+
+    /// Everything in [`v1`], along with the other types, traits and macros of the crate that
+    /// are commonly used together.
+    pub mod v2 {
+        #[doc(no_inline)]
+        pub use super::v1::*;
+
+        #[doc(no_inline)]
+        pub use crate::{
+            f8e4m3, f8e5m2,
+            macros::{bf16, f16},
+            FloatToInt,
+        };
+
+        #[cfg(not(target_arch = "spirv"))]
+        #[doc(no_inline)]
+        pub use crate::{
+            ordered::{NonNanBf16, NonNanF16, TotalOrdBf16, TotalOrdF16},
+            packed::{f16x4, f16x8},
+        };
+
+        #[cfg(feature = "std")]
+        #[doc(no_inline)]
+        #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+        pub use crate::io::{ReadHalfExt, WriteHalfExt};
+    }
 }
 
 // Keep this module private to crate
 mod private {
     use crate::{bf16, f16};
 
-    pub trait SealedHalf {}
+    pub trait SealedHalf {
+        /// Number of finite values of each sign
+        const FINITE_PER_SIGN: u16;
+        const MANTISSA_DIGITS: u32;
+
+        fn from_bits(bits: u16) -> Self;
+        fn from_f32(value: f32) -> Self;
+        fn to_f32(self) -> f32;
+    }
+
+    impl SealedHalf for f16 {
+        const FINITE_PER_SIGN: u16 = 0x7C00;
+        const MANTISSA_DIGITS: u32 = f16::MANTISSA_DIGITS;
+
+        #[inline]
+        fn from_bits(bits: u16) -> f16 {
+            f16::from_bits(bits)
+        }
+
+        #[inline]
+        fn from_f32(value: f32) -> f16 {
+            f16::from_f32(value)
+        }
+
+        #[inline]
+        fn to_f32(self) -> f32 {
+            f16::to_f32(self)
+        }
+    }
+
+    impl SealedHalf for bf16 {
+        const FINITE_PER_SIGN: u16 = 0x7F80;
+        const MANTISSA_DIGITS: u32 = bf16::MANTISSA_DIGITS;
+
+        #[inline]
+        fn from_bits(bits: u16) -> bf16 {
+            bf16::from_bits(bits)
+        }
+
+        #[inline]
+        fn from_f32(value: f32) -> bf16 {
+            bf16::from_f32(value)
+        }
+
+        #[inline]
+        fn to_f32(self) -> f32 {
+            bf16::to_f32(self)
+        }
+    }
 
-    impl SealedHalf for f16 {}
-    impl SealedHalf for bf16 {}
+    pub trait SealedInt: Sized {
+        fn saturating_from_f32(value: f32) -> Self;
+        fn saturating_from_f64(value: f64) -> Self;
+        unsafe fn unchecked_from_f32(value: f32) -> Self;
+        fn into_sign_magnitude(self) -> (bool, u128);
+    }
 }
@@ -0,0 +1,100 @@
+//! Macros to construct [`f16`][crate::f16] and [`bf16`][crate::bf16] constants from literals.
+//!
+//! The macros live in this module rather than the crate root, where they would share their names
+//! with the types. Import them from here or from [`prelude::v2`][crate::prelude::v2].
+
+/// Constructs an [`f16`][crate::f16] constant from a numeric literal or constant expression.
+///
+/// The value is converted to [`f64`] with an `as` cast and then rounded to the nearest
+/// [`f16`][crate::f16] with [`from_f64_const`][crate::f16::from_f64_const]. A decimal literal with
+/// more digits than [`f64`] can hold is therefore rounded twice, which gives a different result
+/// than rounding the exact decimal when it lies just past halfway between two [`f16`][crate::f16]
+/// values. The conversion is always evaluated at compile time, even outside of a `const` context,
+/// so the argument must be a constant expression.
+///
+/// # Examples
+///
+/// ```rust
+/// use half::{f16, macros::f16};
+///
+/// const HALF_PI: f16 = f16!(1.5707963267948966);
+/// assert_eq!(HALF_PI, f16::from_f64(core::f64::consts::FRAC_PI_2));
+/// assert_eq!(f16!(-3), f16::from_f32(-3.0));
+/// assert_eq!(f16!(1e10), f16::INFINITY);
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __f16 {
+    ($value:expr) => {{
+        #[allow(trivial_numeric_casts, clippy::unnecessary_cast)]
+        const VALUE: $crate::f16 = $crate::f16::from_f64_const($value as f64);
+        VALUE
+    }};
+}
+
+/// Constructs a [`bf16`][crate::bf16] constant from a numeric literal or constant expression.
+///
+/// The value is converted to [`f64`] with an `as` cast and then rounded to the nearest
+/// [`bf16`][crate::bf16] with [`from_f64_const`][crate::bf16::from_f64_const]. Values exact in
+/// [`f64`] are rounded only once, unlike when going through an [`f32`] literal, but a decimal
+/// literal with more digits than [`f64`] can hold is still rounded twice. For example,
+/// `bf16!(1.00390625000000000001)` rounds to `1.00390625` in [`f64`], exactly halfway between
+/// two [`bf16`][crate::bf16] values, and so gives `1.0` instead of the next value up. The
+/// conversion is always evaluated at compile time, even outside of a `const` context, so the
+/// argument must be a constant expression.
+///
+/// # Examples
+///
+/// ```rust
+/// use half::{bf16, macros::bf16};
+///
+/// const PI: bf16 = bf16!(3.14159);
+/// assert_eq!(PI, bf16::from_f64(3.14159));
+/// assert_eq!(PI.to_bits(), 0x4049);
+/// assert_eq!(bf16!(1 << 20), bf16::from_f32(1048576.0));
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bf16 {
+    ($value:expr) => {{
+        #[allow(trivial_numeric_casts, clippy::unnecessary_cast)]
+        const VALUE: $crate::bf16 = $crate::bf16::from_f64_const($value as f64);
+        VALUE
+    }};
+}
+
+#[doc(inline)]
+pub use crate::{__bf16 as bf16, __f16 as f16};
+
+#[cfg(test)]
+mod test {
+    use crate::{bf16, f16};
+
+    const TABLE: [bf16; 3] = [super::bf16!(0.1), super::bf16!(-2), super::bf16!(f32::MAX)];
+
+    #[test]
+    fn test_literals() {
+        assert_eq!(TABLE[0], bf16::from_f64(0.1));
+        assert_eq!(TABLE[1], bf16::from_f32(-2.0));
+        assert_eq!(TABLE[2], bf16::INFINITY);
+        assert_eq!(super::f16!(0.1), f16::from_f64(0.1));
+        assert_eq!(super::f16!(65504u32), f16::MAX);
+        assert!(super::f16!(f64::NAN).is_nan());
+        assert_eq!(super::f16!(-0.0).to_bits(), 0x8000);
+    }
+
+    #[test]
+    fn test_single_rounding() {
+        // Rounding to `f32` first would land on the midpoint and round to even
+        let value = super::bf16!(1.0 + 1.0 / 256.0 + 1.0 / 1099511627776.0);
+        assert_eq!(value.to_bits(), 0x3F81);
+        assert_eq!(
+            bf16::from_f32((1.0 + 1.0 / 256.0 + 1.0 / 1099511627776.0) as f32).to_bits(),
+            0x3F80
+        );
+        // A literal with more digits than `f64` holds is rounded to `f64` first
+        #[allow(clippy::excessive_precision)]
+        let value = super::bf16!(1.00390625000000000001);
+        assert_eq!(value.to_bits(), 0x3F80);
+    }
+}
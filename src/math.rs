@@ -0,0 +1,109 @@
+//! The [`f64`] functions behind the math methods of [`f16`][crate::f16] and [`bf16`][crate::bf16].
+//!
+//! They come from `std` when it is available, and from the [`libm`] crate otherwise, except for the
+//! special functions, which always come from [`libm`]. Every half
+//! precision value is exact in [`f64`], and the [`f64`] results are far more precise than half
+//! precision, so rounding them once gives the nearest half precision value except when the exact
+//! result lies extremely close to the midpoint of two values.
+//!
+//! [`libm`]: https://crates.io/crates/libm
+
+// Each function is given with the name of its `f64` method and of its `libm` function.
+macro_rules! math_fn {
+    ($($name:ident($($arg:ident),+) => $std:ident, $libm:ident;)*) => {$(
+        #[cfg(feature = "std")]
+        #[inline]
+        pub(crate) fn $name($($arg: f64),+) -> f64 {
+            f64::$std($($arg),+)
+        }
+
+        #[cfg(not(feature = "std"))]
+        pub(crate) use libm::$libm as $name;
+    )*};
+}
+
+math_fn! {
+    pow(x, y) => powf, pow;
+    ln_1p(x) => ln_1p, log1p;
+    exp_m1(x) => exp_m1, expm1;
+    exp2(x) => exp2, exp2;
+}
+
+// The special functions have no stable `f64` methods, so they always come from `libm`
+#[cfg(feature = "math")]
+pub(crate) use libm::{erf, erfc, lgamma_r, tgamma};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{bf16, f16};
+
+    // `f64::abs` is not available in `core` before Rust 1.85
+    fn abs(x: f64) -> f64 {
+        f64::from_bits(x.to_bits() & !(1 << 63))
+    }
+
+    // Checks exhaustively that the `f64` result of a function is never so close to the midpoint of
+    // two half precision values that the error of the `f64` function could change the rounding.
+    // Results exactly on a midpoint are exact powers, which are rounded correctly to even.
+    macro_rules! assert_correctly_rounded {
+        ($ty:ident, |$x:ident| $exact:expr, $method:expr) => {
+            for $x in $ty::all_finite() {
+                let exact: f64 = $exact;
+                let rounded: $ty = $method;
+                if !rounded.is_finite() || exact == rounded.to_f64() {
+                    continue;
+                }
+                for neighbor in [rounded.next_down(), rounded.next_up()] {
+                    if neighbor.is_finite() {
+                        let midpoint = (rounded.to_f64() + neighbor.to_f64()) / 2.0;
+                        assert!(
+                            exact == midpoint || abs(exact - midpoint) > abs(midpoint) * 1e-14,
+                            "{}: {} is too close to a midpoint",
+                            $x,
+                            exact
+                        );
+                    }
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn test_f16_correctly_rounded() {
+        let three = f16::from_f32(3.0);
+        assert_correctly_rounded!(f16, |x| ln_1p(x.to_f64()), x.ln_1p());
+        assert_correctly_rounded!(f16, |x| exp_m1(x.to_f64()), x.exp_m1());
+        assert_correctly_rounded!(f16, |x| exp2(x.to_f64()), x.exp2());
+        assert_correctly_rounded!(f16, |x| pow(3.0, x.to_f64()), three.powf(x));
+        assert_correctly_rounded!(f16, |x| pow(x.to_f64(), 3.0), x.powf(three));
+    }
+
+    #[cfg(feature = "math")]
+    #[test]
+    fn test_f16_special_functions_correctly_rounded() {
+        assert_correctly_rounded!(f16, |x| erf(x.to_f64()), x.erf());
+        assert_correctly_rounded!(f16, |x| erfc(x.to_f64()), x.erfc());
+        assert_correctly_rounded!(f16, |x| tgamma(x.to_f64()), x.gamma());
+        assert_correctly_rounded!(f16, |x| lgamma_r(x.to_f64()).0, x.ln_gamma().0);
+    }
+
+    #[test]
+    fn test_bf16_correctly_rounded() {
+        let three = bf16::from_f32(3.0);
+        assert_correctly_rounded!(bf16, |x| ln_1p(x.to_f64()), x.ln_1p());
+        assert_correctly_rounded!(bf16, |x| exp_m1(x.to_f64()), x.exp_m1());
+        assert_correctly_rounded!(bf16, |x| exp2(x.to_f64()), x.exp2());
+        assert_correctly_rounded!(bf16, |x| pow(3.0, x.to_f64()), three.powf(x));
+        assert_correctly_rounded!(bf16, |x| pow(x.to_f64(), 3.0), x.powf(three));
+    }
+
+    #[cfg(feature = "math")]
+    #[test]
+    fn test_bf16_special_functions_correctly_rounded() {
+        assert_correctly_rounded!(bf16, |x| erf(x.to_f64()), x.erf());
+        assert_correctly_rounded!(bf16, |x| erfc(x.to_f64()), x.erfc());
+        assert_correctly_rounded!(bf16, |x| tgamma(x.to_f64()), x.gamma());
+        assert_correctly_rounded!(bf16, |x| lgamma_r(x.to_f64()).0, x.ln_gamma().0);
+    }
+}
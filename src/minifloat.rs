@@ -0,0 +1,552 @@
+// `f32::from_bits`/`f64::from_bits` are not `const` on our MSRV
+#![allow(
+    unknown_lints,
+    unnecessary_transmutes,
+    clippy::missing_transmute_annotations
+)]
+#[cfg(not(target_arch = "spirv"))]
+use crate::fmt;
+use crate::{
+    rounding::{self, Rounding},
+    RoundingMode,
+};
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt::{Debug, Display, Error, Formatter, LowerExp, UpperExp};
+use core::{cmp::Ordering, mem, num::FpCategory, ops::Neg};
+
+/// A floating point type with `EXP` exponent bits and `MANT` explicit mantissa bits, for
+/// prototyping custom small formats.
+///
+/// The format follows the IEEE 754 conventions: one sign bit, a biased exponent with a bias of
+/// `2^(EXP-1) - 1`, subnormals, and the largest exponent reserved for ±∞ and NaN. The bits are
+/// stored in the low `1 + EXP + MANT` bits of a [`u16`]. `EXP` must be between 2 and 8 and `MANT`
+/// at least 1, with `EXP + MANT` at most 15, so that every value converts to [`f32`] exactly.
+/// Using other widths fails to compile.
+///
+/// Conversions round with the same code as the conversions of [`f16`][crate::f16] and
+/// [`bf16`][crate::bf16]. `Minifloat<5, 10>` has the same bits as [`f16`][crate::f16],
+/// `Minifloat<8, 7>` as [`bf16`][crate::bf16] and `Minifloat<5, 2>` as
+/// [`f8e5m2`][crate::f8e5m2]. `Minifloat<4, 3>` is an IEEE-style E4M3 format with infinities,
+/// unlike [`f8e4m3`][crate::f8e4m3].
+///
+/// Like the other types in this crate, [`Minifloat`] is meant for storage and does not implement
+/// arithmetic.
+///
+/// # Examples
+///
+/// ```rust
+/// use half::Minifloat;
+///
+/// // A 6-bit format with 3 exponent bits and 2 mantissa bits
+/// type F6 = Minifloat<3, 2>;
+///
+/// assert_eq!(F6::MAX.to_f32(), 14.0);
+/// assert_eq!(F6::from_f32(2.7).to_f32(), 2.5);
+/// assert!(F6::from_f32(16.0).is_infinite());
+///
+/// // Printed with the fewest digits that round back to the same value
+/// let x = F6::from_f32(-0.3);
+/// assert_eq!(x.to_f32(), -0.3125);
+/// assert_eq!(x.to_string(), "-0.3");
+/// ```
+///
+/// Unsupported widths are rejected when the type is used:
+///
+/// ```compile_fail
+/// let x = half::Minifloat::<9, 6>::from_f32(1.0);
+/// ```
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct Minifloat<const EXP: u32, const MANT: u32>(u16);
+
+impl<const EXP: u32, const MANT: u32> Minifloat<EXP, MANT> {
+    // Evaluated for every format that is used, so unsupported widths fail to compile
+    const VALID: () = assert!(
+        EXP >= 2 && EXP <= 8 && MANT >= 1 && EXP + MANT <= 15,
+        "Minifloat needs 2 to 8 exponent bits, at least 1 mantissa bit, and at most 16 bits in total"
+    );
+    const SIGN_MASK: u16 = 1 << (EXP + MANT);
+    const EXP_MASK: u16 = ((1 << EXP) - 1) << MANT;
+    const MAN_MASK: u16 = (1 << MANT) - 1;
+    const BIAS: i32 = (1 << (EXP - 1)) - 1;
+
+    /// Constructs a value from the raw bits. Bits above the `1 + EXP + MANT` bits of the format
+    /// are ignored.
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: u16) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+        Minifloat(bits & (Self::SIGN_MASK | Self::EXP_MASK | Self::MAN_MASK))
+    }
+
+    /// Converts the value into the underlying bit representation, in the low `1 + EXP + MANT`
+    /// bits of a [`u16`].
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Constructs a value from a 32-bit floating point value, rounding to nearest with ties to
+    /// even.
+    ///
+    /// Values that are too large become ±∞, and NaN values are preserved.
+    #[inline]
+    #[must_use]
+    pub const fn from_f32(value: f32) -> Self {
+        Self::from_f32_round(value, RoundingMode::NearestEven)
+    }
+
+    /// Constructs a value from a 64-bit floating point value, rounding to nearest with ties to
+    /// even.
+    ///
+    /// Values that are too large become ±∞, and NaN values are preserved.
+    #[inline]
+    #[must_use]
+    pub const fn from_f64(value: f64) -> Self {
+        Self::from_f64_round(value, RoundingMode::NearestEven)
+    }
+
+    /// Constructs a value from a 32-bit floating point value, rounding in the given direction.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use half::{Minifloat, RoundingMode};
+    ///
+    /// type F6 = Minifloat<3, 2>;
+    /// assert_eq!(F6::from_f32_round(2.7, RoundingMode::TowardPositive).to_f32(), 3.0);
+    /// assert_eq!(F6::from_f32_round(100.0, RoundingMode::TowardZero), F6::MAX);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_f32_round(value: f32, mode: RoundingMode) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+        Self::from_ieee16(rounding::f32_to_ieee_round(
+            value,
+            Rounding::Mode(mode),
+            MANT,
+            EXP,
+        ))
+    }
+
+    /// Constructs a value from a 64-bit floating point value, rounding in the given direction.
+    #[inline]
+    #[must_use]
+    pub const fn from_f64_round(value: f64, mode: RoundingMode) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+        Self::from_ieee16(rounding::f64_to_ieee_round(
+            value,
+            Rounding::Mode(mode),
+            MANT,
+            EXP,
+        ))
+    }
+
+    // Moves the sign of a result of the 16-bit rounding functions into place
+    const fn from_ieee16(bits: u16) -> Self {
+        Minifloat(((bits >> (15 - EXP - MANT)) & Self::SIGN_MASK) | (bits & 0x7FFF))
+    }
+
+    // The significand and exponent of a nonzero finite value, so that it is `man * 2^exp`
+    const fn decode(self) -> (u32, i32) {
+        let exp = ((self.0 & Self::EXP_MASK) >> MANT) as i32;
+        let man = (self.0 & Self::MAN_MASK) as u32;
+        if exp == 0 {
+            (man, 1 - Self::BIAS - MANT as i32)
+        } else {
+            (man | (1 << MANT), exp - Self::BIAS - MANT as i32)
+        }
+    }
+
+    /// Converts the value into an [`f32`] value.
+    ///
+    /// This conversion is lossless as all values of the supported formats can be represented
+    /// exactly in [`f32`].
+    #[must_use]
+    pub const fn to_f32(self) -> f32 {
+        let sign = if self.is_sign_negative() {
+            0x8000_0000
+        } else {
+            0
+        };
+        let bits = if self.0 & Self::EXP_MASK == Self::EXP_MASK {
+            if self.0 & Self::MAN_MASK == 0 {
+                sign | 0x7F80_0000
+            } else {
+                // Quiet the NaN and keep the payload in the high mantissa bits
+                sign | 0x7FC0_0000 | (((self.0 & Self::MAN_MASK) as u32) << (23 - MANT))
+            }
+        } else if self.0 & !Self::SIGN_MASK == 0 {
+            sign
+        } else {
+            let (man, exp) = self.decode();
+            let msb = 31 - man.leading_zeros() as i32;
+            if exp + msb < -126 {
+                // Only the subnormals of formats with 8 exponent bits are below the f32 normals
+                sign | (man << (exp + 149))
+            } else {
+                let fraction = (man << (23 - msb)) & 0x007F_FFFF;
+                sign | (((exp + msb + 127) as u32) << 23) | fraction
+            }
+        };
+        // TODO: Replace mem::transmute with from_bits() once from_bits is const-stabilized
+        unsafe { mem::transmute::<u32, f32>(bits) }
+    }
+
+    /// Converts the value into an [`f64`] value.
+    ///
+    /// This conversion is lossless as all values can be represented exactly in [`f64`].
+    #[must_use]
+    pub const fn to_f64(self) -> f64 {
+        let sign = if self.is_sign_negative() {
+            0x8000_0000_0000_0000
+        } else {
+            0
+        };
+        let bits = if self.0 & Self::EXP_MASK == Self::EXP_MASK {
+            if self.0 & Self::MAN_MASK == 0 {
+                sign | 0x7FF0_0000_0000_0000
+            } else {
+                sign | 0x7FF8_0000_0000_0000 | (((self.0 & Self::MAN_MASK) as u64) << (52 - MANT))
+            }
+        } else if self.0 & !Self::SIGN_MASK == 0 {
+            sign
+        } else {
+            let (man, exp) = self.decode();
+            let msb = 31 - man.leading_zeros() as i32;
+            let fraction = ((man as u64) << (52 - msb)) & 0x000F_FFFF_FFFF_FFFF;
+            sign | (((exp + msb + 1023) as u64) << 52) | fraction
+        };
+        // TODO: Replace mem::transmute with from_bits() once from_bits is const-stabilized
+        unsafe { mem::transmute::<u64, f64>(bits) }
+    }
+
+    /// Returns `true` if this value is NaN and `false` otherwise.
+    #[inline]
+    #[must_use]
+    pub const fn is_nan(self) -> bool {
+        self.0 & !Self::SIGN_MASK > Self::EXP_MASK
+    }
+
+    /// Returns `true` if this value is ±∞ and `false` otherwise.
+    #[inline]
+    #[must_use]
+    pub const fn is_infinite(self) -> bool {
+        self.0 & !Self::SIGN_MASK == Self::EXP_MASK
+    }
+
+    /// Returns `true` if this number is neither infinite nor NaN.
+    #[inline]
+    #[must_use]
+    pub const fn is_finite(self) -> bool {
+        self.0 & Self::EXP_MASK != Self::EXP_MASK
+    }
+
+    /// Returns `true` if the number is neither zero, infinite, subnormal, or NaN.
+    #[inline]
+    #[must_use]
+    pub const fn is_normal(self) -> bool {
+        let exp = self.0 & Self::EXP_MASK;
+        exp != Self::EXP_MASK && exp != 0
+    }
+
+    /// Returns the floating point category of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use half::Minifloat;
+    /// use std::num::FpCategory;
+    ///
+    /// type F6 = Minifloat<3, 2>;
+    /// assert_eq!(F6::ONE.classify(), FpCategory::Normal);
+    /// assert_eq!(F6::MIN_POSITIVE_SUBNORMAL.classify(), FpCategory::Subnormal);
+    /// assert_eq!(F6::INFINITY.classify(), FpCategory::Infinite);
+    /// ```
+    #[must_use]
+    pub const fn classify(self) -> FpCategory {
+        let exp = self.0 & Self::EXP_MASK;
+        let man = self.0 & Self::MAN_MASK;
+        if exp == Self::EXP_MASK {
+            if man == 0 {
+                FpCategory::Infinite
+            } else {
+                FpCategory::Nan
+            }
+        } else if exp != 0 {
+            FpCategory::Normal
+        } else if man != 0 {
+            FpCategory::Subnormal
+        } else {
+            FpCategory::Zero
+        }
+    }
+
+    /// Returns `true` if and only if `self` has a positive sign, including `+0.0`, NaN with a
+    /// positive sign bit and +∞.
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_positive(self) -> bool {
+        self.0 & Self::SIGN_MASK == 0
+    }
+
+    /// Returns `true` if and only if `self` has a negative sign, including `-0.0`, NaN with a
+    /// negative sign bit and −∞.
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_negative(self) -> bool {
+        self.0 & Self::SIGN_MASK != 0
+    }
+
+    /// Number of significant digits in base 2
+    pub const MANTISSA_DIGITS: u32 = MANT + 1;
+    /// One greater than the minimum possible normal power of 2 exponent
+    pub const MIN_EXP: i32 = 2 - Self::BIAS;
+    /// Maximum possible power of 2 exponent
+    pub const MAX_EXP: i32 = Self::BIAS + 1;
+
+    /// The difference between 1.0 and the next largest representable number
+    pub const EPSILON: Self = if Self::BIAS > MANT as i32 {
+        Minifloat(((Self::BIAS - MANT as i32) as u16) << MANT)
+    } else {
+        // Subnormal in formats with few exponent bits
+        Minifloat(1 << (Self::BIAS - 1))
+    };
+    /// Positive infinity (+∞)
+    pub const INFINITY: Self = Minifloat(Self::EXP_MASK);
+    /// Negative infinity (-∞)
+    pub const NEG_INFINITY: Self = Minifloat(Self::SIGN_MASK | Self::EXP_MASK);
+    /// Not a Number (NaN)
+    pub const NAN: Self = Minifloat(Self::EXP_MASK | (1 << (MANT - 1)));
+    /// Largest finite value
+    pub const MAX: Self = Minifloat(Self::EXP_MASK - 1);
+    /// Smallest finite value
+    pub const MIN: Self = Minifloat(Self::SIGN_MASK | (Self::EXP_MASK - 1));
+    /// Smallest positive normal value
+    pub const MIN_POSITIVE: Self = Minifloat(1 << MANT);
+    /// Smallest positive subnormal value
+    pub const MIN_POSITIVE_SUBNORMAL: Self = Minifloat(1);
+
+    /// 1
+    pub const ONE: Self = Minifloat((Self::BIAS as u16) << MANT);
+    /// 0
+    pub const ZERO: Self = Minifloat(0);
+    /// -0
+    pub const NEG_ZERO: Self = Minifloat(Self::SIGN_MASK);
+    /// -1
+    pub const NEG_ONE: Self = Minifloat(Self::SIGN_MASK | ((Self::BIAS as u16) << MANT));
+}
+
+impl<const EXP: u32, const MANT: u32> From<Minifloat<EXP, MANT>> for f32 {
+    #[inline]
+    fn from(x: Minifloat<EXP, MANT>) -> f32 {
+        x.to_f32()
+    }
+}
+
+impl<const EXP: u32, const MANT: u32> From<Minifloat<EXP, MANT>> for f64 {
+    #[inline]
+    fn from(x: Minifloat<EXP, MANT>) -> f64 {
+        x.to_f64()
+    }
+}
+
+impl<const EXP: u32, const MANT: u32> PartialEq for Minifloat<EXP, MANT> {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_f32() == other.to_f32()
+    }
+}
+
+impl<const EXP: u32, const MANT: u32> PartialOrd for Minifloat<EXP, MANT> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
+    }
+}
+
+impl<const EXP: u32, const MANT: u32> Neg for Minifloat<EXP, MANT> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Minifloat(self.0 ^ Self::SIGN_MASK)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<const EXP: u32, const MANT: u32> Minifloat<EXP, MANT> {
+    // The exact arithmetic of the shortest digits search only covers the exponents of f16
+    fn shortest_decimal(self) -> Option<fmt::Decimal> {
+        if !self.is_finite() || EXP > 5 {
+            None
+        } else if self.0 & !Self::SIGN_MASK == 0 {
+            Some(fmt::Decimal { digits: 0, exp: 0 })
+        } else {
+            let (man, exp) = self.decode();
+            let asymmetric = man == 1 << MANT && self.0 & Self::EXP_MASK > 1 << MANT;
+            Some(fmt::shortest_decimal(man as u64, exp, asymmetric))
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<const EXP: u32, const MANT: u32> Debug for Minifloat<EXP, MANT> {
+    /// Formats the numeric value like [`Display`], but always includes a fractional part or
+    /// exponent like [`f32`] does.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self.shortest_decimal() {
+            Some(decimal) if f.precision().is_none() => {
+                fmt::write_debug(f, self.is_sign_negative(), decimal)
+            }
+            _ => Debug::fmt(&self.to_f32(), f),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<const EXP: u32, const MANT: u32> Display for Minifloat<EXP, MANT> {
+    /// Formats the value with the fewest decimal digits that convert back to the same value, or
+    /// the exact value rounded to the given precision.
+    ///
+    /// Formats with more than 5 exponent bits print the shortest digits of the same [`f32`]
+    /// value instead, which may be longer than needed.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self.shortest_decimal() {
+            Some(decimal) if f.precision().is_none() => {
+                fmt::write_decimal(f, self.is_sign_negative(), decimal)
+            }
+            _ => Display::fmt(&self.to_f32(), f),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<const EXP: u32, const MANT: u32> LowerExp for Minifloat<EXP, MANT> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self.shortest_decimal() {
+            Some(decimal) if f.precision().is_none() => {
+                fmt::write_exp(f, self.is_sign_negative(), decimal, false)
+            }
+            _ => LowerExp::fmt(&self.to_f32(), f),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<const EXP: u32, const MANT: u32> UpperExp for Minifloat<EXP, MANT> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self.shortest_decimal() {
+            Some(decimal) if f.precision().is_none() => {
+                fmt::write_exp(f, self.is_sign_negative(), decimal, true)
+            }
+            _ => UpperExp::fmt(&self.to_f32(), f),
+        }
+    }
+}
+
+#[allow(clippy::float_cmp)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{bf16, f16, f8e5m2};
+    use quickcheck_macros::quickcheck;
+
+    type F6 = Minifloat<3, 2>;
+
+    #[test]
+    fn test_matches_f16() {
+        for bits in 0..=u16::MAX {
+            let m = Minifloat::<5, 10>::from_bits(bits);
+            let h = f16::from_bits(bits);
+            assert_eq!(m.to_f32().to_bits(), h.to_f32().to_bits(), "{:#x}", bits);
+            assert_eq!(m.to_f64().to_bits(), h.to_f64().to_bits(), "{:#x}", bits);
+            assert_eq!(m.classify(), h.classify());
+        }
+    }
+
+    #[test]
+    fn test_matches_bf16() {
+        for bits in 0..=u16::MAX {
+            let m = Minifloat::<8, 7>::from_bits(bits);
+            let b = bf16::from_bits(bits);
+            assert_eq!(m.to_f32().to_bits(), b.to_f32().to_bits(), "{:#x}", bits);
+            assert_eq!(m.to_f64().to_bits(), b.to_f64().to_bits(), "{:#x}", bits);
+            assert_eq!(m.classify(), b.classify());
+        }
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(F6::ONE.to_f32(), 1.0);
+        assert_eq!(F6::NEG_ONE.to_f32(), -1.0);
+        assert_eq!(F6::EPSILON.to_f32(), 0.25);
+        assert_eq!(F6::MAX.to_f32(), 14.0);
+        assert_eq!(F6::MIN.to_f32(), -14.0);
+        assert_eq!(F6::MIN_POSITIVE.to_f32(), 0.25);
+        assert_eq!(F6::MIN_POSITIVE_SUBNORMAL.to_f32(), 0.0625);
+        assert_eq!(F6::INFINITY.to_f32(), f32::INFINITY);
+        assert_eq!(F6::NEG_INFINITY.to_f32(), f32::NEG_INFINITY);
+        assert!(F6::NAN.is_nan());
+        assert!(F6::NEG_ZERO.is_sign_negative() && F6::NEG_ZERO == F6::ZERO);
+        assert_eq!(F6::MANTISSA_DIGITS, 3);
+
+        type H = Minifloat<5, 10>;
+        assert_eq!(H::MAX.to_bits(), f16::MAX.to_bits());
+        assert_eq!(H::EPSILON.to_bits(), f16::EPSILON.to_bits());
+        assert_eq!(H::NAN.to_bits(), f16::NAN.to_bits());
+        assert_eq!(H::MIN_EXP, f16::MIN_EXP);
+        assert_eq!(H::MAX_EXP, f16::MAX_EXP);
+        assert_eq!(Minifloat::<8, 7>::MIN.to_bits(), bf16::MIN.to_bits());
+        assert_eq!(Minifloat::<2, 3>::EPSILON.to_f32(), 0.125);
+    }
+
+    #[test]
+    fn test_from_bits_masks() {
+        assert_eq!(F6::from_bits(0xFFC0).to_bits(), 0);
+        assert_eq!(F6::from_bits(0x0020).to_bits(), F6::NEG_ZERO.to_bits());
+    }
+
+    #[test]
+    fn test_round_to_nearest_even() {
+        let values = || (0..F6::INFINITY.0).map(F6::from_bits);
+        for (low, high) in values().zip(values().skip(1)) {
+            let mid = (low.to_f32() + high.to_f32()) / 2.0;
+            let even = if low.0 & 1 == 0 { low } else { high };
+            assert_eq!(F6::from_f32(mid).to_bits(), even.0, "{}", mid);
+            assert_eq!(F6::from_f64(-mid as f64).to_bits(), even.0 | 0x20);
+        }
+        // Halfway between MAX and the next power of two rounds to even, which is infinity
+        assert!(F6::from_f32(15.0).is_infinite());
+        assert_eq!(F6::from_f32(14.9), F6::MAX);
+        assert!(F6::from_f32(f32::NAN).is_nan());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_formatting() {
+        for bits in 0..=u16::MAX {
+            let m = Minifloat::<5, 10>::from_bits(bits);
+            let h = f16::from_bits(bits);
+            assert_eq!(m.to_string(), h.to_string());
+            assert_eq!(format!("{:?}", m), format!("{:?}", h));
+            assert_eq!(format!("{:e}", m), format!("{:e}", h));
+        }
+        assert_eq!(F6::MAX.to_string(), "14");
+        assert_eq!(format!("{:?}", F6::MIN_POSITIVE_SUBNORMAL), "0.06");
+        assert_eq!(
+            Minifloat::<8, 7>::MAX.to_string(),
+            bf16::MAX.to_f32().to_string()
+        );
+    }
+
+    #[quickcheck]
+    fn qc_from_f32_matches(x: f32) -> bool {
+        let h = Minifloat::<5, 10>::from_f32(x).to_bits() == f16::from_f32(x).to_bits();
+        let b = Minifloat::<8, 7>::from_f32(x).to_bits() == bf16::from_f32(x).to_bits();
+        let e = Minifloat::<5, 2>::from_f32(x).to_bits() as u8 == f8e5m2::from_f32(x).to_bits();
+        h && b && e
+    }
+}
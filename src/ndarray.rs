@@ -0,0 +1,112 @@
+use crate::{bf16, f16};
+use core::ops::{Add, Div, Mul, Rem, Sub};
+use ndarray::{Array, ArrayBase, Data, DataMut, DataOwned, Dimension, ScalarOperand};
+
+macro_rules! impl_scalar_lhs_op {
+    ($ty:ident, $trait:ident, $method:ident, $op:tt) => {
+        impl<S, D> $trait<ArrayBase<S, D>> for $ty
+        where
+            S: DataOwned<Elem = $ty> + DataMut,
+            D: Dimension,
+        {
+            type Output = ArrayBase<S, D>;
+
+            #[inline]
+            fn $method(self, rhs: ArrayBase<S, D>) -> ArrayBase<S, D> {
+                let mut rhs = rhs;
+                rhs.map_inplace(move |elt| *elt = self $op *elt);
+                rhs
+            }
+        }
+
+        impl<'a, S, D> $trait<&'a ArrayBase<S, D>> for $ty
+        where
+            S: Data<Elem = $ty>,
+            D: Dimension,
+        {
+            type Output = Array<$ty, D>;
+
+            #[inline]
+            fn $method(self, rhs: &'a ArrayBase<S, D>) -> Array<$ty, D> {
+                rhs.map(move |elt| self $op *elt)
+            }
+        }
+    };
+}
+
+macro_rules! impl_ndarray {
+    ($ty:ident) => {
+        impl ScalarOperand for $ty {}
+
+        impl_scalar_lhs_op!($ty, Add, add, +);
+        impl_scalar_lhs_op!($ty, Sub, sub, -);
+        impl_scalar_lhs_op!($ty, Mul, mul, *);
+        impl_scalar_lhs_op!($ty, Div, div, /);
+        impl_scalar_lhs_op!($ty, Rem, rem, %);
+    };
+}
+
+impl_ndarray!(f16);
+impl_ndarray!(bf16);
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use crate::{bf16, f16};
+    use ndarray::array;
+
+    #[test]
+    fn scalar_operands() {
+        let a = array![
+            [f16::ONE, f16::from_f32(2.)],
+            [f16::from_f32(3.), f16::from_f32(4.)]
+        ];
+        let two = f16::from_f32(2.);
+        assert_eq!(&a * two, a.map(|x| *x * two));
+        assert_eq!(two - &a, a.map(|x| two - *x));
+        assert_eq!(
+            f16::from_f32(12.) / a.clone(),
+            a.map(|x| f16::from_f32(12.) / *x)
+        );
+        let mut b = a.clone();
+        b += two;
+        b %= f16::from_f32(3.);
+        assert_eq!(
+            b,
+            array![[f16::ZERO, f16::ONE], [f16::from_f32(2.), f16::ZERO]]
+        );
+        assert_eq!(&a + &a, &a * two);
+        assert_eq!(-&a, a.map(|x| -*x));
+
+        let c = array![bf16::ONE, bf16::from_f32(-0.5)];
+        assert_eq!(
+            bf16::from_f32(3.) + c,
+            array![bf16::from_f32(4.), bf16::from_f32(2.5)]
+        );
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn linear_algebra() {
+        let a = array![
+            [f16::ONE, f16::from_f32(2.)],
+            [f16::from_f32(3.), f16::from_f32(4.)]
+        ];
+        let identity = ndarray::Array2::<f16>::eye(2);
+        assert_eq!(a.dot(&identity), a);
+        assert_eq!(
+            a.dot(&a),
+            array![
+                [f16::from_f32(7.), f16::from_f32(10.)],
+                [f16::from_f32(15.), f16::from_f32(22.)]
+            ]
+        );
+        let v = array![bf16::from_f32(3.), bf16::from_f32(4.)];
+        assert_eq!(v.dot(&v), bf16::from_f32(25.));
+        assert_eq!(a.sum(), f16::from_f32(10.));
+        assert_eq!(a.mean(), Some(f16::from_f32(2.5)));
+        assert_eq!(
+            a.sum_axis(ndarray::Axis(0)),
+            array![f16::from_f32(4.), f16::from_f32(6.)]
+        );
+    }
+}
@@ -2,7 +2,7 @@ use crate::{bf16, f16};
 use core::cmp::Ordering;
 use core::{num::FpCategory, ops::Div};
 use num_traits::{
-    AsPrimitive, Bounded, FloatConst, FromPrimitive, Num, NumCast, One, ToPrimitive, Zero,
+    AsPrimitive, Bounded, FloatConst, FromPrimitive, Num, NumCast, One, Signed, ToPrimitive, Zero,
 };
 
 impl ToPrimitive for f16 {
@@ -51,11 +51,11 @@ impl ToPrimitive for f16 {
 impl FromPrimitive for f16 {
     #[inline]
     fn from_i64(n: i64) -> Option<Self> {
-        n.to_f32().map(Self::from_f32)
+        Some(Self::from_i64(n))
     }
     #[inline]
     fn from_u64(n: u64) -> Option<Self> {
-        n.to_f32().map(Self::from_f32)
+        Some(Self::from_u64(n))
     }
     #[inline]
     fn from_i8(n: i8) -> Option<Self> {
@@ -67,19 +67,19 @@ impl FromPrimitive for f16 {
     }
     #[inline]
     fn from_i16(n: i16) -> Option<Self> {
-        n.to_f32().map(Self::from_f32)
+        Some(Self::from_i16(n))
     }
     #[inline]
     fn from_u16(n: u16) -> Option<Self> {
-        n.to_f32().map(Self::from_f32)
+        Some(Self::from_u16(n))
     }
     #[inline]
     fn from_i32(n: i32) -> Option<Self> {
-        n.to_f32().map(Self::from_f32)
+        Some(Self::from_i32(n))
     }
     #[inline]
     fn from_u32(n: u32) -> Option<Self> {
-        n.to_f32().map(Self::from_f32)
+        Some(Self::from_u32(n))
     }
     #[inline]
     fn from_f32(n: f32) -> Option<Self> {
@@ -96,7 +96,21 @@ impl Num for f16 {
 
     #[inline]
     fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
-        Ok(Self::from_f32(f32::from_str_radix(str, radix)?))
+        #[cfg(not(target_arch = "spirv"))]
+        {
+            use num_traits::{FloatErrorKind, ParseFloatError};
+            f16::from_str_radix(str, radix).map_err(|_| ParseFloatError {
+                kind: if str.is_empty() {
+                    FloatErrorKind::Empty
+                } else {
+                    FloatErrorKind::Invalid
+                },
+            })
+        }
+        #[cfg(target_arch = "spirv")]
+        {
+            Ok(Self::from_f32(f32::from_str_radix(str, radix)?))
+        }
     }
 }
 
@@ -187,6 +201,11 @@ impl num_traits::float::FloatCore for f16 {
         self.is_normal()
     }
 
+    #[inline]
+    fn is_subnormal(self) -> bool {
+        self.to_bits() & 0x7C00 == 0 && self.to_bits() & 0x03FF != 0
+    }
+
     #[inline]
     fn classify(self) -> FpCategory {
         self.classify()
@@ -352,6 +371,11 @@ impl num_traits::float::Float for f16 {
         self.is_normal()
     }
 
+    #[inline]
+    fn is_subnormal(self) -> bool {
+        self.to_bits() & 0x7C00 == 0 && self.to_bits() & 0x03FF != 0
+    }
+
     #[inline]
     fn classify(self) -> FpCategory {
         self.classify()
@@ -384,12 +408,12 @@ impl num_traits::float::Float for f16 {
 
     #[inline]
     fn abs(self) -> Self {
-        Self::from_f32(self.to_f32().abs())
+        Self::from_bits(self.to_bits() & 0x7FFF)
     }
 
     #[inline]
     fn signum(self) -> Self {
-        Self::from_f32(self.to_f32().signum())
+        self.signum()
     }
 
     #[inline]
@@ -404,7 +428,7 @@ impl num_traits::float::Float for f16 {
 
     #[inline]
     fn mul_add(self, a: Self, b: Self) -> Self {
-        Self::from_f32(self.to_f32().mul_add(a.to_f32(), b.to_f32()))
+        crate::binary16::arith::mul_add(self, a, b)
     }
 
     #[inline]
@@ -573,6 +597,11 @@ impl num_traits::float::Float for f16 {
         Self::from_f32(self.to_f32().atanh())
     }
 
+    #[inline]
+    fn copysign(self, sign: Self) -> Self {
+        self.copysign(sign)
+    }
+
     #[inline]
     fn integer_decode(self) -> (u64, i16, i8) {
         num_traits::float::Float::integer_decode(self.to_f32())
@@ -688,6 +717,37 @@ impl Bounded for f16 {
     }
 }
 
+impl Signed for f16 {
+    #[inline]
+    fn abs(&self) -> Self {
+        Self::from_bits(self.to_bits() & 0x7FFF)
+    }
+
+    #[inline]
+    fn abs_sub(&self, other: &Self) -> Self {
+        if *self <= *other {
+            Self::ZERO
+        } else {
+            *self - *other
+        }
+    }
+
+    #[inline]
+    fn signum(&self) -> Self {
+        f16::signum(*self)
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        self.is_sign_positive()
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        self.is_sign_negative()
+    }
+}
+
 macro_rules! impl_as_primitive_to_f16 {
     ($ty:ty, $meth:ident) => {
         impl AsPrimitive<$ty> for f16 {
@@ -708,6 +768,8 @@ impl AsPrimitive<f16> for f16 {
 
 impl_as_primitive_to_f16!(i64, to_f32);
 impl_as_primitive_to_f16!(u64, to_f32);
+impl_as_primitive_to_f16!(i128, to_f32);
+impl_as_primitive_to_f16!(u128, to_f32);
 impl_as_primitive_to_f16!(i8, to_f32);
 impl_as_primitive_to_f16!(u8, to_f32);
 impl_as_primitive_to_f16!(i16, to_f32);
@@ -730,19 +792,40 @@ macro_rules! impl_as_primitive_f16_from {
     };
 }
 
-impl_as_primitive_f16_from!(i64, from_f32);
-impl_as_primitive_f16_from!(u64, from_f32);
-impl_as_primitive_f16_from!(i8, from_f32);
-impl_as_primitive_f16_from!(u8, from_f32);
-impl_as_primitive_f16_from!(i16, from_f32);
-impl_as_primitive_f16_from!(u16, from_f32);
-impl_as_primitive_f16_from!(i32, from_f32);
-impl_as_primitive_f16_from!(u32, from_f32);
-impl_as_primitive_f16_from!(isize, from_f32);
-impl_as_primitive_f16_from!(usize, from_f32);
+impl_as_primitive_f16_from!(i64, from_i64);
+impl_as_primitive_f16_from!(u64, from_u64);
+impl_as_primitive_f16_from!(i8, from_i16);
+impl_as_primitive_f16_from!(u8, from_u16);
+impl_as_primitive_f16_from!(i16, from_i16);
+impl_as_primitive_f16_from!(u16, from_u16);
+impl_as_primitive_f16_from!(i32, from_i32);
+impl_as_primitive_f16_from!(u32, from_u32);
+impl_as_primitive_f16_from!(isize, from_i64);
+impl_as_primitive_f16_from!(usize, from_u64);
 impl_as_primitive_f16_from!(f32, from_f32);
 impl_as_primitive_f16_from!(f64, from_f64);
 
+impl AsPrimitive<f16> for i128 {
+    #[inline]
+    fn as_(self) -> f16 {
+        f16::from_fixed(self, 0)
+    }
+}
+
+impl AsPrimitive<f16> for u128 {
+    #[inline]
+    fn as_(self) -> f16 {
+        f16::from_fixed(self, 0)
+    }
+}
+
+impl AsPrimitive<bf16> for f16 {
+    #[inline]
+    fn as_(self) -> bf16 {
+        bf16::from_f16(self)
+    }
+}
+
 impl ToPrimitive for bf16 {
     #[inline]
     fn to_i64(&self) -> Option<i64> {
@@ -789,11 +872,11 @@ impl ToPrimitive for bf16 {
 impl FromPrimitive for bf16 {
     #[inline]
     fn from_i64(n: i64) -> Option<Self> {
-        n.to_f32().map(Self::from_f32)
+        Some(Self::from_i64(n))
     }
     #[inline]
     fn from_u64(n: u64) -> Option<Self> {
-        n.to_f32().map(Self::from_f32)
+        Some(Self::from_u64(n))
     }
     #[inline]
     fn from_i8(n: i8) -> Option<Self> {
@@ -805,19 +888,19 @@ impl FromPrimitive for bf16 {
     }
     #[inline]
     fn from_i16(n: i16) -> Option<Self> {
-        n.to_f32().map(Self::from_f32)
+        Some(Self::from_i16(n))
     }
     #[inline]
     fn from_u16(n: u16) -> Option<Self> {
-        n.to_f32().map(Self::from_f32)
+        Some(Self::from_u16(n))
     }
     #[inline]
     fn from_i32(n: i32) -> Option<Self> {
-        n.to_f32().map(Self::from_f32)
+        Some(Self::from_i32(n))
     }
     #[inline]
     fn from_u32(n: u32) -> Option<Self> {
-        n.to_f32().map(Self::from_f32)
+        Some(Self::from_u32(n))
     }
     #[inline]
     fn from_f32(n: f32) -> Option<Self> {
@@ -834,7 +917,21 @@ impl Num for bf16 {
 
     #[inline]
     fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
-        Ok(Self::from_f32(f32::from_str_radix(str, radix)?))
+        #[cfg(not(target_arch = "spirv"))]
+        {
+            use num_traits::{FloatErrorKind, ParseFloatError};
+            bf16::from_str_radix(str, radix).map_err(|_| ParseFloatError {
+                kind: if str.is_empty() {
+                    FloatErrorKind::Empty
+                } else {
+                    FloatErrorKind::Invalid
+                },
+            })
+        }
+        #[cfg(target_arch = "spirv")]
+        {
+            Ok(Self::from_f32(f32::from_str_radix(str, radix)?))
+        }
     }
 }
 
@@ -925,6 +1022,11 @@ impl num_traits::float::FloatCore for bf16 {
         self.is_normal()
     }
 
+    #[inline]
+    fn is_subnormal(self) -> bool {
+        self.to_bits() & 0x7F80 == 0 && self.to_bits() & 0x007F != 0
+    }
+
     #[inline]
     fn classify(self) -> FpCategory {
         self.classify()
@@ -1090,6 +1192,11 @@ impl num_traits::float::Float for bf16 {
         self.is_normal()
     }
 
+    #[inline]
+    fn is_subnormal(self) -> bool {
+        self.to_bits() & 0x7F80 == 0 && self.to_bits() & 0x007F != 0
+    }
+
     #[inline]
     fn classify(self) -> FpCategory {
         self.classify()
@@ -1122,12 +1229,12 @@ impl num_traits::float::Float for bf16 {
 
     #[inline]
     fn abs(self) -> Self {
-        Self::from_f32(self.to_f32().abs())
+        Self::from_bits(self.to_bits() & 0x7FFF)
     }
 
     #[inline]
     fn signum(self) -> Self {
-        Self::from_f32(self.to_f32().signum())
+        self.signum()
     }
 
     #[inline]
@@ -1311,6 +1418,11 @@ impl num_traits::float::Float for bf16 {
         Self::from_f32(self.to_f32().atanh())
     }
 
+    #[inline]
+    fn copysign(self, sign: Self) -> Self {
+        self.copysign(sign)
+    }
+
     #[inline]
     fn integer_decode(self) -> (u64, i16, i8) {
         num_traits::float::Float::integer_decode(self.to_f32())
@@ -1427,6 +1539,37 @@ impl Bounded for bf16 {
     }
 }
 
+impl Signed for bf16 {
+    #[inline]
+    fn abs(&self) -> Self {
+        Self::from_bits(self.to_bits() & 0x7FFF)
+    }
+
+    #[inline]
+    fn abs_sub(&self, other: &Self) -> Self {
+        if *self <= *other {
+            Self::ZERO
+        } else {
+            *self - *other
+        }
+    }
+
+    #[inline]
+    fn signum(&self) -> Self {
+        bf16::signum(*self)
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        self.is_sign_positive()
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        self.is_sign_negative()
+    }
+}
+
 impl AsPrimitive<bf16> for bf16 {
     #[inline]
     fn as_(self) -> bf16 {
@@ -1447,6 +1590,8 @@ macro_rules! impl_as_primitive_to_bf16 {
 
 impl_as_primitive_to_bf16!(i64, to_f32);
 impl_as_primitive_to_bf16!(u64, to_f32);
+impl_as_primitive_to_bf16!(i128, to_f32);
+impl_as_primitive_to_bf16!(u128, to_f32);
 impl_as_primitive_to_bf16!(i8, to_f32);
 impl_as_primitive_to_bf16!(u8, to_f32);
 impl_as_primitive_to_bf16!(i16, to_f32);
@@ -1469,15 +1614,79 @@ macro_rules! impl_as_primitive_bf16_from {
     };
 }
 
-impl_as_primitive_bf16_from!(i64, from_f32);
-impl_as_primitive_bf16_from!(u64, from_f32);
-impl_as_primitive_bf16_from!(i8, from_f32);
-impl_as_primitive_bf16_from!(u8, from_f32);
-impl_as_primitive_bf16_from!(i16, from_f32);
-impl_as_primitive_bf16_from!(u16, from_f32);
-impl_as_primitive_bf16_from!(i32, from_f32);
-impl_as_primitive_bf16_from!(u32, from_f32);
-impl_as_primitive_bf16_from!(isize, from_f32);
-impl_as_primitive_bf16_from!(usize, from_f32);
+impl_as_primitive_bf16_from!(i64, from_i64);
+impl_as_primitive_bf16_from!(u64, from_u64);
+impl_as_primitive_bf16_from!(i8, from_i16);
+impl_as_primitive_bf16_from!(u8, from_u16);
+impl_as_primitive_bf16_from!(i16, from_i16);
+impl_as_primitive_bf16_from!(u16, from_u16);
+impl_as_primitive_bf16_from!(i32, from_i32);
+impl_as_primitive_bf16_from!(u32, from_u32);
+impl_as_primitive_bf16_from!(isize, from_i64);
+impl_as_primitive_bf16_from!(usize, from_u64);
 impl_as_primitive_bf16_from!(f32, from_f32);
 impl_as_primitive_bf16_from!(f64, from_f64);
+
+impl AsPrimitive<bf16> for i128 {
+    #[inline]
+    fn as_(self) -> bf16 {
+        bf16::from_fixed(self, 0)
+    }
+}
+
+impl AsPrimitive<bf16> for u128 {
+    #[inline]
+    fn as_(self) -> bf16 {
+        bf16::from_fixed(self, 0)
+    }
+}
+
+impl AsPrimitive<f16> for bf16 {
+    #[inline]
+    fn as_(self) -> f16 {
+        f16::from_bf16(self)
+    }
+}
+
+#[allow(clippy::float_cmp)]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_as_primitive() {
+        let x: f16 = 1000i32.as_();
+        assert_eq!(x, f16::from_f32(1000.));
+        let x: f16 = (-70000i64).as_();
+        assert_eq!(x, f16::NEG_INFINITY);
+        let x: f16 = u128::MAX.as_();
+        assert_eq!(x, f16::INFINITY);
+        let x: f16 = (-3i128).as_();
+        assert_eq!(x, f16::from_f32(-3.));
+        let y: u8 = f16::from_f32(300.).as_();
+        assert_eq!(y, 255);
+        let y: i128 = f16::MIN.as_();
+        assert_eq!(y, -65504);
+        let y: u128 = bf16::MAX.as_();
+        assert_eq!(y as f32, bf16::MAX.to_f32());
+
+        // Integers round once, unlike converting through f32
+        let i = (1 << 24) + (1 << 16) + 1;
+        let x: bf16 = i.as_();
+        assert_eq!(x, bf16::from_f32((1 << 24) as f32 + (1 << 17) as f32));
+        assert_eq!(bf16::from_f32(i as f32), bf16::from_f32((1 << 24) as f32));
+        let x: bf16 = ((1u128 << 100) + (1 << 92) + 1).as_();
+        assert_eq!(x.to_f32(), (1u128 << 100) as f32 + (1u128 << 93) as f32);
+        let x: bf16 = (-(1i128 << 100) - (1 << 92)).as_();
+        assert_eq!(x.to_f32(), -((1u128 << 100) as f32));
+        let x: bf16 = u128::MAX.as_();
+        assert_eq!(x, bf16::INFINITY);
+        let x: bf16 = (u128::MAX >> 1).as_();
+        assert_eq!(x.to_f32(), (1u128 << 127) as f32);
+
+        let x: bf16 = f16::MAX.as_();
+        assert_eq!(x, bf16::from_f32(65536.));
+        let x: f16 = bf16::from_f32(0.5).as_();
+        assert_eq!(x, f16::from_f32(0.5));
+    }
+}
@@ -0,0 +1,397 @@
+//! Contains wrappers of [`f16`] and [`bf16`] with a total order, so they can be used as keys of
+//! ordered or hashed collections.
+//!
+//! [`TotalOrdF16`] and [`TotalOrdBf16`] compare values with the IEEE 754 `totalOrder` predicate,
+//! as [`f16::total_cmp`] and [`bf16::total_cmp`] do. Every bit pattern is equal only to itself, so
+//! negative and positive zero are distinct, NaNs are equal to themselves, and positive NaNs sort
+//! above positive infinity while negative NaNs sort below negative infinity.
+//!
+//! [`NonNanF16`] and [`NonNanBf16`] instead guarantee that the wrapped value is never NaN, so the
+//! usual IEEE 754 comparison is already a total order. Like the primitive types, they consider
+//! negative and positive zero equal.
+
+use crate::{bf16, f16};
+use core::{
+    cmp::Ordering,
+    fmt::{Debug, Display, Formatter, LowerExp, UpperExp},
+    hash::{Hash, Hasher},
+};
+
+macro_rules! impl_total_ord {
+    ($name:ident, $ty:ident) => {
+        impl $name {
+            #[doc = concat!("Wraps a [`", stringify!($ty), "`] value.")]
+            #[inline]
+            #[must_use]
+            pub const fn new(value: $ty) -> $name {
+                $name(value)
+            }
+
+            #[doc = concat!("Returns the wrapped [`", stringify!($ty), "`] value.")]
+            #[inline]
+            #[must_use]
+            pub const fn get(self) -> $ty {
+                self.0
+            }
+        }
+
+        impl From<$ty> for $name {
+            #[inline]
+            fn from(value: $ty) -> $name {
+                $name(value)
+            }
+        }
+
+        impl From<$name> for $ty {
+            #[inline]
+            fn from(value: $name) -> $ty {
+                value.0
+            }
+        }
+
+        impl PartialEq for $name {
+            #[inline]
+            fn eq(&self, other: &$name) -> bool {
+                self.0.to_bits() == other.0.to_bits()
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl PartialOrd for $name {
+            #[inline]
+            fn partial_cmp(&self, other: &$name) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            #[inline]
+            fn cmp(&self, other: &$name) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        impl Hash for $name {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.to_bits().hash(state);
+            }
+        }
+
+        impl Debug for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+
+        impl LowerExp for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                LowerExp::fmt(&self.0, f)
+            }
+        }
+
+        impl UpperExp for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                UpperExp::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+macro_rules! impl_non_nan {
+    ($name:ident, $ty:ident) => {
+        impl $name {
+            #[doc = concat!("Wraps a [`", stringify!($ty), "`] value, or returns [`None`] if it is NaN.")]
+            #[inline]
+            #[must_use]
+            pub const fn new(value: $ty) -> Option<$name> {
+                if value.is_nan() {
+                    None
+                } else {
+                    Some($name(value))
+                }
+            }
+
+            #[doc = concat!("Wraps a [`", stringify!($ty), "`] value, or returns [`None`] if it is NaN or infinite.")]
+            #[inline]
+            #[must_use]
+            pub const fn new_finite(value: $ty) -> Option<$name> {
+                if value.is_finite() {
+                    Some($name(value))
+                } else {
+                    None
+                }
+            }
+
+            #[doc = concat!("Wraps a [`", stringify!($ty), "`] value without checking that it is not NaN.")]
+            ///
+            /// # Safety
+            ///
+            /// `value` must not be NaN. Wrapping a NaN breaks the guarantees of the [`Eq`] and
+            /// [`Ord`] implementations, which other code is allowed to rely on.
+            #[inline]
+            #[must_use]
+            pub const unsafe fn new_unchecked(value: $ty) -> $name {
+                $name(value)
+            }
+
+            #[doc = concat!("Returns the wrapped [`", stringify!($ty), "`] value, which is never NaN.")]
+            #[inline]
+            #[must_use]
+            pub const fn get(self) -> $ty {
+                self.0
+            }
+        }
+
+        impl From<$name> for $ty {
+            #[inline]
+            fn from(value: $name) -> $ty {
+                value.0
+            }
+        }
+
+        impl PartialEq for $name {
+            #[inline]
+            fn eq(&self, other: &$name) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl PartialOrd for $name {
+            #[inline]
+            fn partial_cmp(&self, other: &$name) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            #[inline]
+            fn cmp(&self, other: &$name) -> Ordering {
+                // Only NaN is unordered, which the constructors reject
+                self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal)
+            }
+        }
+
+        impl Hash for $name {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                // Negative zero is equal to positive zero, so they must hash the same
+                let bits = if self.0 == $ty::ZERO { 0 } else { self.0.to_bits() };
+                bits.hash(state);
+            }
+        }
+
+        impl Debug for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+
+        impl LowerExp for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                LowerExp::fmt(&self.0, f)
+            }
+        }
+
+        impl UpperExp for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                UpperExp::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+/// A [`f16`] wrapper implementing [`Eq`], [`Ord`] and [`Hash`] with `totalOrder` semantics.
+///
+/// Two values are equal exactly when their bits are equal, and they are ordered like
+/// [`f16::total_cmp`]. Formatting is forwarded to the wrapped value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::{f16, ordered::TotalOrdF16};
+/// use std::collections::BTreeMap;
+///
+/// let mut counts = BTreeMap::new();
+/// for x in [f16::ONE, f16::NAN, f16::NEG_ZERO, f16::ZERO, f16::ONE] {
+///     *counts.entry(TotalOrdF16(x)).or_insert(0) += 1;
+/// }
+///
+/// let keys: Vec<f16> = counts.keys().map(|k| k.get()).collect();
+/// assert_eq!(keys[0].to_bits(), f16::NEG_ZERO.to_bits());
+/// assert!(keys[3].is_nan());
+/// assert_eq!(counts[&TotalOrdF16(f16::ONE)], 2);
+/// ```
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct TotalOrdF16(pub f16);
+
+/// A [`bf16`] wrapper implementing [`Eq`], [`Ord`] and [`Hash`] with `totalOrder` semantics.
+///
+/// Two values are equal exactly when their bits are equal, and they are ordered like
+/// [`bf16::total_cmp`]. Formatting is forwarded to the wrapped value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::{bf16, ordered::TotalOrdBf16};
+/// use std::collections::BinaryHeap;
+///
+/// let mut heap: BinaryHeap<_> = [bf16::ONE, bf16::INFINITY, -bf16::NAN]
+///     .into_iter()
+///     .map(TotalOrdBf16)
+///     .collect();
+///
+/// assert_eq!(heap.pop(), Some(TotalOrdBf16(bf16::INFINITY)));
+/// assert_eq!(heap.pop(), Some(TotalOrdBf16(bf16::ONE)));
+/// assert!(heap.pop().unwrap().get().is_nan());
+/// ```
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct TotalOrdBf16(pub bf16);
+
+impl_total_ord!(TotalOrdF16, f16);
+impl_total_ord!(TotalOrdBf16, bf16);
+
+/// A [`f16`] value that is guaranteed not to be NaN, implementing [`Eq`], [`Ord`] and [`Hash`].
+///
+/// Values are compared like [`f16`] itself, which is a total order once NaN is excluded. Negative
+/// and positive zero are equal. Infinities are allowed unless the value is created with
+/// [`new_finite`][Self::new_finite].
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::{f16, ordered::NonNanF16};
+/// assert!(NonNanF16::new(f16::NAN).is_none());
+/// assert!(NonNanF16::new_finite(f16::INFINITY).is_none());
+///
+/// let mut values: Vec<NonNanF16> = [3., -1., 2.]
+///     .iter()
+///     .filter_map(|x| NonNanF16::new(f16::from_f32(*x)))
+///     .collect();
+/// values.sort();
+/// assert_eq!(values.iter().max().unwrap().get(), f16::from_f32(3.));
+/// assert_eq!(values[0].get(), f16::NEG_ONE);
+/// ```
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct NonNanF16(f16);
+
+/// A [`bf16`] value that is guaranteed not to be NaN, implementing [`Eq`], [`Ord`] and [`Hash`].
+///
+/// Values are compared like [`bf16`] itself, which is a total order once NaN is excluded. Negative
+/// and positive zero are equal. Infinities are allowed unless the value is created with
+/// [`new_finite`][Self::new_finite].
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct NonNanBf16(bf16);
+
+impl_non_nan!(NonNanF16, f16);
+impl_non_nan!(NonNanBf16, bf16);
+
+#[cfg(test)]
+mod test {
+    use super::{NonNanBf16, NonNanF16, TotalOrdBf16, TotalOrdF16};
+    use crate::{bf16, f16};
+    use core::cmp::Ordering;
+
+    #[test]
+    fn total_ord() {
+        let values = [
+            -f16::NAN,
+            f16::NEG_INFINITY,
+            f16::MIN,
+            f16::NEG_ONE,
+            -f16::MIN_POSITIVE_SUBNORMAL,
+            f16::NEG_ZERO,
+            f16::ZERO,
+            f16::MIN_POSITIVE_SUBNORMAL,
+            f16::ONE,
+            f16::MAX,
+            f16::INFINITY,
+            f16::NAN,
+        ];
+        for (i, a) in values.iter().enumerate() {
+            for (j, b) in values.iter().enumerate() {
+                let (a, b) = (TotalOrdF16::from(*a), TotalOrdF16::new(*b));
+                assert_eq!(a.cmp(&b), i.cmp(&j));
+                assert_eq!(a == b, i == j);
+            }
+        }
+
+        assert_eq!(TotalOrdBf16(bf16::NAN), TotalOrdBf16(bf16::NAN));
+        assert_ne!(TotalOrdBf16(bf16::ZERO), TotalOrdBf16(bf16::NEG_ZERO));
+        assert_eq!(
+            TotalOrdBf16(bf16::ONE).partial_cmp(&TotalOrdBf16(bf16::NAN)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(bf16::from(TotalOrdBf16(bf16::E)), bf16::E);
+    }
+
+    #[test]
+    fn non_nan() {
+        assert_eq!(NonNanF16::new(f16::NAN), None);
+        assert_eq!(NonNanF16::new(-f16::NAN), None);
+        assert_eq!(NonNanBf16::new_finite(bf16::NAN), None);
+        assert_eq!(NonNanBf16::new_finite(bf16::NEG_INFINITY), None);
+        assert_eq!(
+            NonNanBf16::new_finite(bf16::MAX).map(NonNanBf16::get),
+            Some(bf16::MAX)
+        );
+        let inf = NonNanF16::new(f16::INFINITY).unwrap();
+        assert_eq!(f16::from(inf), f16::INFINITY);
+
+        let zero = NonNanF16::new(f16::ZERO).unwrap();
+        let neg_zero = NonNanF16::new(f16::NEG_ZERO).unwrap();
+        assert_eq!(zero, neg_zero);
+        assert_eq!(zero.cmp(&neg_zero), Ordering::Equal);
+        assert_eq!(zero, NonNanF16::default());
+
+        const ONE: NonNanF16 = unsafe { NonNanF16::new_unchecked(f16::ONE) };
+        assert!(ONE > zero && ONE < inf);
+        assert_eq!(ONE.max(inf), inf);
+        assert_eq!(
+            NonNanF16::new(f16::NEG_INFINITY).unwrap().cmp(&neg_zero),
+            Ordering::Less
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_and_format() {
+        use std::{collections::HashSet, format};
+
+        let set: HashSet<_> = [f16::NAN, f16::NAN, f16::ZERO, f16::NEG_ZERO, f16::ONE]
+            .iter()
+            .map(|x| TotalOrdF16(*x))
+            .collect();
+        assert_eq!(set.len(), 4);
+
+        let set: HashSet<_> = [f16::ZERO, f16::NEG_ZERO, f16::ONE]
+            .iter()
+            .filter_map(|x| NonNanF16::new(*x))
+            .collect();
+        assert_eq!(set.len(), 2);
+
+        let x = TotalOrdF16(f16::from_f32(1.5));
+        assert_eq!(format!("{} {:?} {:e}", x, x, x), "1.5 1.5 1.5e0");
+        let y = NonNanBf16::new(bf16::from_f32(-2.)).unwrap();
+        assert_eq!(format!("{} {:?} {:E}", y, y, y), "-2 -2.0 -2E0");
+    }
+}
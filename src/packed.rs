@@ -0,0 +1,381 @@
+//! Contains small aligned vectors of [`f16`] values that work on stable Rust.
+//!
+//! [`f16x4`] and [`f16x8`] are `#[repr(C)]` arrays aligned to their size of 8 and 16 bytes, so they
+//! have the layout expected for half precision vertex attributes, texels and shader or GPU buffer
+//! fields, and can be loaded into vector registers in one go. Unlike the vectors of the `simd`
+//! module, they do not need nightly Rust.
+//!
+//! Addition, subtraction, multiplication and division convert all lanes to [`f32`] with
+//! [`unpack_f16x4`] or [`unpack_f16x8`], which use a single F16C or NEON instruction when the
+//! `use-intrinsics` feature is enabled and fall back to software conversions otherwise, compute in
+//! [`f32`] and convert the result back. This gives the correctly rounded half precision result in
+//! every lane. [`mul_add`][f16x4::mul_add] is computed lane by lane with a single rounding, using
+//! native half precision instructions where available. Like [`f32::min`] and [`f32::max`],
+//! [`min`][f16x4::min] and [`max`][f16x4::max] ignore NaN lanes.
+//!
+//! The [`pack_f32x4`], [`unpack_f16x4`], [`pack_f32x8`] and [`unpack_f16x8`] functions perform
+//! these conversions on plain arrays, for example to pack vertex attributes.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use half::prelude::*;
+//! use half::packed::f16x4;
+//!
+//! let color = f16x4::from_f32_array([1.0, 0.5, 0.25, 1.0]);
+//! let tint = f16x4::splat(f16::from_f32(0.5));
+//! let blended = color.mul_add(tint, f16x4::splat(f16::from_f32(0.125)));
+//! assert_eq!(blended.to_f32_array(), [0.625, 0.375, 0.25, 0.625]);
+//! ```
+
+use crate::{
+    binary16::{arith, convert},
+    f16,
+};
+use core::{
+    fmt::{Debug, Formatter},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// Converts four [`f32`] values into the nearest [`f16`] values in one call.
+///
+/// With the `use-intrinsics` feature, this is a single F16C or NEON instruction when the CPU
+/// supports it, which is detected at runtime with the `std` feature. This avoids the overhead of the
+/// slice conversions of [`HalfFloatSliceExt`] for short arrays, such as when packing vertex
+/// attributes. The results are the same as [`f16::from_f32`] on each value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::prelude::*;
+/// use half::packed::{pack_f32x4, unpack_f16x4};
+///
+/// let normal = pack_f32x4([0.0, 0.6, 0.8, 1.0]);
+/// assert_eq!(normal, [0.0, 0.6, 0.8, 1.0].map(f16::from_f32));
+/// assert_eq!(unpack_f16x4(normal), normal.map(f16::to_f32));
+/// ```
+///
+/// [`f16::from_f32`]: crate::f16::from_f32
+/// [`HalfFloatSliceExt`]: crate::slice::HalfFloatSliceExt
+#[inline]
+#[must_use]
+pub fn pack_f32x4(values: [f32; 4]) -> [f16; 4] {
+    convert::f32x4_to_f16x4(values).map(f16::from_bits)
+}
+
+/// Converts four [`f16`] values into [`f32`] values in one call. This conversion is lossless.
+///
+/// See [`pack_f32x4`] for how the conversion is performed.
+#[inline]
+#[must_use]
+pub fn unpack_f16x4(values: [f16; 4]) -> [f32; 4] {
+    convert::f16x4_to_f32x4(values.map(f16::to_bits))
+}
+
+/// Converts eight [`f32`] values into the nearest [`f16`] values in one call.
+///
+/// See [`pack_f32x4`] for how the conversion is performed.
+#[inline]
+#[must_use]
+pub fn pack_f32x8(values: [f32; 8]) -> [f16; 8] {
+    convert::f32x8_to_f16x8(values).map(f16::from_bits)
+}
+
+/// Converts eight [`f16`] values into [`f32`] values in one call. This conversion is lossless.
+///
+/// See [`pack_f32x4`] for how the conversion is performed.
+#[inline]
+#[must_use]
+pub fn unpack_f16x8(values: [f16; 8]) -> [f32; 8] {
+    convert::f16x8_to_f32x8(values.map(f16::to_bits))
+}
+
+macro_rules! impl_packed {
+    ($name:ident, $lanes:literal, $align:literal, $pack:ident, $unpack:ident) => {
+        #[doc = concat!(
+            "A vector of ",
+            stringify!($lanes),
+            " [`f16`] values, aligned to ",
+            stringify!($align),
+            " bytes."
+        )]
+        ///
+        /// See the [module documentation](self) for details.
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Default, PartialEq)]
+        #[repr(C, align($align))]
+        pub struct $name([f16; $lanes]);
+
+        impl $name {
+            /// Number of lanes in the vector.
+            pub const LANES: usize = $lanes;
+
+            /// Constructs a vector with every lane set to `value`.
+            #[inline]
+            #[must_use]
+            pub const fn splat(value: f16) -> Self {
+                Self([value; $lanes])
+            }
+
+            /// Constructs a vector from an array of values.
+            #[inline]
+            #[must_use]
+            pub const fn from_array(values: [f16; $lanes]) -> Self {
+                Self(values)
+            }
+
+            /// Returns the lanes of the vector as an array.
+            #[inline]
+            #[must_use]
+            pub const fn to_array(self) -> [f16; $lanes] {
+                self.0
+            }
+
+            /// Returns a reference to the lanes of the vector.
+            #[inline]
+            #[must_use]
+            pub const fn as_array(&self) -> &[f16; $lanes] {
+                &self.0
+            }
+
+            /// Returns a mutable reference to the lanes of the vector.
+            #[inline]
+            #[must_use]
+            pub fn as_mut_array(&mut self) -> &mut [f16; $lanes] {
+                &mut self.0
+            }
+
+            /// Converts an array of [`f32`] values into a vector, rounding each lane to the
+            /// nearest [`f16`] value.
+            #[inline]
+            #[must_use]
+            pub fn from_f32_array(values: [f32; $lanes]) -> Self {
+                Self($pack(values))
+            }
+
+            /// Converts each lane of the vector into an [`f32`] value. This conversion is
+            /// lossless.
+            #[inline]
+            #[must_use]
+            pub fn to_f32_array(self) -> [f32; $lanes] {
+                $unpack(self.0)
+            }
+
+            /// Computes `(self * a) + b` in each lane with only one rounding error, yielding a
+            /// more accurate result than an unfused multiply-add.
+            #[inline]
+            #[must_use]
+            pub fn mul_add(self, a: Self, b: Self) -> Self {
+                let mut result = self;
+                for ((x, a), b) in result.0.iter_mut().zip(a.0).zip(b.0) {
+                    *x = arith::mul_add(*x, a, b);
+                }
+                result
+            }
+
+            /// Returns the minimum of each pair of lanes. If one of the lanes is NaN, the other
+            /// lane is returned.
+            #[inline]
+            #[must_use]
+            pub fn min(self, other: Self) -> Self {
+                let mut result = self;
+                for (x, y) in result.0.iter_mut().zip(other.0) {
+                    if x.is_nan() || y < *x {
+                        *x = y;
+                    }
+                }
+                result
+            }
+
+            /// Returns the maximum of each pair of lanes. If one of the lanes is NaN, the other
+            /// lane is returned.
+            #[inline]
+            #[must_use]
+            pub fn max(self, other: Self) -> Self {
+                let mut result = self;
+                for (x, y) in result.0.iter_mut().zip(other.0) {
+                    if x.is_nan() || y > *x {
+                        *x = y;
+                    }
+                }
+                result
+            }
+
+            /// Computes the absolute value of each lane.
+            #[inline]
+            #[must_use]
+            pub fn abs(self) -> Self {
+                Self(self.0.map(|x| f16::from_bits(x.to_bits() & 0x7FFF)))
+            }
+
+            // Widens both vectors, applies `op` to each pair of lanes and rounds the results back.
+            #[inline]
+            fn zip_f32(self, rhs: Self, op: impl Fn(f32, f32) -> f32) -> Self {
+                let mut lhs = self.to_f32_array();
+                for (x, y) in lhs.iter_mut().zip(rhs.to_f32_array()) {
+                    *x = op(*x, y);
+                }
+                Self::from_f32_array(lhs)
+            }
+        }
+
+        impl Debug for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.0).finish()
+            }
+        }
+
+        impl From<[f16; $lanes]> for $name {
+            #[inline]
+            fn from(values: [f16; $lanes]) -> Self {
+                Self(values)
+            }
+        }
+
+        impl From<$name> for [f16; $lanes] {
+            #[inline]
+            fn from(vector: $name) -> Self {
+                vector.0
+            }
+        }
+
+        impl From<[f32; $lanes]> for $name {
+            #[inline]
+            fn from(values: [f32; $lanes]) -> Self {
+                Self::from_f32_array(values)
+            }
+        }
+
+        impl From<$name> for [f32; $lanes] {
+            #[inline]
+            fn from(vector: $name) -> Self {
+                vector.to_f32_array()
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self(self.0.map(Neg::neg))
+            }
+        }
+
+        impl_binary_op!($name, Add, add, AddAssign, add_assign, +);
+        impl_binary_op!($name, Sub, sub, SubAssign, sub_assign, -);
+        impl_binary_op!($name, Mul, mul, MulAssign, mul_assign, *);
+        impl_binary_op!($name, Div, div, DivAssign, div_assign, /);
+    };
+}
+
+macro_rules! impl_binary_op {
+    ($name:ident, $op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident, $tok:tt) => {
+        impl $op for $name {
+            type Output = Self;
+
+            #[inline]
+            fn $fn(self, rhs: Self) -> Self {
+                self.zip_f32(rhs, |a, b| a $tok b)
+            }
+        }
+
+        impl $op_assign for $name {
+            #[inline]
+            fn $fn_assign(&mut self, rhs: Self) {
+                *self = $op::$fn(*self, rhs);
+            }
+        }
+    };
+}
+
+impl_packed!(f16x4, 4, 8, pack_f32x4, unpack_f16x4);
+impl_packed!(f16x8, 8, 16, pack_f32x8, unpack_f16x8);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::mem::{align_of, size_of};
+
+    const VALUES: [f32; 8] = [1.0, -2.5, 0.1, 65504.0, 6.0e-8, -0.0, 1000.0, 3.0];
+    const OTHERS: [f32; 8] = [0.75, 4.0, -0.3, 2.0, 6.0e-8, 1.0, -1000.0, 7.0];
+
+    #[test]
+    fn layout() {
+        assert_eq!(size_of::<f16x4>(), 8);
+        assert_eq!(align_of::<f16x4>(), 8);
+        assert_eq!(size_of::<f16x8>(), 16);
+        assert_eq!(align_of::<f16x8>(), 16);
+    }
+
+    #[test]
+    fn pack_unpack() {
+        for i in (0..=u16::MAX).step_by(8) {
+            let mut halves = [f16::ZERO; 8];
+            for (j, half) in halves.iter_mut().enumerate() {
+                *half = f16::from_bits(i + j as u16);
+            }
+            let low = [halves[0], halves[1], halves[2], halves[3]];
+            let expected = halves.map(|h| h.to_f32().to_bits());
+            assert_eq!(unpack_f16x8(halves).map(f32::to_bits), expected);
+            assert_eq!(unpack_f16x4(low).map(f32::to_bits)[..], expected[..4]);
+
+            let floats = halves.map(|h| h.to_f32() * 1.0009765);
+            let low = [floats[0], floats[1], floats[2], floats[3]];
+            let expected = floats.map(|f| f16::from_f32(f).to_bits());
+            assert_eq!(pack_f32x8(floats).map(f16::to_bits), expected);
+            assert_eq!(pack_f32x4(low).map(f16::to_bits)[..], expected[..4]);
+        }
+    }
+
+    #[test]
+    fn matches_scalar() {
+        let a = f16x8::from_f32_array(VALUES);
+        let b = f16x8::from(OTHERS);
+        let (xs, ys) = (a.to_array(), b.to_array());
+        assert_eq!(xs, VALUES.map(f16::from_f32));
+        assert_eq!(a.to_f32_array(), xs.map(f16::to_f32));
+
+        let check = |v: f16x8, op: fn(f16, f16) -> f16| {
+            for i in 0..8 {
+                assert_eq!(v.to_array()[i].to_bits(), op(xs[i], ys[i]).to_bits());
+            }
+        };
+        check(a + b, |x, y| x + y);
+        check(a - b, |x, y| x - y);
+        check(a * b, |x, y| x * y);
+        check(a / b, |x, y| x / y);
+        check(a.min(b), f16::min);
+        check(a.max(b), f16::max);
+        check(-a, |x, _| -x);
+
+        let mut c = a;
+        c += b;
+        c *= b;
+        assert_eq!(c, (a + b) * b);
+
+        let nan = f16x4::splat(f16::NAN);
+        let one = f16x4::splat(f16::ONE);
+        assert_eq!(nan.min(one), one);
+        assert_eq!(one.min(nan), one);
+        assert_eq!(nan.max(one), one);
+        assert_eq!(one.max(nan), one);
+    }
+
+    #[test]
+    fn mul_add() {
+        let a = f16x4::from_f32_array([2.0, 1.5, -3.0, 0.5]);
+        let b = f16x4::from_f32_array([3.0, 2.0, 0.25, 0.5]);
+        let c = f16x4::from_f32_array([1.0, -3.0, 1.0, 0.25]);
+        assert_eq!(a.mul_add(b, c).to_f32_array(), [7.0, 0.0, 0.25, 0.5]);
+
+        // (1 + 2^-10)^2 - (1 + 2^-9) is 2^-20, but 0 if the product is rounded first
+        let x = f16x4::splat(f16::from_bits(0x3C01));
+        let y = x.mul_add(x, f16x4::splat(-f16::from_bits(0x3C02)));
+        assert_eq!(y, f16x4::splat(f16::from_bits(0x0010)));
+        assert_eq!(
+            x * x - f16x4::splat(f16::from_bits(0x3C02)),
+            f16x4::default()
+        );
+    }
+}
@@ -0,0 +1,506 @@
+// `f32::to_bits` is not `const` on our MSRV
+#![allow(
+    unknown_lints,
+    unnecessary_transmutes,
+    clippy::missing_transmute_annotations
+)]
+use crate::{f16, Minifloat};
+#[cfg(feature = "bytemuck")]
+use bytemuck::{Pod, Zeroable};
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt::{Debug, Error, Formatter};
+use core::mem;
+#[cfg(feature = "zerocopy")]
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
+
+/// Three unsigned floating point color channels packed into 32 bits, as in the GPU texture
+/// formats `R11G11B10_FLOAT` of Direct3D, `B10G11R11_UFLOAT_PACK32` of Vulkan and `R11F_G11F_B10F`
+/// of OpenGL.
+///
+/// The red channel is stored in the low 11 bits, followed by 11 bits of green and 10 bits of blue.
+/// Each channel has 5 exponent bits with the same bias as [`f16`] and no sign bit, with 6 mantissa
+/// bits for red and green and 5 for blue. The channels have the range of [`f16`], including
+/// infinity and NaN, so converting to [`f16`] or [`f32`] is exact.
+///
+/// Conversions into the format follow the rules of the OpenGL specification for unsigned small
+/// floats: values are rounded to nearest with ties to even, negative values including −∞ become
+/// zero, finite values too large for a channel saturate to its largest finite value, +∞ is kept
+/// and NaN becomes a positive NaN.
+///
+/// # Examples
+///
+/// ```rust
+/// use half::Rg11b10Ufloat;
+///
+/// let color = Rg11b10Ufloat::from_f32([0.5, 1.03, -2.0]);
+/// assert_eq!(color.to_f32(), [0.5, 1.03125, 0.0]);
+/// assert_eq!(color.to_bits(), 0x001E_1380);
+///
+/// // Too large for the format, but finite
+/// assert_eq!(Rg11b10Ufloat::from_f32([1e6, 0.0, 0.0]).to_f32()[0], 65024.0);
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+#[cfg_attr(feature = "bytemuck", derive(Zeroable, Pod))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(FromBytes, Immutable, IntoBytes, KnownLayout)
+)]
+pub struct Rg11b10Ufloat(u32);
+
+impl Rg11b10Ufloat {
+    /// Constructs a value from the raw bits.
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: u32) -> Rg11b10Ufloat {
+        Rg11b10Ufloat(bits)
+    }
+
+    /// Converts the value into the underlying bit representation.
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+
+    /// Packs red, green and blue [`f32`] values, rounding each one to the nearest value of its
+    /// channel.
+    ///
+    /// See the [type documentation](Rg11b10Ufloat) for the handling of negative, large and NaN
+    /// values.
+    #[must_use]
+    pub const fn from_f32(rgb: [f32; 3]) -> Rg11b10Ufloat {
+        Rg11b10Ufloat(
+            encode_channel::<6>(rgb[0])
+                | (encode_channel::<6>(rgb[1]) << 11)
+                | (encode_channel::<5>(rgb[2]) << 22),
+        )
+    }
+
+    /// Packs red, green and blue [`f16`] values, rounding each one to the nearest value of its
+    /// channel.
+    ///
+    /// See the [type documentation](Rg11b10Ufloat) for the handling of negative, large and NaN
+    /// values.
+    #[must_use]
+    pub const fn from_f16(rgb: [f16; 3]) -> Rg11b10Ufloat {
+        Self::from_f32([
+            rgb[0].to_f32_const(),
+            rgb[1].to_f32_const(),
+            rgb[2].to_f32_const(),
+        ])
+    }
+
+    /// Unpacks the red, green and blue channels into [`f32`] values. This conversion is lossless.
+    #[must_use]
+    pub const fn to_f32(self) -> [f32; 3] {
+        [
+            decode_channel::<6>(self.0),
+            decode_channel::<6>(self.0 >> 11),
+            decode_channel::<5>(self.0 >> 22),
+        ]
+    }
+
+    /// Unpacks the red, green and blue channels into [`f16`] values. This conversion is lossless.
+    #[inline]
+    #[must_use]
+    pub const fn to_f16(self) -> [f16; 3] {
+        // The channels are the high bits of the positive `f16` with the same value
+        [
+            f16::from_bits(((self.0 & 0x7FF) << 4) as u16),
+            f16::from_bits(((self.0 >> 11 & 0x7FF) << 4) as u16),
+            f16::from_bits(((self.0 >> 22) << 5) as u16),
+        ]
+    }
+}
+
+// Rounds `value` to an unsigned channel with 5 exponent bits and `MANT` mantissa bits
+const fn encode_channel<const MANT: u32>(value: f32) -> u32 {
+    // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
+    let bits: u32 = unsafe { mem::transmute::<f32, u32>(value) };
+    let inf = 0x1F << MANT;
+    if bits & 0x7FFF_FFFF > 0x7F80_0000 {
+        inf | (1 << (MANT - 1))
+    } else if bits & 0x8000_0000 != 0 {
+        0
+    } else if bits == 0x7F80_0000 {
+        inf
+    } else {
+        let channel = Minifloat::<5, MANT>::from_f32(value).to_bits() as u32;
+        if channel == inf {
+            inf - 1
+        } else {
+            channel
+        }
+    }
+}
+
+// Widens the unsigned channel in the low bits of `bits`
+const fn decode_channel<const MANT: u32>(bits: u32) -> f32 {
+    Minifloat::<5, MANT>::from_bits((bits & ((1 << (5 + MANT)) - 1)) as u16).to_f32()
+}
+
+impl From<[f32; 3]> for Rg11b10Ufloat {
+    #[inline]
+    fn from(rgb: [f32; 3]) -> Self {
+        Self::from_f32(rgb)
+    }
+}
+
+impl From<[f16; 3]> for Rg11b10Ufloat {
+    #[inline]
+    fn from(rgb: [f16; 3]) -> Self {
+        Self::from_f16(rgb)
+    }
+}
+
+impl From<Rg11b10Ufloat> for [f32; 3] {
+    #[inline]
+    fn from(value: Rg11b10Ufloat) -> Self {
+        value.to_f32()
+    }
+}
+
+impl From<Rg11b10Ufloat> for [f16; 3] {
+    #[inline]
+    fn from(value: Rg11b10Ufloat) -> Self {
+        value.to_f16()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl Debug for Rg11b10Ufloat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.debug_tuple("Rg11b10Ufloat")
+            .field(&self.to_f32())
+            .finish()
+    }
+}
+
+/// Three unsigned color channels with 9-bit mantissas and a shared 5-bit exponent packed into 32
+/// bits, as in the GPU texture formats `R9G9B9E5_SHAREDEXP` of Direct3D,
+/// `E5B9G9R9_UFLOAT_PACK32` of Vulkan and `RGB9_E5` of OpenGL, which are commonly used for HDR
+/// images.
+///
+/// The red, green and blue mantissas are stored in the low 27 bits, 9 bits each, followed by the
+/// exponent in the high 5 bits. A channel with mantissa `m` has the value `m * 2^(exponent - 24)`,
+/// so the format covers the finite range of [`f16`] and converting to [`f16`] or [`f32`] is exact.
+/// There are no infinities or NaNs, and no implicit leading mantissa bit, so channels much smaller
+/// than the largest one lose precision.
+///
+/// Conversions into the format use the reference algorithm of the OpenGL and Vulkan
+/// specifications: each channel is clamped to the range from zero to the largest representable
+/// value, with NaN becoming zero, the exponent is chosen for the largest channel, and the
+/// mantissas are rounded to nearest with ties rounded up.
+///
+/// # Examples
+///
+/// ```rust
+/// use half::Rgb9e5Ufloat;
+///
+/// let color = Rgb9e5Ufloat::from_f32([4.0, 0.5, 0.01]);
+/// assert_eq!(color.to_f32(), [4.0, 0.5, 0.015625]);
+/// assert_eq!(color.to_bits(), 0x9004_4100);
+///
+/// // Clamped to the range of the format
+/// let color = Rgb9e5Ufloat::from_f32([1e6, -1.0, f32::NAN]);
+/// assert_eq!(color.to_f32(), [65408.0, 0.0, 0.0]);
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+#[cfg_attr(feature = "bytemuck", derive(Zeroable, Pod))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(FromBytes, Immutable, IntoBytes, KnownLayout)
+)]
+pub struct Rgb9e5Ufloat(u32);
+
+impl Rgb9e5Ufloat {
+    /// The largest value of a channel, `511 * 2^7`.
+    pub const MAX_CHANNEL: f32 = 65408.0;
+
+    /// Constructs a value from the raw bits.
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: u32) -> Rgb9e5Ufloat {
+        Rgb9e5Ufloat(bits)
+    }
+
+    /// Converts the value into the underlying bit representation.
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+
+    /// Packs red, green and blue [`f32`] values with a shared exponent.
+    ///
+    /// See the [type documentation](Rgb9e5Ufloat) for how the values are rounded and clamped.
+    #[must_use]
+    pub const fn from_f32(rgb: [f32; 3]) -> Rgb9e5Ufloat {
+        let r = clamp_shared(rgb[0]);
+        let g = clamp_shared(rgb[1]);
+        let b = clamp_shared(rgb[2]);
+        let max = if r > g { r } else { g };
+        let max = if b > max { b } else { max };
+
+        // The exponent of the largest channel, so that its mantissa is at least 256 unless the
+        // exponent is already the smallest one
+        let max_exp = (max >> 23) as i32 - 127;
+        let mut exp = if max_exp < -16 { -16 } else { max_exp } + 16;
+        if scale_shared(max, exp) == 512 {
+            exp += 1;
+        }
+        Rgb9e5Ufloat(
+            scale_shared(r, exp)
+                | (scale_shared(g, exp) << 9)
+                | (scale_shared(b, exp) << 18)
+                | ((exp as u32) << 27),
+        )
+    }
+
+    /// Packs red, green and blue [`f16`] values with a shared exponent.
+    ///
+    /// See the [type documentation](Rgb9e5Ufloat) for how the values are rounded and clamped.
+    #[must_use]
+    pub const fn from_f16(rgb: [f16; 3]) -> Rgb9e5Ufloat {
+        Self::from_f32([
+            rgb[0].to_f32_const(),
+            rgb[1].to_f32_const(),
+            rgb[2].to_f32_const(),
+        ])
+    }
+
+    /// Unpacks the red, green and blue channels into [`f32`] values. This conversion is lossless.
+    #[must_use]
+    pub const fn to_f32(self) -> [f32; 3] {
+        let exp = (self.0 >> 27) as i32;
+        [
+            decode_shared(self.0 & 0x1FF, exp),
+            decode_shared((self.0 >> 9) & 0x1FF, exp),
+            decode_shared((self.0 >> 18) & 0x1FF, exp),
+        ]
+    }
+
+    /// Unpacks the red, green and blue channels into [`f16`] values. This conversion is lossless.
+    #[must_use]
+    pub const fn to_f16(self) -> [f16; 3] {
+        let [r, g, b] = self.to_f32();
+        [
+            f16::from_f32_const(r),
+            f16::from_f32_const(g),
+            f16::from_f32_const(b),
+        ]
+    }
+}
+
+// Clamps a channel to the range of the shared exponent format and returns the bits of the result,
+// which are ordered like the values
+const fn clamp_shared(value: f32) -> u32 {
+    // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
+    let bits: u32 = unsafe { mem::transmute::<f32, u32>(value) };
+    let max: u32 = unsafe { mem::transmute::<f32, u32>(Rgb9e5Ufloat::MAX_CHANNEL) };
+    if bits > 0x7F80_0000 {
+        // Negative values and NaN
+        0
+    } else if bits > max {
+        max
+    } else {
+        bits
+    }
+}
+
+// Computes `floor(value / 2^(exp - 24) + 0.5)` for the bits of a clamped channel
+const fn scale_shared(bits: u32, exp: i32) -> u32 {
+    let (sig, field) = if bits >> 23 == 0 {
+        (bits, 1)
+    } else {
+        ((bits & 0x7F_FFFF) | 0x80_0000, (bits >> 23) as i32)
+    };
+    // At least 15, since the channel is at most the largest channel
+    let shift = 126 + exp - field;
+    if shift > 31 {
+        0
+    } else {
+        (sig + (1 << (shift - 1))) >> shift
+    }
+}
+
+// Computes `man * 2^(exp - 24)`
+const fn decode_shared(man: u32, exp: i32) -> f32 {
+    let bits = if man == 0 {
+        0
+    } else {
+        let msb = 31 - man.leading_zeros() as i32;
+        (((exp - 24 + msb + 127) as u32) << 23) | ((man << (23 - msb)) & 0x7F_FFFF)
+    };
+    // TODO: Replace mem::transmute with from_bits() once from_bits is const-stabilized
+    unsafe { mem::transmute::<u32, f32>(bits) }
+}
+
+impl From<[f32; 3]> for Rgb9e5Ufloat {
+    #[inline]
+    fn from(rgb: [f32; 3]) -> Self {
+        Self::from_f32(rgb)
+    }
+}
+
+impl From<[f16; 3]> for Rgb9e5Ufloat {
+    #[inline]
+    fn from(rgb: [f16; 3]) -> Self {
+        Self::from_f16(rgb)
+    }
+}
+
+impl From<Rgb9e5Ufloat> for [f32; 3] {
+    #[inline]
+    fn from(value: Rgb9e5Ufloat) -> Self {
+        value.to_f32()
+    }
+}
+
+impl From<Rgb9e5Ufloat> for [f16; 3] {
+    #[inline]
+    fn from(value: Rgb9e5Ufloat) -> Self {
+        value.to_f16()
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl Debug for Rgb9e5Ufloat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.debug_tuple("Rgb9e5Ufloat").field(&self.to_f32()).finish()
+    }
+}
+
+#[allow(clippy::float_cmp)]
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rg11b10_channels_round_trip() {
+        for bits in 0..0x800u32 {
+            let packed = Rg11b10Ufloat::from_bits(bits | (bits << 11) | ((bits >> 1) << 22));
+            let [r, g, b] = packed.to_f32();
+            let [hr, hg, hb] = packed.to_f16();
+            assert_eq!(r.to_bits(), hr.to_f32().to_bits(), "{:#x}", bits);
+            assert_eq!(g.to_bits(), r.to_bits());
+            assert_eq!(b.to_bits(), hb.to_f32().to_bits(), "{:#x}", bits);
+            assert_eq!(hg.to_bits(), hr.to_bits());
+
+            if !r.is_nan() {
+                assert_eq!(Rg11b10Ufloat::from_f32([r, g, b]), packed, "{:#x}", bits);
+                assert_eq!(Rg11b10Ufloat::from_f16([hr, hg, hb]), packed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rg11b10_special_values() {
+        let from = |value: f32| Rg11b10Ufloat::from_f32([value; 3]).to_f32();
+        assert_eq!(from(-1.0), [0.0; 3]);
+        assert_eq!(from(f32::NEG_INFINITY), [0.0; 3]);
+        assert_eq!(from(-0.0).map(f32::to_bits), [0; 3]);
+        assert_eq!(from(f32::INFINITY), [f32::INFINITY; 3]);
+        assert_eq!(from(f32::MAX), [65024.0, 65024.0, 64512.0]);
+        assert!(from(f32::NAN).iter().all(|x| x.is_nan()));
+        assert!(from(-f32::NAN).iter().all(|x| x.is_nan()));
+
+        // Smallest subnormals and ties to even
+        let tiny = f32::from_bits(0x3580_0000);
+        assert_eq!(from(tiny), [tiny, tiny, 0.0]);
+        assert_eq!(from(1.0 + 1.0 / 128.0), [1.0; 3]);
+        assert_eq!(from(1.0 + 3.0 / 128.0), [1.03125; 3]);
+    }
+
+    // The algorithm of the Vulkan specification, computed with `f64` arithmetic
+    fn rgb9e5_reference(rgb: [f32; 3]) -> u32 {
+        let clamp = |x: f32| {
+            if x > 0.0 {
+                (x as f64).min(65408.0)
+            } else {
+                0.0
+            }
+        };
+        let [r, g, b] = rgb.map(clamp);
+        let max = r.max(g).max(b);
+        let pow2 = |e: i32| {
+            let mut x = 1.0f64;
+            for _ in 0..e.abs() {
+                x = if e > 0 { x * 2.0 } else { x / 2.0 };
+            }
+            x
+        };
+        let mut exp = -16;
+        while exp < 15 && pow2(exp + 1) <= max {
+            exp += 1;
+        }
+        let mut exp = exp + 16;
+        let scale = |x: f64, exp: i32| (x / pow2(exp - 24) + 0.5) as u32;
+        if scale(max, exp) == 512 {
+            exp += 1;
+        }
+        scale(r, exp) | (scale(g, exp) << 9) | (scale(b, exp) << 18) | ((exp as u32) << 27)
+    }
+
+    #[test]
+    fn test_rgb9e5_matches_reference() {
+        for bits in 0..=u16::MAX {
+            let x = f16::from_bits(bits).to_f32();
+            for &other in &[0.0, 1.0, 0.1, 1000.0, 65504.0] {
+                for rgb in [[x, other, 0.0], [other, 0.0, x], [x, x * 0.3, other]] {
+                    assert_eq!(
+                        Rgb9e5Ufloat::from_f32(rgb).to_bits(),
+                        rgb9e5_reference(rgb),
+                        "{:?}",
+                        rgb
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rgb9e5_round_trip() {
+        // Every exponent and mantissa, with the largest mantissa normalized so that the encoding
+        // is unique
+        for exp in 0..32u32 {
+            for man in 0..512u32 {
+                let bits = (exp << 27) | ((man / 3) << 18) | ((511 - man) << 9) | man;
+                let packed = Rgb9e5Ufloat::from_bits(bits);
+                let rgb = packed.to_f32();
+                for (i, x) in rgb.iter().enumerate() {
+                    let man = (bits >> (9 * i)) & 0x1FF;
+                    assert_eq!(*x, man as f32 * rgb9e5_scale(exp));
+                }
+                assert_eq!(packed.to_f16().map(f16::to_f32), rgb);
+                assert_eq!(Rgb9e5Ufloat::from_f32(rgb), packed, "{:#x}", bits);
+                assert_eq!(Rgb9e5Ufloat::from_f16(packed.to_f16()), packed);
+            }
+        }
+    }
+
+    fn rgb9e5_scale(exp: u32) -> f32 {
+        f32::from_bits((exp + 127 - 24) << 23)
+    }
+
+    #[test]
+    fn test_rgb9e5_special_values() {
+        let from = |rgb: [f32; 3]| Rgb9e5Ufloat::from_f32(rgb).to_f32();
+        assert_eq!(
+            from([f32::INFINITY, f32::NEG_INFINITY, f32::NAN]),
+            [65408.0, 0.0, 0.0]
+        );
+        assert_eq!(from([-0.0, 0.0, 0.0]).map(f32::to_bits), [0; 3]);
+        assert_eq!(Rgb9e5Ufloat::from_f32([0.0; 3]).to_bits(), 0);
+
+        // Ties round up, and rounding up to 512 moves to the next exponent
+        assert_eq!(
+            from([1.0 + 1.0 / 512.0, 0.0, 0.0]),
+            [1.0 + 1.0 / 256.0, 0.0, 0.0]
+        );
+        assert_eq!(from([511.75, 0.0, 0.0]), [512.0, 0.0, 0.0]);
+        assert_eq!(from([65407.0, 1.0, 0.0]), [65408.0, 0.0, 0.0]);
+    }
+}
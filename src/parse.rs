@@ -0,0 +1,343 @@
+//! Parsing shared by the half-precision types.
+
+use crate::{
+    rounding::{self, Rounding},
+    RoundingMode,
+};
+use core::{cmp::Ordering, num::ParseFloatError, str::FromStr};
+
+// Parses a float in the given radix into the bits of an IEEE 754 format, for the `FromStr` and
+// `from_str_radix` implementations of the half-precision types.
+pub(crate) fn parse_float(
+    src: &str,
+    radix: u32,
+    man_bits: u32,
+    exp_bits: u32,
+) -> Result<u16, ParseFloatError> {
+    assert!(
+        (2..=36).contains(&radix),
+        "from_str_radix: radix must lie in the range `[2, 36]` - found {}",
+        radix
+    );
+    let (negative, rest) = match src.as_bytes().first() {
+        Some(b'-') => (true, &src[1..]),
+        Some(b'+') => (false, &src[1..]),
+        _ => (false, src),
+    };
+    let sign = if negative { 0x8000 } else { 0 };
+    let inf_bits = (((1u32 << exp_bits) - 1) << man_bits) as u16;
+    let bits = if rest.eq_ignore_ascii_case("inf") || rest.eq_ignore_ascii_case("infinity") {
+        Some(sign | inf_bits)
+    } else if rest.eq_ignore_ascii_case("nan") {
+        Some(sign | inf_bits | 1 << (man_bits - 1))
+    } else if radix == 10 && (rest.starts_with("0x") || rest.starts_with("0X")) {
+        parse_hex(&rest[2..], negative, man_bits, exp_bits)
+    } else if radix == 16 {
+        parse_hex(rest, negative, man_bits, exp_bits)
+    } else {
+        parse_radix(rest, negative, radix, man_bits, exp_bits)
+    };
+    match bits {
+        Some(bits) => Ok(bits),
+        // Borrow the matching errors from core
+        None if src.is_empty() => Err(f32::from_str("").unwrap_err()),
+        None => Err(f32::from_str("x").unwrap_err()),
+    }
+}
+
+// Parses the digits of a hexadecimal float after the `0x` prefix, such as `1.8p-2`, into the bits
+// of an IEEE 754 format, rounding to nearest even. The binary exponent is optional.
+pub(crate) fn parse_hex(src: &str, negative: bool, man_bits: u32, exp_bits: u32) -> Option<u16> {
+    let bytes = src.as_bytes();
+    let mut man = 0u64;
+    let mut exp = 0i32;
+    let mut sticky = false;
+    let mut any_digits = false;
+    let mut seen_point = false;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let digit = match bytes[i] {
+            b'p' | b'P' => break,
+            b'.' if !seen_point => {
+                seen_point = true;
+                i += 1;
+                continue;
+            }
+            b => (b as char).to_digit(16)? as u64,
+        };
+        any_digits = true;
+        // Once the significand is full, the remaining digits only matter for rounding
+        if man >> 60 == 0 {
+            man = man << 4 | digit;
+            if seen_point {
+                exp = exp.saturating_sub(4);
+            }
+        } else {
+            sticky |= digit != 0;
+            if !seen_point {
+                exp = exp.saturating_add(4);
+            }
+        }
+        i += 1;
+    }
+    if !any_digits {
+        return None;
+    }
+
+    if i < bytes.len() {
+        exp = exp.saturating_add(parse_exponent(&bytes[i + 1..])?);
+    }
+
+    let sign = if negative { 0x8000 } else { 0 };
+    // A full significand has its lowest bit far below the rounding position of any 16-bit format
+    let man = man | sticky as u64;
+    Some(
+        sign | rounding::round_to_ieee(
+            negative,
+            man,
+            exp,
+            Rounding::Mode(RoundingMode::NearestEven),
+            man_bits,
+            exp_bits,
+        ),
+    )
+}
+
+// Parses an optionally signed decimal exponent, saturating far outside the range of any format.
+fn parse_exponent(bytes: &[u8]) -> Option<i32> {
+    let (negative, digits) = match bytes.first() {
+        Some(b'-') => (true, &bytes[1..]),
+        Some(b'+') => (false, &bytes[1..]),
+        _ => (false, bytes),
+    };
+    if digits.is_empty() {
+        return None;
+    }
+
+    let mut exp = 0i32;
+    for &b in digits {
+        let digit = (b as char).to_digit(10)? as i32;
+        exp = (exp * 10 + digit).min(1_000_000);
+    }
+    Some(if negative { -exp } else { exp })
+}
+
+// Parses digits in any radix from 2 to 36 with an optional radix point into the bits of an IEEE
+// 754 format, correctly rounded to nearest even. In radix 10, a decimal exponent like `e-3` may
+// follow, the same as for primitive floats.
+pub(crate) fn parse_radix(
+    src: &str,
+    negative: bool,
+    radix: u32,
+    man_bits: u32,
+    exp_bits: u32,
+) -> Option<u16> {
+    let bytes = src.as_bytes();
+    let exp_start = bytes
+        .iter()
+        .position(|&b| radix == 10 && matches!(b, b'e' | b'E'));
+    let (mantissa, exp) = match exp_start {
+        Some(i) => (&bytes[..i], parse_exponent(&bytes[i + 1..])?),
+        None => (bytes, 0),
+    };
+    let (int, frac) = match mantissa.iter().position(|&b| b == b'.') {
+        Some(point) => (&mantissa[..point], &mantissa[point + 1..]),
+        None => (mantissa, &[][..]),
+    };
+    if int.is_empty() && frac.is_empty() {
+        return None;
+    }
+    if !int.iter().chain(frac).all(|&b| (b as char).is_digit(radix)) {
+        return None;
+    }
+
+    let digits = Digits {
+        int,
+        frac,
+        radix,
+        point: int.len() as i64 + exp as i64,
+    };
+    let sign = if negative { 0x8000 } else { 0 };
+    let bias = (1i64 << (exp_bits - 1)) - 1;
+    let inf_bits = (((1u32 << exp_bits) - 1) << man_bits) as u16;
+
+    // With `lead` digits before the radix point, radix^(lead - 1) <= value < radix^lead. Values of
+    // at least 2^(bias + 1) overflow, and values below half the smallest subnormal round to zero.
+    let first = match (0..digits.len()).find(|&i| digits.get(i) != 0) {
+        Some(first) => first,
+        None => return Some(sign),
+    };
+    let lead = digits.point - first;
+    if lead > bias + 1 {
+        return Some(sign | inf_bits);
+    }
+    if lead <= -(bias + man_bits as i64) {
+        return Some(sign);
+    }
+
+    let mut int_value = 0u128;
+    for i in first..digits.point {
+        int_value = int_value
+            .saturating_mul(radix as u128)
+            .saturating_add(digits.get(i) as u128);
+    }
+
+    // Find the first value whose halfway point to the next value is above the input, with ties
+    // going to the even one.
+    let (mut low, mut high) = (0u16, inf_bits);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let exp = (mid >> man_bits) as i64;
+        let man = (mid & ((1 << man_bits) - 1)) as u64;
+        let (man, exp) = if exp == 0 {
+            (man, 1 - bias - man_bits as i64)
+        } else {
+            (man | 1 << man_bits, exp - bias - man_bits as i64)
+        };
+        match digits.cmp_scaled(int_value, 2 * man + 1, exp - 1) {
+            Ordering::Less => high = mid,
+            Ordering::Equal if mid & 1 == 0 => high = mid,
+            _ => low = mid + 1,
+        }
+    }
+    Some(sign | low)
+}
+
+// The digits of a number in some radix, with the radix point before the digit at index `point`.
+// Indices outside the digits are zeros.
+struct Digits<'a> {
+    int: &'a [u8],
+    frac: &'a [u8],
+    radix: u32,
+    point: i64,
+}
+
+impl Digits<'_> {
+    fn len(&self) -> i64 {
+        (self.int.len() + self.frac.len()) as i64
+    }
+
+    fn get(&self, i: i64) -> u32 {
+        if i < 0 || i >= self.len() {
+            return 0;
+        }
+        let i = i as usize;
+        let b = if i < self.int.len() {
+            self.int[i]
+        } else {
+            self.frac[i - self.int.len()]
+        };
+        (b as char).to_digit(self.radix).unwrap_or(0)
+    }
+
+    fn any_nonzero_from(&self, start: i64) -> bool {
+        (start.max(0)..self.len()).any(|i| self.get(i) != 0)
+    }
+
+    // Compares the number to `man * 2^exp`, given its integer part. The fractional part is
+    // compared by expanding the fraction of `man * 2^exp` in the same radix one digit at a time.
+    fn cmp_scaled(&self, int_value: u128, man: u64, exp: i64) -> Ordering {
+        if exp >= 0 {
+            let int_part = (man as u128) << exp;
+            return int_value.cmp(&int_part).then_with(|| {
+                if self.any_nonzero_from(self.point) {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            });
+        }
+
+        let frac_bits = -exp as u32;
+        let int_part = if frac_bits >= 64 { 0 } else { man >> frac_bits };
+        if int_value != int_part as u128 {
+            return int_value.cmp(&(int_part as u128));
+        }
+        let frac = if frac_bits >= 64 {
+            man
+        } else {
+            man & ((1 << frac_bits) - 1)
+        };
+        let mut rem = Wide([frac, 0, 0]);
+        let mut i = self.point;
+        while !rem.is_zero() {
+            if i >= self.len() {
+                return Ordering::Less;
+            }
+            rem.mul_small(self.radix);
+            let expected = rem.split_off(frac_bits);
+            let digit = self.get(i);
+            if digit != expected {
+                return digit.cmp(&expected);
+            }
+            i += 1;
+        }
+        if self.any_nonzero_from(i) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+// A little-endian 192-bit integer, enough for the fractions of every halfway point between two
+// 16-bit floats times a radix.
+struct Wide([u64; 3]);
+
+impl Wide {
+    fn is_zero(&self) -> bool {
+        self.0 == [0; 3]
+    }
+
+    fn mul_small(&mut self, factor: u32) {
+        let mut carry = 0;
+        for limb in &mut self.0 {
+            let x = *limb as u128 * factor as u128 + carry;
+            *limb = x as u64;
+            carry = x >> 64;
+        }
+    }
+
+    // Removes and returns the bits from `bit` upwards, which must fit in a `u32`.
+    fn split_off(&mut self, bit: u32) -> u32 {
+        let limb = (bit / 64) as usize;
+        let shift = bit % 64;
+        let mut high = self.0[limb] >> shift;
+        if shift > 0 && limb + 1 < self.0.len() {
+            high |= self.0[limb + 1] << (64 - shift);
+        }
+        self.0[limb] &= (1 << shift) - 1;
+        for higher in &mut self.0[limb + 1..] {
+            *higher = 0;
+        }
+        high as u32
+    }
+}
+
+// Checks a value parsed by `FromStr` from `src` for overflow to infinity or underflow to zero.
+pub(crate) fn check_range(
+    src: &str,
+    is_infinite: bool,
+    is_zero: bool,
+) -> Result<(), crate::ParseError> {
+    let rest = src.strip_prefix(['+', '-']).unwrap_or(src);
+    if is_infinite && !(rest.eq_ignore_ascii_case("inf") || rest.eq_ignore_ascii_case("infinity")) {
+        return Err(crate::ParseError::overflow());
+    }
+    if is_zero {
+        // Only the significand can make the value nonzero
+        let (digits, hex) = match rest.get(..2) {
+            Some("0x" | "0X") => (&rest[2..], true),
+            _ => (rest, false),
+        };
+        let nonzero = digits
+            .bytes()
+            .take_while(|&b| !matches!(b, b'p' | b'P') && (hex || !matches!(b, b'e' | b'E')))
+            .any(|b| b.is_ascii_alphanumeric() && b != b'0');
+        if nonzero {
+            return Err(crate::ParseError::underflow());
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,310 @@
+use crate::{bf16, f16, private::SealedHalf, slice::HalfFloatSliceExt};
+use rand::{
+    distributions::{
+        uniform::{SampleBorrow, SampleUniform, UniformFloat, UniformSampler},
+        Distribution, Open01, OpenClosed01, Standard,
+    },
+    Rng,
+};
+
+/// Number of values the fill functions generate at a time before converting them together.
+pub(crate) const BATCH_LEN: usize = 256;
+
+macro_rules! impl_rand {
+    ($ty:ident, $uniform:ident, $digits:expr) => {
+        impl Distribution<$ty> for Standard {
+            /// Generates a value uniformly distributed in the half-open interval `[0, 1)`.
+            ///
+            /// Every multiple of `2^-MANTISSA_DIGITS` in the interval is equally likely, which is
+            /// the finest evenly spaced grid that can be represented exactly.
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $ty {
+                const SCALE: f32 = 1.0 / (1u32 << $digits) as f32;
+                let value = rng.next_u32() >> (32 - $digits);
+                $ty::from_f32(value as f32 * SCALE)
+            }
+        }
+
+        impl Distribution<$ty> for Open01 {
+            /// Generates a value uniformly distributed in the open interval `(0, 1)`.
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $ty {
+                const SCALE: f32 = 1.0 / (1u32 << ($digits - 1)) as f32;
+                let value = rng.next_u32() >> (32 - ($digits - 1));
+                $ty::from_f32((value as f32 + 0.5) * SCALE)
+            }
+        }
+
+        impl Distribution<$ty> for OpenClosed01 {
+            /// Generates a value uniformly distributed in the half-open interval `(0, 1]`.
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $ty {
+                const SCALE: f32 = 1.0 / (1u32 << $digits) as f32;
+                let value = rng.next_u32() >> (32 - $digits);
+                $ty::from_f32((value + 1) as f32 * SCALE)
+            }
+        }
+
+        #[doc = concat!("The back-end implementing [`UniformSampler`] for [`", stringify!($ty), "`].")]
+        ///
+        /// Values are sampled uniformly over the real interval as [`f32`] and rounded to the
+        /// nearest representable value, resampling in the rare case that rounding reaches the
+        /// excluded upper bound.
+        ///
+        /// Unless you are implementing [`UniformSampler`] for your own type, this type should not
+        /// be used directly, use [`Uniform`][rand::distributions::Uniform] instead.
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub struct $uniform {
+            inner: UniformFloat<f32>,
+            high: $ty,
+            inclusive: bool,
+        }
+
+        impl UniformSampler for $uniform {
+            type X = $ty;
+
+            fn new<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: SampleBorrow<$ty> + Sized,
+                B2: SampleBorrow<$ty> + Sized,
+            {
+                let (low, high) = (*low.borrow(), *high.borrow());
+                Self {
+                    inner: UniformFloat::<f32>::new(low.to_f32(), high.to_f32()),
+                    high,
+                    inclusive: false,
+                }
+            }
+
+            fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: SampleBorrow<$ty> + Sized,
+                B2: SampleBorrow<$ty> + Sized,
+            {
+                let (low, high) = (*low.borrow(), *high.borrow());
+                Self {
+                    inner: UniformFloat::<f32>::new_inclusive(low.to_f32(), high.to_f32()),
+                    high,
+                    inclusive: true,
+                }
+            }
+
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $ty {
+                loop {
+                    let value = $ty::from_f32(self.inner.sample(rng));
+                    if self.inclusive || value < self.high {
+                        return value;
+                    }
+                }
+            }
+        }
+
+        impl SampleUniform for $ty {
+            type Sampler = $uniform;
+        }
+    };
+}
+
+impl_rand!(f16, UniformF16, f16::MANTISSA_DIGITS);
+impl_rand!(bf16, UniformBf16, bf16::MANTISSA_DIGITS);
+
+/// Fills `dst` with values uniformly distributed in the half-open interval `[0, 1)`, the same
+/// distribution as sampling [`Standard`] for each element.
+///
+/// The random bits for a whole batch of elements are generated at once with [`Rng::fill`], and
+/// each batch is turned into [`f16`] or [`bf16`] values with the vectorized
+/// [`convert_from_f32_slice`][HalfFloatSliceExt::convert_from_f32_slice], which is much faster
+/// than sampling the elements one by one for large buffers. The values are not necessarily the
+/// same as those sampled one by one from the same generator.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::prelude::*;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let mut weights = vec![f16::ZERO; 1000];
+/// half::fill_with_standard(&mut weights, &mut StdRng::seed_from_u64(0));
+///
+/// assert!(weights.iter().all(|&x| x >= f16::ZERO && x < f16::ONE));
+/// ```
+pub fn fill_with_standard<H, R>(dst: &mut [H], rng: &mut R)
+where
+    H: SealedHalf,
+    [H]: HalfFloatSliceExt,
+    R: Rng + ?Sized,
+{
+    let digits = H::MANTISSA_DIGITS;
+    let scale = 1.0 / (1u32 << digits) as f32;
+    let mut bits = [0u32; BATCH_LEN];
+    let mut values = [0f32; BATCH_LEN];
+    for dst in dst.chunks_mut(BATCH_LEN) {
+        let bits = &mut bits[..dst.len()];
+        let values = &mut values[..dst.len()];
+        rng.fill(bits);
+        for (value, bits) in values.iter_mut().zip(bits.iter()) {
+            *value = (bits >> (32 - digits)) as f32 * scale;
+        }
+        dst.convert_from_f32_slice(values);
+    }
+}
+
+/// Fills `dst` with values sampled as [`f32`] from `distribution` and rounded to the nearest
+/// [`f16`] or [`bf16`] value.
+///
+/// The samples are collected in batches and rounded together with the vectorized
+/// [`convert_from_f32_slice`][HalfFloatSliceExt::convert_from_f32_slice], so this is faster than
+/// sampling and converting each element separately.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::prelude::*;
+/// use rand::{distributions::Uniform, rngs::StdRng, SeedableRng};
+///
+/// let mut weights = [bf16::ZERO; 64];
+/// let distribution = Uniform::new(-0.1f32, 0.1);
+/// half::fill_with_distribution(&mut weights, &mut StdRng::seed_from_u64(0), &distribution);
+///
+/// assert!(weights.iter().all(|x| x.to_f32().abs() <= 0.1));
+/// ```
+pub fn fill_with_distribution<H, R, D>(dst: &mut [H], rng: &mut R, distribution: &D)
+where
+    [H]: HalfFloatSliceExt,
+    R: Rng + ?Sized,
+    D: Distribution<f32> + ?Sized,
+{
+    let mut values = [0f32; BATCH_LEN];
+    for dst in dst.chunks_mut(BATCH_LEN) {
+        let values = &mut values[..dst.len()];
+        for value in values.iter_mut() {
+            *value = distribution.sample(rng);
+        }
+        dst.convert_from_f32_slice(values);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{bf16, f16};
+    use rand::{
+        distributions::{Open01, OpenClosed01, Uniform},
+        rngs::StdRng,
+        Rng, SeedableRng,
+    };
+
+    #[test]
+    fn standard() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut sum = 0.0;
+        for _ in 0..10_000 {
+            let x: f16 = rng.gen();
+            assert!(x >= f16::ZERO && x < f16::ONE);
+            sum += x.to_f32();
+            let x: bf16 = rng.gen();
+            assert!(x >= bf16::ZERO && x < bf16::ONE);
+        }
+        assert!((sum / 10_000.0 - 0.5).abs() < 0.02);
+    }
+
+    #[test]
+    fn open_intervals() {
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..10_000 {
+            let x: f16 = rng.sample(Open01);
+            assert!(x > f16::ZERO && x < f16::ONE);
+            let x: bf16 = rng.sample(Open01);
+            assert!(x > bf16::ZERO && x < bf16::ONE);
+            let x: f16 = rng.sample(OpenClosed01);
+            assert!(x > f16::ZERO && x <= f16::ONE);
+            let x: bf16 = rng.sample(OpenClosed01);
+            assert!(x > bf16::ZERO && x <= bf16::ONE);
+        }
+
+        // Extremes of the grids are exact
+        let mut max = rand::rngs::mock::StepRng::new(u64::MAX, 0);
+        let x: f16 = max.sample(Open01);
+        assert_eq!(x, f16::from_f32(1.0 - 1.0 / 2048.0));
+        let x: bf16 = max.sample(OpenClosed01);
+        assert_eq!(x, bf16::ONE);
+        let mut min = rand::rngs::mock::StepRng::new(0, 0);
+        let x: f16 = min.sample(Open01);
+        assert_eq!(x, f16::from_f32(1.0 / 2048.0));
+        let x: f16 = min.sample(OpenClosed01);
+        assert_eq!(x, f16::from_f32(1.0 / 2048.0));
+        let x: bf16 = min.sample(Open01);
+        assert_eq!(x, bf16::from_f32(1.0 / 256.0));
+        let x: bf16 = max.sample(Open01);
+        assert_eq!(x, bf16::from_f32(1.0 - 1.0 / 256.0));
+    }
+
+    #[test]
+    fn uniform() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let (low, high) = (f16::from_f32(-2.), f16::from_f32(3.));
+        let next = f16::from_bits(f16::ONE.to_bits() + 1);
+        for _ in 0..10_000 {
+            let x = rng.gen_range(low..high);
+            assert!(x >= low && x < high);
+            let x = rng.gen_range(low..=high);
+            assert!(x >= low && x <= high);
+            assert_eq!(rng.gen_range(f16::ONE..next), f16::ONE);
+            let x = rng.gen_range(bf16::MIN_POSITIVE..bf16::MAX);
+            assert!(x.is_finite() && x >= bf16::MIN_POSITIVE);
+        }
+
+        let dist = Uniform::new_inclusive(f16::ONE, next);
+        let hits = (&mut rng)
+            .sample_iter(dist)
+            .take(1000)
+            .filter(|x| *x == next)
+            .count();
+        assert!(hits > 0 && hits < 1000);
+
+        let (low, high) = (bf16::from_f32(-0.5), bf16::from_f32(0.5));
+        let next = bf16::ONE.next_up();
+        for _ in 0..10_000 {
+            let x = rng.gen_range(low..high);
+            assert!(x >= low && x < high);
+            let x = rng.gen_range(low..=high);
+            assert!(x >= low && x <= high);
+            assert_eq!(rng.gen_range(bf16::ONE..next), bf16::ONE);
+        }
+        let dist = Uniform::new_inclusive(bf16::ONE, next);
+        let hits = rng
+            .sample_iter(dist)
+            .take(1000)
+            .filter(|x| *x == next)
+            .count();
+        assert!(hits > 0 && hits < 1000);
+    }
+
+    #[test]
+    fn fill() {
+        let mut rng = StdRng::seed_from_u64(7);
+        // Not a multiple of the batch length
+        let mut values = [f16::ZERO; 1000];
+        super::fill_with_standard(&mut values, &mut rng);
+        assert!(values.iter().all(|&x| x >= f16::ZERO && x < f16::ONE));
+        let mean = values.iter().map(|x| x.to_f32()).sum::<f32>() / 1000.0;
+        assert!((mean - 0.5).abs() < 0.05);
+
+        // Every value is on the grid of the standard distribution
+        let mut values = [bf16::ZERO; 300];
+        super::fill_with_standard(&mut values, &mut rng);
+        for x in values {
+            assert!(x >= bf16::ZERO && x < bf16::ONE);
+            assert_eq!((x.to_f32() * 256.0).fract(), 0.0);
+        }
+        let mut max = rand::rngs::mock::StepRng::new(u64::MAX, 0);
+        super::fill_with_standard(&mut values[..3], &mut max);
+        assert_eq!(values[..3], [bf16::from_f32(1.0 - 1.0 / 256.0); 3]);
+
+        let mut values = [f16::ZERO; 500];
+        let dist = Uniform::new_inclusive(-3.0f32, -2.0);
+        super::fill_with_distribution(&mut values, &mut rng, &dist);
+        assert!(values
+            .iter()
+            .all(|&x| x >= f16::from_f32(-3.0) && x <= f16::from_f32(-2.0)));
+    }
+}
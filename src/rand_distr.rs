@@ -0,0 +1,152 @@
+use crate::{bf16, f16, rand::BATCH_LEN, slice::HalfFloatSliceExt};
+use rand::Rng;
+use rand_distr::{Distribution, Exp1, StandardNormal};
+
+macro_rules! impl_rand_distr {
+    ($ty:ident) => {
+        impl Distribution<$ty> for StandardNormal {
+            /// Generates a normally distributed value with mean 0 and standard deviation 1.
+            ///
+            /// The value is sampled as [`f64`] and rounded once to the nearest representable value.
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $ty {
+                $ty::from_f64(Distribution::<f64>::sample(self, rng))
+            }
+        }
+
+        impl Distribution<$ty> for Exp1 {
+            /// Generates an exponentially distributed value with rate 1.
+            ///
+            /// The value is sampled as [`f64`] and rounded once to the nearest representable value.
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $ty {
+                $ty::from_f64(Distribution::<f64>::sample(self, rng))
+            }
+        }
+    };
+}
+
+impl_rand_distr!(f16);
+impl_rand_distr!(bf16);
+
+/// Fills `dst` with normally distributed values with the given `mean` and standard deviation.
+///
+/// Batches of [`StandardNormal`] samples are generated as [`f32`], then scaled, shifted and
+/// rounded together with the vectorized
+/// [`convert_from_f32_slice_scaled`][HalfFloatSliceExt::convert_from_f32_slice_scaled]. This is
+/// the usual way to initialize large weight tensors, and much faster than sampling a
+/// `Normal` distribution for each element.
+///
+/// # Panics
+///
+/// This function will panic if `std_dev` is negative or not finite, like `Normal::new` returns an
+/// error.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::prelude::*;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let mut weights = vec![bf16::ZERO; 4096];
+/// half::fill_with_normal(&mut weights, &mut StdRng::seed_from_u64(0), 0.0, 0.02);
+///
+/// let mean = weights.sum_f32() / 4096.0;
+/// assert!(mean.abs() < 0.01);
+/// ```
+pub fn fill_with_normal<H, R>(dst: &mut [H], rng: &mut R, mean: f32, std_dev: f32)
+where
+    [H]: HalfFloatSliceExt,
+    R: Rng + ?Sized,
+{
+    assert!(
+        std_dev >= 0.0 && std_dev.is_finite(),
+        "standard deviation must be finite and non-negative"
+    );
+    let mut values = [0f32; BATCH_LEN];
+    for dst in dst.chunks_mut(BATCH_LEN) {
+        let values = &mut values[..dst.len()];
+        for value in values.iter_mut() {
+            *value = rng.sample(StandardNormal);
+        }
+        dst.convert_from_f32_slice_scaled(values, std_dev, mean);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{bf16, f16};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rand_distr::{Exp1, StandardNormal};
+
+    #[test]
+    fn standard_normal() {
+        let mut rng = StdRng::seed_from_u64(4);
+        let (mut sum, mut sum_sq) = (0.0, 0.0);
+        for _ in 0..10_000 {
+            let x: f16 = rng.sample(StandardNormal);
+            sum += x.to_f32();
+            sum_sq += x.to_f32() * x.to_f32();
+            let x: bf16 = rng.sample(StandardNormal);
+            assert!(x.is_finite());
+        }
+        assert!((sum / 10_000.0).abs() < 0.05);
+        assert!((sum_sq / 10_000.0 - 1.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn exp1() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut sum = 0.0;
+        for _ in 0..10_000 {
+            let x: f16 = rng.sample(Exp1);
+            assert!(x >= f16::ZERO && x.is_finite());
+            sum += x.to_f32();
+            let x: bf16 = rng.sample(Exp1);
+            assert!(x >= bf16::ZERO && x.is_finite());
+        }
+        assert!((sum / 10_000.0 - 1.0).abs() < 0.05);
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn generic_distributions() {
+        use rand_distr::{Exp, Normal};
+
+        let mut rng = StdRng::seed_from_u64(6);
+        let normal = Normal::new(f16::from_f32(10.), f16::from_f32(0.5)).unwrap();
+        let exp = Exp::new(bf16::from_f32(2.)).unwrap();
+        for _ in 0..1000 {
+            let x = rng.sample(normal);
+            assert!(x > f16::from_f32(7.) && x < f16::from_f32(13.));
+            assert!(rng.sample(exp) >= bf16::ZERO);
+        }
+    }
+
+    #[test]
+    fn fill_normal() {
+        let mut rng = StdRng::seed_from_u64(8);
+        let mut values = [f16::ZERO; 10_000];
+        super::fill_with_normal(&mut values, &mut rng, 3.0, 0.5);
+        let n = values.len() as f32;
+        let mean = values.iter().map(|x| x.to_f32()).sum::<f32>() / n;
+        let var = values
+            .iter()
+            .map(|x| (x.to_f32() - mean) * (x.to_f32() - mean))
+            .sum::<f32>()
+            / n;
+        assert!((mean - 3.0).abs() < 0.02);
+        assert!((var.sqrt() - 0.5).abs() < 0.02);
+
+        let mut values = [bf16::ONE; 10];
+        super::fill_with_normal(&mut values, &mut rng, -1.0, 0.0);
+        assert_eq!(values, [bf16::NEG_ONE; 10]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fill_normal_negative_std_dev() {
+        let mut values = [f16::ZERO; 4];
+        super::fill_with_normal(&mut values, &mut StdRng::seed_from_u64(9), 0.0, -1.0);
+    }
+}
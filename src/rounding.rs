@@ -0,0 +1,263 @@
+// `f32::to_bits`/`f64::to_bits` are not `const` on our MSRV
+#![allow(
+    unknown_lints,
+    unnecessary_transmutes,
+    clippy::missing_transmute_annotations
+)]
+use core::mem;
+
+/// The rounding direction used by conversions such as [`f16::from_f32_round`].
+///
+/// All other conversions in this crate round to nearest, ties to even, which is
+/// [`RoundingMode::NearestEven`].
+///
+/// [`f16::from_f32_round`]: crate::f16::from_f32_round
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(not(target_arch = "spirv"), derive(Debug))]
+pub enum RoundingMode {
+    /// Round to the nearest representable value. Ties round to the value with an even least
+    /// significant bit. This is the IEEE 754 default.
+    NearestEven,
+    /// Round toward zero, discarding any excess precision. Values too large to represent become
+    /// the largest finite value of the same sign.
+    TowardZero,
+    /// Round toward positive infinity.
+    TowardPositive,
+    /// Round toward negative infinity.
+    TowardNegative,
+    /// Round to the nearest representable value. Ties round away from zero.
+    NearestAway,
+}
+
+impl Default for RoundingMode {
+    #[inline]
+    fn default() -> Self {
+        RoundingMode::NearestEven
+    }
+}
+
+// How the conversions below pick between the two nearest representable values. Stochastic rounding
+// carries the random bits to add below the last retained bit, so it is not a public mode.
+#[derive(Clone, Copy)]
+pub(crate) enum Rounding {
+    Mode(RoundingMode),
+    Stochastic(u16),
+}
+
+// Rounds the exact magnitude `man * 2^exp` to an IEEE 754 binary interchange format with
+// `man_bits` explicit mantissa bits and `exp_bits` exponent bits, returning the bits of the result
+// without its sign. `negative` is only used to pick the direction of directed rounding.
+pub(crate) const fn round_to_ieee(
+    negative: bool,
+    man: u64,
+    exp: i32,
+    rounding: Rounding,
+    man_bits: u32,
+    exp_bits: u32,
+) -> u16 {
+    let inf_bits = ((1u64 << exp_bits) - 1) << man_bits;
+    match round_to_format(
+        negative,
+        man,
+        exp,
+        rounding,
+        man_bits,
+        exp_bits,
+        inf_bits - 1,
+    ) {
+        Some(bits) => bits,
+        None => overflow(negative, rounding, inf_bits),
+    }
+}
+
+// Like `round_to_ieee`, but for formats such as FP8 E4M3 that use the top exponent for finite
+// values, so that `max_finite` is the bits of the largest finite value. Returns `None` if the
+// rounded magnitude is larger than that, leaving the overflow behaviour to the caller.
+pub(crate) const fn round_to_format(
+    negative: bool,
+    man: u64,
+    exp: i32,
+    rounding: Rounding,
+    man_bits: u32,
+    exp_bits: u32,
+    max_finite: u64,
+) -> Option<u16> {
+    if man == 0 {
+        return Some(0);
+    }
+
+    let bias = (1i32 << (exp_bits - 1)) - 1;
+    let min_exp = 1 - bias;
+    let max_exp = (max_finite >> man_bits) as i32 - bias;
+
+    // Unbiased exponent of the most significant set bit
+    let msb = 63 - man.leading_zeros() as i32;
+    let value_exp = exp + msb;
+    if value_exp > max_exp {
+        return None;
+    }
+
+    // Exponent of the least significant bit of the result, which is fixed for subnormals
+    let lsb_exp = if value_exp < min_exp {
+        min_exp - man_bits as i32
+    } else {
+        value_exp - man_bits as i32
+    };
+    let shift = lsb_exp - exp;
+    let (mut result, round, sticky) = if shift <= 0 {
+        (man << -shift, false, false)
+    } else if shift > 64 {
+        (0, false, true)
+    } else {
+        let result = if shift == 64 { 0 } else { man >> shift };
+        let round_bit = 1u64 << (shift - 1);
+        (result, man & round_bit != 0, man & (round_bit - 1) != 0)
+    };
+
+    let round_up = match rounding {
+        Rounding::Mode(RoundingMode::NearestEven) => round && (sticky || result & 1 != 0),
+        Rounding::Mode(RoundingMode::NearestAway) => round,
+        Rounding::Mode(RoundingMode::TowardZero) => false,
+        Rounding::Mode(RoundingMode::TowardPositive) => !negative && (round || sticky),
+        Rounding::Mode(RoundingMode::TowardNegative) => negative && (round || sticky),
+        // Add the random bits just below the discarded part and see if it carries into the result
+        Rounding::Stochastic(random) => {
+            if shift <= 0 || shift > 80 {
+                false
+            } else {
+                let discarded = if shift >= 64 {
+                    man
+                } else {
+                    man & ((1 << shift) - 1)
+                } as u128;
+                (discarded << 16) + ((random as u128) << shift) >= 1u128 << (shift + 16)
+            }
+        }
+    };
+    if round_up {
+        result += 1;
+    }
+
+    // Subnormals have no exponent, and a rounding carry out of them produces the smallest normal.
+    // For normals, adding the hidden bit into the exponent field also carries a rounding overflow.
+    if value_exp >= min_exp {
+        result += ((value_exp - min_exp) as u64) << man_bits;
+    }
+    if result > max_finite {
+        None
+    } else {
+        Some(result as u16)
+    }
+}
+
+const fn overflow(negative: bool, rounding: Rounding, inf_bits: u64) -> u16 {
+    let to_infinity = match rounding {
+        Rounding::Mode(RoundingMode::NearestEven | RoundingMode::NearestAway) => true,
+        Rounding::Mode(RoundingMode::TowardZero) => false,
+        Rounding::Mode(RoundingMode::TowardPositive) => !negative,
+        Rounding::Mode(RoundingMode::TowardNegative) => negative,
+        Rounding::Stochastic(_) => true,
+    };
+    if to_infinity {
+        inf_bits as u16
+    } else {
+        (inf_bits - 1) as u16
+    }
+}
+
+// Converts to the given 16-bit IEEE 754 format with the requested rounding. NaN payloads are
+// truncated and quieted the same way as the default conversions.
+pub(crate) const fn f32_to_ieee_round(
+    value: f32,
+    rounding: Rounding,
+    man_bits: u32,
+    exp_bits: u32,
+) -> u16 {
+    // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
+    let x: u32 = unsafe { mem::transmute(value) };
+    let sign = ((x >> 16) & 0x8000) as u16;
+    let exp = (x >> 23) & 0xFF;
+    let man = (x & 0x007F_FFFF) as u64;
+
+    if exp == 0xFF {
+        return sign | nan_or_infinity(man, 23, man_bits, exp_bits);
+    }
+
+    let (man, exp) = if exp == 0 {
+        (man, -149)
+    } else {
+        (man | 0x0080_0000, exp as i32 - 150)
+    };
+    sign | round_to_ieee(sign != 0, man, exp, rounding, man_bits, exp_bits)
+}
+
+// See `f32_to_ieee_round`.
+pub(crate) const fn f64_to_ieee_round(
+    value: f64,
+    rounding: Rounding,
+    man_bits: u32,
+    exp_bits: u32,
+) -> u16 {
+    // TODO: Replace mem::transmute with to_bits() once to_bits is const-stabilized
+    let x: u64 = unsafe { mem::transmute(value) };
+    let sign = ((x >> 48) & 0x8000) as u16;
+    let exp = (x >> 52) & 0x7FF;
+    let man = x & 0x000F_FFFF_FFFF_FFFF;
+
+    if exp == 0x7FF {
+        return sign | nan_or_infinity(man, 52, man_bits, exp_bits);
+    }
+
+    let (man, exp) = if exp == 0 {
+        (man, -1074)
+    } else {
+        (man | 0x0010_0000_0000_0000, exp as i32 - 1075)
+    };
+    sign | round_to_ieee(sign != 0, man, exp, rounding, man_bits, exp_bits)
+}
+
+// Converts the bits of one 16-bit IEEE 754 format with `src_man_bits` mantissa bits and
+// `src_exp_bits` exponent bits to another, see `f32_to_ieee_round`.
+pub(crate) const fn ieee16_to_ieee_round(
+    bits: u16,
+    src_man_bits: u32,
+    src_exp_bits: u32,
+    rounding: Rounding,
+    man_bits: u32,
+    exp_bits: u32,
+) -> u16 {
+    let sign = bits & 0x8000;
+    let max_exp = (1u16 << src_exp_bits) - 1;
+    let exp = (bits >> src_man_bits) & max_exp;
+    let man = (bits & ((1 << src_man_bits) - 1)) as u64;
+
+    if exp == max_exp {
+        return sign | nan_or_infinity(man, src_man_bits, man_bits, exp_bits);
+    }
+
+    let bias = (1i32 << (src_exp_bits - 1)) - 1;
+    let (man, exp) = if exp == 0 {
+        (man, 1 - bias - src_man_bits as i32)
+    } else {
+        (
+            man | (1 << src_man_bits),
+            exp as i32 - bias - src_man_bits as i32,
+        )
+    };
+    sign | round_to_ieee(sign != 0, man, exp, rounding, man_bits, exp_bits)
+}
+
+const fn nan_or_infinity(man: u64, src_man_bits: u32, man_bits: u32, exp_bits: u32) -> u16 {
+    let inf_bits = (((1u32 << exp_bits) - 1) << man_bits) as u16;
+    if man == 0 {
+        inf_bits
+    } else {
+        // Keep the high part of the payload and set the quiet bit
+        let payload = if src_man_bits > man_bits {
+            man >> (src_man_bits - man_bits)
+        } else {
+            man << (man_bits - src_man_bits)
+        };
+        inf_bits | (1u16 << (man_bits - 1)) | payload as u16
+    }
+}
@@ -0,0 +1,860 @@
+//! Adapters to customize how [`f16`][crate::f16] and [`bf16`][crate::bf16] values are serialized
+//! with serde.
+//!
+//! By default, both types are serialized as a newtype of their [`u16`] bits, which is compact in
+//! binary formats but unreadable in text formats such as JSON. The modules here are meant to be
+//! used with the `#[serde(with = "...")]` field attribute to choose a different representation,
+//! or to reject NaN and infinite values when deserializing untrusted input.
+//!
+//! This module is only available with the `serde` feature. The adapters for sequences also require
+//! the `std` or `alloc` feature.
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::{fmt, marker::PhantomData};
+#[cfg(feature = "alloc")]
+use serde::de::SeqAccess;
+use serde::{
+    de::{self, Unexpected, Visitor},
+    Deserializer, Serializer,
+};
+
+/// Serializes floats as numbers in human-readable formats and as raw bits in binary formats.
+///
+/// Human-readable serializers, such as JSON, receive the value converted to [`f32`], while other
+/// serializers receive the same compact [`u16`] bits as the default `Serialize` implementation.
+/// When deserializing from a human-readable format, numbers and numeric strings are both accepted
+/// and rounded to the nearest value, otherwise the default bits representation is expected.
+///
+/// # Examples
+///
+/// ```rust
+/// use half::f16;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Layer {
+///     #[serde(with = "half::serde::human_readable")]
+///     scale: f16,
+/// }
+///
+/// let layer = Layer { scale: f16::from_f32(0.5) };
+/// let json = serde_json::to_string(&layer).unwrap();
+/// assert_eq!(json, r#"{"scale":0.5}"#);
+/// assert_eq!(serde_json::from_str::<Layer>(&json).unwrap(), layer);
+/// ```
+///
+/// The same adapter works for [`bf16`][crate::bf16] fields:
+///
+/// ```rust
+/// use half::bf16;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Config {
+///     #[serde(with = "half::serde::human_readable")]
+///     learning_rate: bf16,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"learning_rate":0.001}"#).unwrap();
+/// assert_eq!(config.learning_rate, bf16::from_f32(0.001));
+/// assert_eq!(
+///     serde_json::to_string(&config).unwrap(),
+///     r#"{"learning_rate":0.0009994507}"#
+/// );
+/// ```
+pub mod human_readable {
+    use super::*;
+
+    /// Serializes `value` as [`f32`] if the serializer is human-readable, and as bits otherwise.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: private::SerdeFloat,
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_f32(value.to_f32())
+        } else {
+            value.serialize(serializer)
+        }
+    }
+
+    /// Deserializes a number or numeric string if the deserializer is human-readable, and bits
+    /// otherwise.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: private::SerdeFloat,
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(NumberVisitor(PhantomData))
+        } else {
+            T::deserialize(deserializer)
+        }
+    }
+
+    /// Like [`human_readable`], but NaN and infinite values are serialized as the strings `"NaN"`,
+    /// `"Infinity"` and `"-Infinity"` in human-readable formats.
+    ///
+    /// JSON has no representation of these values, so `serde_json` writes them as `null`, which
+    /// cannot be read back as a number, while data produced with Python or NumPy commonly holds
+    /// these strings instead. Both adapters accept the strings when deserializing, along with
+    /// `"inf"` and any capitalization.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use half::f16;
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    /// struct Bounds {
+    ///     #[serde(with = "half::serde::human_readable::non_finite_strings")]
+    ///     low: f16,
+    ///     #[serde(with = "half::serde::human_readable::non_finite_strings")]
+    ///     high: f16,
+    /// }
+    ///
+    /// let bounds = Bounds { low: f16::ZERO, high: f16::INFINITY };
+    /// let json = serde_json::to_string(&bounds).unwrap();
+    /// assert_eq!(json, r#"{"low":0.0,"high":"Infinity"}"#);
+    /// assert_eq!(serde_json::from_str::<Bounds>(&json).unwrap(), bounds);
+    ///
+    /// let nan: Bounds = serde_json::from_str(r#"{"low":"NaN","high":1}"#).unwrap();
+    /// assert!(nan.low.is_nan());
+    /// ```
+    pub mod non_finite_strings {
+        use super::super::*;
+
+        /// Serializes `value` as [`f32`], or as a string if it is not finite, if the serializer is
+        /// human-readable, and as bits otherwise.
+        pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: private::SerdeFloat,
+            S: Serializer,
+        {
+            if !serializer.is_human_readable() || value.is_finite() {
+                super::serialize(value, serializer)
+            } else if value.to_f32().is_nan() {
+                serializer.serialize_str("NaN")
+            } else if value.to_f32() > 0.0 {
+                serializer.serialize_str("Infinity")
+            } else {
+                serializer.serialize_str("-Infinity")
+            }
+        }
+
+        /// Deserializes a number or numeric string if the deserializer is human-readable, and bits
+        /// otherwise.
+        #[inline]
+        pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+        where
+            T: private::SerdeFloat,
+            D: Deserializer<'de>,
+        {
+            super::deserialize(deserializer)
+        }
+    }
+
+    /// Like [`human_readable`], but deserializing NaN or infinite values is an error.
+    ///
+    /// Finite numbers that are too large for the type are also rejected, since they round to an
+    /// infinity. Serialization is the same as [`human_readable`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use half::f16;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize, Debug)]
+    /// struct Request {
+    ///     #[serde(with = "half::serde::human_readable::finite")]
+    ///     threshold: f16,
+    /// }
+    ///
+    /// assert!(serde_json::from_str::<Request>(r#"{"threshold":0.75}"#).is_ok());
+    /// assert!(serde_json::from_str::<Request>(r#"{"threshold":1e9}"#).is_err());
+    /// assert!(serde_json::from_str::<Request>(r#"{"threshold":"NaN"}"#).is_err());
+    /// ```
+    pub mod finite {
+        use super::super::*;
+
+        /// Serializes `value` as [`f32`] if the serializer is human-readable, and as bits
+        /// otherwise.
+        #[inline]
+        pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: private::SerdeFloat,
+            S: Serializer,
+        {
+            super::serialize(value, serializer)
+        }
+
+        /// Deserializes a number or numeric string if the deserializer is human-readable, and bits
+        /// otherwise, and checks that the value is finite.
+        pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+        where
+            T: private::SerdeFloat,
+            D: Deserializer<'de>,
+        {
+            check_finite(super::deserialize(deserializer)?)
+        }
+    }
+}
+
+/// Serializes floats with their default representation, but rejects NaN and infinite values when
+/// deserializing.
+///
+/// This is meant for fields filled from untrusted input that must hold a finite number. To also
+/// accept numbers in human-readable formats, use
+/// [`human_readable::finite`] instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use half::f16;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Weight(#[serde(with = "half::serde::finite")] f16);
+///
+/// let json = serde_json::to_string(&Weight(f16::ONE)).unwrap();
+/// assert_eq!(json, "15360");
+/// assert_eq!(serde_json::from_str::<Weight>(&json).unwrap(), Weight(f16::ONE));
+///
+/// let json = serde_json::to_string(&Weight(f16::INFINITY)).unwrap();
+/// assert!(serde_json::from_str::<Weight>(&json).is_err());
+/// ```
+pub mod finite {
+    use super::*;
+
+    /// Serializes `value` with its default `Serialize` implementation.
+    #[inline]
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: private::SerdeFloat,
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+
+    /// Deserializes a value with its default `Deserialize` implementation, and checks that it is
+    /// finite.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: private::SerdeFloat,
+        D: Deserializer<'de>,
+    {
+        check_finite(T::deserialize(deserializer)?)
+    }
+}
+
+fn check_finite<T: private::SerdeFloat, E: de::Error>(value: T) -> Result<T, E> {
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        let unexpected = Unexpected::Float(f64::from(value.to_f32()));
+        Err(E::invalid_value(unexpected, &"a finite number"))
+    }
+}
+
+/// Serializes sequences of floats as a single blob of little-endian bytes.
+///
+/// Formats with native byte arrays, such as bincode or CBOR, store the bits of each element as two
+/// bytes with no per-element overhead, which is much more compact and faster than the default
+/// sequence of newtypes. Formats without byte arrays, such as JSON, fall back to a sequence of
+/// bytes. Deserializing a byte array with an odd length is an error.
+///
+/// Any slice can be serialized, while deserialization produces a [`Vec`].
+///
+/// This module is only available with the `std` or `alloc` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use half::f16;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Tensor {
+///     #[serde(with = "half::serde::as_bytes")]
+///     data: Vec<f16>,
+/// }
+///
+/// let tensor = Tensor { data: vec![f16::ONE, f16::NEG_INFINITY] };
+/// let json = serde_json::to_string(&tensor).unwrap();
+/// assert_eq!(json, r#"{"data":[0,60,0,252]}"#);
+/// assert_eq!(serde_json::from_str::<Tensor>(&json).unwrap(), tensor);
+/// ```
+#[cfg(feature = "alloc")]
+pub mod as_bytes {
+    use super::*;
+
+    /// Serializes `values` as a byte array of their little-endian bits.
+    pub fn serialize<T, S>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: private::SerdeFloat,
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&to_le_bytes(values))
+    }
+
+    /// Deserializes a vector of values from a byte array of their little-endian bits.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        T: private::SerdeFloat,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(BytesVisitor {
+            base64: false,
+            marker: PhantomData,
+        })
+    }
+}
+
+/// Serializes sequences of floats as base64 in human-readable formats and as bytes otherwise.
+///
+/// Human-readable serializers, such as JSON, receive a padded base64 string of the little-endian
+/// bits of the elements using the standard alphabet, which is much smaller than a sequence of
+/// numbers. Other serializers receive the same byte array as [`as_bytes`].
+///
+/// Any slice can be serialized, while deserialization produces a [`Vec`].
+///
+/// This module is only available with the `std` or `alloc` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use half::f16;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Tensor {
+///     #[serde(with = "half::serde::as_base64")]
+///     data: Vec<f16>,
+/// }
+///
+/// let tensor = Tensor { data: vec![f16::ONE, f16::NEG_INFINITY] };
+/// let json = serde_json::to_string(&tensor).unwrap();
+/// assert_eq!(json, r#"{"data":"ADwA/A=="}"#);
+/// assert_eq!(serde_json::from_str::<Tensor>(&json).unwrap(), tensor);
+/// ```
+#[cfg(feature = "alloc")]
+pub mod as_base64 {
+    use super::*;
+
+    /// Serializes `values` as a base64 string if the serializer is human-readable, and as a byte
+    /// array otherwise.
+    pub fn serialize<T, S>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: private::SerdeFloat,
+        S: Serializer,
+    {
+        let bytes = to_le_bytes(values);
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&encode_base64(&bytes))
+        } else {
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+
+    /// Deserializes a vector of values from a base64 string if the deserializer is
+    /// human-readable, and from a byte array otherwise.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        T: private::SerdeFloat,
+        D: Deserializer<'de>,
+    {
+        let visitor = BytesVisitor {
+            base64: true,
+            marker: PhantomData,
+        };
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(visitor)
+        } else {
+            deserializer.deserialize_bytes(visitor)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn to_le_bytes<T: private::SerdeFloat>(values: &[T]) -> Vec<u8> {
+    values
+        .iter()
+        .flat_map(|x| x.to_bits().to_le_bytes())
+        .collect()
+}
+
+#[cfg(feature = "alloc")]
+fn from_le_bytes<T: private::SerdeFloat, E: de::Error>(bytes: &[u8]) -> Result<Vec<T>, E> {
+    if bytes.len() % 2 != 0 {
+        return Err(E::invalid_length(bytes.len(), &"an even number of bytes"));
+    }
+    Ok(bytes
+        .chunks_exact(2)
+        .map(|b| T::from_bits(u16::from_le_bytes([b[0], b[1]])))
+        .collect())
+}
+
+#[cfg(feature = "alloc")]
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+#[cfg(feature = "alloc")]
+fn encode_base64(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let block = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |block, (i, &b)| block | (b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (block >> (18 - 6 * i)) & 0x3F;
+                encoded.push(BASE64_ALPHABET[index as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Decodes padded or unpadded standard base64, returning `None` on any invalid input.
+#[cfg(feature = "alloc")]
+fn decode_base64(encoded: &str) -> Option<Vec<u8>> {
+    let encoded = encoded.as_bytes();
+    let unpadded = match encoded {
+        [rest @ .., b'=', b'='] | [rest @ .., b'='] if encoded.len() % 4 == 0 => rest,
+        _ => encoded,
+    };
+    if unpadded.len() % 4 == 1 {
+        return None;
+    }
+
+    let mut decoded = Vec::with_capacity(unpadded.len() / 4 * 3 + 2);
+    for chunk in unpadded.chunks(4) {
+        let mut block = 0u32;
+        for (i, &c) in chunk.iter().enumerate() {
+            let value = BASE64_ALPHABET.iter().position(|&a| a == c)? as u32;
+            block |= value << (18 - 6 * i);
+        }
+        let bytes = block.to_be_bytes();
+        decoded.extend_from_slice(&bytes[1..chunk.len()]);
+    }
+    Some(decoded)
+}
+
+/// Visits a byte array, a sequence of bytes, or optionally a base64 string of little-endian bits.
+#[cfg(feature = "alloc")]
+struct BytesVisitor<T> {
+    base64: bool,
+    marker: PhantomData<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T: private::SerdeFloat> Visitor<'de> for BytesVisitor<T> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        if self.base64 {
+            write!(formatter, "a base64 string or byte array")
+        } else {
+            write!(formatter, "a byte array")
+        }
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Vec<T>, E> {
+        from_le_bytes(v)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<T>, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(byte) = seq.next_element::<u8>()? {
+            bytes.push(byte);
+        }
+        from_le_bytes(&bytes)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Vec<T>, E> {
+        if !self.base64 {
+            return Err(E::invalid_type(Unexpected::Str(v), &self));
+        }
+        match decode_base64(v) {
+            Some(bytes) => from_le_bytes(&bytes),
+            None => Err(E::invalid_value(Unexpected::Str(v), &self)),
+        }
+    }
+}
+
+/// Visits any number or numeric string, rounding it to the nearest `T` value.
+struct NumberVisitor<T>(PhantomData<T>);
+
+impl<'de, T: private::SerdeFloat> Visitor<'de> for NumberVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a number or numeric string")
+    }
+
+    fn visit_f32<E: de::Error>(self, v: f32) -> Result<T, E> {
+        Ok(T::from_f32(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<T, E> {
+        Ok(T::from_f64(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        Ok(T::from_f64(v as f64))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        Ok(T::from_f64(v as f64))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        v.parse()
+            .map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+mod private {
+    use crate::{bf16, f16};
+    use core::str::FromStr;
+    use serde::{Deserialize, Serialize};
+
+    pub trait SerdeFloat: Copy + Serialize + for<'de> Deserialize<'de> + FromStr {
+        fn to_f32(self) -> f32;
+        fn is_finite(self) -> bool;
+        fn from_f32(value: f32) -> Self;
+        fn from_f64(value: f64) -> Self;
+        fn to_bits(self) -> u16;
+        fn from_bits(bits: u16) -> Self;
+    }
+
+    macro_rules! impl_serde_float {
+        ($($ty:ident)*) => {$(
+            impl SerdeFloat for $ty {
+                #[inline]
+                fn to_f32(self) -> f32 {
+                    $ty::to_f32(self)
+                }
+
+                #[inline]
+                fn is_finite(self) -> bool {
+                    $ty::is_finite(self)
+                }
+
+                #[inline]
+                fn from_f32(value: f32) -> Self {
+                    $ty::from_f32(value)
+                }
+
+                #[inline]
+                fn from_f64(value: f64) -> Self {
+                    $ty::from_f64(value)
+                }
+
+                #[inline]
+                fn to_bits(self) -> u16 {
+                    $ty::to_bits(self)
+                }
+
+                #[inline]
+                fn from_bits(bits: u16) -> Self {
+                    $ty::from_bits(bits)
+                }
+            }
+        )*};
+    }
+
+    impl_serde_float!(f16 bf16);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{bf16, f16};
+    use serde::{Deserialize, Serialize};
+    use serde_test::{
+        assert_de_tokens, assert_de_tokens_error, assert_ser_tokens, assert_tokens, Configure,
+        Readable, Token,
+    };
+
+    #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+    struct HumanReadable(#[serde(with = "crate::serde::human_readable")] f16);
+
+    #[test]
+    fn human_readable() {
+        let value = HumanReadable(f16::from_f32(-1.5));
+        assert_tokens(
+            &value.readable(),
+            &[
+                Token::NewtypeStruct {
+                    name: "HumanReadable",
+                },
+                Token::F32(-1.5),
+            ],
+        );
+        assert_tokens(
+            &value.compact(),
+            &[
+                Token::NewtypeStruct {
+                    name: "HumanReadable",
+                },
+                Token::NewtypeStruct { name: "f16" },
+                Token::U16(0xBE00),
+            ],
+        );
+
+        let one = HumanReadable(f16::ONE);
+        for token in [
+            Token::F64(1.0),
+            Token::I8(1),
+            Token::U64(1),
+            Token::Str("1.0"),
+        ] {
+            assert_de_tokens(
+                &one.readable(),
+                &[
+                    Token::NewtypeStruct {
+                        name: "HumanReadable",
+                    },
+                    token,
+                ],
+            );
+        }
+        assert_de_tokens(
+            &HumanReadable(f16::MAX).readable(),
+            &[
+                Token::NewtypeStruct {
+                    name: "HumanReadable",
+                },
+                Token::F64(65519.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn human_readable_json() {
+        let value = HumanReadable(f16::from_f32(0.25));
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, "0.25");
+        assert_eq!(serde_json::from_str::<HumanReadable>(&json).unwrap(), value);
+        assert_eq!(
+            serde_json::from_str::<HumanReadable>("\"0.1\"").unwrap(),
+            HumanReadable(f16::from_f32(0.1))
+        );
+        assert!(serde_json::from_str::<HumanReadable>("\"x\"").is_err());
+    }
+
+    #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+    struct HumanReadableBf16(#[serde(with = "crate::serde::human_readable")] bf16);
+
+    #[test]
+    fn human_readable_bf16() {
+        let value = HumanReadableBf16(bf16::from_f32(-1.5));
+        assert_tokens(
+            &value.readable(),
+            &[
+                Token::NewtypeStruct {
+                    name: "HumanReadableBf16",
+                },
+                Token::F32(-1.5),
+            ],
+        );
+        assert_tokens(
+            &value.compact(),
+            &[
+                Token::NewtypeStruct {
+                    name: "HumanReadableBf16",
+                },
+                Token::NewtypeStruct { name: "bf16" },
+                Token::U16(0xBFC0),
+            ],
+        );
+
+        let json = serde_json::to_string(&HumanReadableBf16(bf16::MAX)).unwrap();
+        assert_eq!(json, "3.3895314e+38");
+        assert_eq!(
+            serde_json::from_str::<HumanReadableBf16>(&json).unwrap(),
+            HumanReadableBf16(bf16::MAX)
+        );
+        assert_eq!(
+            serde_json::from_str::<HumanReadableBf16>("\"1e5\"").unwrap(),
+            HumanReadableBf16(bf16::from_f32(1e5))
+        );
+    }
+
+    #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+    struct Finite(#[serde(with = "crate::serde::finite")] bf16);
+
+    #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+    struct ReadableFinite(#[serde(with = "crate::serde::human_readable::finite")] f16);
+
+    #[test]
+    fn finite() {
+        assert_tokens(
+            &Finite(bf16::MAX),
+            &[
+                Token::NewtypeStruct { name: "Finite" },
+                Token::NewtypeStruct { name: "bf16" },
+                Token::U16(0x7F7F),
+            ],
+        );
+        for (bits, value) in [(0x7F80, "inf"), (0xFF80, "-inf"), (0x7FC0, "NaN")] {
+            assert_de_tokens_error::<Finite>(
+                &[
+                    Token::NewtypeStruct { name: "Finite" },
+                    Token::NewtypeStruct { name: "bf16" },
+                    Token::U16(bits),
+                ],
+                &alloc::format!(
+                    "invalid value: floating point `{}`, expected a finite number",
+                    value
+                ),
+            );
+        }
+
+        let value = ReadableFinite(f16::from_f32(-2.5));
+        assert_tokens(
+            &value.readable(),
+            &[
+                Token::NewtypeStruct {
+                    name: "ReadableFinite",
+                },
+                Token::F32(-2.5),
+            ],
+        );
+        assert_de_tokens_error::<Readable<ReadableFinite>>(
+            &[
+                Token::NewtypeStruct {
+                    name: "ReadableFinite",
+                },
+                Token::F64(65520.0),
+            ],
+            "invalid value: floating point `inf`, expected a finite number",
+        );
+        assert!(serde_json::from_str::<ReadableFinite>("\"-inf\"").is_err());
+        assert_eq!(
+            serde_json::from_str::<ReadableFinite>("65504").unwrap(),
+            ReadableFinite(f16::MAX)
+        );
+    }
+
+    #[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+    struct NonFiniteStrings(
+        #[serde(with = "crate::serde::human_readable::non_finite_strings")] bf16,
+    );
+
+    #[test]
+    fn non_finite_strings() {
+        let name = "NonFiniteStrings";
+        for (value, string) in [
+            (bf16::NAN, "NaN"),
+            (-bf16::NAN, "NaN"),
+            (bf16::INFINITY, "Infinity"),
+            (bf16::NEG_INFINITY, "-Infinity"),
+        ] {
+            let value = NonFiniteStrings(value);
+            assert_ser_tokens(
+                &value.readable(),
+                &[Token::NewtypeStruct { name }, Token::Str(string)],
+            );
+            assert_ser_tokens(
+                &value.compact(),
+                &[
+                    Token::NewtypeStruct { name },
+                    Token::NewtypeStruct { name: "bf16" },
+                    Token::U16(value.0.to_bits()),
+                ],
+            );
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(json, alloc::format!("\"{}\"", string));
+            let parsed = serde_json::from_str::<NonFiniteStrings>(&json).unwrap();
+            assert_eq!(parsed.0.to_bits() & 0x7FFF, value.0.to_bits() & 0x7FFF);
+        }
+        assert_ser_tokens(
+            &NonFiniteStrings(bf16::MAX).readable(),
+            &[
+                Token::NewtypeStruct { name },
+                Token::F32(bf16::MAX.to_f32()),
+            ],
+        );
+        let parsed = serde_json::from_str::<NonFiniteStrings>("\"-infinity\"").unwrap();
+        assert_eq!(parsed.0, bf16::NEG_INFINITY);
+    }
+
+    #[cfg(feature = "std")]
+    #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+    struct Tensor {
+        #[serde(with = "crate::serde::as_bytes")]
+        bytes: std::vec::Vec<f16>,
+        #[serde(with = "crate::serde::as_base64")]
+        base64: std::vec::Vec<f16>,
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn as_bytes_and_base64() {
+        let data = std::vec![f16::ONE, f16::from_bits(0x1234), f16::NEG_INFINITY];
+        let tensor = Tensor {
+            bytes: data.clone(),
+            base64: data,
+        };
+        let header = [
+            Token::Struct {
+                name: "Tensor",
+                len: 2,
+            },
+            Token::Str("bytes"),
+            Token::Bytes(&[0x00, 0x3C, 0x34, 0x12, 0x00, 0xFC]),
+            Token::Str("base64"),
+        ];
+        let mut compact = header.to_vec();
+        compact.extend([
+            Token::Bytes(&[0x00, 0x3C, 0x34, 0x12, 0x00, 0xFC]),
+            Token::StructEnd,
+        ]);
+        assert_tokens(&tensor.clone().compact(), &compact);
+        let mut readable = header.to_vec();
+        readable.extend([Token::Str("ADw0EgD8"), Token::StructEnd]);
+        assert_tokens(&tensor.clone().readable(), &readable);
+
+        let json = serde_json::to_string(&tensor).unwrap();
+        assert_eq!(serde_json::from_str::<Tensor>(&json).unwrap(), tensor);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn base64_codec() {
+        use super::{decode_base64, encode_base64};
+
+        let cases: [(&[u8], &str); 5] = [
+            (b"", ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foob", "Zm9vYg=="),
+        ];
+        for (bytes, encoded) in cases {
+            assert_eq!(encode_base64(bytes), encoded);
+            assert_eq!(decode_base64(encoded).unwrap(), bytes);
+            assert_eq!(decode_base64(encoded.trim_end_matches('=')).unwrap(), bytes);
+        }
+        assert_eq!(decode_base64("Zm9vYg="), None);
+        assert_eq!(decode_base64("Z"), None);
+        assert_eq!(decode_base64("Zm9*"), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn invalid_bytes() {
+        assert!(serde_json::from_str::<Tensor>(r#"{"bytes":[0,60,0],"base64":""}"#).is_err());
+        assert!(serde_json::from_str::<Tensor>(r#"{"bytes":[],"base64":"AD"}"#).is_err());
+        assert!(serde_json::from_str::<Tensor>(r#"{"bytes":[],"base64":"AD!A"}"#).is_err());
+        let tensor = serde_json::from_str::<Tensor>(r#"{"bytes":[],"base64":"ADwA/A"}"#).unwrap();
+        assert_eq!(tensor.base64, [f16::ONE, f16::NEG_INFINITY]);
+    }
+}
@@ -0,0 +1,533 @@
+use crate::{bf16, f16};
+use num_traits::Float;
+use simba::{
+    scalar::{ComplexField, Field, RealField, SubsetOf},
+    simd::{PrimitiveSimdValue, SimdValue},
+};
+
+// The math functions are computed in `f32` through the `num_traits::Float` implementations, so each
+// operation is rounded once to the half precision result.
+macro_rules! impl_simba {
+    ($ty:ident) => {
+        impl PrimitiveSimdValue for $ty {}
+
+        impl SimdValue for $ty {
+            const LANES: usize = 1;
+            type Element = $ty;
+            type SimdBool = bool;
+
+            #[inline(always)]
+            fn splat(val: $ty) -> $ty {
+                val
+            }
+
+            #[inline(always)]
+            fn extract(&self, _: usize) -> $ty {
+                *self
+            }
+
+            #[inline(always)]
+            unsafe fn extract_unchecked(&self, _: usize) -> $ty {
+                *self
+            }
+
+            #[inline(always)]
+            fn replace(&mut self, _: usize, val: $ty) {
+                *self = val
+            }
+
+            #[inline(always)]
+            unsafe fn replace_unchecked(&mut self, _: usize, val: $ty) {
+                *self = val
+            }
+
+            #[inline(always)]
+            fn select(self, cond: bool, other: $ty) -> $ty {
+                if cond {
+                    self
+                } else {
+                    other
+                }
+            }
+        }
+
+        impl SubsetOf<$ty> for $ty {
+            #[inline]
+            fn to_superset(&self) -> $ty {
+                *self
+            }
+
+            #[inline]
+            fn from_superset_unchecked(element: &$ty) -> $ty {
+                *element
+            }
+
+            #[inline]
+            fn is_in_subset(_: &$ty) -> bool {
+                true
+            }
+        }
+
+        impl SubsetOf<$ty> for f32 {
+            #[inline]
+            fn to_superset(&self) -> $ty {
+                $ty::from_f32(*self)
+            }
+
+            #[inline]
+            fn from_superset_unchecked(element: &$ty) -> f32 {
+                element.to_f32()
+            }
+
+            #[inline]
+            fn is_in_subset(_: &$ty) -> bool {
+                true
+            }
+        }
+
+        impl SubsetOf<$ty> for f64 {
+            #[inline]
+            fn to_superset(&self) -> $ty {
+                $ty::from_f64(*self)
+            }
+
+            #[inline]
+            fn from_superset_unchecked(element: &$ty) -> f64 {
+                element.to_f64()
+            }
+
+            #[inline]
+            fn is_in_subset(_: &$ty) -> bool {
+                true
+            }
+        }
+
+        impl Field for $ty {}
+
+        impl ComplexField for $ty {
+            type RealField = $ty;
+
+            #[inline]
+            fn from_real(re: $ty) -> $ty {
+                re
+            }
+
+            #[inline]
+            fn real(self) -> $ty {
+                self
+            }
+
+            #[inline]
+            fn imaginary(self) -> $ty {
+                $ty::ZERO
+            }
+
+            #[inline]
+            fn modulus(self) -> $ty {
+                Float::abs(self)
+            }
+
+            #[inline]
+            fn modulus_squared(self) -> $ty {
+                self * self
+            }
+
+            #[inline]
+            fn argument(self) -> $ty {
+                if self >= $ty::ZERO {
+                    $ty::ZERO
+                } else {
+                    $ty::PI
+                }
+            }
+
+            #[inline]
+            fn norm1(self) -> $ty {
+                Float::abs(self)
+            }
+
+            #[inline]
+            fn scale(self, factor: $ty) -> $ty {
+                self * factor
+            }
+
+            #[inline]
+            fn unscale(self, factor: $ty) -> $ty {
+                self / factor
+            }
+
+            #[inline]
+            fn floor(self) -> $ty {
+                Float::floor(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> $ty {
+                Float::ceil(self)
+            }
+
+            #[inline]
+            fn round(self) -> $ty {
+                Float::round(self)
+            }
+
+            #[inline]
+            fn trunc(self) -> $ty {
+                Float::trunc(self)
+            }
+
+            #[inline]
+            fn fract(self) -> $ty {
+                Float::fract(self)
+            }
+
+            #[inline]
+            fn mul_add(self, a: $ty, b: $ty) -> $ty {
+                Float::mul_add(self, a, b)
+            }
+
+            #[inline]
+            fn abs(self) -> $ty {
+                Float::abs(self)
+            }
+
+            #[inline]
+            fn hypot(self, other: $ty) -> $ty {
+                Float::hypot(self, other)
+            }
+
+            #[inline]
+            fn recip(self) -> $ty {
+                Float::recip(self)
+            }
+
+            #[inline]
+            fn conjugate(self) -> $ty {
+                self
+            }
+
+            #[inline]
+            fn sin(self) -> $ty {
+                Float::sin(self)
+            }
+
+            #[inline]
+            fn cos(self) -> $ty {
+                Float::cos(self)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> ($ty, $ty) {
+                Float::sin_cos(self)
+            }
+
+            #[inline]
+            fn tan(self) -> $ty {
+                Float::tan(self)
+            }
+
+            #[inline]
+            fn asin(self) -> $ty {
+                Float::asin(self)
+            }
+
+            #[inline]
+            fn acos(self) -> $ty {
+                Float::acos(self)
+            }
+
+            #[inline]
+            fn atan(self) -> $ty {
+                Float::atan(self)
+            }
+
+            #[inline]
+            fn sinh(self) -> $ty {
+                Float::sinh(self)
+            }
+
+            #[inline]
+            fn cosh(self) -> $ty {
+                Float::cosh(self)
+            }
+
+            #[inline]
+            fn tanh(self) -> $ty {
+                Float::tanh(self)
+            }
+
+            #[inline]
+            fn asinh(self) -> $ty {
+                Float::asinh(self)
+            }
+
+            #[inline]
+            fn acosh(self) -> $ty {
+                Float::acosh(self)
+            }
+
+            #[inline]
+            fn atanh(self) -> $ty {
+                Float::atanh(self)
+            }
+
+            #[inline]
+            fn log(self, base: $ty) -> $ty {
+                Float::log(self, base)
+            }
+
+            #[inline]
+            fn log2(self) -> $ty {
+                Float::log2(self)
+            }
+
+            #[inline]
+            fn log10(self) -> $ty {
+                Float::log10(self)
+            }
+
+            #[inline]
+            fn ln(self) -> $ty {
+                Float::ln(self)
+            }
+
+            #[inline]
+            fn ln_1p(self) -> $ty {
+                Float::ln_1p(self)
+            }
+
+            #[inline]
+            fn sqrt(self) -> $ty {
+                Float::sqrt(self)
+            }
+
+            #[inline]
+            fn exp(self) -> $ty {
+                Float::exp(self)
+            }
+
+            #[inline]
+            fn exp2(self) -> $ty {
+                Float::exp2(self)
+            }
+
+            #[inline]
+            fn exp_m1(self) -> $ty {
+                Float::exp_m1(self)
+            }
+
+            #[inline]
+            fn powi(self, n: i32) -> $ty {
+                Float::powi(self, n)
+            }
+
+            #[inline]
+            fn powf(self, n: $ty) -> $ty {
+                Float::powf(self, n)
+            }
+
+            #[inline]
+            fn powc(self, n: $ty) -> $ty {
+                Float::powf(self, n)
+            }
+
+            #[inline]
+            fn cbrt(self) -> $ty {
+                Float::cbrt(self)
+            }
+
+            #[inline]
+            fn is_finite(&self) -> bool {
+                $ty::is_finite(*self)
+            }
+
+            #[inline]
+            fn try_sqrt(self) -> Option<$ty> {
+                if self >= $ty::ZERO {
+                    Some(Float::sqrt(self))
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl RealField for $ty {
+            #[inline]
+            fn is_sign_positive(&self) -> bool {
+                $ty::is_sign_positive(*self)
+            }
+
+            #[inline]
+            fn is_sign_negative(&self) -> bool {
+                $ty::is_sign_negative(*self)
+            }
+
+            #[inline]
+            fn copysign(self, sign: $ty) -> $ty {
+                $ty::copysign(self, sign)
+            }
+
+            #[inline]
+            fn max(self, other: $ty) -> $ty {
+                $ty::max(self, other)
+            }
+
+            #[inline]
+            fn min(self, other: $ty) -> $ty {
+                $ty::min(self, other)
+            }
+
+            #[inline]
+            fn clamp(self, min: $ty, max: $ty) -> $ty {
+                if self < min {
+                    min
+                } else if self > max {
+                    max
+                } else {
+                    self
+                }
+            }
+
+            #[inline]
+            fn atan2(self, other: $ty) -> $ty {
+                Float::atan2(self, other)
+            }
+
+            #[inline]
+            fn min_value() -> Option<$ty> {
+                Some($ty::MIN)
+            }
+
+            #[inline]
+            fn max_value() -> Option<$ty> {
+                Some($ty::MAX)
+            }
+
+            #[inline]
+            fn pi() -> $ty {
+                $ty::PI
+            }
+
+            #[inline]
+            fn two_pi() -> $ty {
+                $ty::PI + $ty::PI
+            }
+
+            #[inline]
+            fn frac_pi_2() -> $ty {
+                $ty::FRAC_PI_2
+            }
+
+            #[inline]
+            fn frac_pi_3() -> $ty {
+                $ty::FRAC_PI_3
+            }
+
+            #[inline]
+            fn frac_pi_4() -> $ty {
+                $ty::FRAC_PI_4
+            }
+
+            #[inline]
+            fn frac_pi_6() -> $ty {
+                $ty::FRAC_PI_6
+            }
+
+            #[inline]
+            fn frac_pi_8() -> $ty {
+                $ty::FRAC_PI_8
+            }
+
+            #[inline]
+            fn frac_1_pi() -> $ty {
+                $ty::FRAC_1_PI
+            }
+
+            #[inline]
+            fn frac_2_pi() -> $ty {
+                $ty::FRAC_2_PI
+            }
+
+            #[inline]
+            fn frac_2_sqrt_pi() -> $ty {
+                $ty::FRAC_2_SQRT_PI
+            }
+
+            #[inline]
+            fn e() -> $ty {
+                $ty::E
+            }
+
+            #[inline]
+            fn log2_e() -> $ty {
+                $ty::LOG2_E
+            }
+
+            #[inline]
+            fn log10_e() -> $ty {
+                $ty::LOG10_E
+            }
+
+            #[inline]
+            fn ln_2() -> $ty {
+                $ty::LN_2
+            }
+
+            #[inline]
+            fn ln_10() -> $ty {
+                $ty::LN_10
+            }
+        }
+    };
+}
+
+impl_simba!(f16);
+impl_simba!(bf16);
+
+#[cfg(test)]
+mod test {
+    use crate::{bf16, f16};
+    use approx::assert_relative_eq;
+    use na::{Matrix2, Rotation2, Vector2, Vector3};
+    use simba::scalar::{ComplexField, RealField};
+
+    #[test]
+    fn real_field() {
+        let x = f16::from_f32(2.);
+        assert_eq!(ComplexField::sqrt(x), f16::SQRT_2);
+        assert_eq!(x.try_sqrt(), Some(f16::SQRT_2));
+        assert_eq!((-x).try_sqrt(), None);
+        assert_eq!(RealField::atan2(f16::ONE, f16::ZERO), f16::FRAC_PI_2);
+        assert_eq!(
+            <bf16 as RealField>::two_pi(),
+            bf16::from_f32(core::f32::consts::TAU)
+        );
+        assert_eq!(ComplexField::argument(-x), f16::PI);
+        assert_eq!(na::convert::<f64, f16>(0.1), f16::from_f64(0.1));
+        assert_eq!(na::try_convert::<f16, f32>(x), Some(2.));
+    }
+
+    #[test]
+    fn matrices() {
+        let m = Matrix2::new(
+            f16::from_f32(4.),
+            f16::from_f32(7.),
+            f16::from_f32(2.),
+            f16::from_f32(6.),
+        );
+        let inv = m.try_inverse().unwrap();
+        assert_relative_eq!(m * inv, Matrix2::identity(), epsilon = f16::from_f32(0.01));
+
+        let v = Vector3::new(bf16::from_f32(3.), bf16::from_f32(4.), bf16::ZERO);
+        assert_eq!(v.norm(), bf16::from_f32(5.));
+        assert_eq!(v.dot(&v), bf16::from_f32(25.));
+
+        let rotation = Rotation2::new(f16::FRAC_PI_2);
+        let rotated = rotation * Vector2::new(f16::ONE, f16::ZERO);
+        assert_relative_eq!(
+            rotated,
+            Vector2::new(f16::ZERO, f16::ONE),
+            epsilon = f16::from_f32(0.001)
+        );
+    }
+}
@@ -0,0 +1,394 @@
+//! Contains vectors of [`f16`] values built on the unstable portable SIMD types of [`core::simd`].
+//!
+//! [`f16x4`], [`f16x8`] and [`f16x16`] store their lanes as a [`Simd<u16, N>`][Simd], so they take
+//! half the space of the [`f32`] vectors of the same length and data can stay in half precision
+//! between operations. Conversions to and from [`Simd<f32, N>`][Simd] are computed lane-wise with
+//! vector instructions, with the same results as [`f16::to_f32`] and [`f16::from_f32`] on each
+//! lane. Arithmetic widens both operands to [`f32`], where the operation is exact enough that
+//! rounding the result back gives the correctly rounded half precision result.
+//!
+//! This module is only available with the `portable-simd` feature, which requires nightly Rust.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use half::prelude::*;
+//! use half::simd::f16x4;
+//!
+//! let a = f16x4::from_array([1., 2., 3., 4.].map(f16::from_f32));
+//! let b = f16x4::splat(f16::from_f32(0.5));
+//! let c = a * b + b;
+//! assert_eq!(c.to_array(), [1., 1.5, 2., 2.5].map(f16::from_f32));
+//! ```
+//!
+//! [`f16::to_f32`]: crate::f16::to_f32
+//! [`f16::from_f32`]: crate::f16::from_f32
+
+use crate::f16;
+use core::{
+    fmt::{Debug, Formatter},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+    simd::{prelude::*, Mask},
+};
+
+macro_rules! impl_f16xn {
+    ($name:ident, $lanes:literal) => {
+        #[doc = concat!("A vector of ", stringify!($lanes), " [`f16`] values.")]
+        ///
+        /// See the [module documentation](self) for details.
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Default)]
+        #[repr(transparent)]
+        pub struct $name(Simd<u16, $lanes>);
+
+        impl $name {
+            /// Number of lanes in the vector.
+            pub const LANES: usize = $lanes;
+
+            /// Constructs a vector with every lane set to `value`.
+            #[inline]
+            #[must_use]
+            pub fn splat(value: f16) -> Self {
+                Self(Simd::splat(value.to_bits()))
+            }
+
+            /// Constructs a vector from an array of values.
+            #[inline]
+            #[must_use]
+            pub fn from_array(values: [f16; $lanes]) -> Self {
+                Self(Simd::from_array(values.map(f16::to_bits)))
+            }
+
+            /// Returns the lanes of the vector as an array.
+            #[inline]
+            #[must_use]
+            pub fn to_array(self) -> [f16; $lanes] {
+                self.0.to_array().map(f16::from_bits)
+            }
+
+            /// Constructs a vector from the raw bits of each lane.
+            #[inline]
+            #[must_use]
+            pub const fn from_bits(bits: Simd<u16, $lanes>) -> Self {
+                Self(bits)
+            }
+
+            /// Returns the raw bits of each lane.
+            #[inline]
+            #[must_use]
+            pub const fn to_bits(self) -> Simd<u16, $lanes> {
+                self.0
+            }
+
+            /// Converts each lane of a [`f32`] vector to the nearest [`f16`] value.
+            #[inline]
+            #[must_use]
+            pub fn from_f32(values: Simd<f32, $lanes>) -> Self {
+                Self(F32Bits::f32_bits_to_f16(values.to_bits()).cast())
+            }
+
+            /// Converts each lane to [`f32`]. The conversion is lossless.
+            #[inline]
+            #[must_use]
+            pub fn to_f32(self) -> Simd<f32, $lanes> {
+                Simd::from_bits(F32Bits::f16_bits_to_f32(self.0.cast::<u32>()))
+            }
+
+            /// Returns the absolute value of each lane.
+            #[inline]
+            #[must_use]
+            pub fn abs(self) -> Self {
+                Self(self.0 & Simd::splat(0x7FFF))
+            }
+
+            /// Returns the lane-wise minimum of two vectors, ignoring NaN like [`f16::min`].
+            #[inline]
+            #[must_use]
+            pub fn simd_min(self, other: Self) -> Self {
+                Self::from_f32(self.to_f32().simd_min(other.to_f32()))
+            }
+
+            /// Returns the lane-wise maximum of two vectors, ignoring NaN like [`f16::max`].
+            #[inline]
+            #[must_use]
+            pub fn simd_max(self, other: Self) -> Self {
+                Self::from_f32(self.to_f32().simd_max(other.to_f32()))
+            }
+
+            /// Returns a mask of the lanes where `self` is equal to `other`.
+            #[inline]
+            #[must_use]
+            pub fn simd_eq(self, other: Self) -> Mask<i32, $lanes> {
+                self.to_f32().simd_eq(other.to_f32())
+            }
+
+            /// Returns a mask of the lanes where `self` is not equal to `other`, including NaNs.
+            #[inline]
+            #[must_use]
+            pub fn simd_ne(self, other: Self) -> Mask<i32, $lanes> {
+                self.to_f32().simd_ne(other.to_f32())
+            }
+
+            /// Returns a mask of the lanes where `self` is less than `other`.
+            #[inline]
+            #[must_use]
+            pub fn simd_lt(self, other: Self) -> Mask<i32, $lanes> {
+                self.to_f32().simd_lt(other.to_f32())
+            }
+
+            /// Returns a mask of the lanes where `self` is less than or equal to `other`.
+            #[inline]
+            #[must_use]
+            pub fn simd_le(self, other: Self) -> Mask<i32, $lanes> {
+                self.to_f32().simd_le(other.to_f32())
+            }
+
+            /// Returns a mask of the lanes where `self` is greater than `other`.
+            #[inline]
+            #[must_use]
+            pub fn simd_gt(self, other: Self) -> Mask<i32, $lanes> {
+                self.to_f32().simd_gt(other.to_f32())
+            }
+
+            /// Returns a mask of the lanes where `self` is greater than or equal to `other`.
+            #[inline]
+            #[must_use]
+            pub fn simd_ge(self, other: Self) -> Mask<i32, $lanes> {
+                self.to_f32().simd_ge(other.to_f32())
+            }
+
+            /// Returns a mask of the lanes that are NaN.
+            #[inline]
+            #[must_use]
+            pub fn is_nan(self) -> Mask<i16, $lanes> {
+                (self.0 & Simd::splat(0x7FFF)).simd_gt(Simd::splat(0x7C00))
+            }
+        }
+
+        impl Debug for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Debug::fmt(&self.to_array(), f)
+            }
+        }
+
+        impl From<[f16; $lanes]> for $name {
+            #[inline]
+            fn from(values: [f16; $lanes]) -> Self {
+                Self::from_array(values)
+            }
+        }
+
+        impl From<$name> for [f16; $lanes] {
+            #[inline]
+            fn from(vector: $name) -> Self {
+                vector.to_array()
+            }
+        }
+
+        impl From<Simd<f32, $lanes>> for $name {
+            #[inline]
+            fn from(values: Simd<f32, $lanes>) -> Self {
+                Self::from_f32(values)
+            }
+        }
+
+        impl From<$name> for Simd<f32, $lanes> {
+            #[inline]
+            fn from(vector: $name) -> Self {
+                vector.to_f32()
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self(self.0 ^ Simd::splat(0x8000))
+            }
+        }
+
+        impl_binary_op!($name, Add, add, AddAssign, add_assign, +);
+        impl_binary_op!($name, Sub, sub, SubAssign, sub_assign, -);
+        impl_binary_op!($name, Mul, mul, MulAssign, mul_assign, *);
+        impl_binary_op!($name, Div, div, DivAssign, div_assign, /);
+        impl_binary_op!($name, Rem, rem, RemAssign, rem_assign, %);
+    };
+}
+
+macro_rules! impl_binary_op {
+    ($name:ident, $trait:ident, $method:ident, $assign:ident, $assign_method:ident, $op:tt) => {
+        impl $trait for $name {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self {
+                Self::from_f32(self.to_f32() $op rhs.to_f32())
+            }
+        }
+
+        impl $assign for $name {
+            #[inline]
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+impl_f16xn!(f16x4, 4);
+impl_f16xn!(f16x8, 8);
+impl_f16xn!(f16x16, 16);
+
+// Lane-wise conversions between the bits of f16 and f32 values, with every case computed for all
+// lanes and the results selected with masks. They are implemented for each lane count by a macro
+// rather than generically, as the bound needed on the lane count has changed across nightly
+// versions.
+
+trait F32Bits {
+    /// Converts `f32` bits to the bits of the nearest `f16`, rounding to nearest with ties to
+    /// even, in the low 16 bits of each lane.
+    fn f32_bits_to_f16(self) -> Self;
+
+    /// Converts `f16` bits in the low 16 bits of each lane to `f32` bits.
+    fn f16_bits_to_f32(self) -> Self;
+}
+
+macro_rules! impl_f32_bits {
+    ($lanes:literal) => {
+        impl F32Bits for Simd<u32, $lanes> {
+            #[inline]
+            fn f32_bits_to_f16(self) -> Self {
+                let splat = Simd::<u32, $lanes>::splat;
+                let sign = (self & splat(0x8000_0000)) >> splat(16);
+                let abs = self & splat(0x7FFF_FFFF);
+
+                // Infinity, or NaN with its quiet bit set and the top of its payload kept
+                let nan = splat(0x7E00) | ((abs >> splat(13)) & splat(0x03FF));
+                let special = abs.simd_gt(splat(0x7F80_0000)).select(nan, splat(0x7C00));
+
+                // Adding a magic number places the rounded subnormal mantissa in the low bits
+                let magic = splat(((127 - 15) + (23 - 10) + 1) << 23);
+                let subnormal = (Simd::<f32, $lanes>::from_bits(abs)
+                    + Simd::<f32, $lanes>::from_bits(magic))
+                .to_bits()
+                    - magic;
+
+                // Rebias the exponent and round the mantissa to nearest, ties to even
+                let odd = (abs >> splat(13)) & splat(1);
+                let normal = (abs + splat((((15 - 127) << 23) + 0xFFF) as u32) + odd) >> splat(13);
+
+                let finite = abs.simd_lt(splat(113 << 23)).select(subnormal, normal);
+                let bits = abs.simd_ge(splat((127 + 16) << 23)).select(special, finite);
+                bits | sign
+            }
+
+            #[inline]
+            fn f16_bits_to_f32(self) -> Self {
+                let splat = Simd::<u32, $lanes>::splat;
+                let sign = (self & splat(0x8000)) << splat(16);
+                let shifted = (self & splat(0x7FFF)) << splat(13);
+                let exp = shifted & splat(0x7C00 << 13);
+                let rebiased = shifted + splat((127 - 15) << 23);
+
+                // Infinity and NaN keep the maximum exponent, and NaNs become quiet
+                let quiet = (shifted & splat(0x03FF << 13))
+                    .simd_ne(splat(0))
+                    .select(splat(0x0040_0000), splat(0));
+                let special = rebiased + splat((128 - 16) << 23) | quiet;
+
+                // Zero and subnormals are normalized by subtracting the implicit bit in f32
+                let magic = Simd::<f32, $lanes>::from_bits(splat(113 << 23));
+                let subnormal =
+                    (Simd::<f32, $lanes>::from_bits(rebiased + splat(1 << 23)) - magic).to_bits();
+
+                let bits = exp.simd_eq(splat(0x7C00 << 13)).select(special, rebiased);
+                let bits = exp.simd_eq(splat(0)).select(subnormal, bits);
+                bits | sign
+            }
+        }
+    };
+}
+
+impl_f32_bits!(4);
+impl_f32_bits!(8);
+impl_f32_bits!(16);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn conversions_match_scalar() {
+        for bits in (0..=u16::MAX).step_by(4) {
+            let lanes = [bits, bits | 1, bits | 2, bits | 3].map(f16::from_bits);
+            let vector = f16x4::from_array(lanes);
+            let widened = vector.to_f32().to_array();
+            for (x, y) in lanes.iter().zip(widened) {
+                assert_eq!(x.to_f32().to_bits(), y.to_bits(), "{:#06X}", x.to_bits());
+            }
+            assert_eq!(
+                f16x4::from_f32(vector.to_f32()).to_bits(),
+                vector
+                    .to_f32()
+                    .to_array()
+                    .map(|x| f16::from_f32(x).to_bits())
+                    .into()
+            );
+        }
+
+        // Every rounding case, including ties, subnormals, overflow and NaN payloads
+        let mut x = 0u32;
+        loop {
+            let lanes = [x, x + 0x1000, x + 0x2000, x + 0x0FFF].map(f32::from_bits);
+            let halves = f16x4::from_f32(Simd::from_array(lanes)).to_array();
+            for (x, h) in lanes.iter().zip(halves) {
+                assert_eq!(
+                    f16::from_f32(*x).to_bits(),
+                    h.to_bits(),
+                    "{:#010X}",
+                    x.to_bits()
+                );
+            }
+            x = match x.checked_add(0x1_0001) {
+                Some(next) if next < 0xFFFF_0000 => next,
+                _ => break,
+            };
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = f16x8::from_array([1., 2., 3., 4., -5., 6., 65504., 0.1].map(f16::from_f32));
+        let b = f16x8::splat(f16::from_f32(3.));
+        let expected = |op: fn(f16, f16) -> f16| {
+            let (a, b) = (a.to_array(), b.to_array());
+            core::array::from_fn::<_, 8, _>(|i| op(a[i], b[i]).to_bits())
+        };
+        assert_eq!((a + b).to_bits().to_array(), expected(|x, y| x + y));
+        assert_eq!((a - b).to_bits().to_array(), expected(|x, y| x - y));
+        assert_eq!((a * b).to_bits().to_array(), expected(|x, y| x * y));
+        assert_eq!((a / b).to_bits().to_array(), expected(|x, y| x / y));
+        assert_eq!((a % b).to_bits().to_array(), expected(|x, y| x % y));
+        assert_eq!((-a).to_array(), a.to_array().map(|x| -x));
+
+        let mut c = f16x16::splat(f16::ONE);
+        c += f16x16::splat(f16::ONE);
+        c *= c;
+        assert_eq!(c.to_array(), [f16::from_f32(4.); 16]);
+    }
+
+    #[test]
+    fn comparisons() {
+        let a = f16x4::from_array([f16::ONE, f16::NAN, f16::ZERO, f16::NEG_INFINITY]);
+        let b = f16x4::from_array([f16::ONE, f16::NAN, f16::NEG_ZERO, f16::MIN]);
+        assert_eq!(a.simd_eq(b).to_array(), [true, false, true, false]);
+        assert_eq!(a.simd_ne(b).to_array(), [false, true, false, true]);
+        assert_eq!(a.simd_lt(b).to_array(), [false, false, false, true]);
+        assert_eq!(a.simd_ge(b).to_array(), [true, false, true, false]);
+        assert_eq!(a.is_nan().to_array(), [false, true, false, false]);
+        let max = a.simd_max(b).to_array();
+        assert!(max[1].is_nan());
+        assert_eq!([max[0], max[2], max[3]], [f16::ONE, f16::ZERO, f16::MIN]);
+    }
+}
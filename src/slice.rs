@@ -5,17 +5,39 @@
 //! while the utility [`HalfFloatSliceExt`] sealed extension trait is implemented for both `[f16]`
 //! and `[bf16]` slices. These traits provide efficient conversions and reinterpret casting of
 //! larger buffers of floating point values, and are automatically included in the
-//! [`prelude`][crate::prelude] module.
+//! [`prelude`][crate::prelude] module. The [`HalfUninitSliceExt`] sealed extension trait is
+//! implemented for `[MaybeUninit<f16>]` and `[MaybeUninit<bf16>]` slices to convert into
+//! uninitialized buffers.
 
-use crate::{bf16, binary16::convert, f16};
+use crate::{
+    bf16, bfloat::convert as bf16_convert, binary16::convert, f16, flush, FlushMode, RoundingMode,
+};
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
-use core::slice;
+use core::{mem::MaybeUninit, slice};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Number of elements each thread converts at a time in the parallel conversions, large enough
+/// that the per-task overhead is negligible next to the vectorized conversion.
+#[cfg(feature = "rayon")]
+const PAR_CHUNK_LEN: usize = 1 << 14;
+
+/// Number of elements the scaled conversions and reductions process at a time, small enough that
+/// each chunk stays in the L1 cache between the conversion and the arithmetic.
+const CHUNK_LEN: usize = 256;
+
+/// Number of independent accumulators in the reductions, enough to fill a 512-bit vector of
+/// [`f32`] values.
+const SUM_LANES: usize = 16;
 
 /// Extensions to `[f16]` and `[bf16]` slices to support conversion and reinterpret operations.
 ///
 /// This trait is sealed and cannot be implemented outside of this crate.
 pub trait HalfFloatSliceExt: private::SealedHalfFloatSlice {
+    /// The [`f16`] or [`bf16`] element type of the slice.
+    type Half;
+
     /// Reinterprets a slice of [`f16`] or [`bf16`] numbers as a slice of [`u16`] bits.
     ///
     /// This is a zero-copy operation. The reinterpreted slice has the same lifetime and memory
@@ -60,6 +82,125 @@ pub trait HalfFloatSliceExt: private::SealedHalfFloatSlice {
     #[must_use]
     fn reinterpret_cast_mut(&mut self) -> &mut [u16];
 
+    /// Reinterprets a slice of [`f16`] or [`bf16`] numbers as a slice of bytes in native byte
+    /// order.
+    ///
+    /// This is a zero-copy operation. Use [`copy_to_le_bytes`][Self::copy_to_le_bytes] or
+    /// [`copy_to_be_bytes`][Self::copy_to_be_bytes] to write bytes in a fixed byte order instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let float_buffer = [f16::from_bits(0x3C00), f16::from_bits(0xC000)];
+    /// let bytes = float_buffer.as_bytes();
+    ///
+    /// assert_eq!(bytes.len(), 4);
+    /// if cfg!(target_endian = "little") {
+    ///     assert_eq!(bytes, [0x00, 0x3C, 0x00, 0xC0]);
+    /// }
+    /// ```
+    #[must_use]
+    fn as_bytes(&self) -> &[u8];
+
+    /// Reinterprets a mutable slice of [`f16`] or [`bf16`] numbers as a mutable slice of bytes in
+    /// native byte order.
+    ///
+    /// This is a zero-copy operation, useful to fill a buffer directly from APIs that write raw
+    /// bytes, such as reads from a file or copies from GPU memory. Any bytes written form valid
+    /// values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut float_buffer = [bf16::ZERO; 2];
+    /// let bytes = float_buffer.as_bytes_mut();
+    /// bytes.copy_from_slice(&bf16::ONE.to_ne_bytes().repeat(2));
+    ///
+    /// assert_eq!(float_buffer, [bf16::ONE; 2]);
+    /// ```
+    #[must_use]
+    fn as_bytes_mut(&mut self) -> &mut [u8];
+
+    /// Copies little-endian bytes from `src` into the [`f16`] or [`bf16`] elements of `self`.
+    ///
+    /// The length of `src` must be twice the length of `self`. On big-endian targets, the bytes of
+    /// each element are swapped while copying.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `src` is not twice as long as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut buffer = [f16::ZERO; 2];
+    /// buffer.copy_from_le_bytes(&[0x00, 0x3C, 0x00, 0xC0]);
+    ///
+    /// assert_eq!(buffer, [f16::from_f32(1.), f16::from_f32(-2.)]);
+    /// ```
+    fn copy_from_le_bytes(&mut self, src: &[u8]);
+
+    /// Copies big-endian bytes from `src` into the [`f16`] or [`bf16`] elements of `self`.
+    ///
+    /// The length of `src` must be twice the length of `self`. On little-endian targets, the bytes
+    /// of each element are swapped while copying.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `src` is not twice as long as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut buffer = [bf16::ZERO; 2];
+    /// buffer.copy_from_be_bytes(&[0x3F, 0x80, 0xC0, 0x00]);
+    ///
+    /// assert_eq!(buffer, [bf16::from_f32(1.), bf16::from_f32(-2.)]);
+    /// ```
+    fn copy_from_be_bytes(&mut self, src: &[u8]);
+
+    /// Copies the [`f16`] or [`bf16`] elements of `self` into `dst` as little-endian bytes.
+    ///
+    /// The length of `dst` must be twice the length of `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `dst` is not twice as long as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut bytes = [0u8; 4];
+    /// [f16::from_f32(1.), f16::from_f32(-2.)].copy_to_le_bytes(&mut bytes);
+    ///
+    /// assert_eq!(bytes, [0x00, 0x3C, 0x00, 0xC0]);
+    /// ```
+    fn copy_to_le_bytes(&self, dst: &mut [u8]);
+
+    /// Copies the [`f16`] or [`bf16`] elements of `self` into `dst` as big-endian bytes.
+    ///
+    /// The length of `dst` must be twice the length of `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `dst` is not twice as long as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut bytes = [0u8; 4];
+    /// [bf16::from_f32(1.), bf16::from_f32(-2.)].copy_to_be_bytes(&mut bytes);
+    ///
+    /// assert_eq!(bytes, [0x3F, 0x80, 0xC0, 0x00]);
+    /// ```
+    fn copy_to_be_bytes(&self, dst: &mut [u8]);
+
     /// Converts all of the elements of a `[f32]` slice into [`f16`] or [`bf16`] values in `self`.
     ///
     /// The length of `src` must be the same as `self`.
@@ -118,6 +259,59 @@ pub trait HalfFloatSliceExt: private::SealedHalfFloatSlice {
     /// ```
     fn convert_from_f64_slice(&mut self, src: &[f64]);
 
+    /// Converts all of the elements of a `[f32]` slice into [`f16`] or [`bf16`] values in `self`
+    /// using stochastic rounding.
+    ///
+    /// The length of `src` must be the same as `self`. `rng` is called once per element for the
+    /// random bits to round with; see
+    /// [`f16::from_f32_stochastic`][crate::f16::from_f32_stochastic] and
+    /// [`bf16::from_f32_stochastic`] for details.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut buffer = [f16::ZERO; 4];
+    /// let float_values = [1., 2., 3., 4.];
+    ///
+    /// // Any source of random `u16` values will do, this is just a simple LCG
+    /// let mut state = 12345u32;
+    /// buffer.convert_from_f32_slice_stochastic(&float_values, || {
+    ///     state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+    ///     (state >> 16) as u16
+    /// });
+    ///
+    /// // Exactly representable values are unaffected
+    /// assert_eq!(buffer, [f16::from_f32(1.), f16::from_f32(2.), f16::from_f32(3.), f16::from_f32(4.)]);
+    /// ```
+    fn convert_from_f32_slice_stochastic<R: FnMut() -> u16>(&mut self, src: &[f32], rng: R);
+
+    /// Converts all of the elements of a `[f32]` slice into [`f16`] or [`bf16`] values in `self`,
+    /// rounding toward zero.
+    ///
+    /// The length of `src` must be the same as `self`. For [`bf16`] this simply truncates the low
+    /// bits of each value, see [`bf16::from_f32_truncate`], which is faster than
+    /// [`convert_from_f32_slice`][Self::convert_from_f32_slice] when no hardware conversion is
+    /// available. For [`f16`] it is the same as
+    /// [`f16::from_f32_round`][crate::f16::from_f32_round] with [`RoundingMode::TowardZero`].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut buffer = [bf16::ZERO; 2];
+    /// buffer.convert_from_f32_slice_truncate(&[1.0, f32::from_bits(0x3F80_FFFF)]);
+    ///
+    /// assert_eq!(buffer, [bf16::ONE, bf16::ONE]);
+    /// ```
+    fn convert_from_f32_slice_truncate(&mut self, src: &[f32]);
+
     /// Converts all of the [`f16`] or [`bf16`] elements of `self` into [`f32`] values in `dst`.
     ///
     /// The length of `src` must be the same as `self`.
@@ -174,134 +368,1277 @@ pub trait HalfFloatSliceExt: private::SealedHalfFloatSlice {
     /// ```
     fn convert_to_f64_slice(&self, dst: &mut [f64]);
 
-    // Because trait is sealed, we can get away with different interfaces between features.
-
-    /// Converts all of the [`f16`] or [`bf16`] elements of `self` into [`f32`] values in a new
-    /// vector
+    /// Converts all of the [`f16`] or [`bf16`] elements of `self` into [`f16`] values in `dst`.
     ///
-    /// The conversion operation is vectorized over the slice, meaning the conversion may be more
-    /// efficient than converting individual elements on some hardware that supports SIMD
-    /// conversions. See [crate documentation](crate) for more information on hardware conversion
-    /// support.
+    /// Each element is rounded to nearest like [`f16::from_bf16`][crate::f16::from_bf16], so
+    /// [`bf16`] values outside the range of [`f16`] become ±∞ and the smallest become subnormal
+    /// or ±0. The conversion goes through [`f32`] in small chunks with the vectorized conversions
+    /// of both formats, which is exact on the way in and rounds only once on the way out. For a
+    /// `[f16]` slice this is a plain copy.
     ///
-    /// This method is only available with the `std` or `alloc` feature.
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
     ///
     /// # Examples
     /// ```rust
     /// # use half::prelude::*;
-    /// let half_values = [f16::from_f32(1.), f16::from_f32(2.), f16::from_f32(3.), f16::from_f32(4.)];
-    /// let vec = half_values.to_f32_vec();
+    /// let bfloats = [1.5, -0.25, 1e10].map(bf16::from_f32);
+    /// let mut halves = [f16::ZERO; 3];
+    /// bfloats.convert_to_f16_slice(&mut halves);
     ///
-    /// assert_eq!(vec, vec![1., 2., 3., 4.]);
+    /// assert_eq!(halves, [f16::from_f32(1.5), f16::from_f32(-0.25), f16::INFINITY]);
     /// ```
-    #[cfg(any(feature = "alloc", feature = "std"))]
-    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-    #[must_use]
-    fn to_f32_vec(&self) -> Vec<f32>;
+    fn convert_to_f16_slice(&self, dst: &mut [f16]);
 
-    /// Converts all of the [`f16`] or [`bf16`] elements of `self` into [`f64`] values in a new
-    /// vector.
+    /// Converts all of the [`f16`] or [`bf16`] elements of `self` into [`bf16`] values in `dst`.
     ///
-    /// The conversion operation is vectorized over the slice, meaning the conversion may be more
-    /// efficient than converting individual elements on some hardware that supports SIMD
-    /// conversions. See [crate documentation](crate) for more information on hardware conversion
-    /// support.
+    /// Each element is rounded to nearest like [`bf16::from_f16`], which only loses precision
+    /// since every [`f16`] value is within the range of [`bf16`]. As with
+    /// [`convert_to_f16_slice`][Self::convert_to_f16_slice], the conversion goes through [`f32`]
+    /// with the vectorized conversions. For a `[bf16]` slice this is a plain copy.
     ///
-    /// This method is only available with the `std` or `alloc` feature.
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
     ///
     /// # Examples
     /// ```rust
     /// # use half::prelude::*;
-    /// let half_values = [f16::from_f64(1.), f16::from_f64(2.), f16::from_f64(3.), f16::from_f64(4.)];
-    /// let vec = half_values.to_f64_vec();
+    /// let halves = [1.5, -0.25, 65504.].map(f16::from_f32);
+    /// let mut bfloats = [bf16::ZERO; 3];
+    /// halves.convert_to_bf16_slice(&mut bfloats);
     ///
-    /// assert_eq!(vec, vec![1., 2., 3., 4.]);
+    /// assert_eq!(bfloats, [1.5, -0.25, 65536.].map(bf16::from_f32));
     /// ```
-    #[cfg(feature = "alloc")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-    #[must_use]
-    fn to_f64_vec(&self) -> Vec<f64>;
-}
+    fn convert_to_bf16_slice(&self, dst: &mut [bf16]);
 
-/// Extensions to `[u16]` slices to support reinterpret operations.
-///
-/// This trait is sealed and cannot be implemented outside of this crate.
-pub trait HalfBitsSliceExt: private::SealedHalfBitsSlice {
-    /// Reinterprets a slice of [`u16`] bits as a slice of [`f16`] or [`bf16`] numbers.
+    /// Converts all of the [`f16`] or [`bf16`] elements of `self` into [`f32`] values in the
+    /// uninitialized buffer `dst`, returning it as an initialized slice.
     ///
-    /// `H` is the type to cast to, and must be either the [`f16`] or [`bf16`] type.
+    /// This is the same as [`convert_to_f32_slice`][Self::convert_to_f32_slice], but avoids
+    /// having to initialize large output buffers before they are overwritten.
     ///
-    /// This is a zero-copy operation. The reinterpreted slice has the same lifetime and memory
-    /// location as `self`.
+    /// # Panics
     ///
-    /// # Examples
+    /// This function will panic if the two slices have different lengths.
     ///
+    /// # Examples
     /// ```rust
     /// # use half::prelude::*;
-    /// let int_buffer = [f16::from_f32(1.).to_bits(), f16::from_f32(2.).to_bits(), f16::from_f32(3.).to_bits()];
-    /// let float_buffer: &[f16] = int_buffer.reinterpret_cast();
+    /// use std::mem::MaybeUninit;
     ///
-    /// assert_eq!(float_buffer, [f16::from_f32(1.), f16::from_f32(2.), f16::from_f32(3.)]);
+    /// let half_values = [f16::from_f32(1.), f16::from_f32(2.), f16::from_f32(3.)];
+    /// let mut buffer = [MaybeUninit::<f32>::uninit(); 3];
     ///
-    /// // You may have to specify the cast type directly if the compiler can't infer the type.
-    /// // The following is also valid in Rust.
-    /// let typed_buffer = int_buffer.reinterpret_cast::<f16>();
+    /// let floats = half_values.convert_to_f32_slice_uninit(&mut buffer);
+    /// assert_eq!(floats, [1., 2., 3.]);
     /// ```
-    #[must_use]
-    fn reinterpret_cast<H>(&self) -> &[H]
-    where
-        H: crate::private::SealedHalf;
+    fn convert_to_f32_slice_uninit<'a>(&self, dst: &'a mut [MaybeUninit<f32>]) -> &'a mut [f32];
 
-    /// Reinterprets a mutable slice of [`u16`] bits as a mutable slice of [`f16`] or [`bf16`]
-    /// numbers.
+    /// Converts all of the [`f16`] or [`bf16`] elements of `self` into [`f64`] values in the
+    /// uninitialized buffer `dst`, returning it as an initialized slice.
     ///
-    /// `H` is the type to cast to, and must be either the [`f16`] or [`bf16`] type.
+    /// See [`convert_to_f32_slice_uninit`][Self::convert_to_f32_slice_uninit].
     ///
-    /// This is a zero-copy operation. The transmuted slice has the same lifetime as the original,
-    /// which prevents mutating `self` as long as the returned `&mut [f16]` is borrowed.
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    fn convert_to_f64_slice_uninit<'a>(&self, dst: &'a mut [MaybeUninit<f64>]) -> &'a mut [f64];
+
+    /// Converts a `width` × `height` region of a row-pitched `[f32]` buffer into a region of
+    /// [`f16`] or [`bf16`] values in `self`.
+    ///
+    /// Row `y` of the region starts at element `y * src_pitch` of `src` and at element
+    /// `y * pitch` of `self`, so a sub-rectangle of a larger image starting at column `x0` and row
+    /// `y0` can be selected by slicing from `y0 * pitch + x0`. Elements outside the region, such as
+    /// row padding, are left untouched. Each row is converted with the same vectorized conversion
+    /// as [`convert_from_f32_slice`][Self::convert_from_f32_slice], without copying rows into a
+    /// temporary buffer.
+    ///
+    /// Both pitches are in elements, not bytes.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if either pitch is smaller than `width`, or if either slice is too
+    /// short to contain the region.
     ///
     /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// // A 4×3 image with a pitch of 5
+    /// let image = [
+    ///     0., 1., 2., 3., -1.,
+    ///     4., 5., 6., 7., -1.,
+    ///     8., 9., 10., 11., -1.,
+    /// ];
+    ///
+    /// // Convert the bottom-right 2×2 corner into a tightly packed buffer
+    /// let mut buffer = [f16::ZERO; 4];
+    /// buffer.convert_from_f32_rect(2, &image[1 * 5 + 2..], 5, 2, 2);
+    ///
+    /// assert_eq!(buffer, [6., 7., 10., 11.].map(f16::from_f32));
+    /// ```
+    fn convert_from_f32_rect(
+        &mut self,
+        pitch: usize,
+        src: &[f32],
+        src_pitch: usize,
+        width: usize,
+        height: usize,
+    );
+
+    /// Converts a `width` × `height` region of [`f16`] or [`bf16`] values in `self` into a region
+    /// of a row-pitched `[f32]` buffer.
+    ///
+    /// This is the inverse of [`convert_from_f32_rect`][Self::convert_from_f32_rect]: row `y` of
+    /// the region starts at element `y * pitch` of `self` and at element `y * dst_pitch` of `dst`,
+    /// and elements of `dst` outside the region are left untouched.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if either pitch is smaller than `width`, or if either slice is too
+    /// short to contain the region.
     ///
+    /// # Examples
     /// ```rust
     /// # use half::prelude::*;
-    /// let mut int_buffer = [f16::from_f32(1.).to_bits(), f16::from_f32(2.).to_bits(), f16::from_f32(3.).to_bits()];
+    /// let texture = [1., 2., 3., 4.].map(bf16::from_f32);
     ///
-    /// {
-    ///     let float_buffer: &mut [f16] = int_buffer.reinterpret_cast_mut();
+    /// // Write the 2×2 texture into the top-left corner of a 3×3 image
+    /// let mut image = [0f32; 9];
+    /// texture.convert_to_f32_rect(2, &mut image, 3, 2, 2);
     ///
-    ///     assert_eq!(float_buffer, [f16::from_f32(1.), f16::from_f32(2.), f16::from_f32(3.)]);
+    /// assert_eq!(image, [1., 2., 0., 3., 4., 0., 0., 0., 0.]);
+    /// ```
+    fn convert_to_f32_rect(
+        &self,
+        pitch: usize,
+        dst: &mut [f32],
+        dst_pitch: usize,
+        width: usize,
+        height: usize,
+    );
+
+    /// Converts all of the elements of a `[f32]` slice into [`f16`] or [`bf16`] values in `self`
+    /// after applying the affine transform `x * scale + bias` to each of them.
+    ///
+    /// The transform is computed in [`f32`] without a fused multiply-add, so the result is the
+    /// same as transforming `src` first and then calling
+    /// [`convert_from_f32_slice`][Self::convert_from_f32_slice], but `src` is only read once
+    /// rather than written to an intermediate buffer and read again.
     ///
-    ///     // Mutating the f16 slice will mutating the original
-    ///     float_buffer[0] = f16::from_f32(0.);
-    /// }
+    /// The length of `src` must be the same as `self`.
     ///
-    /// // Note that we need to drop float_buffer before using int_buffer again or we will get a borrow error.
-    /// assert_eq!(int_buffer, [f16::from_f32(0.).to_bits(), f16::from_f32(2.).to_bits(), f16::from_f32(3.).to_bits()]);
+    /// # Panics
     ///
-    /// // You may have to specify the cast type directly if the compiler can't infer the type.
-    /// // The following is also valid in Rust.
-    /// let typed_buffer = int_buffer.reinterpret_cast_mut::<f16>();
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// // Normalize 8-bit pixel values into the [-1, 1] range
+    /// let pixels = [0., 51., 127.5, 255.];
+    /// let mut buffer = [f16::ZERO; 4];
+    /// buffer.convert_from_f32_slice_scaled(&pixels, 2. / 255., -1.);
+    ///
+    /// assert_eq!(buffer, [-1., -0.6, 0., 1.].map(f16::from_f32));
     /// ```
-    #[must_use]
-    fn reinterpret_cast_mut<H>(&mut self) -> &mut [H]
-    where
-        H: crate::private::SealedHalf;
-}
+    fn convert_from_f32_slice_scaled(&mut self, src: &[f32], scale: f32, bias: f32);
 
-mod private {
-    use crate::{bf16, f16};
+    /// Converts all of the [`f16`] or [`bf16`] elements of `self` into [`f32`] values in `dst`,
+    /// applying the affine transform `x * scale + bias` to each converted value.
+    ///
+    /// As with [`convert_from_f32_slice_scaled`][Self::convert_from_f32_slice_scaled], the
+    /// transform is not fused, so the result is the same as calling
+    /// [`convert_to_f32_slice`][Self::convert_to_f32_slice] and then transforming `dst`, but in a
+    /// single pass over memory.
+    ///
+    /// The length of `dst` must be the same as `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// // Dequantize with a per-tensor scale and zero point
+    /// let quantized = [0., 1., 2., 3.].map(bf16::from_f32);
+    /// let mut buffer = [0f32; 4];
+    /// quantized.convert_to_f32_slice_scaled(&mut buffer, 0.5, -1.);
+    ///
+    /// assert_eq!(buffer, [-1., -0.5, 0., 0.5]);
+    /// ```
+    fn convert_to_f32_slice_scaled(&self, dst: &mut [f32], scale: f32, bias: f32);
 
-    pub trait SealedHalfFloatSlice {}
-    impl SealedHalfFloatSlice for [f16] {}
-    impl SealedHalfFloatSlice for [bf16] {}
+    /// Converts all of the elements of a `[f32]` slice into [`f16`] or [`bf16`] values in `self`,
+    /// flushing subnormal inputs or results to zero as selected by `mode`.
+    ///
+    /// Every element is the same as converting it with
+    /// [`f16::from_f32_flush`][crate::f16::from_f32_flush] or
+    /// [`bf16::from_f32_flush`][crate::bf16::from_f32_flush], but the conversion itself is
+    /// vectorized like [`convert_from_f32_slice`][Self::convert_from_f32_slice].
+    ///
+    /// The length of `src` must be the same as `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// use half::FlushMode;
+    ///
+    /// let mut buffer = [f16::ZERO; 3];
+    /// buffer.convert_from_f32_slice_flush(&[1e-6, -1e-6, 1.], FlushMode::FlushToZero);
+    ///
+    /// assert_eq!(buffer, [f16::ZERO, f16::NEG_ZERO, f16::ONE]);
+    /// assert!(buffer[1].is_sign_negative());
+    /// ```
+    fn convert_from_f32_slice_flush(&mut self, src: &[f32], mode: FlushMode);
 
-    pub trait SealedHalfBitsSlice {}
-    impl SealedHalfBitsSlice for [u16] {}
-}
+    /// Converts all of the [`f16`] or [`bf16`] elements of `self` into [`f32`] values in `dst`,
+    /// flushing subnormal inputs or results to zero as selected by `mode`.
+    ///
+    /// Every element is the same as converting it with
+    /// [`f16::to_f32_flush`][crate::f16::to_f32_flush] or
+    /// [`bf16::to_f32_flush`][crate::bf16::to_f32_flush], but the conversion itself is
+    /// vectorized like [`convert_to_f32_slice`][Self::convert_to_f32_slice].
+    ///
+    /// The length of `dst` must be the same as `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// use half::FlushMode;
+    ///
+    /// let values = [f16::from_bits(0x0001), f16::ONE];
+    /// let mut buffer = [1f32; 2];
+    /// values.convert_to_f32_slice_flush(&mut buffer, FlushMode::DenormalsAreZero);
+    ///
+    /// assert_eq!(buffer, [0., 1.]);
+    /// ```
+    fn convert_to_f32_slice_flush(&self, dst: &mut [f32], mode: FlushMode);
 
-impl HalfFloatSliceExt for [f16] {
-    #[inline]
-    fn reinterpret_cast(&self) -> &[u16] {
+    /// Replaces every subnormal element of `self` with a zero of the same sign, leaving all
+    /// other elements unchanged.
+    ///
+    /// This is the same as calling [`f16::flush_subnormal_to_zero`][crate::f16::flush_subnormal_to_zero]
+    /// or [`bf16::flush_subnormal_to_zero`][crate::bf16::flush_subnormal_to_zero] on each
+    /// element, without branches so that it is vectorized on most targets.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut values = [bf16::from_bits(0x8001), bf16::MIN_POSITIVE, bf16::NAN];
+    /// values.flush_subnormals_to_zero();
+    ///
+    /// assert_eq!(values[0].to_bits(), 0x8000);
+    /// assert_eq!(values[1], bf16::MIN_POSITIVE);
+    /// assert!(values[2].is_nan());
+    /// ```
+    fn flush_subnormals_to_zero(&mut self);
+
+    /// Returns the sum of all [`f16`] or [`bf16`] elements of `self`, accumulated in [`f32`].
+    ///
+    /// Each element is widened to [`f32`] with the vectorized conversion of
+    /// [`convert_to_f32_slice`][Self::convert_to_f32_slice] before being added, without allocating
+    /// a temporary buffer for the whole slice. The elements are summed into several interleaved
+    /// accumulators so the additions can be vectorized, which means the result may differ slightly
+    /// from adding the elements one by one in order.
+    ///
+    /// Returns `0.0` for an empty slice.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let values = [1., 2., 3., 4.].map(f16::from_f32);
+    /// assert_eq!(values.sum_f32(), 10.);
+    ///
+    /// // The sum does not overflow at the f16 maximum
+    /// assert_eq!([f16::MAX; 2].sum_f32(), 131008.);
+    /// ```
+    fn sum_f32(&self) -> f32;
+
+    /// Returns the smallest element of `self`, ignoring NaNs.
+    ///
+    /// Like [`f16::min`][crate::f16::min], NaNs are only returned if there is nothing else to
+    /// return: this returns [`None`] if `self` is empty or contains only NaNs. Negative zero is
+    /// considered smaller than positive zero.
+    ///
+    /// The elements are compared as integers without converting them, which is vectorized on
+    /// most targets. See [`minimum`][Self::minimum] to propagate NaNs instead.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let values = [3., f32::NAN, -2., 5.].map(f16::from_f32);
+    /// assert_eq!(values.min(), Some(f16::from_f32(-2.)));
+    /// assert_eq!([f16::NAN; 2].min(), None);
+    /// ```
+    fn min(&self) -> Option<Self::Half>;
+
+    /// Returns the largest element of `self`, ignoring NaNs.
+    ///
+    /// This returns [`None`] if `self` is empty or contains only NaNs. Positive zero is
+    /// considered larger than negative zero. See [`min`][Self::min] for details, and
+    /// [`maximum`][Self::maximum] to propagate NaNs instead.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let values = [3., f32::NAN, -2., 5.].map(bf16::from_f32);
+    /// assert_eq!(values.max(), Some(bf16::from_f32(5.)));
+    /// ```
+    fn max(&self) -> Option<Self::Half>;
+
+    /// Returns both the smallest and the largest elements of `self` in a single pass, ignoring
+    /// NaNs.
+    ///
+    /// This is the same as calling [`min`][Self::min] and [`max`][Self::max], and returns
+    /// [`None`] if `self` is empty or contains only NaNs.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// // Find the range of a buffer to quantize it
+    /// let values = [0.5, -1.25, 4., f32::NAN].map(f16::from_f32);
+    /// let (low, high) = values.minmax().unwrap();
+    ///
+    /// assert_eq!((low.to_f32(), high.to_f32()), (-1.25, 4.));
+    /// ```
+    fn minmax(&self) -> Option<(Self::Half, Self::Half)>;
+
+    /// Returns the smallest element of `self`, propagating NaNs.
+    ///
+    /// This returns [`None`] if `self` is empty, and a NaN if any element of `self` is NaN, like
+    /// the IEEE 754-2019 `minimum` operation. Otherwise this is the same as [`min`][Self::min].
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let values = [3., -2., 5.].map(f16::from_f32);
+    /// assert_eq!(values.minimum(), Some(f16::from_f32(-2.)));
+    ///
+    /// let values = [3., f32::NAN, -2., 5.].map(f16::from_f32);
+    /// assert!(values.minimum().unwrap().is_nan());
+    /// ```
+    fn minimum(&self) -> Option<Self::Half>;
+
+    /// Returns the largest element of `self`, propagating NaNs.
+    ///
+    /// This returns [`None`] if `self` is empty, and a NaN if any element of `self` is NaN, like
+    /// the IEEE 754-2019 `maximum` operation. Otherwise this is the same as [`max`][Self::max].
+    fn maximum(&self) -> Option<Self::Half>;
+
+    /// Returns both the smallest and the largest elements of `self` in a single pass, propagating
+    /// NaNs.
+    ///
+    /// This returns [`None`] if `self` is empty, and a pair of NaNs if any element of `self` is
+    /// NaN. Otherwise this is the same as [`minmax`][Self::minmax].
+    fn minimum_maximum(&self) -> Option<(Self::Half, Self::Half)>;
+
+    /// Counts the NaNs, infinities, subnormals and zeros in `self`.
+    ///
+    /// The elements are classified by their bits without converting them, which is vectorized on
+    /// most targets, so this is much faster than calling [`classify`][crate::f16::classify] on each
+    /// element to validate large buffers.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let values = [1., f32::NAN, -0., f32::NEG_INFINITY, 1e-6, 0.].map(f16::from_f32);
+    /// let counts = values.count_special();
+    ///
+    /// assert_eq!(counts.nan, 1);
+    /// assert_eq!((counts.pos_inf, counts.neg_inf), (0, 1));
+    /// assert_eq!(counts.subnormal, 1);
+    /// assert_eq!(counts.zero, 2);
+    /// ```
+    #[must_use]
+    fn count_special(&self) -> SpecialCounts;
+
+    /// Converts all of the elements of a `[f32]` slice into [`f16`] or [`bf16`] values in `self`,
+    /// splitting the work across the [`rayon`](https://crates.io/crates/rayon) thread pool.
+    ///
+    /// Each thread converts a large chunk of the slices with the same vectorized conversion as
+    /// [`convert_from_f32_slice`][Self::convert_from_f32_slice], producing identical results. This
+    /// is only worthwhile for large buffers.
+    ///
+    /// This method is only available with the `rayon` feature.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let float_values: Vec<f32> = (0..100_000).map(|i| i as f32).collect();
+    /// let mut buffer = vec![f16::ZERO; float_values.len()];
+    ///
+    /// buffer.par_convert_from_f32_slice(&float_values);
+    ///
+    /// assert_eq!(buffer[2048], f16::from_f32(2048.));
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    fn par_convert_from_f32_slice(&mut self, src: &[f32]);
+
+    /// Converts all of the [`f16`] or [`bf16`] elements of `self` into [`f32`] values in `dst`,
+    /// splitting the work across the [`rayon`](https://crates.io/crates/rayon) thread pool.
+    ///
+    /// Each thread converts a large chunk of the slices with the same vectorized conversion as
+    /// [`convert_to_f32_slice`][Self::convert_to_f32_slice]. This is only worthwhile for large
+    /// buffers.
+    ///
+    /// This method is only available with the `rayon` feature.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let half_values = vec![bf16::ONE; 100_000];
+    /// let mut buffer = vec![0f32; half_values.len()];
+    ///
+    /// half_values.par_convert_to_f32_slice(&mut buffer);
+    ///
+    /// assert!(buffer.iter().all(|&f| f == 1.));
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    fn par_convert_to_f32_slice(&self, dst: &mut [f32]);
+
+    // Because trait is sealed, we can get away with different interfaces between features.
+
+    /// Converts all of the [`f16`] or [`bf16`] elements of `self` into [`f32`] values in a new
+    /// vector
+    ///
+    /// The conversion operation is vectorized over the slice, meaning the conversion may be more
+    /// efficient than converting individual elements on some hardware that supports SIMD
+    /// conversions. See [crate documentation](crate) for more information on hardware conversion
+    /// support.
+    ///
+    /// This method is only available with the `std` or `alloc` feature.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let half_values = [f16::from_f32(1.), f16::from_f32(2.), f16::from_f32(3.), f16::from_f32(4.)];
+    /// let vec = half_values.to_f32_vec();
+    ///
+    /// assert_eq!(vec, vec![1., 2., 3., 4.]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    fn to_f32_vec(&self) -> Vec<f32>;
+
+    /// Converts all of the [`f16`] or [`bf16`] elements of `self` into [`f64`] values in a new
+    /// vector.
+    ///
+    /// The conversion operation is vectorized over the slice, meaning the conversion may be more
+    /// efficient than converting individual elements on some hardware that supports SIMD
+    /// conversions. See [crate documentation](crate) for more information on hardware conversion
+    /// support.
+    ///
+    /// This method is only available with the `std` or `alloc` feature.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let half_values = [f16::from_f64(1.), f16::from_f64(2.), f16::from_f64(3.), f16::from_f64(4.)];
+    /// let vec = half_values.to_f64_vec();
+    ///
+    /// assert_eq!(vec, vec![1., 2., 3., 4.]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    fn to_f64_vec(&self) -> Vec<f64>;
+
+    /// Converts all of the [`f16`] or [`bf16`] elements of `self` into [`f32`] values in a new
+    /// vector, splitting the work across the [`rayon`](https://crates.io/crates/rayon) thread
+    /// pool.
+    ///
+    /// See [`par_convert_to_f32_slice`][Self::par_convert_to_f32_slice].
+    ///
+    /// This method is only available with the `rayon` and `alloc` features.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let half_values = vec![f16::from_f32(0.5); 100_000];
+    /// let vec = half_values.par_to_f32_vec();
+    ///
+    /// assert_eq!(vec, vec![0.5; 100_000]);
+    /// ```
+    #[cfg(all(feature = "rayon", feature = "alloc"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "rayon", feature = "alloc"))))]
+    #[must_use]
+    fn par_to_f32_vec(&self) -> Vec<f32>;
+}
+
+/// The number of elements of each special kind in a slice of [`f16`] or [`bf16`] values, as
+/// returned by [`HalfFloatSliceExt::count_special`].
+///
+/// Zeros and infinities of both signs are counted by [`zero`][Self::zero] and the infinity
+/// counts, and NaNs of both signs by [`nan`][Self::nan].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SpecialCounts {
+    /// Number of NaN values.
+    pub nan: usize,
+    /// Number of positive infinities.
+    pub pos_inf: usize,
+    /// Number of negative infinities.
+    pub neg_inf: usize,
+    /// Number of subnormal values.
+    pub subnormal: usize,
+    /// Number of positive or negative zeros.
+    pub zero: usize,
+}
+
+impl SpecialCounts {
+    /// Number of independent lanes, enough to fill a 512-bit vector of [`u16`] counters.
+    const LANES: usize = 32;
+
+    /// Counts the special values in `bits`, where `inf_bits` are the bits of positive infinity.
+    fn of(bits: &[u16], inf_bits: u16) -> Self {
+        #[inline(always)]
+        fn update(
+            lanes: &mut [[u16; SpecialCounts::LANES]; 5],
+            i: usize,
+            bits: u16,
+            inf_bits: u16,
+        ) {
+            let abs = bits & 0x7FFF;
+            lanes[0][i] += u16::from(abs > inf_bits);
+            lanes[1][i] += u16::from(bits == inf_bits);
+            lanes[2][i] += u16::from(bits == inf_bits | 0x8000);
+            lanes[3][i] += u16::from(abs != 0 && abs & inf_bits == 0);
+            lanes[4][i] += u16::from(abs == 0);
+        }
+
+        let mut totals = [0; 5];
+        // Small counters vectorize best, so the lanes are added to the totals before they can
+        // overflow
+        for block in bits.chunks(Self::LANES * usize::from(u16::MAX)) {
+            let mut lanes = [[0; Self::LANES]; 5];
+            let mut groups = block.chunks_exact(Self::LANES);
+            for group in &mut groups {
+                for (i, &bits) in group.iter().enumerate() {
+                    update(&mut lanes, i, bits, inf_bits);
+                }
+            }
+            for (i, &bits) in groups.remainder().iter().enumerate() {
+                update(&mut lanes, i, bits, inf_bits);
+            }
+            for (total, lanes) in totals.iter_mut().zip(&lanes) {
+                *total += lanes.iter().map(|&n| usize::from(n)).sum::<usize>();
+            }
+        }
+
+        let [nan, pos_inf, neg_inf, subnormal, zero] = totals;
+        Self {
+            nan,
+            pos_inf,
+            neg_inf,
+            subnormal,
+            zero,
+        }
+    }
+}
+
+/// Extensions to `[u16]` slices to support reinterpret operations.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait HalfBitsSliceExt: private::SealedHalfBitsSlice {
+    /// Reinterprets a slice of [`u16`] bits as a slice of [`f16`] or [`bf16`] numbers.
+    ///
+    /// `H` is the type to cast to, and must be either the [`f16`] or [`bf16`] type.
+    ///
+    /// This is a zero-copy operation. The reinterpreted slice has the same lifetime and memory
+    /// location as `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let int_buffer = [f16::from_f32(1.).to_bits(), f16::from_f32(2.).to_bits(), f16::from_f32(3.).to_bits()];
+    /// let float_buffer: &[f16] = int_buffer.reinterpret_cast();
+    ///
+    /// assert_eq!(float_buffer, [f16::from_f32(1.), f16::from_f32(2.), f16::from_f32(3.)]);
+    ///
+    /// // You may have to specify the cast type directly if the compiler can't infer the type.
+    /// // The following is also valid in Rust.
+    /// let typed_buffer = int_buffer.reinterpret_cast::<f16>();
+    /// ```
+    #[must_use]
+    fn reinterpret_cast<H>(&self) -> &[H]
+    where
+        H: crate::private::SealedHalf;
+
+    /// Reinterprets a mutable slice of [`u16`] bits as a mutable slice of [`f16`] or [`bf16`]
+    /// numbers.
+    ///
+    /// `H` is the type to cast to, and must be either the [`f16`] or [`bf16`] type.
+    ///
+    /// This is a zero-copy operation. The transmuted slice has the same lifetime as the original,
+    /// which prevents mutating `self` as long as the returned `&mut [f16]` is borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let mut int_buffer = [f16::from_f32(1.).to_bits(), f16::from_f32(2.).to_bits(), f16::from_f32(3.).to_bits()];
+    ///
+    /// {
+    ///     let float_buffer: &mut [f16] = int_buffer.reinterpret_cast_mut();
+    ///
+    ///     assert_eq!(float_buffer, [f16::from_f32(1.), f16::from_f32(2.), f16::from_f32(3.)]);
+    ///
+    ///     // Mutating the f16 slice will mutating the original
+    ///     float_buffer[0] = f16::from_f32(0.);
+    /// }
+    ///
+    /// // Note that we need to drop float_buffer before using int_buffer again or we will get a borrow error.
+    /// assert_eq!(int_buffer, [f16::from_f32(0.).to_bits(), f16::from_f32(2.).to_bits(), f16::from_f32(3.).to_bits()]);
+    ///
+    /// // You may have to specify the cast type directly if the compiler can't infer the type.
+    /// // The following is also valid in Rust.
+    /// let typed_buffer = int_buffer.reinterpret_cast_mut::<f16>();
+    /// ```
+    #[must_use]
+    fn reinterpret_cast_mut<H>(&mut self) -> &mut [H]
+    where
+        H: crate::private::SealedHalf;
+}
+
+/// Extensions to uninitialized `[MaybeUninit<f16>]` and `[MaybeUninit<bf16>]` slices to convert
+/// wider floats into them without initializing them first.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait HalfUninitSliceExt: private::SealedHalfUninitSlice {
+    /// The [`f16`] or [`bf16`] element type of the initialized slice.
+    type Half;
+
+    /// Converts all of the elements of a `[f32]` slice into [`f16`] or [`bf16`] values in `self`,
+    /// returning `self` as an initialized slice.
+    ///
+    /// This is the same as [`HalfFloatSliceExt::convert_from_f32_slice`], but avoids having to
+    /// initialize large output buffers before they are overwritten.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buffer = [MaybeUninit::<bf16>::uninit(); 3];
+    /// let halves = buffer.convert_from_f32_slice_uninit(&[1., 2., 3.]);
+    ///
+    /// assert_eq!(halves, [bf16::from_f32(1.), bf16::from_f32(2.), bf16::from_f32(3.)]);
+    /// ```
+    fn convert_from_f32_slice_uninit(&mut self, src: &[f32]) -> &mut [Self::Half];
+
+    /// Converts all of the elements of a `[f64]` slice into [`f16`] or [`bf16`] values in `self`,
+    /// returning `self` as an initialized slice.
+    ///
+    /// See [`convert_from_f32_slice_uninit`][Self::convert_from_f32_slice_uninit].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    fn convert_from_f64_slice_uninit(&mut self, src: &[f64]) -> &mut [Self::Half];
+}
+
+mod private {
+    use crate::{bf16, f16};
+    use core::mem::MaybeUninit;
+
+    pub trait SealedHalfFloatSlice {}
+    impl SealedHalfFloatSlice for [f16] {}
+    impl SealedHalfFloatSlice for [bf16] {}
+
+    pub trait SealedHalfBitsSlice {}
+    impl SealedHalfBitsSlice for [u16] {}
+
+    pub trait SealedHalfUninitSlice {}
+    impl SealedHalfUninitSlice for [MaybeUninit<f16>] {}
+    impl SealedHalfUninitSlice for [MaybeUninit<bf16>] {}
+}
+
+/// Panics if a `width` × `height` region with rows `pitch` elements apart does not fit in a slice of
+/// `len` elements.
+fn check_rect(buffer: &str, len: usize, pitch: usize, width: usize, height: usize) {
+    assert!(
+        width <= pitch,
+        "{} pitch is smaller than the region width",
+        buffer
+    );
+    if height > 0 {
+        let required = (height - 1)
+            .checked_mul(pitch)
+            .and_then(|n| n.checked_add(width));
+        assert!(
+            matches!(required, Some(required) if required <= len),
+            "{} slice is too short for the region",
+            buffer
+        );
+    }
+}
+
+/// Converts between the two half-precision formats through chunks of [`f32`] in a stack buffer.
+fn convert_via_f32<S, D>(src: &[S], dst: &mut [D])
+where
+    [S]: HalfFloatSliceExt,
+    [D]: HalfFloatSliceExt,
+{
+    assert_eq!(
+        src.len(),
+        dst.len(),
+        "destination and source slices have different lengths"
+    );
+
+    let mut buffer = [0f32; CHUNK_LEN];
+    for (src, dst) in src.chunks(CHUNK_LEN).zip(dst.chunks_mut(CHUNK_LEN)) {
+        let buffer = &mut buffer[..src.len()];
+        src.convert_to_f32_slice(buffer);
+        dst.convert_from_f32_slice(buffer);
+    }
+}
+
+/// Sums `values` in chunks that are widened to [`f32`] in a stack buffer.
+fn sum_f32<H>(values: &[H]) -> f32
+where
+    [H]: HalfFloatSliceExt,
+{
+    let mut lanes = [0f32; SUM_LANES];
+    let mut buffer = [0f32; CHUNK_LEN];
+    for chunk in values.chunks(CHUNK_LEN) {
+        let buffer = &mut buffer[..chunk.len()];
+        chunk.convert_to_f32_slice(buffer);
+
+        let mut groups = buffer.chunks_exact(SUM_LANES);
+        for group in &mut groups {
+            for (lane, x) in lanes.iter_mut().zip(group) {
+                *lane += x;
+            }
+        }
+        for (lane, x) in lanes.iter_mut().zip(groups.remainder()) {
+            *lane += x;
+        }
+    }
+    lanes.iter().sum()
+}
+
+/// The range of the non-NaN elements of a slice of [`f16`] or [`bf16`] bits, tracked as keys
+/// whose integer order is the IEEE 754 total order of the floats.
+struct Bounds {
+    min: i16,
+    max: i16,
+    has_nan: bool,
+}
+
+impl Bounds {
+    /// Number of independent lanes, enough to fill a 512-bit vector of keys.
+    const LANES: usize = 32;
+
+    /// Finds the bounds of `bits`, where the NaNs are the values with a magnitude above
+    /// `inf_bits`.
+    fn of(bits: &[u16], inf_bits: u16) -> Self {
+        // NaNs are replaced with the largest key for the minimum and the smallest for the maximum.
+        // These are the keys of NaNs themselves, so they can only remain if there are no other
+        // values.
+        #[inline(always)]
+        fn update(min: &mut i16, max: &mut i16, has_nan: &mut bool, bits: u16, inf_bits: u16) {
+            let is_nan = bits & 0x7FFF > inf_bits;
+            let key = total_order_key(bits);
+            *min = (*min).min(if is_nan { i16::MAX } else { key });
+            *max = (*max).max(if is_nan { i16::MIN } else { key });
+            *has_nan |= is_nan;
+        }
+
+        let mut min = [i16::MAX; Self::LANES];
+        let mut max = [i16::MIN; Self::LANES];
+        let mut has_nan = [false; Self::LANES];
+
+        let mut groups = bits.chunks_exact(Self::LANES);
+        for group in &mut groups {
+            for (i, &bits) in group.iter().enumerate() {
+                update(&mut min[i], &mut max[i], &mut has_nan[i], bits, inf_bits);
+            }
+        }
+        for (i, &bits) in groups.remainder().iter().enumerate() {
+            update(&mut min[i], &mut max[i], &mut has_nan[i], bits, inf_bits);
+        }
+
+        Self {
+            min: min.iter().copied().fold(i16::MAX, i16::min),
+            max: max.iter().copied().fold(i16::MIN, i16::max),
+            has_nan: has_nan.contains(&true),
+        }
+    }
+
+    /// Returns the bits of the smallest non-NaN element, if any.
+    fn min(&self) -> Option<u16> {
+        if self.min == i16::MAX {
+            None
+        } else {
+            Some(total_order_key(self.min as u16) as u16)
+        }
+    }
+
+    /// Returns the bits of the largest non-NaN element, if any.
+    fn max(&self) -> Option<u16> {
+        if self.max == i16::MIN {
+            None
+        } else {
+            Some(total_order_key(self.max as u16) as u16)
+        }
+    }
+}
+
+/// Maps the bits of an [`f16`] or [`bf16`] to a key that orders like `total_cmp` when compared as
+/// an integer, by flipping the magnitude bits of negative values. Mapping a key again returns the
+/// original bits.
+#[inline(always)]
+fn total_order_key(bits: u16) -> i16 {
+    let key = bits as i16;
+    key ^ ((key >> 15) as u16 >> 1) as i16
+}
+
+/// Applies `mode` to `dst` after it was converted from `src`. With DAZ, subnormal elements of
+/// `src` produce a signed zero even where they would round up to the smallest normal number.
+fn flush_converted_from_f32(dst: &mut [u16], src: &[f32], mode: FlushMode, exp_mask: u16) {
+    if mode.flushes_inputs() {
+        for (dst, src) in dst.iter_mut().zip(src) {
+            let bits = src.to_bits();
+            if bits & 0x7F80_0000 == 0 {
+                *dst = (bits >> 16) as u16 & 0x8000;
+            }
+        }
+    }
+    if mode.flushes_outputs() {
+        flush_subnormals(dst, exp_mask);
+    }
+}
+
+/// Applies `mode` to `dst` after it was converted from the bits in `src`.
+fn flush_converted_to_f32(src: &[u16], dst: &mut [f32], mode: FlushMode, exp_mask: u16) {
+    if mode.flushes_inputs() {
+        for (dst, &src) in dst.iter_mut().zip(src) {
+            if src & exp_mask == 0 {
+                *dst = f32::from_bits(u32::from(src & 0x8000) << 16);
+            }
+        }
+    }
+    if mode.flushes_outputs() {
+        for x in dst {
+            *x = flush::flush_f32(*x);
+        }
+    }
+}
+
+#[inline]
+fn flush_subnormals(bits: &mut [u16], exp_mask: u16) {
+    for bits in bits {
+        *bits = flush::flush_bits(*bits, exp_mask);
+    }
+}
+
+/// Copies `src` into the bits of `dst`, two bytes per element in the byte order of `from_bytes`.
+#[inline]
+fn copy_from_bytes(dst: &mut [u16], src: &[u8], from_bytes: fn([u8; 2]) -> u16) {
+    assert_eq!(
+        dst.len() * 2,
+        src.len(),
+        "source slice is not twice as long as destination slice"
+    );
+
+    for (dst, bytes) in dst.iter_mut().zip(src.chunks_exact(2)) {
+        *dst = from_bytes([bytes[0], bytes[1]]);
+    }
+}
+
+/// Copies the bits of `src` into `dst`, two bytes per element in the byte order of `to_bytes`.
+#[inline]
+fn copy_to_bytes(src: &[u16], dst: &mut [u8], to_bytes: fn(u16) -> [u8; 2]) {
+    assert_eq!(
+        src.len() * 2,
+        dst.len(),
+        "destination slice is not twice as long as source slice"
+    );
+
+    for (bytes, src) in dst.chunks_exact_mut(2).zip(src) {
+        bytes.copy_from_slice(&to_bytes(*src));
+    }
+}
+
+// Used to write into uninitialized buffers with the conversions above, which only ever write to
+// their destination.
+//
+// SAFETY: The returned slice must not be read before every element has been written.
+unsafe fn assume_init_mut<T>(slice: &mut [MaybeUninit<T>]) -> &mut [T] {
+    &mut *(slice as *mut [MaybeUninit<T>] as *mut [T])
+}
+
+impl HalfFloatSliceExt for [f16] {
+    type Half = f16;
+
+    #[inline]
+    fn reinterpret_cast(&self) -> &[u16] {
+        let pointer = self.as_ptr() as *const u16;
+        let length = self.len();
+        // SAFETY: We are reconstructing full length of original slice, using its same lifetime,
+        // and the size of elements are identical
+        unsafe { slice::from_raw_parts(pointer, length) }
+    }
+
+    #[inline]
+    fn reinterpret_cast_mut(&mut self) -> &mut [u16] {
+        let pointer = self.as_mut_ptr().cast::<u16>();
+        let length = self.len();
+        // SAFETY: We are reconstructing full length of original slice, using its same lifetime,
+        // and the size of elements are identical
+        unsafe { slice::from_raw_parts_mut(pointer, length) }
+    }
+
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        let pointer = self.as_ptr().cast::<u8>();
+        let length = self.len() * 2;
+        // SAFETY: The bytes cover exactly the memory of the original slice, using its same
+        // lifetime, and any byte is a valid u8 with no alignment requirement
+        unsafe { slice::from_raw_parts(pointer, length) }
+    }
+
+    #[inline]
+    fn as_bytes_mut(&mut self) -> &mut [u8] {
+        let pointer = self.as_mut_ptr().cast::<u8>();
+        let length = self.len() * 2;
+        // SAFETY: The bytes cover exactly the memory of the original slice, which is borrowed
+        // mutably for the same lifetime, and every byte pattern is a valid element
+        unsafe { slice::from_raw_parts_mut(pointer, length) }
+    }
+
+    #[inline]
+    fn copy_from_le_bytes(&mut self, src: &[u8]) {
+        copy_from_bytes(self.reinterpret_cast_mut(), src, u16::from_le_bytes);
+    }
+
+    #[inline]
+    fn copy_from_be_bytes(&mut self, src: &[u8]) {
+        copy_from_bytes(self.reinterpret_cast_mut(), src, u16::from_be_bytes);
+    }
+
+    #[inline]
+    fn copy_to_le_bytes(&self, dst: &mut [u8]) {
+        copy_to_bytes(self.reinterpret_cast(), dst, u16::to_le_bytes);
+    }
+
+    #[inline]
+    fn copy_to_be_bytes(&self, dst: &mut [u8]) {
+        copy_to_bytes(self.reinterpret_cast(), dst, u16::to_be_bytes);
+    }
+
+    fn convert_from_f32_slice(&mut self, src: &[f32]) {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        convert::f32_to_f16_slice(src, self.reinterpret_cast_mut());
+    }
+
+    fn convert_from_f64_slice(&mut self, src: &[f64]) {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        convert::f64_to_f16_slice(src, self.reinterpret_cast_mut());
+    }
+
+    fn convert_from_f32_slice_stochastic<R: FnMut() -> u16>(&mut self, src: &[f32], mut rng: R) {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        for (dst, f) in self.iter_mut().zip(src) {
+            *dst = f16::from_f32_stochastic(*f, rng());
+        }
+    }
+
+    fn convert_from_f32_slice_truncate(&mut self, src: &[f32]) {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        for (dst, f) in self.iter_mut().zip(src) {
+            *dst = f16::from_f32_round(*f, RoundingMode::TowardZero);
+        }
+    }
+
+    fn convert_to_f32_slice(&self, dst: &mut [f32]) {
+        assert_eq!(
+            self.len(),
+            dst.len(),
+            "destination and source slices have different lengths"
+        );
+
+        convert::f16_to_f32_slice(self.reinterpret_cast(), dst);
+    }
+
+    fn convert_to_f64_slice(&self, dst: &mut [f64]) {
+        assert_eq!(
+            self.len(),
+            dst.len(),
+            "destination and source slices have different lengths"
+        );
+
+        convert::f16_to_f64_slice(self.reinterpret_cast(), dst);
+    }
+
+    fn convert_to_f16_slice(&self, dst: &mut [f16]) {
+        dst.copy_from_slice(self);
+    }
+
+    fn convert_to_bf16_slice(&self, dst: &mut [bf16]) {
+        convert_via_f32(self, dst);
+    }
+
+    fn convert_to_f32_slice_uninit<'a>(&self, dst: &'a mut [MaybeUninit<f32>]) -> &'a mut [f32] {
+        // SAFETY: The conversion initializes every element without reading any of them
+        let dst = unsafe { assume_init_mut(dst) };
+        self.convert_to_f32_slice(dst);
+        dst
+    }
+
+    fn convert_to_f64_slice_uninit<'a>(&self, dst: &'a mut [MaybeUninit<f64>]) -> &'a mut [f64] {
+        // SAFETY: The conversion initializes every element without reading any of them
+        let dst = unsafe { assume_init_mut(dst) };
+        self.convert_to_f64_slice(dst);
+        dst
+    }
+
+    fn convert_from_f32_rect(
+        &mut self,
+        pitch: usize,
+        src: &[f32],
+        src_pitch: usize,
+        width: usize,
+        height: usize,
+    ) {
+        check_rect("destination", self.len(), pitch, width, height);
+        check_rect("source", src.len(), src_pitch, width, height);
+
+        for row in 0..height {
+            self[row * pitch..][..width].convert_from_f32_slice(&src[row * src_pitch..][..width]);
+        }
+    }
+
+    fn convert_to_f32_rect(
+        &self,
+        pitch: usize,
+        dst: &mut [f32],
+        dst_pitch: usize,
+        width: usize,
+        height: usize,
+    ) {
+        check_rect("source", self.len(), pitch, width, height);
+        check_rect("destination", dst.len(), dst_pitch, width, height);
+
+        for row in 0..height {
+            self[row * pitch..][..width].convert_to_f32_slice(&mut dst[row * dst_pitch..][..width]);
+        }
+    }
+
+    fn convert_from_f32_slice_scaled(&mut self, src: &[f32], scale: f32, bias: f32) {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        let mut buffer = [0f32; CHUNK_LEN];
+        for (dst, src) in self.chunks_mut(CHUNK_LEN).zip(src.chunks(CHUNK_LEN)) {
+            let buffer = &mut buffer[..src.len()];
+            for (scaled, x) in buffer.iter_mut().zip(src) {
+                *scaled = x * scale + bias;
+            }
+            dst.convert_from_f32_slice(buffer);
+        }
+    }
+
+    fn convert_to_f32_slice_scaled(&self, dst: &mut [f32], scale: f32, bias: f32) {
+        assert_eq!(
+            self.len(),
+            dst.len(),
+            "destination and source slices have different lengths"
+        );
+
+        // Transform each chunk while it is still in cache
+        for (src, dst) in self.chunks(CHUNK_LEN).zip(dst.chunks_mut(CHUNK_LEN)) {
+            src.convert_to_f32_slice(dst);
+            for x in dst {
+                *x = *x * scale + bias;
+            }
+        }
+    }
+
+    fn convert_from_f32_slice_flush(&mut self, src: &[f32], mode: FlushMode) {
+        self.convert_from_f32_slice(src);
+        flush_converted_from_f32(self.reinterpret_cast_mut(), src, mode, 0x7C00);
+    }
+
+    fn convert_to_f32_slice_flush(&self, dst: &mut [f32], mode: FlushMode) {
+        self.convert_to_f32_slice(dst);
+        flush_converted_to_f32(self.reinterpret_cast(), dst, mode, 0x7C00);
+    }
+
+    #[inline]
+    fn flush_subnormals_to_zero(&mut self) {
+        flush_subnormals(self.reinterpret_cast_mut(), 0x7C00);
+    }
+
+    #[inline]
+    fn sum_f32(&self) -> f32 {
+        sum_f32(self)
+    }
+
+    #[inline]
+    fn min(&self) -> Option<f16> {
+        let bounds = Bounds::of(self.reinterpret_cast(), f16::INFINITY.to_bits());
+        bounds.min().map(f16::from_bits)
+    }
+
+    #[inline]
+    fn max(&self) -> Option<f16> {
+        let bounds = Bounds::of(self.reinterpret_cast(), f16::INFINITY.to_bits());
+        bounds.max().map(f16::from_bits)
+    }
+
+    #[inline]
+    fn minmax(&self) -> Option<(f16, f16)> {
+        let bounds = Bounds::of(self.reinterpret_cast(), f16::INFINITY.to_bits());
+        Some((f16::from_bits(bounds.min()?), f16::from_bits(bounds.max()?)))
+    }
+
+    #[inline]
+    fn minimum(&self) -> Option<f16> {
+        self.minimum_maximum().map(|(min, _)| min)
+    }
+
+    #[inline]
+    fn maximum(&self) -> Option<f16> {
+        self.minimum_maximum().map(|(_, max)| max)
+    }
+
+    #[inline]
+    fn minimum_maximum(&self) -> Option<(f16, f16)> {
+        let bounds = Bounds::of(self.reinterpret_cast(), f16::INFINITY.to_bits());
+        if bounds.has_nan {
+            Some((f16::NAN, f16::NAN))
+        } else {
+            Some((f16::from_bits(bounds.min()?), f16::from_bits(bounds.max()?)))
+        }
+    }
+
+    #[inline]
+    fn count_special(&self) -> SpecialCounts {
+        SpecialCounts::of(self.reinterpret_cast(), f16::INFINITY.to_bits())
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_convert_from_f32_slice(&mut self, src: &[f32]) {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        self.par_chunks_mut(PAR_CHUNK_LEN)
+            .zip(src.par_chunks(PAR_CHUNK_LEN))
+            .for_each(|(dst, src)| dst.convert_from_f32_slice(src));
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_convert_to_f32_slice(&self, dst: &mut [f32]) {
+        assert_eq!(
+            self.len(),
+            dst.len(),
+            "destination and source slices have different lengths"
+        );
+
+        self.par_chunks(PAR_CHUNK_LEN)
+            .zip(dst.par_chunks_mut(PAR_CHUNK_LEN))
+            .for_each(|(src, dst)| src.convert_to_f32_slice(dst));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    #[allow(clippy::uninit_vec)]
+    fn to_f32_vec(&self) -> Vec<f32> {
+        let mut vec = Vec::with_capacity(self.len());
+        // SAFETY: convert will initialize every value in the vector without reading them,
+        // so this is safe to do instead of double initialize from resize, and we're setting it to
+        // same value as capacity.
+        unsafe { vec.set_len(self.len()) };
+        self.convert_to_f32_slice(&mut vec);
+        vec
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    #[allow(clippy::uninit_vec)]
+    fn to_f64_vec(&self) -> Vec<f64> {
+        let mut vec = Vec::with_capacity(self.len());
+        // SAFETY: convert will initialize every value in the vector without reading them,
+        // so this is safe to do instead of double initialize from resize, and we're setting it to
+        // same value as capacity.
+        unsafe { vec.set_len(self.len()) };
+        self.convert_to_f64_slice(&mut vec);
+        vec
+    }
+
+    #[cfg(all(feature = "rayon", feature = "alloc"))]
+    #[inline]
+    #[allow(clippy::uninit_vec)]
+    fn par_to_f32_vec(&self) -> Vec<f32> {
+        let mut vec = Vec::with_capacity(self.len());
+        // SAFETY: convert will initialize every value in the vector without reading them,
+        // so this is safe to do instead of double initialize from resize, and we're setting it to
+        // same value as capacity.
+        unsafe { vec.set_len(self.len()) };
+        self.par_convert_to_f32_slice(&mut vec);
+        vec
+    }
+}
+
+impl HalfFloatSliceExt for [bf16] {
+    type Half = bf16;
+
+    #[inline]
+    fn reinterpret_cast(&self) -> &[u16] {
         let pointer = self.as_ptr() as *const u16;
         let length = self.len();
         // SAFETY: We are reconstructing full length of original slice, using its same lifetime,
@@ -318,57 +1655,98 @@ impl HalfFloatSliceExt for [f16] {
         unsafe { slice::from_raw_parts_mut(pointer, length) }
     }
 
-    fn convert_from_f32_slice(&mut self, src: &[f32]) {
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        let pointer = self.as_ptr().cast::<u8>();
+        let length = self.len() * 2;
+        // SAFETY: The bytes cover exactly the memory of the original slice, using its same
+        // lifetime, and any byte is a valid u8 with no alignment requirement
+        unsafe { slice::from_raw_parts(pointer, length) }
+    }
+
+    #[inline]
+    fn as_bytes_mut(&mut self) -> &mut [u8] {
+        let pointer = self.as_mut_ptr().cast::<u8>();
+        let length = self.len() * 2;
+        // SAFETY: The bytes cover exactly the memory of the original slice, which is borrowed
+        // mutably for the same lifetime, and every byte pattern is a valid element
+        unsafe { slice::from_raw_parts_mut(pointer, length) }
+    }
+
+    #[inline]
+    fn copy_from_le_bytes(&mut self, src: &[u8]) {
+        copy_from_bytes(self.reinterpret_cast_mut(), src, u16::from_le_bytes);
+    }
+
+    #[inline]
+    fn copy_from_be_bytes(&mut self, src: &[u8]) {
+        copy_from_bytes(self.reinterpret_cast_mut(), src, u16::from_be_bytes);
+    }
+
+    #[inline]
+    fn copy_to_le_bytes(&self, dst: &mut [u8]) {
+        copy_to_bytes(self.reinterpret_cast(), dst, u16::to_le_bytes);
+    }
+
+    #[inline]
+    fn copy_to_be_bytes(&self, dst: &mut [u8]) {
+        copy_to_bytes(self.reinterpret_cast(), dst, u16::to_be_bytes);
+    }
+
+    fn convert_from_f32_slice(&mut self, src: &[f32]) {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        bf16_convert::f32_to_bf16_slice(src, self.reinterpret_cast_mut());
+    }
+
+    fn convert_from_f64_slice(&mut self, src: &[f64]) {
         assert_eq!(
             self.len(),
             src.len(),
             "destination and source slices have different lengths"
         );
 
-        let mut chunks = src.chunks_exact(4);
-        let mut chunk_count = 0usize; // Not using .enumerate() because we need this value for remainder
-        for chunk in &mut chunks {
-            let vec = convert::f32x4_to_f16x4(chunk);
-            let dst_idx = chunk_count * 4;
-            self[dst_idx..dst_idx + 4].copy_from_slice(vec.reinterpret_cast());
-            chunk_count += 1;
+        // Just use regular loop here until there's any bf16 SIMD support.
+        for (i, f) in src.iter().enumerate() {
+            self[i] = bf16::from_f64(*f);
         }
+    }
+
+    fn convert_from_f32_slice_stochastic<R: FnMut() -> u16>(&mut self, src: &[f32], mut rng: R) {
+        assert_eq!(
+            self.len(),
+            src.len(),
+            "destination and source slices have different lengths"
+        );
 
-        // Process remainder
-        if !chunks.remainder().is_empty() {
-            let mut buf = [0f32; 4];
-            buf[..chunks.remainder().len()].copy_from_slice(chunks.remainder());
-            let vec = convert::f32x4_to_f16x4(&buf);
-            let dst_idx = chunk_count * 4;
-            self[dst_idx..dst_idx + chunks.remainder().len()]
-                .copy_from_slice(vec[..chunks.remainder().len()].reinterpret_cast());
+        for (dst, f) in self.iter_mut().zip(src) {
+            *dst = bf16::from_f32_stochastic(*f, rng());
         }
     }
 
-    fn convert_from_f64_slice(&mut self, src: &[f64]) {
+    fn convert_from_f32_slice_truncate(&mut self, src: &[f32]) {
         assert_eq!(
             self.len(),
             src.len(),
             "destination and source slices have different lengths"
         );
 
-        let mut chunks = src.chunks_exact(4);
-        let mut chunk_count = 0usize; // Not using .enumerate() because we need this value for remainder
-        for chunk in &mut chunks {
-            let vec = convert::f64x4_to_f16x4(chunk);
-            let dst_idx = chunk_count * 4;
-            self[dst_idx..dst_idx + 4].copy_from_slice(vec.reinterpret_cast());
-            chunk_count += 1;
+        let mut chunks = src.chunks_exact(8);
+        let mut dst_chunks = self.chunks_exact_mut(8);
+        for (dst, chunk) in (&mut dst_chunks).zip(&mut chunks) {
+            dst.copy_from_slice(bf16_convert::f32x8_to_bf16x8_truncate(chunk).reinterpret_cast());
         }
 
-        // Process remainder
-        if !chunks.remainder().is_empty() {
-            let mut buf = [0f64; 4];
-            buf[..chunks.remainder().len()].copy_from_slice(chunks.remainder());
-            let vec = convert::f64x4_to_f16x4(&buf);
-            let dst_idx = chunk_count * 4;
-            self[dst_idx..dst_idx + chunks.remainder().len()]
-                .copy_from_slice(vec[..chunks.remainder().len()].reinterpret_cast());
+        for (dst, f) in dst_chunks
+            .into_remainder()
+            .iter_mut()
+            .zip(chunks.remainder())
+        {
+            *dst = bf16::from_f32_truncate(*f);
         }
     }
 
@@ -379,23 +1757,9 @@ impl HalfFloatSliceExt for [f16] {
             "destination and source slices have different lengths"
         );
 
-        let mut chunks = self.chunks_exact(4);
-        let mut chunk_count = 0usize; // Not using .enumerate() because we need this value for remainder
-        for chunk in &mut chunks {
-            let vec = convert::f16x4_to_f32x4(chunk.reinterpret_cast());
-            let dst_idx = chunk_count * 4;
-            dst[dst_idx..dst_idx + 4].copy_from_slice(&vec);
-            chunk_count += 1;
-        }
-
-        // Process remainder
-        if !chunks.remainder().is_empty() {
-            let mut buf = [0u16; 4];
-            buf[..chunks.remainder().len()].copy_from_slice(chunks.remainder().reinterpret_cast());
-            let vec = convert::f16x4_to_f32x4(&buf);
-            let dst_idx = chunk_count * 4;
-            dst[dst_idx..dst_idx + chunks.remainder().len()]
-                .copy_from_slice(&vec[..chunks.remainder().len()]);
+        // Just use regular loop here until there's any bf16 SIMD support.
+        for (i, f) in self.iter().enumerate() {
+            dst[i] = f.to_f32();
         }
     }
 
@@ -406,125 +1770,195 @@ impl HalfFloatSliceExt for [f16] {
             "destination and source slices have different lengths"
         );
 
-        let mut chunks = self.chunks_exact(4);
-        let mut chunk_count = 0usize; // Not using .enumerate() because we need this value for remainder
-        for chunk in &mut chunks {
-            let vec = convert::f16x4_to_f64x4(chunk.reinterpret_cast());
-            let dst_idx = chunk_count * 4;
-            dst[dst_idx..dst_idx + 4].copy_from_slice(&vec);
-            chunk_count += 1;
+        // Just use regular loop here until there's any bf16 SIMD support.
+        for (i, f) in self.iter().enumerate() {
+            dst[i] = f.to_f64();
         }
+    }
 
-        // Process remainder
-        if !chunks.remainder().is_empty() {
-            let mut buf = [0u16; 4];
-            buf[..chunks.remainder().len()].copy_from_slice(chunks.remainder().reinterpret_cast());
-            let vec = convert::f16x4_to_f64x4(&buf);
-            let dst_idx = chunk_count * 4;
-            dst[dst_idx..dst_idx + chunks.remainder().len()]
-                .copy_from_slice(&vec[..chunks.remainder().len()]);
-        }
+    fn convert_to_f16_slice(&self, dst: &mut [f16]) {
+        convert_via_f32(self, dst);
     }
 
-    #[cfg(any(feature = "alloc", feature = "std"))]
-    #[inline]
-    #[allow(clippy::uninit_vec)]
-    fn to_f32_vec(&self) -> Vec<f32> {
-        let mut vec = Vec::with_capacity(self.len());
-        // SAFETY: convert will initialize every value in the vector without reading them,
-        // so this is safe to do instead of double initialize from resize, and we're setting it to
-        // same value as capacity.
-        unsafe { vec.set_len(self.len()) };
-        self.convert_to_f32_slice(&mut vec);
-        vec
+    fn convert_to_bf16_slice(&self, dst: &mut [bf16]) {
+        dst.copy_from_slice(self);
     }
 
-    #[cfg(any(feature = "alloc", feature = "std"))]
-    #[inline]
-    #[allow(clippy::uninit_vec)]
-    fn to_f64_vec(&self) -> Vec<f64> {
-        let mut vec = Vec::with_capacity(self.len());
-        // SAFETY: convert will initialize every value in the vector without reading them,
-        // so this is safe to do instead of double initialize from resize, and we're setting it to
-        // same value as capacity.
-        unsafe { vec.set_len(self.len()) };
-        self.convert_to_f64_slice(&mut vec);
-        vec
+    fn convert_to_f32_slice_uninit<'a>(&self, dst: &'a mut [MaybeUninit<f32>]) -> &'a mut [f32] {
+        // SAFETY: The conversion initializes every element without reading any of them
+        let dst = unsafe { assume_init_mut(dst) };
+        self.convert_to_f32_slice(dst);
+        dst
     }
-}
 
-impl HalfFloatSliceExt for [bf16] {
-    #[inline]
-    fn reinterpret_cast(&self) -> &[u16] {
-        let pointer = self.as_ptr() as *const u16;
-        let length = self.len();
-        // SAFETY: We are reconstructing full length of original slice, using its same lifetime,
-        // and the size of elements are identical
-        unsafe { slice::from_raw_parts(pointer, length) }
+    fn convert_to_f64_slice_uninit<'a>(&self, dst: &'a mut [MaybeUninit<f64>]) -> &'a mut [f64] {
+        // SAFETY: The conversion initializes every element without reading any of them
+        let dst = unsafe { assume_init_mut(dst) };
+        self.convert_to_f64_slice(dst);
+        dst
     }
 
-    #[inline]
-    fn reinterpret_cast_mut(&mut self) -> &mut [u16] {
-        let pointer = self.as_mut_ptr().cast::<u16>();
-        let length = self.len();
-        // SAFETY: We are reconstructing full length of original slice, using its same lifetime,
-        // and the size of elements are identical
-        unsafe { slice::from_raw_parts_mut(pointer, length) }
+    fn convert_from_f32_rect(
+        &mut self,
+        pitch: usize,
+        src: &[f32],
+        src_pitch: usize,
+        width: usize,
+        height: usize,
+    ) {
+        check_rect("destination", self.len(), pitch, width, height);
+        check_rect("source", src.len(), src_pitch, width, height);
+
+        for row in 0..height {
+            self[row * pitch..][..width].convert_from_f32_slice(&src[row * src_pitch..][..width]);
+        }
     }
 
-    fn convert_from_f32_slice(&mut self, src: &[f32]) {
+    fn convert_to_f32_rect(
+        &self,
+        pitch: usize,
+        dst: &mut [f32],
+        dst_pitch: usize,
+        width: usize,
+        height: usize,
+    ) {
+        check_rect("source", self.len(), pitch, width, height);
+        check_rect("destination", dst.len(), dst_pitch, width, height);
+
+        for row in 0..height {
+            self[row * pitch..][..width].convert_to_f32_slice(&mut dst[row * dst_pitch..][..width]);
+        }
+    }
+
+    fn convert_from_f32_slice_scaled(&mut self, src: &[f32], scale: f32, bias: f32) {
         assert_eq!(
             self.len(),
             src.len(),
             "destination and source slices have different lengths"
         );
 
-        // Just use regular loop here until there's any bf16 SIMD support.
-        for (i, f) in src.iter().enumerate() {
-            self[i] = bf16::from_f32(*f);
+        let mut buffer = [0f32; CHUNK_LEN];
+        for (dst, src) in self.chunks_mut(CHUNK_LEN).zip(src.chunks(CHUNK_LEN)) {
+            let buffer = &mut buffer[..src.len()];
+            for (scaled, x) in buffer.iter_mut().zip(src) {
+                *scaled = x * scale + bias;
+            }
+            dst.convert_from_f32_slice(buffer);
         }
     }
 
-    fn convert_from_f64_slice(&mut self, src: &[f64]) {
+    fn convert_to_f32_slice_scaled(&self, dst: &mut [f32], scale: f32, bias: f32) {
         assert_eq!(
             self.len(),
-            src.len(),
+            dst.len(),
             "destination and source slices have different lengths"
         );
 
-        // Just use regular loop here until there's any bf16 SIMD support.
-        for (i, f) in src.iter().enumerate() {
-            self[i] = bf16::from_f64(*f);
+        // Transform each chunk while it is still in cache
+        for (src, dst) in self.chunks(CHUNK_LEN).zip(dst.chunks_mut(CHUNK_LEN)) {
+            src.convert_to_f32_slice(dst);
+            for x in dst {
+                *x = *x * scale + bias;
+            }
         }
     }
 
-    fn convert_to_f32_slice(&self, dst: &mut [f32]) {
+    fn convert_from_f32_slice_flush(&mut self, src: &[f32], mode: FlushMode) {
+        self.convert_from_f32_slice(src);
+        flush_converted_from_f32(self.reinterpret_cast_mut(), src, mode, 0x7F80);
+    }
+
+    fn convert_to_f32_slice_flush(&self, dst: &mut [f32], mode: FlushMode) {
+        self.convert_to_f32_slice(dst);
+        flush_converted_to_f32(self.reinterpret_cast(), dst, mode, 0x7F80);
+    }
+
+    #[inline]
+    fn flush_subnormals_to_zero(&mut self) {
+        flush_subnormals(self.reinterpret_cast_mut(), 0x7F80);
+    }
+
+    #[inline]
+    fn sum_f32(&self) -> f32 {
+        sum_f32(self)
+    }
+
+    #[inline]
+    fn min(&self) -> Option<bf16> {
+        let bounds = Bounds::of(self.reinterpret_cast(), bf16::INFINITY.to_bits());
+        bounds.min().map(bf16::from_bits)
+    }
+
+    #[inline]
+    fn max(&self) -> Option<bf16> {
+        let bounds = Bounds::of(self.reinterpret_cast(), bf16::INFINITY.to_bits());
+        bounds.max().map(bf16::from_bits)
+    }
+
+    #[inline]
+    fn minmax(&self) -> Option<(bf16, bf16)> {
+        let bounds = Bounds::of(self.reinterpret_cast(), bf16::INFINITY.to_bits());
+        Some((
+            bf16::from_bits(bounds.min()?),
+            bf16::from_bits(bounds.max()?),
+        ))
+    }
+
+    #[inline]
+    fn minimum(&self) -> Option<bf16> {
+        self.minimum_maximum().map(|(min, _)| min)
+    }
+
+    #[inline]
+    fn maximum(&self) -> Option<bf16> {
+        self.minimum_maximum().map(|(_, max)| max)
+    }
+
+    #[inline]
+    fn minimum_maximum(&self) -> Option<(bf16, bf16)> {
+        let bounds = Bounds::of(self.reinterpret_cast(), bf16::INFINITY.to_bits());
+        if bounds.has_nan {
+            Some((bf16::NAN, bf16::NAN))
+        } else {
+            Some((
+                bf16::from_bits(bounds.min()?),
+                bf16::from_bits(bounds.max()?),
+            ))
+        }
+    }
+
+    #[inline]
+    fn count_special(&self) -> SpecialCounts {
+        SpecialCounts::of(self.reinterpret_cast(), bf16::INFINITY.to_bits())
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_convert_from_f32_slice(&mut self, src: &[f32]) {
         assert_eq!(
             self.len(),
-            dst.len(),
+            src.len(),
             "destination and source slices have different lengths"
         );
 
-        // Just use regular loop here until there's any bf16 SIMD support.
-        for (i, f) in self.iter().enumerate() {
-            dst[i] = f.to_f32();
-        }
+        self.par_chunks_mut(PAR_CHUNK_LEN)
+            .zip(src.par_chunks(PAR_CHUNK_LEN))
+            .for_each(|(dst, src)| dst.convert_from_f32_slice(src));
     }
 
-    fn convert_to_f64_slice(&self, dst: &mut [f64]) {
+    #[cfg(feature = "rayon")]
+    fn par_convert_to_f32_slice(&self, dst: &mut [f32]) {
         assert_eq!(
             self.len(),
             dst.len(),
             "destination and source slices have different lengths"
         );
 
-        // Just use regular loop here until there's any bf16 SIMD support.
-        for (i, f) in self.iter().enumerate() {
-            dst[i] = f.to_f64();
-        }
+        self.par_chunks(PAR_CHUNK_LEN)
+            .zip(dst.par_chunks_mut(PAR_CHUNK_LEN))
+            .for_each(|(src, dst)| src.convert_to_f32_slice(dst));
     }
 
-    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg(feature = "alloc")]
     #[inline]
     #[allow(clippy::uninit_vec)]
     fn to_f32_vec(&self) -> Vec<f32> {
@@ -537,7 +1971,7 @@ impl HalfFloatSliceExt for [bf16] {
         vec
     }
 
-    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg(feature = "alloc")]
     #[inline]
     #[allow(clippy::uninit_vec)]
     fn to_f64_vec(&self) -> Vec<f64> {
@@ -549,6 +1983,19 @@ impl HalfFloatSliceExt for [bf16] {
         self.convert_to_f64_slice(&mut vec);
         vec
     }
+
+    #[cfg(all(feature = "rayon", feature = "alloc"))]
+    #[inline]
+    #[allow(clippy::uninit_vec)]
+    fn par_to_f32_vec(&self) -> Vec<f32> {
+        let mut vec = Vec::with_capacity(self.len());
+        // SAFETY: convert will initialize every value in the vector without reading them,
+        // so this is safe to do instead of double initialize from resize, and we're setting it to
+        // same value as capacity.
+        unsafe { vec.set_len(self.len()) };
+        self.par_convert_to_f32_slice(&mut vec);
+        vec
+    }
 }
 
 impl HalfBitsSliceExt for [u16] {
@@ -578,11 +2025,48 @@ impl HalfBitsSliceExt for [u16] {
     }
 }
 
+impl HalfUninitSliceExt for [MaybeUninit<f16>] {
+    type Half = f16;
+
+    fn convert_from_f32_slice_uninit(&mut self, src: &[f32]) -> &mut [f16] {
+        // SAFETY: The conversion initializes every element without reading any of them
+        let dst = unsafe { assume_init_mut(self) };
+        dst.convert_from_f32_slice(src);
+        dst
+    }
+
+    fn convert_from_f64_slice_uninit(&mut self, src: &[f64]) -> &mut [f16] {
+        // SAFETY: The conversion initializes every element without reading any of them
+        let dst = unsafe { assume_init_mut(self) };
+        dst.convert_from_f64_slice(src);
+        dst
+    }
+}
+
+impl HalfUninitSliceExt for [MaybeUninit<bf16>] {
+    type Half = bf16;
+
+    fn convert_from_f32_slice_uninit(&mut self, src: &[f32]) -> &mut [bf16] {
+        // SAFETY: The conversion initializes every element without reading any of them
+        let dst = unsafe { assume_init_mut(self) };
+        dst.convert_from_f32_slice(src);
+        dst
+    }
+
+    fn convert_from_f64_slice_uninit(&mut self, src: &[f64]) -> &mut [bf16] {
+        // SAFETY: The conversion initializes every element without reading any of them
+        let dst = unsafe { assume_init_mut(self) };
+        dst.convert_from_f64_slice(src);
+        dst
+    }
+}
+
 #[allow(clippy::float_cmp)]
 #[cfg(test)]
 mod test {
-    use super::{HalfBitsSliceExt, HalfFloatSliceExt};
-    use crate::{bf16, f16};
+    use super::{HalfBitsSliceExt, HalfFloatSliceExt, HalfUninitSliceExt, SpecialCounts};
+    use crate::{bf16, f16, FlushMode, RoundingMode};
+    use core::{mem::MaybeUninit, num::FpCategory};
 
     #[test]
     fn test_slice_conversions_f16() {
@@ -772,6 +2256,422 @@ mod test {
         assert_eq!(&vf16, &buf16);
     }
 
+    #[test]
+    fn slice_convert_bf16_f32_matches_scalar() {
+        // A spread of bit patterns, including subnormals, NaNs and exact ties
+        let mut src = [0f32; 1024];
+        let mut dst = [bf16::ZERO; 1024];
+        for start in (0..=u32::MAX).step_by(1 << 22) {
+            for (i, f) in src.iter_mut().enumerate() {
+                *f = f32::from_bits(start.wrapping_add(i as u32 * 0x8001));
+            }
+            src[3] = f32::from_bits(0x0000_8000);
+            src[9] = f32::from_bits(0x8040_0001);
+            src[10] = f32::from_bits(0x3F80_8000);
+            // Leave a remainder after the last full chunk
+            dst[..1021].convert_from_f32_slice(&src[..1021]);
+            for (h, f) in dst.iter().zip(&src[..1021]) {
+                assert_eq!(h.to_bits(), bf16::from_f32(*f).to_bits(), "{:e}", f);
+            }
+        }
+    }
+
+    #[test]
+    fn slice_convert_from_f32_truncate() {
+        let mut src = [0f32; 1024];
+        let mut dst16 = [f16::ZERO; 1024];
+        let mut dstbf = [bf16::ZERO; 1024];
+        for start in (0..=u32::MAX).step_by(1 << 22) {
+            for (i, f) in src.iter_mut().enumerate() {
+                *f = f32::from_bits(start.wrapping_add(i as u32 * 0x8001));
+            }
+            src[5] = f32::from_bits(0x7F80_0001);
+            // Leave a remainder after the last full chunk
+            dst16[..1021].convert_from_f32_slice_truncate(&src[..1021]);
+            dstbf[..1021].convert_from_f32_slice_truncate(&src[..1021]);
+            for ((h, b), f) in dst16.iter().zip(&dstbf).zip(&src[..1021]) {
+                let expected = f16::from_f32_round(*f, RoundingMode::TowardZero);
+                assert_eq!(h.to_bits(), expected.to_bits(), "{:e}", f);
+                assert_eq!(
+                    b.to_bits(),
+                    bf16::from_f32_truncate(*f).to_bits(),
+                    "{:e}",
+                    f
+                );
+                if !f.is_nan() {
+                    let expected = bf16::from_f32_round(*f, RoundingMode::TowardZero);
+                    assert_eq!(b.to_bits(), expected.to_bits(), "{:e}", f);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn slice_convert_uninit() {
+        let src = [1.5f32, -0.0, f32::INFINITY, 65504., 1e-7, 3.3, -7.25];
+        let src64: [f64; 7] = [1.5, -0.0, f64::INFINITY, 65504., 1e-7, 3.3, -7.25];
+
+        let mut uninit16 = [MaybeUninit::<f16>::uninit(); 7];
+        let halves = uninit16.convert_from_f32_slice_uninit(&src);
+        for (h, f) in halves.iter().zip(&src) {
+            assert_eq!(h.to_bits(), f16::from_f32(*f).to_bits());
+        }
+        let mut uninit = [MaybeUninit::<f32>::uninit(); 7];
+        let floats = halves.convert_to_f32_slice_uninit(&mut uninit);
+        for (f, h) in floats.iter().zip(halves.iter()) {
+            assert_eq!(f.to_bits(), h.to_f32().to_bits());
+        }
+
+        let mut uninitbf = [MaybeUninit::<bf16>::uninit(); 7];
+        let halves = uninitbf.convert_from_f64_slice_uninit(&src64);
+        for (h, f) in halves.iter().zip(&src64) {
+            assert_eq!(h.to_bits(), bf16::from_f64(*f).to_bits());
+        }
+        let mut uninit64 = [MaybeUninit::<f64>::uninit(); 7];
+        let floats = halves.convert_to_f64_slice_uninit(&mut uninit64);
+        for (f, h) in floats.iter().zip(halves.iter()) {
+            assert_eq!(f.to_bits(), h.to_f64().to_bits());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn convert_to_f32_slice_uninit_len_mismatch() {
+        let mut uninit = [MaybeUninit::<f32>::uninit(); 3];
+        [f16::ONE; 4].convert_to_f32_slice_uninit(&mut uninit);
+    }
+
+    #[test]
+    fn slice_convert_f16_bf16() {
+        let mut halves = [f16::ZERO; 1 << 16];
+        let mut bfloats = [bf16::ZERO; 1 << 16];
+        for (i, (h, b)) in halves.iter_mut().zip(&mut bfloats).enumerate() {
+            *h = f16::from_bits(i as u16);
+            *b = bf16::from_bits(i as u16);
+        }
+
+        let mut converted = [bf16::ZERO; 1 << 16];
+        halves.convert_to_bf16_slice(&mut converted);
+        for (c, h) in converted.iter().zip(&halves) {
+            assert_eq!(c.to_bits(), bf16::from_f16(*h).to_bits());
+        }
+        let mut converted = [f16::ZERO; 1 << 16];
+        bfloats.convert_to_f16_slice(&mut converted);
+        for (c, b) in converted.iter().zip(&bfloats) {
+            assert_eq!(
+                c.to_bits(),
+                f16::from_bf16(*b).to_bits(),
+                "{:#06x}",
+                b.to_bits()
+            );
+        }
+
+        halves.convert_to_f16_slice(&mut converted);
+        assert_eq!(converted.reinterpret_cast(), halves.reinterpret_cast());
+    }
+
+    #[test]
+    #[should_panic]
+    fn convert_to_bf16_slice_len_mismatch() {
+        [f16::ONE; 3].convert_to_bf16_slice(&mut [bf16::ZERO; 4]);
+    }
+
+    #[test]
+    fn slice_sum_f32() {
+        assert_eq!(<[f16]>::sum_f32(&[]), 0.);
+        assert_eq!([bf16::ONE; 3].sum_f32(), 3.);
+
+        // Integers are summed exactly in any order
+        let mut halves = [f16::ZERO; 1000];
+        for (i, h) in halves.iter_mut().enumerate() {
+            *h = f16::from_f32((i % 100) as f32 - 50.);
+        }
+        assert_eq!(halves.sum_f32(), -500.);
+
+        // Far beyond the range where accumulating in f16 would lose precision
+        let bfloats = [bf16::from_f32(0.1); 1000];
+        let expected = bf16::from_f32(0.1).to_f64() * 1000.;
+        assert!((bfloats.sum_f32() as f64 - expected).abs() < 1e-3);
+
+        halves[999] = f16::NAN;
+        assert!(halves.sum_f32().is_nan());
+        halves[999] = f16::INFINITY;
+        assert_eq!(halves.sum_f32(), f32::INFINITY);
+    }
+
+    #[test]
+    fn slice_bytes() {
+        let halves = [1., -2., 0.1, 65504.].map(f16::from_f32);
+        let mut le = [0u8; 8];
+        let mut be = [0u8; 8];
+        halves.copy_to_le_bytes(&mut le);
+        halves.copy_to_be_bytes(&mut be);
+        for (i, h) in halves.iter().enumerate() {
+            assert_eq!(le[i * 2..][..2], h.to_le_bytes());
+            assert_eq!(be[i * 2..][..2], h.to_be_bytes());
+            assert_eq!(halves.as_bytes()[i * 2..][..2], h.to_ne_bytes());
+        }
+
+        let mut copy = [f16::ZERO; 4];
+        copy.copy_from_le_bytes(&le);
+        assert_eq!(copy, halves);
+        copy = [f16::ZERO; 4];
+        copy.copy_from_be_bytes(&be);
+        assert_eq!(copy, halves);
+
+        let bfloats = [bf16::from_bits(0x1234), bf16::from_bits(0xABCD)];
+        let mut bytes = [0u8; 4];
+        bfloats.copy_to_be_bytes(&mut bytes);
+        assert_eq!(bytes, [0x12, 0x34, 0xAB, 0xCD]);
+        let mut copy = [bf16::ZERO; 2];
+        copy.copy_from_le_bytes(&bytes);
+        assert_eq!(copy, [bf16::from_bits(0x3412), bf16::from_bits(0xCDAB)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn copy_from_bytes_len_mismatch() {
+        [f16::ZERO; 2].copy_from_le_bytes(&[0; 3]);
+    }
+
+    #[test]
+    fn slice_min_max() {
+        let empty: [f16; 0] = [];
+        assert_eq!(empty.minmax(), None);
+        assert_eq!(empty.minimum_maximum(), None);
+
+        // Every f16 bit pattern, including NaNs of both signs
+        let mut halves = [f16::ZERO; 1 << 16];
+        for (i, h) in halves.iter_mut().enumerate() {
+            *h = f16::from_bits((i as u16).wrapping_mul(0x9E37));
+        }
+        assert_eq!(halves.min().unwrap().to_bits(), f16::NEG_INFINITY.to_bits());
+        assert_eq!(halves.max().unwrap().to_bits(), f16::INFINITY.to_bits());
+        assert!(halves.minimum().unwrap().is_nan());
+        assert!(halves.maximum().unwrap().is_nan());
+
+        // Every partial length, to cover the lane remainder
+        let values = [
+            2.5, -0.0, 0.0, -7., 1e-7, 65504., -65504., 3., 0.5, -0.25, 42., 1e4, -1e4, 0.1,
+        ];
+        for len in 1..=values.len() {
+            let mut halves = [bf16::ZERO; 42];
+            let halves = &mut halves[..len * 3];
+            for (i, h) in halves.iter_mut().enumerate() {
+                *h = bf16::from_f32(values[i % len]);
+            }
+            let min = *halves.iter().min_by(|a, b| a.total_cmp(b)).unwrap();
+            let max = *halves.iter().max_by(|a, b| a.total_cmp(b)).unwrap();
+            assert_eq!(halves.minmax().unwrap(), (min, max));
+            assert_eq!(halves.min().unwrap().to_bits(), min.to_bits());
+            assert_eq!(halves.maximum().unwrap().to_bits(), max.to_bits());
+
+            halves[len / 2] = -bf16::NAN;
+            assert_eq!(halves.minmax().unwrap(), (min, max));
+            let (min, max) = halves.minimum_maximum().unwrap();
+            assert!(min.is_nan() && max.is_nan());
+        }
+
+        // Signed zeros are ordered
+        let zeros = [f16::ZERO, f16::NEG_ZERO, f16::ZERO];
+        assert_eq!(zeros.min().unwrap().to_bits(), f16::NEG_ZERO.to_bits());
+        assert_eq!(zeros.max().unwrap().to_bits(), f16::ZERO.to_bits());
+
+        // Only NaNs
+        let nans = [
+            f16::NAN,
+            -f16::NAN,
+            f16::from_bits(0x7FFF),
+            f16::from_bits(0xFFFF),
+        ];
+        assert_eq!(nans.min(), None);
+        assert_eq!(nans.minmax(), None);
+        assert!(nans.maximum().unwrap().is_nan());
+    }
+
+    #[test]
+    fn slice_count_special() {
+        fn expected<H: Copy>(
+            values: &[H],
+            classify: fn(H) -> FpCategory,
+            sign: fn(H) -> bool,
+        ) -> SpecialCounts {
+            let mut counts = SpecialCounts::default();
+            for &x in values {
+                match classify(x) {
+                    FpCategory::Nan => counts.nan += 1,
+                    FpCategory::Infinite if sign(x) => counts.neg_inf += 1,
+                    FpCategory::Infinite => counts.pos_inf += 1,
+                    FpCategory::Subnormal => counts.subnormal += 1,
+                    FpCategory::Zero => counts.zero += 1,
+                    FpCategory::Normal => {}
+                }
+            }
+            counts
+        }
+
+        // Every bit pattern, at every offset to cover the lane remainder
+        let mut halves = [f16::ZERO; 1 << 16];
+        let mut bhalves = [bf16::ZERO; 1 << 16];
+        for (i, (h, b)) in halves.iter_mut().zip(bhalves.iter_mut()).enumerate() {
+            *h = f16::from_bits((i as u16).wrapping_mul(0x9E37));
+            *b = bf16::from_bits((i as u16).wrapping_mul(0x9E37));
+        }
+        let counts = halves.count_special();
+        assert_eq!(counts.nan, 2046);
+        assert_eq!((counts.pos_inf, counts.neg_inf), (1, 1));
+        assert_eq!(counts.subnormal, 2046);
+        assert_eq!(counts.zero, 2);
+        assert_eq!(
+            bhalves.count_special(),
+            expected(&bhalves, bf16::classify, bf16::is_sign_negative)
+        );
+        for start in 0..40 {
+            let halves = &halves[start..start * 1000 + 57];
+            assert_eq!(
+                halves.count_special(),
+                expected(halves, f16::classify, f16::is_sign_negative)
+            );
+        }
+
+        assert_eq!([bf16::ONE; 0].count_special(), SpecialCounts::default());
+
+        // More than one block of lane counters
+        #[cfg(feature = "std")]
+        {
+            let zeros = std::vec![f16::ZERO; SpecialCounts::LANES * usize::from(u16::MAX) + 100];
+            assert_eq!(zeros.count_special().zero, zeros.len());
+        }
+    }
+
+    #[test]
+    fn slice_convert_scaled() {
+        // Long enough to span several chunks with a partial one at the end
+        let mut src = [0f32; 600];
+        for (i, x) in src.iter_mut().enumerate() {
+            *x = i as f32 * 0.37 - 100.;
+        }
+        let (scale, bias) = (1. / 3., 0.25);
+
+        let mut halves = [f16::ZERO; 600];
+        halves.convert_from_f32_slice_scaled(&src, scale, bias);
+        for (h, x) in halves.iter().zip(&src) {
+            assert_eq!(h.to_bits(), f16::from_f32(x * scale + bias).to_bits());
+        }
+        let mut floats = [0f32; 600];
+        halves.convert_to_f32_slice_scaled(&mut floats, scale, bias);
+        for (f, h) in floats.iter().zip(&halves) {
+            assert_eq!(f.to_bits(), (h.to_f32() * scale + bias).to_bits());
+        }
+
+        let mut halves = [bf16::ZERO; 600];
+        halves.convert_from_f32_slice_scaled(&src, scale, bias);
+        for (h, x) in halves.iter().zip(&src) {
+            assert_eq!(h.to_bits(), bf16::from_f32(x * scale + bias).to_bits());
+        }
+        halves.convert_to_f32_slice_scaled(&mut floats, scale, bias);
+        for (f, h) in floats.iter().zip(&halves) {
+            assert_eq!(f.to_bits(), (h.to_f32() * scale + bias).to_bits());
+        }
+    }
+
+    #[cfg(all(feature = "rayon", feature = "std"))]
+    #[test]
+    fn slice_par_convert() {
+        // Long enough to be split into several chunks, with a short one at the end
+        let src: std::vec::Vec<f32> = (0..100_003u32)
+            .map(|i| f32::from_bits(i.wrapping_mul(0x9E37_79B9)))
+            .collect();
+
+        let mut par16 = std::vec![f16::ZERO; src.len()];
+        let mut seq16 = std::vec![f16::ZERO; src.len()];
+        par16.par_convert_from_f32_slice(&src);
+        seq16.convert_from_f32_slice(&src);
+        assert_eq!(par16.reinterpret_cast(), seq16.reinterpret_cast());
+
+        let mut parbf = std::vec![bf16::ZERO; src.len()];
+        let mut seqbf = std::vec![bf16::ZERO; src.len()];
+        parbf.par_convert_from_f32_slice(&src);
+        seqbf.convert_from_f32_slice(&src);
+        assert_eq!(parbf.reinterpret_cast(), seqbf.reinterpret_cast());
+
+        let mut dst = std::vec![0f32; src.len()];
+        par16.par_convert_to_f32_slice(&mut dst);
+        let to_bits = |v: &[f32]| v.iter().map(|f| f.to_bits()).collect::<std::vec::Vec<_>>();
+        assert_eq!(to_bits(&dst), to_bits(&seq16.to_f32_vec()));
+        assert_eq!(
+            to_bits(&parbf.par_to_f32_vec()),
+            to_bits(&seqbf.to_f32_vec())
+        );
+    }
+
+    #[cfg(all(feature = "rayon", feature = "std"))]
+    #[test]
+    #[should_panic]
+    fn par_convert_from_f32_slice_len_mismatch() {
+        let mut dst = [f16::ZERO; 3];
+        dst.par_convert_from_f32_slice(&[1.0; 4]);
+    }
+
+    #[test]
+    fn slice_convert_rect() {
+        // 5×4 image with a pitch of 7 and distinct values everywhere
+        let mut image = [0f32; 7 * 3 + 5];
+        for (i, f) in image.iter_mut().enumerate() {
+            *f = i as f32 * 1.25;
+        }
+
+        // 3×2 region starting at column 1, row 2, into a buffer with a pitch of 4
+        let mut region = [f16::NEG_ONE; 4 * 2];
+        region.convert_from_f32_rect(4, &image[2 * 7 + 1..], 7, 3, 2);
+        for y in 0..2 {
+            for x in 0..4 {
+                let expected = if x < 3 {
+                    f16::from_f32(image[(y + 2) * 7 + x + 1])
+                } else {
+                    f16::NEG_ONE
+                };
+                assert_eq!(region[y * 4 + x], expected);
+            }
+        }
+
+        // Write it back at column 2, row 0, and check nothing else changed
+        let original = image;
+        region.convert_to_f32_rect(4, &mut image[2..], 7, 3, 2);
+        for (i, f) in image.iter().enumerate() {
+            let (y, x) = (i / 7, i % 7);
+            if y < 2 && (2..5).contains(&x) {
+                assert_eq!(*f, original[(y + 2) * 7 + x - 1]);
+            } else {
+                assert_eq!(*f, original[i]);
+            }
+        }
+
+        let mut regionbf = [bf16::ZERO; 3 * 4];
+        regionbf.convert_from_f32_rect(3, &image, 7, 3, 4);
+        let mut packed = [0f32; 3 * 4];
+        regionbf.convert_to_f32_rect(3, &mut packed, 3, 3, 4);
+        for (i, f) in packed.iter().enumerate() {
+            assert_eq!(*f, bf16::from_f32(image[i / 3 * 7 + i % 3]).to_f32());
+        }
+
+        // Empty regions need no storage
+        [f16::ZERO; 0].convert_from_f32_rect(0, &[], 0, 0, 3);
+        [bf16::ZERO; 0].convert_to_f32_rect(4, &mut [], 4, 4, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn convert_from_f32_rect_pitch_too_small() {
+        [f16::ZERO; 16].convert_from_f32_rect(4, &[0.; 16], 3, 4, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn convert_to_f32_rect_too_short() {
+        [bf16::ZERO; 16].convert_to_f32_rect(4, &mut [0.; 11], 4, 4, 3);
+    }
+
     #[test]
     fn slice_convert_f16_f64() {
         // Exact chunks
@@ -888,6 +2788,50 @@ mod test {
         assert_eq!(&vf16, &buf16);
     }
 
+    #[test]
+    fn test_flush_matches_scalar() {
+        const MODES: [FlushMode; 4] = [
+            FlushMode::Preserve,
+            FlushMode::FlushToZero,
+            FlushMode::DenormalsAreZero,
+            FlushMode::Both,
+        ];
+        // Subnormals of both formats and their neighbours, in both signs
+        let mut src = [0f32; 16];
+        for (i, x) in src.iter_mut().enumerate() {
+            let bits = [0x0000_0001, 0x007F_FFFF, 0x0080_0000, 0x3380_0000][i % 4];
+            let sign = if i % 8 < 4 { 0 } else { 0x8000_0000 };
+            *x = f32::from_bits(bits | sign) * if i < 8 { 1. } else { 1e4 };
+        }
+
+        for mode in MODES {
+            let mut f16s = [f16::ZERO; 16];
+            f16s.convert_from_f32_slice_flush(&src, mode);
+            let mut bf16s = [bf16::ZERO; 16];
+            bf16s.convert_from_f32_slice_flush(&src, mode);
+            for (i, &x) in src.iter().enumerate() {
+                assert_eq!(f16s[i].to_bits(), f16::from_f32_flush(x, mode).to_bits());
+                assert_eq!(bf16s[i].to_bits(), bf16::from_f32_flush(x, mode).to_bits());
+            }
+
+            let mut back = [0f32; 16];
+            bf16s.convert_to_f32_slice_flush(&mut back, mode);
+            for (x, y) in bf16s.iter().zip(back) {
+                assert_eq!(x.to_f32_flush(mode).to_bits(), y.to_bits());
+            }
+            f16s.convert_to_f32_slice_flush(&mut back, mode);
+            for (x, y) in f16s.iter().zip(back) {
+                assert_eq!(x.to_f32_flush(mode).to_bits(), y.to_bits());
+            }
+        }
+
+        let mut bits = [0u16, 1, 0x03FF, 0x0400, 0x8001, 0x7C01, 0xFC00, 0x807F];
+        let expected = bits.map(|b| f16::from_bits(b).flush_subnormal_to_zero());
+        let values = bits.reinterpret_cast_mut::<f16>();
+        values.flush_subnormals_to_zero();
+        assert_eq!(values.reinterpret_cast(), expected.map(f16::to_bits));
+    }
+
     #[test]
     #[should_panic]
     fn convert_from_f32_slice_len_mismatch_panics() {
@@ -0,0 +1,677 @@
+//! Software implementation of correctly rounded IEEE 754 [`f16`] arithmetic.
+//!
+//! The operator implementations on [`f16`] normally promote their operands to [`f32`], perform the
+//! operation in hardware and round the result back to [`f16`]. The functions in this module instead
+//! operate directly on the 16-bit format using only integer arithmetic: each operation computes the
+//! exact result and then rounds it to [`f16`] in a single step using round-to-nearest, ties-to-even.
+//!
+//! This makes the results bit-exact with the IEEE 754 `binary16` specification regardless of the
+//! host floating point unit, which is useful for emulators, conformance tooling or targets without
+//! hardware floating point support. It also includes a correctly rounded fused
+//! [`mul_add`], which can not be implemented by promoting to [`f32`] without the risk of double
+//! rounding.
+//!
+//! All functions in this module are `const`. When the `softfloat` cargo feature is enabled, the
+//! [`Add`][core::ops::Add], [`Sub`][core::ops::Sub], [`Mul`][core::ops::Mul] and
+//! [`Div`][core::ops::Div] implementations of [`f16`] use this module as their backend.
+//!
+//! NaN results are always quiet. When an operand is NaN, the first NaN operand is returned with its
+//! quiet bit set. Invalid operations, such as `∞ - ∞` or `0 × ∞`, return [`f16::NAN`].
+//!
+//! The `_with_flags` variants of the functions also return the IEEE 754 exceptions raised by the
+//! operation as [`ExceptionFlags`], for emulators and conformance tests that need to check them.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use half::{f16, softfloat};
+//!
+//! let a = f16::from_f32(1.5);
+//! let b = f16::from_f32(2.25);
+//!
+//! assert_eq!(softfloat::add(a, b), f16::from_f32(3.75));
+//! assert_eq!(softfloat::mul(a, b), f16::from_f32(3.375));
+//! assert_eq!(softfloat::sqrt(f16::from_f32(6.25)), f16::from_f32(2.5));
+//! ```
+
+use crate::{f16, leading_zeros::leading_zeros_u16, ExceptionFlags};
+
+/// Adds two [`f16`] values with a single rounding step.
+#[inline]
+#[must_use]
+pub const fn add(a: f16, b: f16) -> f16 {
+    f16::from_bits(add_bits(a.to_bits(), b.to_bits()).0)
+}
+
+/// Subtracts `b` from `a` with a single rounding step.
+#[inline]
+#[must_use]
+pub const fn sub(a: f16, b: f16) -> f16 {
+    let b = b.to_bits();
+    // Negating a NaN would change the sign of the propagated payload
+    let b = if is_nan(b) { b } else { b ^ SIGN_MASK };
+    f16::from_bits(add_bits(a.to_bits(), b).0)
+}
+
+/// Multiplies two [`f16`] values with a single rounding step.
+#[inline]
+#[must_use]
+pub const fn mul(a: f16, b: f16) -> f16 {
+    f16::from_bits(mul_bits(a.to_bits(), b.to_bits()).0)
+}
+
+/// Divides `a` by `b` with a single rounding step.
+#[inline]
+#[must_use]
+pub const fn div(a: f16, b: f16) -> f16 {
+    f16::from_bits(div_bits(a.to_bits(), b.to_bits()).0)
+}
+
+/// Computes the square root of an [`f16`] value with a single rounding step.
+///
+/// Returns NaN if `a` is a negative number other than `-0.0`.
+#[inline]
+#[must_use]
+pub const fn sqrt(a: f16) -> f16 {
+    f16::from_bits(sqrt_bits(a.to_bits()).0)
+}
+
+/// Computes `(a * b) + c` with a single rounding step.
+///
+/// Unlike performing the multiplication and addition separately, the intermediate product is not
+/// rounded, giving a more accurate result.
+///
+/// # Examples
+///
+/// ```rust
+/// use half::{f16, softfloat};
+///
+/// let a = f16::from_f32(10.0);
+/// let b = f16::from_f32(4.0);
+/// let c = f16::from_f32(60.0);
+///
+/// assert_eq!(softfloat::mul_add(a, b, c), f16::from_f32(100.0));
+/// ```
+#[inline]
+#[must_use]
+pub const fn mul_add(a: f16, b: f16, c: f16) -> f16 {
+    f16::from_bits(mul_add_bits(a.to_bits(), b.to_bits(), c.to_bits()).0)
+}
+
+/// Adds two [`f16`] values like [`add`], and returns the exceptions raised by the operation.
+#[inline]
+#[must_use]
+pub const fn add_with_flags(a: f16, b: f16) -> (f16, ExceptionFlags) {
+    let (bits, flags) = add_bits(a.to_bits(), b.to_bits());
+    (f16::from_bits(bits), flags)
+}
+
+/// Subtracts `b` from `a` like [`sub`], and returns the exceptions raised by the operation.
+#[inline]
+#[must_use]
+pub const fn sub_with_flags(a: f16, b: f16) -> (f16, ExceptionFlags) {
+    let b = b.to_bits();
+    let b = if is_nan(b) { b } else { b ^ SIGN_MASK };
+    let (bits, flags) = add_bits(a.to_bits(), b);
+    (f16::from_bits(bits), flags)
+}
+
+/// Multiplies two [`f16`] values like [`mul`], and returns the exceptions raised by the
+/// operation.
+#[inline]
+#[must_use]
+pub const fn mul_with_flags(a: f16, b: f16) -> (f16, ExceptionFlags) {
+    let (bits, flags) = mul_bits(a.to_bits(), b.to_bits());
+    (f16::from_bits(bits), flags)
+}
+
+/// Divides `a` by `b` like [`div`], and returns the exceptions raised by the operation.
+#[inline]
+#[must_use]
+pub const fn div_with_flags(a: f16, b: f16) -> (f16, ExceptionFlags) {
+    let (bits, flags) = div_bits(a.to_bits(), b.to_bits());
+    (f16::from_bits(bits), flags)
+}
+
+/// Computes the square root like [`sqrt`], and returns the exceptions raised by the operation.
+#[inline]
+#[must_use]
+pub const fn sqrt_with_flags(a: f16) -> (f16, ExceptionFlags) {
+    let (bits, flags) = sqrt_bits(a.to_bits());
+    (f16::from_bits(bits), flags)
+}
+
+/// Computes `(a * b) + c` like [`mul_add`], and returns the exceptions raised by the operation.
+#[inline]
+#[must_use]
+pub const fn mul_add_with_flags(a: f16, b: f16, c: f16) -> (f16, ExceptionFlags) {
+    let (bits, flags) = mul_add_bits(a.to_bits(), b.to_bits(), c.to_bits());
+    (f16::from_bits(bits), flags)
+}
+
+const SIGN_MASK: u16 = 0x8000;
+const EXP_MASK: u16 = 0x7C00;
+const MAN_MASK: u16 = 0x03FF;
+const QUIET_BIT: u16 = 0x0200;
+const INFINITY: u16 = 0x7C00;
+const DEFAULT_NAN: u16 = 0x7E00;
+
+#[inline]
+const fn is_nan(x: u16) -> bool {
+    x & 0x7FFF > EXP_MASK
+}
+
+#[inline]
+const fn is_inf(x: u16) -> bool {
+    x & 0x7FFF == EXP_MASK
+}
+
+#[inline]
+const fn is_zero(x: u16) -> bool {
+    x & 0x7FFF == 0
+}
+
+#[inline]
+const fn is_signaling(x: u16) -> bool {
+    is_nan(x) && x & QUIET_BIT == 0
+}
+
+/// Returns the first NaN operand, quieted. Signaling NaN operands raise the invalid exception.
+#[inline]
+const fn propagate_nan(a: u16, b: u16) -> (u16, ExceptionFlags) {
+    let flags = if is_signaling(a) || is_signaling(b) {
+        ExceptionFlags::INVALID
+    } else {
+        ExceptionFlags::empty()
+    };
+    if is_nan(a) {
+        (a | QUIET_BIT, flags)
+    } else {
+        (b | QUIET_BIT, flags)
+    }
+}
+
+/// The result of an invalid operation.
+const INVALID_NAN: (u16, ExceptionFlags) = (DEFAULT_NAN, ExceptionFlags::INVALID);
+
+/// An exact result.
+#[inline]
+const fn exact(x: u16) -> (u16, ExceptionFlags) {
+    (x, ExceptionFlags::empty())
+}
+
+/// Exact magnitude of a finite value in units of 2⁻²⁴, the smallest subnormal.
+#[inline]
+const fn to_fixed(x: u16) -> u64 {
+    let exp = (x & EXP_MASK) >> 10;
+    let man = (x & MAN_MASK) as u64;
+    if exp == 0 {
+        man
+    } else {
+        (man | 0x0400) << (exp - 1)
+    }
+}
+
+/// Splits a finite non-zero value into a significand with the hidden bit at bit 10 and the
+/// exponent of its least significant bit, normalizing subnormals.
+#[inline]
+const fn unpack_normalized(x: u16) -> (u32, i32) {
+    let exp = ((x & EXP_MASK) >> 10) as i32;
+    let man = x & MAN_MASK;
+    if exp == 0 {
+        let shift = leading_zeros_u16(man) - 5;
+        ((man as u32) << shift, -24 - shift as i32)
+    } else {
+        ((man | 0x0400) as u32, exp - 25)
+    }
+}
+
+/// Shifts right, ORing any bits shifted out into the least significant bit.
+#[inline]
+const fn shift_right_jam(m: u128, shift: i32) -> u128 {
+    if shift <= 0 {
+        m << (-shift)
+    } else if shift >= 128 {
+        (m != 0) as u128
+    } else {
+        (m >> shift) | ((m & ((1u128 << shift) - 1)) != 0) as u128
+    }
+}
+
+/// Rounds `sig` to nearest, ties to even, and packs the result.
+///
+/// `sig` holds the significand with the hidden bit at bit 14 and four extra rounding bits below
+/// the 10 stored mantissa bits. `exp` is the biased exponent minus one, so that the hidden bit
+/// carries into the exponent field when packing, including the carry of a round-up overflow.
+/// `tiny` tells whether the exact value is below the smallest normal number.
+const fn round_pack(sign: u16, exp: i32, sig: u32, tiny: bool) -> (u16, ExceptionFlags) {
+    const OVERFLOW: ExceptionFlags = ExceptionFlags::OVERFLOW.union(ExceptionFlags::INEXACT);
+    const UNDERFLOW: ExceptionFlags = ExceptionFlags::UNDERFLOW.union(ExceptionFlags::INEXACT);
+    if exp > 0x1D {
+        return (sign | INFINITY, OVERFLOW);
+    }
+    let round_bits = sig & 0xF;
+    let mut sig = (sig + 0x8) >> 4;
+    if round_bits == 0x8 {
+        // Exact tie, round to even
+        sig &= !1;
+    }
+    let exp = if sig == 0 { 0 } else { exp };
+    let bits = sign + ((exp as u16) << 10) + sig as u16;
+    let flags = if round_bits == 0 {
+        ExceptionFlags::empty()
+    } else if is_inf(bits) {
+        OVERFLOW
+    } else if tiny {
+        UNDERFLOW
+    } else {
+        ExceptionFlags::INEXACT
+    };
+    (bits, flags)
+}
+
+/// Rounds the exact value `m × 2⁻ᵏ` to the nearest [`f16`].
+const fn round_fixed(sign: u16, m: u128, k: i32) -> (u16, ExceptionFlags) {
+    if m == 0 {
+        return exact(sign);
+    }
+    let msb = 127 - m.leading_zeros() as i32;
+    let biased_exp = msb - k + 15;
+    if biased_exp >= 1 {
+        round_pack(
+            sign,
+            biased_exp - 1,
+            shift_right_jam(m, msb - 14) as u32,
+            false,
+        )
+    } else {
+        // Subnormal result: the rounding bits are in units of 2⁻²⁸
+        round_pack(sign, 0, shift_right_jam(m, k - 28) as u32, true)
+    }
+}
+
+const fn add_bits(a: u16, b: u16) -> (u16, ExceptionFlags) {
+    if is_nan(a) || is_nan(b) {
+        return propagate_nan(a, b);
+    }
+    if is_inf(a) {
+        if is_inf(b) && (a ^ b) & SIGN_MASK != 0 {
+            return INVALID_NAN;
+        }
+        return exact(a);
+    }
+    if is_inf(b) {
+        return exact(b);
+    }
+
+    let ma = to_fixed(a) as i64;
+    let mb = to_fixed(b) as i64;
+    let ma = if a & SIGN_MASK != 0 { -ma } else { ma };
+    let mb = if b & SIGN_MASK != 0 { -mb } else { mb };
+    let sum = ma + mb;
+    if sum == 0 {
+        // Exact zero sums are positive except when both operands are negative
+        return exact(a & b & SIGN_MASK);
+    }
+    let sign = if sum < 0 { SIGN_MASK } else { 0 };
+    round_fixed(sign, sum.unsigned_abs() as u128, 24)
+}
+
+const fn mul_bits(a: u16, b: u16) -> (u16, ExceptionFlags) {
+    if is_nan(a) || is_nan(b) {
+        return propagate_nan(a, b);
+    }
+    let sign = (a ^ b) & SIGN_MASK;
+    if is_inf(a) || is_inf(b) {
+        if is_zero(a) || is_zero(b) {
+            return INVALID_NAN;
+        }
+        return exact(sign | INFINITY);
+    }
+    let product = to_fixed(a) as u128 * to_fixed(b) as u128;
+    round_fixed(sign, product, 48)
+}
+
+const fn div_bits(a: u16, b: u16) -> (u16, ExceptionFlags) {
+    if is_nan(a) || is_nan(b) {
+        return propagate_nan(a, b);
+    }
+    let sign = (a ^ b) & SIGN_MASK;
+    if is_inf(a) {
+        if is_inf(b) {
+            return INVALID_NAN;
+        }
+        return exact(sign | INFINITY);
+    }
+    if is_inf(b) {
+        return exact(sign);
+    }
+    if is_zero(b) {
+        if is_zero(a) {
+            return INVALID_NAN;
+        }
+        return (sign | INFINITY, ExceptionFlags::DIVIDE_BY_ZERO);
+    }
+    if is_zero(a) {
+        return exact(sign);
+    }
+
+    let (sig_a, exp_a) = unpack_normalized(a);
+    let (sig_b, exp_b) = unpack_normalized(b);
+    // Quotient has at least 20 significant bits, enough to hold the rounding bits below the
+    // sticky bit
+    let dividend = (sig_a as u64) << 20;
+    let quotient = dividend / sig_b as u64;
+    let sticky = (dividend % sig_b as u64 != 0) as u128;
+    let m = ((quotient as u128) << 1) | sticky;
+    round_fixed(sign, m, 20 - (exp_a - exp_b) + 1)
+}
+
+const fn sqrt_bits(a: u16) -> (u16, ExceptionFlags) {
+    if is_nan(a) {
+        return propagate_nan(a, a);
+    }
+    if is_zero(a) {
+        return exact(a);
+    }
+    if a & SIGN_MASK != 0 {
+        return INVALID_NAN;
+    }
+    if is_inf(a) {
+        return exact(a);
+    }
+
+    let (sig, exp) = unpack_normalized(a);
+    // Make the exponent even so it can be halved exactly
+    let (sig, exp) = if exp & 1 != 0 {
+        (sig << 1, exp - 1)
+    } else {
+        (sig, exp)
+    };
+    let radicand = (sig as u64) << 20;
+    let root = isqrt(radicand);
+    let sticky = (root * root != radicand) as u128;
+    let m = ((root as u128) << 1) | sticky;
+    round_fixed(0, m, 10 - exp / 2 + 1)
+}
+
+const fn mul_add_bits(a: u16, b: u16, c: u16) -> (u16, ExceptionFlags) {
+    if is_nan(a) || is_nan(b) || is_nan(c) {
+        let (nan, flags) = if is_nan(a) || is_nan(b) {
+            propagate_nan(a, b)
+        } else {
+            propagate_nan(c, c)
+        };
+        // A signaling NaN in any operand is invalid, even if it is not the one propagated
+        return if is_signaling(c) {
+            (nan, ExceptionFlags::INVALID)
+        } else {
+            (nan, flags)
+        };
+    }
+    let product_sign = (a ^ b) & SIGN_MASK;
+    if is_inf(a) || is_inf(b) {
+        if is_zero(a) || is_zero(b) {
+            return INVALID_NAN;
+        }
+        if is_inf(c) && (c & SIGN_MASK) != product_sign {
+            return INVALID_NAN;
+        }
+        return exact(product_sign | INFINITY);
+    }
+    if is_inf(c) {
+        return exact(c);
+    }
+
+    // The product is exact in units of 2⁻⁴⁸, so scale the addend to match
+    let product = to_fixed(a) as i128 * to_fixed(b) as i128;
+    let addend = (to_fixed(c) as i128) << 24;
+    let product = if product_sign != 0 { -product } else { product };
+    let addend = if c & SIGN_MASK != 0 { -addend } else { addend };
+    let sum = product + addend;
+    if sum == 0 {
+        return exact(product_sign & c & SIGN_MASK);
+    }
+    let sign = if sum < 0 { SIGN_MASK } else { 0 };
+    round_fixed(sign, sum.unsigned_abs(), 48)
+}
+
+/// Integer square root, rounded down.
+const fn isqrt(n: u64) -> u64 {
+    let mut rem = n;
+    let mut root = 0u64;
+    let mut bit = 1u64 << 62;
+    while bit > n {
+        bit >>= 2;
+    }
+    while bit != 0 {
+        if rem >= root + bit {
+            rem -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    root
+}
+
+#[allow(clippy::float_cmp)]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use quickcheck_macros::quickcheck;
+
+    fn same(a: f16, b: f16) -> bool {
+        (a.is_nan() && b.is_nan()) || a.to_bits() == b.to_bits()
+    }
+
+    /// Reference result computed in f32 and rounded once more to f16. As f32 has at least twice
+    /// the precision of f16 plus two bits, this double rounding is innocuous for these operations.
+    fn reference(f: impl Fn(f32, f32) -> f32, a: f16, b: f16) -> f16 {
+        f16::from_f32(f(a.to_f32(), b.to_f32()))
+    }
+
+    #[test]
+    fn test_sqrt_exhaustive() {
+        for bits in 0..=u16::MAX {
+            let a = f16::from_bits(bits);
+            let expected = f16::from_f32(a.to_f32().sqrt());
+            assert!(same(sqrt(a), expected), "sqrt({:?})", bits);
+        }
+    }
+
+    #[test]
+    fn test_ops_exhaustive_against_one_operand() {
+        let operands = [
+            f16::ONE,
+            f16::NEG_ONE,
+            f16::PI,
+            f16::MAX,
+            f16::MIN_POSITIVE,
+            f16::MIN_POSITIVE_SUBNORMAL,
+            f16::MAX_SUBNORMAL,
+            f16::from_f32(-3.0),
+        ];
+        for b in operands {
+            for bits in 0..=u16::MAX {
+                let a = f16::from_bits(bits);
+                assert!(same(add(a, b), reference(|x, y| x + y, a, b)));
+                assert!(same(sub(a, b), reference(|x, y| x - y, a, b)));
+                assert!(same(mul(a, b), reference(|x, y| x * y, a, b)));
+                assert!(same(div(a, b), reference(|x, y| x / y, a, b)));
+                assert!(same(div(b, a), reference(|x, y| x / y, b, a)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_special_values() {
+        assert_eq!(add(f16::ZERO, f16::NEG_ZERO).to_bits(), 0);
+        assert_eq!(add(f16::NEG_ZERO, f16::NEG_ZERO).to_bits(), 0x8000);
+        assert_eq!(sub(f16::ONE, f16::ONE).to_bits(), 0);
+        assert!(add(f16::INFINITY, f16::NEG_INFINITY).is_nan());
+        assert!(mul(f16::ZERO, f16::INFINITY).is_nan());
+        assert!(div(f16::ZERO, f16::ZERO).is_nan());
+        assert!(div(f16::INFINITY, f16::INFINITY).is_nan());
+        assert_eq!(div(f16::NEG_ONE, f16::ZERO), f16::NEG_INFINITY);
+        assert!(sqrt(f16::NEG_ONE).is_nan());
+        assert_eq!(sqrt(f16::NEG_ZERO).to_bits(), 0x8000);
+        assert_eq!(add(f16::MAX, f16::MAX), f16::INFINITY);
+        assert_eq!(mul(f16::MIN, f16::from_f32(2.0)), f16::NEG_INFINITY);
+
+        // NaN payloads propagate quieted
+        let snan = f16::from_bits(0x7C01);
+        assert_eq!(add(snan, f16::ONE).to_bits(), 0x7E01);
+        assert_eq!(mul(f16::ONE, -snan).to_bits(), 0xFE01);
+    }
+
+    #[test]
+    fn test_flags_exhaustive_against_one_operand() {
+        // Sums and products of two finite f16 values are exact in f64
+        let exact_flags = |exact: f64, result: f16| {
+            crate::exceptions::conversion_flags(
+                exact,
+                false,
+                result.to_f64(),
+                f16::MIN_POSITIVE.to_f64(),
+            )
+        };
+        for &a in &[
+            f16::ONE,
+            f16::from_f32(1.0 + 1.0 / 1024.0),
+            f16::from_f32(-3.0),
+            f16::MIN_POSITIVE,
+            f16::MIN_POSITIVE_SUBNORMAL,
+            f16::MAX,
+        ] {
+            for b in (0..=u16::MAX).map(f16::from_bits).filter(|b| b.is_finite()) {
+                let (sum, flags) = add_with_flags(a, b);
+                assert_eq!(flags, exact_flags(a.to_f64() + b.to_f64(), sum));
+                let (product, flags) = mul_with_flags(a, b);
+                assert_eq!(flags, exact_flags(a.to_f64() * b.to_f64(), product));
+            }
+        }
+    }
+
+    #[test]
+    fn test_flags_special_values() {
+        let snan = f16::from_bits(0x7C01);
+        assert!(add_with_flags(f16::ONE, f16::ONE).1.is_empty());
+        assert!(sqrt_with_flags(f16::from_f32(4.0)).1.is_empty());
+        assert_eq!(
+            sqrt_with_flags(f16::from_f32(2.0)).1,
+            ExceptionFlags::INEXACT
+        );
+        assert_eq!(
+            div_with_flags(f16::ONE, f16::from_f32(3.0)).1,
+            ExceptionFlags::INEXACT
+        );
+        assert_eq!(
+            div_with_flags(f16::MIN_POSITIVE, f16::from_f32(3.0)).1,
+            ExceptionFlags::UNDERFLOW | ExceptionFlags::INEXACT
+        );
+        assert_eq!(
+            add_with_flags(f16::MAX, f16::MAX).1,
+            ExceptionFlags::OVERFLOW | ExceptionFlags::INEXACT
+        );
+        assert_eq!(
+            div_with_flags(f16::NEG_ONE, f16::ZERO),
+            (f16::NEG_INFINITY, ExceptionFlags::DIVIDE_BY_ZERO)
+        );
+        assert!(div_with_flags(f16::INFINITY, f16::ZERO).1.is_empty());
+        assert_eq!(
+            div_with_flags(f16::ZERO, f16::ZERO).1,
+            ExceptionFlags::INVALID
+        );
+        assert_eq!(
+            sub_with_flags(f16::INFINITY, f16::INFINITY).1,
+            ExceptionFlags::INVALID
+        );
+        assert_eq!(sqrt_with_flags(f16::NEG_ONE).1, ExceptionFlags::INVALID);
+        assert_eq!(
+            mul_add_with_flags(f16::ZERO, f16::INFINITY, f16::ONE).1,
+            ExceptionFlags::INVALID
+        );
+
+        // Quiet NaNs propagate silently, signaling NaNs raise INVALID
+        assert!(add_with_flags(f16::NAN, f16::ONE).1.is_empty());
+        assert_eq!(add_with_flags(snan, f16::ONE).1, ExceptionFlags::INVALID);
+        assert_eq!(
+            mul_add_with_flags(f16::ONE, f16::ONE, snan).1,
+            ExceptionFlags::INVALID
+        );
+        assert_eq!(
+            mul_add_with_flags(f16::ONE, f16::ONE, f16::MIN_POSITIVE_SUBNORMAL).1,
+            ExceptionFlags::INEXACT
+        );
+    }
+
+    #[test]
+    fn test_mul_add_rounds_once() {
+        // a × b = 1 + 2⁻¹¹ exactly, which is the midpoint between 1 and the next f16. Adding the
+        // smallest subnormal puts the exact result just above the midpoint, but a fused f32
+        // operation rounds it back down onto the midpoint, which then rounds to even.
+        let a = f16::from_f32(1.5);
+        let b = f16::from_f32(683.0 / 1024.0);
+        let c = f16::MIN_POSITIVE_SUBNORMAL;
+        let expected = f16::from_f32(1.0 + 2f32.powi(-10));
+        assert_eq!(mul_add(a, b, c), expected);
+        assert_eq!(
+            f16::from_f32(a.to_f32().mul_add(b.to_f32(), c.to_f32())),
+            f16::ONE
+        );
+
+        let a = f16::from_f32(1.0 + 2f32.powi(-10));
+        let b = f16::from_f32(1.0 + 2f32.powi(-9));
+        assert_eq!(
+            mul_add(a, b, f16::NEG_ONE),
+            f16::from_f32(3.0 * 2f32.powi(-10) + 2f32.powi(-19))
+        );
+
+        assert_eq!(
+            mul_add(f16::NEG_ONE, f16::ZERO, f16::NEG_ZERO).to_bits(),
+            0x8000
+        );
+        assert!(mul_add(f16::ZERO, f16::INFINITY, f16::ONE).is_nan());
+        assert!(mul_add(f16::ONE, f16::INFINITY, f16::NEG_INFINITY).is_nan());
+        assert_eq!(mul_add(f16::ONE, f16::ONE, f16::INFINITY), f16::INFINITY);
+    }
+
+    /// Exact value of a finite f16 in units of 2⁻⁴⁸.
+    fn exact(x: f16) -> i128 {
+        let m = (to_fixed(x.to_bits()) as i128) << 24;
+        if x.is_sign_negative() {
+            -m
+        } else {
+            m
+        }
+    }
+
+    #[quickcheck]
+    fn qc_mul_add_is_nearest(a: u16, b: u16, c: u16) -> bool {
+        let (a, b, c) = (f16::from_bits(a), f16::from_bits(b), f16::from_bits(c));
+        let r = mul_add(a, b, c);
+        if !a.is_finite() || !b.is_finite() || !c.is_finite() || !r.is_finite() {
+            return true;
+        }
+        let target = (exact(a) >> 24) * (exact(b) >> 24) + exact(c);
+        let err = (exact(r) - target).abs();
+        // No neighbour of the result may be closer to the exact value
+        let up = f16::from_bits(r.to_bits().wrapping_add(1));
+        let down = f16::from_bits(r.to_bits().wrapping_sub(1));
+        [up, down]
+            .iter()
+            .filter(|n| n.is_finite() && n.is_sign_negative() == r.is_sign_negative())
+            .all(|n| (exact(*n) - target).abs() >= err)
+    }
+
+    #[quickcheck]
+    fn qc_ops_match_reference(a: u16, b: u16) -> bool {
+        let (a, b) = (f16::from_bits(a), f16::from_bits(b));
+        same(add(a, b), reference(|x, y| x + y, a, b))
+            && same(sub(a, b), reference(|x, y| x - y, a, b))
+            && same(mul(a, b), reference(|x, y| x * y, a, b))
+            && same(div(a, b), reference(|x, y| x / y, a, b))
+    }
+}
@@ -0,0 +1,228 @@
+//! Contains functions to measure the error of converting [`f32`] data to [`f16`] or [`bf16`].
+//!
+//! Before storing weights, activations or other data in half precision, it is worth checking how
+//! much is lost by the conversion. The [`f16_quantization_stats`] and [`bf16_quantization_stats`]
+//! functions convert a slice with the vectorized conversions of
+//! [`HalfFloatSliceExt`] and report the error in a
+//! [`QuantizationStats`], while [`quantization_stats`] compares a slice with a conversion that was
+//! already made, for instance with a different [`RoundingMode`][crate::RoundingMode] or stochastic
+//! rounding.
+
+use crate::{bf16, f16, private::SealedHalf, slice::HalfFloatSliceExt};
+
+/// Number of elements converted at a time, small enough to stay in the L1 cache.
+const CHUNK_LEN: usize = 256;
+
+// `f64::abs` is not available in `core` before Rust 1.85
+#[inline]
+fn abs(x: f64) -> f64 {
+    f64::from_bits(x.to_bits() & !(1 << 63))
+}
+
+/// Statistics of the error of converting [`f32`] values to a half precision format.
+///
+/// The error statistics only include the values that are finite both before and after the
+/// conversion, which are counted by [`count`][Self::count]. Finite values that became infinite
+/// are counted by [`overflow_count`][Self::overflow_count] instead, and NaN and infinite values
+/// are ignored. Nonzero values that became zero are included in the error statistics, with a
+/// relative error of one, and also counted by [`underflow_count`][Self::underflow_count].
+///
+/// The statistics are computed in [`f64`], so the errors are exact apart from the summation in
+/// [`mean_squared_error`][Self::mean_squared_error].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct QuantizationStats {
+    /// Number of values included in the error statistics.
+    pub count: usize,
+    /// Largest absolute difference between an original and a converted value.
+    pub max_abs_error: f64,
+    /// Largest absolute difference between an original and a converted value, divided by the
+    /// magnitude of the original value. Zero values are skipped.
+    pub max_rel_error: f64,
+    /// Mean of the squared differences between the original and converted values, or zero if
+    /// [`count`][Self::count] is zero.
+    pub mean_squared_error: f64,
+    /// Number of finite values that were converted to an infinity.
+    pub overflow_count: usize,
+    /// Number of nonzero values that were converted to a zero.
+    pub underflow_count: usize,
+}
+
+impl QuantizationStats {
+    fn accumulate(&mut self, sum_squares: &mut f64, original: f32, converted: f32) {
+        if !original.is_finite() {
+            return;
+        }
+        if converted.is_infinite() {
+            self.overflow_count += 1;
+            return;
+        }
+        if converted == 0.0 && original != 0.0 {
+            self.underflow_count += 1;
+        }
+
+        let error = abs(f64::from(converted) - f64::from(original));
+        self.count += 1;
+        self.max_abs_error = self.max_abs_error.max(error);
+        if original != 0.0 {
+            let relative = error / abs(f64::from(original));
+            self.max_rel_error = self.max_rel_error.max(relative);
+        }
+        *sum_squares += error * error;
+    }
+
+    fn finish(mut self, sum_squares: f64) -> QuantizationStats {
+        if self.count != 0 {
+            self.mean_squared_error = sum_squares / self.count as f64;
+        }
+        self
+    }
+}
+
+/// Measures the error between `original` values and their `converted` [`f16`] or [`bf16`]
+/// values.
+///
+/// # Panics
+///
+/// This function will panic if the two slices have different lengths.
+///
+/// # Examples
+///
+/// ```rust
+/// # use half::prelude::*;
+/// use half::stats;
+///
+/// let original = [0.1, 1.0, 1.0e-9, 1.0e6];
+/// let mut converted = [f16::ZERO; 4];
+/// converted.convert_from_f32_slice_truncate(&original);
+///
+/// let stats = stats::quantization_stats(&original, &converted);
+/// assert_eq!(stats.count, 4);
+/// assert_eq!(stats.underflow_count, 1);
+/// assert_eq!(stats.overflow_count, 0);
+/// assert_eq!(stats.max_rel_error, 1.0);
+/// ```
+#[must_use]
+pub fn quantization_stats<H>(original: &[f32], converted: &[H]) -> QuantizationStats
+where
+    H: SealedHalf + Copy,
+{
+    assert_eq!(
+        original.len(),
+        converted.len(),
+        "original and converted slices have different lengths"
+    );
+    let mut stats = QuantizationStats::default();
+    let mut sum_squares = 0.0;
+    for (&x, &y) in original.iter().zip(converted) {
+        stats.accumulate(&mut sum_squares, x, y.to_f32());
+    }
+    stats.finish(sum_squares)
+}
+
+/// Converts `values` to [`f16`], rounding to nearest like [`f16::from_f32`], and measures the
+/// error of the conversion.
+///
+/// [`f16::from_f32`]: crate::f16::from_f32
+///
+/// # Examples
+///
+/// ```rust
+/// use half::stats::f16_quantization_stats;
+///
+/// let stats = f16_quantization_stats(&[0.5, 1.0e5, 1.0e-8, 2049.0]);
+/// assert_eq!(stats.count, 3);
+/// assert_eq!(stats.overflow_count, 1);
+/// assert_eq!(stats.underflow_count, 1);
+/// assert_eq!(stats.max_abs_error, 1.0);
+/// ```
+#[must_use]
+pub fn f16_quantization_stats(values: &[f32]) -> QuantizationStats {
+    let mut buffer = [f16::ZERO; CHUNK_LEN];
+    let mut stats = QuantizationStats::default();
+    let mut sum_squares = 0.0;
+    for chunk in values.chunks(CHUNK_LEN) {
+        let buffer = &mut buffer[..chunk.len()];
+        buffer.convert_from_f32_slice(chunk);
+        for (&x, &y) in chunk.iter().zip(buffer.iter()) {
+            stats.accumulate(&mut sum_squares, x, y.to_f32());
+        }
+    }
+    stats.finish(sum_squares)
+}
+
+/// Converts `values` to [`bf16`], rounding to nearest like [`bf16::from_f32`], and measures the
+/// error of the conversion.
+///
+/// [`bf16::from_f32`]: crate::bf16::from_f32
+///
+/// # Examples
+///
+/// ```rust
+/// use half::stats::bf16_quantization_stats;
+///
+/// let stats = bf16_quantization_stats(&[0.5, 257.0, f32::MAX]);
+/// assert_eq!(stats.count, 2);
+/// assert_eq!(stats.overflow_count, 1);
+/// assert_eq!(stats.max_abs_error, 1.0);
+/// ```
+#[must_use]
+pub fn bf16_quantization_stats(values: &[f32]) -> QuantizationStats {
+    let mut buffer = [bf16::ZERO; CHUNK_LEN];
+    let mut stats = QuantizationStats::default();
+    let mut sum_squares = 0.0;
+    for chunk in values.chunks(CHUNK_LEN) {
+        let buffer = &mut buffer[..chunk.len()];
+        buffer.convert_from_f32_slice(chunk);
+        for (&x, &y) in chunk.iter().zip(buffer.iter()) {
+            stats.accumulate(&mut sum_squares, x, y.to_f32());
+        }
+    }
+    stats.finish(sum_squares)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_matches_scalar_conversion() {
+        let values: [f32; 600] = {
+            let mut values = [0.0; 600];
+            for (i, x) in values.iter_mut().enumerate() {
+                *x = (i as f32 - 300.0) * 1.37e-3 * (i % 7) as f32;
+            }
+            values
+        };
+        let converted: [f16; 600] = {
+            let mut converted = [f16::ZERO; 600];
+            for (y, &x) in converted.iter_mut().zip(values.iter()) {
+                *y = f16::from_f32(x);
+            }
+            converted
+        };
+        let stats = f16_quantization_stats(&values);
+        assert_eq!(stats, quantization_stats(&values, &converted));
+        assert_eq!(stats.count, 600);
+        assert!(stats.max_rel_error > 0.0 && stats.max_rel_error <= f64::from(f16::EPSILON) / 2.0);
+        assert!(stats.mean_squared_error > 0.0);
+        assert!(stats.mean_squared_error <= stats.max_abs_error * stats.max_abs_error);
+    }
+
+    #[test]
+    fn test_special_values() {
+        let values = [f32::NAN, f32::INFINITY, -1.0e10, 0.0, -0.0, 1.0e-30, 3.0];
+        let stats = bf16_quantization_stats(&values);
+        assert_eq!(stats.count, 5);
+        assert_eq!(stats.overflow_count, 0);
+        assert_eq!(stats.underflow_count, 0);
+
+        let stats = f16_quantization_stats(&values);
+        assert_eq!(stats.count, 4);
+        assert_eq!(stats.overflow_count, 1);
+        assert_eq!(stats.underflow_count, 1);
+        assert_eq!(stats.max_rel_error, 1.0);
+        assert_eq!(stats.max_abs_error, 1.0e-30f32 as f64);
+
+        assert_eq!(f16_quantization_stats(&[]), QuantizationStats::default());
+    }
+}
@@ -7,13 +7,34 @@
 //! reinterpret casting of larger buffers of floating point values, and are automatically included
 //! in the [`prelude`][crate::prelude] module.
 //!
+//! The [`HalfFloatOwnedSliceExt`] and [`HalfBitsOwnedSliceExt`] sealed extension traits provide the
+//! same zero-copy reinterpret casting for owned slices behind a [`Box`] or [`Rc`], or an `Arc` with
+//! the `std` feature, while the [`HalfCowSliceExt`] sealed extension trait borrows or converts slices of any supported
+//! element type as [`f16`] or [`bf16`] slices, copying only when needed.
+//!
+//! [`Vec<f32>`] also implements [`FromIterator`] and [`Extend`] for iterators of [`f16`] and
+//! [`bf16`] values, so `.collect()` widens the values in batches with the vectorized conversion of
+//! [`HalfFloatSliceExt::convert_to_f32_slice`]. The other direction cannot be implemented for
+//! [`Vec<f16>`] by this crate, so collect the batched
+//! [`map_to_f16`][crate::iter::F32IteratorExt::map_to_f16] adaptor instead.
+//!
 //! This module is only available with the `std` or `alloc` feature.
 
-use super::{bf16, f16, slice::HalfFloatSliceExt};
+use super::{
+    bf16, f16,
+    private::SealedHalf,
+    slice::{HalfBitsSliceExt, HalfFloatSliceExt},
+};
+// `alloc::sync` is missing on targets without atomic pointers, which all lack `std` as well
+#[cfg(feature = "std")]
+use alloc::sync::Arc;
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{borrow::Cow, boxed::Box, rc::Rc, vec::Vec};
 use core::mem;
 
+/// Number of elements the collected iterators are converted at a time.
+const CHUNK_LEN: usize = 256;
+
 /// Extensions to [`Vec<f16>`] and [`Vec<bf16>`] to support reinterpret operations.
 ///
 /// This trait is sealed and cannot be implemented outside of this crate.
@@ -70,6 +91,26 @@ pub trait HalfFloatVecExt: private::SealedHalfFloatVec {
     /// ```
     #[must_use]
     fn from_f64_slice(slice: &[f64]) -> Self;
+
+    /// Converts all of the elements of a `[f32]` slice into a new [`f16`] or [`bf16`] vector,
+    /// splitting the work across the [`rayon`](https://crates.io/crates/rayon) thread pool.
+    ///
+    /// See [`HalfFloatSliceExt::par_convert_from_f32_slice`].
+    ///
+    /// This method is only available with the `rayon` feature.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let float_values = vec![3.; 100_000];
+    /// let vec: Vec<bf16> = Vec::par_from_f32_slice(&float_values);
+    ///
+    /// assert_eq!(vec, vec![bf16::from_f32(3.); 100_000]);
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[must_use]
+    fn par_from_f32_slice(slice: &[f32]) -> Self;
 }
 
 /// Extensions to [`Vec<u16>`] to support reinterpret operations.
@@ -98,10 +139,121 @@ pub trait HalfBitsVecExt: private::SealedHalfBitsVec {
         H: crate::private::SealedHalf;
 }
 
+/// Extensions to [`Box<[f16]>`][Box], [`Rc<[f16]>`][Rc] and, with the `std` feature, `Arc<[f16]>`,
+/// and the same pointers to `[bf16]`, to support reinterpret operations.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait HalfFloatOwnedSliceExt: private::SealedHalfFloatOwnedSlice {
+    /// The same kind of pointer to a `[u16]` slice.
+    type Bits;
+
+    /// Reinterprets an owned slice of [`f16`] or [`bf16`] numbers as an owned slice of [`u16`]
+    /// bits, behind the same kind of pointer.
+    ///
+    /// This is a zero-copy operation. The reinterpreted slice has the same memory location as
+    /// `self`, and for [`Rc`] and `Arc` it shares the reference count with any other clones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// use std::rc::Rc;
+    ///
+    /// let floats: Rc<[f16]> = Rc::from([f16::ONE, f16::NEG_ONE]);
+    /// let bits: Rc<[u16]> = floats.reinterpret_into();
+    ///
+    /// assert_eq!(*bits, [0x3C00, 0xBC00]);
+    /// ```
+    #[must_use]
+    fn reinterpret_into(self) -> Self::Bits;
+}
+
+/// Extensions to [`Box<[u16]>`][Box], [`Rc<[u16]>`][Rc] and, with the `std` feature, `Arc<[u16]>`
+/// to support reinterpret operations.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait HalfBitsOwnedSliceExt: private::SealedHalfBitsOwnedSlice {
+    /// Reinterprets an owned slice of [`u16`] bits as an owned slice of [`f16`] or [`bf16`]
+    /// numbers, behind the same kind of pointer.
+    ///
+    /// `H` is the type to cast to, and must be either the [`f16`] or [`bf16`] type.
+    ///
+    /// This is a zero-copy operation. The reinterpreted slice has the same memory location as
+    /// `self`, and for [`Rc`] and `Arc` it shares the reference count with any other clones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let bits: Box<[u16]> = vec![0x3F80, 0xC000].into_boxed_slice();
+    /// let floats: Box<[bf16]> = bits.reinterpret_into::<bf16>();
+    ///
+    /// assert_eq!(*floats, [bf16::ONE, bf16::from_f32(-2.)]);
+    /// ```
+    #[must_use]
+    fn reinterpret_into<H>(self) -> <Self as private::Rebind<H>>::Output
+    where
+        H: SealedHalf,
+        Self: private::Rebind<H>;
+}
+
+/// Extensions to `[u16]`, `[f16]`, `[bf16]`, `[f32]` and `[f64]` slices to view them as [`f16`]
+/// or [`bf16`] slices, converting only when needed.
+///
+/// This is useful to load buffers whose element type is only known at runtime with a single call,
+/// which borrows the buffer if it already holds the requested format and allocates a converted
+/// copy otherwise.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait HalfCowSliceExt: private::SealedHalfCowSlice {
+    /// Returns `self` as a slice of [`f16`] numbers, borrowed if possible.
+    ///
+    /// `[u16]` bits are reinterpreted as [`f16`] and `[f16]` slices are borrowed as is, without
+    /// copying. Other slices are converted into a new vector, rounding like
+    /// [`HalfFloatSliceExt::convert_from_f32_slice`] and the related conversions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// use std::borrow::Cow;
+    ///
+    /// let bits = [0x3C00u16, 0x4000];
+    /// assert!(matches!(bits.as_f16_cow(), Cow::Borrowed(_)));
+    ///
+    /// let floats = [1f32, 2.];
+    /// let halves = floats.as_f16_cow();
+    /// assert!(matches!(halves, Cow::Owned(_)));
+    /// assert_eq!(*halves, [f16::ONE, f16::from_f32(2.)]);
+    /// ```
+    fn as_f16_cow(&self) -> Cow<'_, [f16]>;
+
+    /// Returns `self` as a slice of [`bf16`] numbers, borrowed if possible.
+    ///
+    /// `[u16]` bits are reinterpreted as [`bf16`] and `[bf16]` slices are borrowed as is, without
+    /// copying. Other slices are converted into a new vector, rounding like
+    /// [`HalfFloatSliceExt::convert_from_f32_slice`] and the related conversions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// use std::borrow::Cow;
+    ///
+    /// let halves = [f16::ONE, f16::from_f32(-2.)];
+    /// let bfloats = halves.as_bf16_cow();
+    /// assert!(matches!(bfloats, Cow::Owned(_)));
+    /// assert_eq!(*bfloats, [bf16::ONE, bf16::from_f32(-2.)]);
+    /// ```
+    fn as_bf16_cow(&self) -> Cow<'_, [bf16]>;
+}
+
 mod private {
-    use crate::{bf16, f16};
+    use crate::{bf16, f16, private::SealedHalf};
+    #[cfg(feature = "std")]
+    use alloc::sync::Arc;
     #[cfg(feature = "alloc")]
-    use alloc::vec::Vec;
+    use alloc::{boxed::Box, rc::Rc, vec::Vec};
 
     pub trait SealedHalfFloatVec {}
     impl SealedHalfFloatVec for Vec<f16> {}
@@ -109,6 +261,32 @@ mod private {
 
     pub trait SealedHalfBitsVec {}
     impl SealedHalfBitsVec for Vec<u16> {}
+
+    pub trait SealedHalfFloatOwnedSlice {}
+    impl<H: SealedHalf> SealedHalfFloatOwnedSlice for Box<[H]> {}
+    impl<H: SealedHalf> SealedHalfFloatOwnedSlice for Rc<[H]> {}
+    #[cfg(feature = "std")]
+    impl<H: SealedHalf> SealedHalfFloatOwnedSlice for Arc<[H]> {}
+
+    pub trait SealedHalfCowSlice {}
+    impl SealedHalfCowSlice for [u16] {}
+    impl SealedHalfCowSlice for [f16] {}
+    impl SealedHalfCowSlice for [bf16] {}
+    impl SealedHalfCowSlice for [f32] {}
+    impl SealedHalfCowSlice for [f64] {}
+
+    pub trait SealedHalfBitsOwnedSlice {}
+    impl SealedHalfBitsOwnedSlice for Box<[u16]> {}
+    impl SealedHalfBitsOwnedSlice for Rc<[u16]> {}
+    #[cfg(feature = "std")]
+    impl SealedHalfBitsOwnedSlice for Arc<[u16]> {}
+
+    /// The same kind of pointer as `Self`, to a `[H]` slice instead.
+    pub trait Rebind<H> {
+        type Output;
+
+        fn rebind(self) -> Self::Output;
+    }
 }
 
 impl HalfFloatVecExt for Vec<f16> {
@@ -153,6 +331,18 @@ impl HalfFloatVecExt for Vec<f16> {
         vec.convert_from_f64_slice(slice);
         vec
     }
+
+    #[cfg(feature = "rayon")]
+    #[allow(clippy::uninit_vec)]
+    fn par_from_f32_slice(slice: &[f32]) -> Self {
+        let mut vec = Vec::with_capacity(slice.len());
+        // SAFETY: convert will initialize every value in the vector without reading them,
+        // so this is safe to do instead of double initialize from resize, and we're setting it to
+        // same value as capacity.
+        unsafe { vec.set_len(slice.len()) };
+        vec.par_convert_from_f32_slice(slice);
+        vec
+    }
 }
 
 impl HalfFloatVecExt for Vec<bf16> {
@@ -197,6 +387,18 @@ impl HalfFloatVecExt for Vec<bf16> {
         vec.convert_from_f64_slice(slice);
         vec
     }
+
+    #[cfg(feature = "rayon")]
+    #[allow(clippy::uninit_vec)]
+    fn par_from_f32_slice(slice: &[f32]) -> Self {
+        let mut vec = Vec::with_capacity(slice.len());
+        // SAFETY: convert will initialize every value in the vector without reading them,
+        // so this is safe to do instead of double initialize from resize, and we're setting it to
+        // same value as capacity.
+        unsafe { vec.set_len(slice.len()) };
+        vec.par_convert_from_f32_slice(slice);
+        vec
+    }
 }
 
 impl HalfBitsVecExt for Vec<u16> {
@@ -225,16 +427,194 @@ impl HalfBitsVecExt for Vec<u16> {
     }
 }
 
+// Box, Rc and Arc all round-trip slices through raw pointers, which keep the slice length
+macro_rules! impl_owned_slice_ext {
+    ($($ptr:ident),*) => {$(
+        impl<H: SealedHalf> HalfFloatOwnedSliceExt for $ptr<[H]> {
+            type Bits = $ptr<[u16]>;
+
+            #[inline]
+            fn reinterpret_into(self) -> $ptr<[u16]> {
+                // SAFETY: The pointer comes from the same kind of pointer, to a slice of the same
+                // length with elements of identical size and alignment
+                unsafe { $ptr::from_raw($ptr::into_raw(self) as _) }
+            }
+        }
+
+        impl<H: SealedHalf> private::Rebind<H> for $ptr<[u16]> {
+            type Output = $ptr<[H]>;
+
+            #[inline]
+            fn rebind(self) -> $ptr<[H]> {
+                // SAFETY: The pointer comes from the same kind of pointer, to a slice of the same
+                // length with elements of identical size and alignment
+                unsafe { $ptr::from_raw($ptr::into_raw(self) as _) }
+            }
+        }
+
+        impl HalfBitsOwnedSliceExt for $ptr<[u16]> {
+            #[inline]
+            fn reinterpret_into<H>(self) -> <Self as private::Rebind<H>>::Output
+            where
+                H: SealedHalf,
+                Self: private::Rebind<H>,
+            {
+                private::Rebind::rebind(self)
+            }
+        }
+    )*};
+}
+
+impl_owned_slice_ext!(Box, Rc);
+#[cfg(feature = "std")]
+impl_owned_slice_ext!(Arc);
+
+impl HalfCowSliceExt for [u16] {
+    #[inline]
+    fn as_f16_cow(&self) -> Cow<'_, [f16]> {
+        Cow::Borrowed(self.reinterpret_cast())
+    }
+
+    #[inline]
+    fn as_bf16_cow(&self) -> Cow<'_, [bf16]> {
+        Cow::Borrowed(self.reinterpret_cast())
+    }
+}
+
+impl HalfCowSliceExt for [f16] {
+    #[inline]
+    fn as_f16_cow(&self) -> Cow<'_, [f16]> {
+        Cow::Borrowed(self)
+    }
+
+    fn as_bf16_cow(&self) -> Cow<'_, [bf16]> {
+        let mut vec = alloc::vec![bf16::ZERO; self.len()];
+        self.convert_to_bf16_slice(&mut vec);
+        Cow::Owned(vec)
+    }
+}
+
+impl HalfCowSliceExt for [bf16] {
+    fn as_f16_cow(&self) -> Cow<'_, [f16]> {
+        let mut vec = alloc::vec![f16::ZERO; self.len()];
+        self.convert_to_f16_slice(&mut vec);
+        Cow::Owned(vec)
+    }
+
+    #[inline]
+    fn as_bf16_cow(&self) -> Cow<'_, [bf16]> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl HalfCowSliceExt for [f32] {
+    fn as_f16_cow(&self) -> Cow<'_, [f16]> {
+        Cow::Owned(Vec::from_f32_slice(self))
+    }
+
+    fn as_bf16_cow(&self) -> Cow<'_, [bf16]> {
+        Cow::Owned(Vec::from_f32_slice(self))
+    }
+}
+
+impl HalfCowSliceExt for [f64] {
+    fn as_f16_cow(&self) -> Cow<'_, [f16]> {
+        Cow::Owned(Vec::from_f64_slice(self))
+    }
+
+    fn as_bf16_cow(&self) -> Cow<'_, [bf16]> {
+        Cow::Owned(Vec::from_f64_slice(self))
+    }
+}
+
+impl FromIterator<f16> for Vec<f32> {
+    /// Collects [`f16`] values into a vector of [`f32`] values, converting them in batches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use half::prelude::*;
+    /// let halves = [f16::ONE, f16::MAX, f16::NEG_INFINITY];
+    /// let floats: Vec<f32> = halves.iter().copied().collect();
+    ///
+    /// assert_eq!(floats, [1., 65504., f32::NEG_INFINITY]);
+    /// ```
+    fn from_iter<I: IntoIterator<Item = f16>>(iter: I) -> Self {
+        let mut vec = Vec::new();
+        extend_widened(&mut vec, iter.into_iter());
+        vec
+    }
+}
+
+impl FromIterator<bf16> for Vec<f32> {
+    /// Collects [`bf16`] values into a vector of [`f32`] values, converting them in batches.
+    fn from_iter<I: IntoIterator<Item = bf16>>(iter: I) -> Self {
+        let mut vec = Vec::new();
+        extend_widened(&mut vec, iter.into_iter());
+        vec
+    }
+}
+
+impl Extend<f16> for Vec<f32> {
+    /// Appends [`f16`] values to a vector of [`f32`] values, converting them in batches.
+    fn extend<I: IntoIterator<Item = f16>>(&mut self, iter: I) {
+        extend_widened(self, iter.into_iter());
+    }
+}
+
+impl Extend<bf16> for Vec<f32> {
+    /// Appends [`bf16`] values to a vector of [`f32`] values, converting them in batches.
+    fn extend<I: IntoIterator<Item = bf16>>(&mut self, iter: I) {
+        extend_widened(self, iter.into_iter());
+    }
+}
+
+/// Appends the values of `iter` to `vec`, pulling them into a buffer on the stack and converting
+/// each full buffer at once.
+fn extend_widened<H, I>(vec: &mut Vec<f32>, mut iter: I)
+where
+    H: SealedHalf + Copy,
+    [H]: HalfFloatSliceExt,
+    I: Iterator<Item = H>,
+{
+    vec.reserve(iter.size_hint().0);
+    let mut buffer = [H::from_bits(0); CHUNK_LEN];
+    loop {
+        let mut len = 0;
+        // The buffer is zipped first so no value is taken from `iter` once it is full
+        for (slot, value) in buffer.iter_mut().zip(&mut iter) {
+            *slot = value;
+            len += 1;
+        }
+        if len == 0 {
+            break;
+        }
+
+        let start = vec.len();
+        vec.resize(start + len, 0.0);
+        buffer[..len].convert_to_f32_slice(&mut vec[start..]);
+        if len < CHUNK_LEN {
+            break;
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::{HalfBitsVecExt, HalfFloatVecExt};
+    use super::{
+        HalfBitsOwnedSliceExt, HalfBitsVecExt, HalfCowSliceExt, HalfFloatOwnedSliceExt,
+        HalfFloatVecExt,
+    };
     use crate::{bf16, f16};
+    #[cfg(feature = "std")]
+    use alloc::sync::Arc;
     #[cfg(all(feature = "alloc", not(feature = "std")))]
     use alloc::vec;
+    use alloc::{borrow::Cow, boxed::Box, rc::Rc, vec::Vec};
 
     #[test]
     fn test_vec_conversions_f16() {
-        let numbers = vec![f16::E, f16::PI, f16::EPSILON, f16::FRAC_1_SQRT_2];
+        let numbers = [f16::E, f16::PI, f16::EPSILON, f16::FRAC_1_SQRT_2];
         let bits = vec![
             f16::E.to_bits(),
             f16::PI.to_bits(),
@@ -254,7 +634,7 @@ mod test {
 
     #[test]
     fn test_vec_conversions_bf16() {
-        let numbers = vec![bf16::E, bf16::PI, bf16::EPSILON, bf16::FRAC_1_SQRT_2];
+        let numbers = [bf16::E, bf16::PI, bf16::EPSILON, bf16::FRAC_1_SQRT_2];
         let bits = vec![
             bf16::E.to_bits(),
             bf16::PI.to_bits(),
@@ -271,4 +651,106 @@ mod test {
         let to_bits = from_bits.reinterpret_into();
         assert_eq!(&to_bits[..], &bits_cloned[..]);
     }
+
+    #[test]
+    fn test_owned_slice_conversions() {
+        let bits = [f16::E.to_bits(), f16::PI.to_bits(), f16::NAN.to_bits()];
+
+        let boxed: Box<[u16]> = Box::from(bits);
+        let pointer = boxed.as_ptr() as usize;
+        let floats = boxed.reinterpret_into::<f16>();
+        assert_eq!(floats.as_ptr() as usize, pointer);
+        assert_eq!(floats[..2], [f16::E, f16::PI]);
+        assert_eq!(*floats.reinterpret_into(), bits);
+
+        // Other clones keep sharing the same allocation
+        let shared: Rc<[u16]> = Rc::from(bits);
+        let clone = Rc::clone(&shared);
+        let floats = shared.reinterpret_into::<bf16>();
+        assert_eq!(Rc::strong_count(&floats), 2);
+        assert_eq!(floats.as_ptr() as usize, clone.as_ptr() as usize);
+        drop(clone);
+        let shared = floats.reinterpret_into();
+        assert_eq!(Rc::strong_count(&shared), 1);
+        assert_eq!(*shared, bits);
+
+        #[cfg(feature = "std")]
+        {
+            let shared: Arc<[u16]> = Arc::from(bits);
+            let floats = shared.reinterpret_into::<f16>();
+            assert_eq!(floats[1], f16::PI);
+            let empty: Arc<[bf16]> = Arc::from([]);
+            assert!(empty.reinterpret_into().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_cow_slices() {
+        let bits = [0x3C00u16, 0xC000, 0x7E00];
+        let halves = bits.as_f16_cow();
+        assert!(
+            matches!(halves, Cow::Borrowed(h) if core::ptr::eq(h.as_ptr().cast(), bits.as_ptr()))
+        );
+        assert!(matches!(bits.as_bf16_cow(), Cow::Borrowed(_)));
+        assert!(matches!(halves.as_f16_cow(), Cow::Borrowed(_)));
+
+        let bfloats = halves.as_bf16_cow();
+        assert!(matches!(bfloats, Cow::Owned(_)));
+        assert_eq!(bfloats[..2], [bf16::ONE, bf16::from_f32(-2.)]);
+        assert!(bfloats[2].is_nan());
+        assert!(matches!(bfloats.as_bf16_cow(), Cow::Borrowed(_)));
+        assert_eq!(bfloats.as_f16_cow()[..2], halves[..2]);
+
+        let floats = [0.1f32, 65520., -1e-8];
+        let expected = floats.map(f16::from_f32);
+        assert_eq!(*floats.as_f16_cow(), expected);
+        let doubles = [0.1f64, 1e300, -1e-300];
+        let expected = doubles.map(bf16::from_f64);
+        assert_eq!(*doubles.as_bf16_cow(), expected);
+    }
+
+    #[cfg(all(feature = "rayon", feature = "std"))]
+    #[test]
+    fn test_vec_par_from_f32_slice() {
+        let floats: Vec<f32> = (0..50_000).map(|i| i as f32 * 0.37).collect();
+
+        let par: Vec<f16> = Vec::par_from_f32_slice(&floats);
+        assert_eq!(
+            par.reinterpret_into(),
+            Vec::<f16>::from_f32_slice(&floats).reinterpret_into()
+        );
+
+        let par: Vec<bf16> = Vec::par_from_f32_slice(&floats);
+        assert_eq!(
+            par.reinterpret_into(),
+            Vec::<bf16>::from_f32_slice(&floats).reinterpret_into()
+        );
+    }
+
+    #[test]
+    fn test_collect_f32() {
+        // Longer than a batch, and not a multiple of its length
+        let halves: Vec<f16> = (0..1000u16).map(|i| f16::from_bits(i * 61)).collect();
+        let floats: Vec<f32> = halves.iter().copied().collect();
+        assert_eq!(floats.len(), halves.len());
+        assert!(floats
+            .iter()
+            .zip(&halves)
+            .all(|(x, h)| x.to_bits() == h.to_f32().to_bits()));
+
+        let mut floats = vec![0.5f32];
+        floats.extend((0..300).map(|i| bf16::from_f32(i as f32)));
+        assert_eq!(floats.len(), 301);
+        assert_eq!(floats[0], 0.5);
+        assert_eq!(floats[300], bf16::from_f32(299.).to_f32());
+        floats.extend(core::iter::empty::<f16>());
+        assert_eq!(floats.len(), 301);
+
+        // Iterators filling exactly one batch are not advanced further
+        let mut iter = (0..256).map(|i| f16::from_f32(i as f32)).chain([f16::ONE]);
+        let mut floats: Vec<f32> = (&mut iter).take(256).collect();
+        floats.extend(iter);
+        assert_eq!(floats.len(), 257);
+        assert_eq!(floats[255..], [255., 1.]);
+    }
 }